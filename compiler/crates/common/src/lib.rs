@@ -23,6 +23,7 @@ mod rollout;
 mod span;
 pub mod sync;
 mod text_source;
+mod trace_logger;
 
 pub use console_logger::ConsoleLogEvent;
 pub use console_logger::ConsoleLogger;
@@ -66,3 +67,6 @@ pub use rollout::Rollout;
 pub use rollout::RolloutRange;
 pub use span::Span;
 pub use text_source::TextSource;
+pub use trace_logger::TraceLogEvent;
+pub use trace_logger::TraceLogger;
+pub use trace_logger::TraceSpan;
@@ -40,6 +40,16 @@ pub trait PerfLogEvent: Send + Sync {
         res
     }
 
+    /// Measure a time of calling a callback for one item of a step, like a
+    /// file. The item is only described for loggers recording items.
+    fn time_item<T, F, I>(&self, _name: &'static str, _item: I, f: F) -> T
+    where
+        F: FnOnce() -> T,
+        I: FnOnce() -> String,
+    {
+        f()
+    }
+
     /// Log the event
     fn complete(self);
 }
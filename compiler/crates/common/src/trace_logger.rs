@@ -0,0 +1,299 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::io;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use super::console_logger::print_time;
+use super::perf_logger::PerfLogEvent;
+use super::perf_logger::PerfLogger;
+
+/// A span of time recorded by a `TraceLogger`.
+#[derive(Clone, Debug)]
+pub struct TraceSpan {
+    /// Name of the timer, or the name of the event for the span of a whole
+    /// event.
+    pub name: &'static str,
+    /// Name of the event the span was recorded by.
+    pub event: &'static str,
+    /// The item of a step timed with `time_item`, like a file path.
+    pub item: Option<String>,
+    /// Strings, numbers and booleans logged on the event.
+    pub args: Vec<(&'static str, Value)>,
+    pub thread_id: u64,
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+impl TraceSpan {
+    /// The value of a string logged on the event of the span, like `project`.
+    pub fn arg(&self, name: &str) -> Option<&str> {
+        self.args
+            .iter()
+            .find(|(arg_name, _)| *arg_name == name)
+            .and_then(|(_, value)| value.as_str())
+    }
+}
+
+/// Records every event and timer as spans, which can be written in the
+/// Chrome Trace Event format and opened in `chrome://tracing` or Perfetto.
+/// Timers are also logged like the `ConsoleLogger` does.
+#[derive(Clone)]
+pub struct TraceLogger {
+    trace: Arc<Trace>,
+}
+
+struct Trace {
+    start: Instant,
+    spans: Mutex<Vec<TraceSpan>>,
+    threads: Mutex<Vec<(u64, String)>>,
+}
+
+impl TraceLogger {
+    pub fn new() -> Self {
+        Self {
+            trace: Arc::new(Trace {
+                start: Instant::now(),
+                spans: Default::default(),
+                threads: Default::default(),
+            }),
+        }
+    }
+
+    /// The spans recorded so far, in the order they ended.
+    pub fn spans(&self) -> Vec<TraceSpan> {
+        self.trace.spans.lock().unwrap().clone()
+    }
+
+    /// Write the recorded spans as a Chrome trace.
+    pub fn write_chrome_trace(&self, writer: impl Write) -> io::Result<()> {
+        let threads = self.trace.threads.lock().unwrap();
+        let spans = self.trace.spans.lock().unwrap();
+        let mut trace_events = threads
+            .iter()
+            .map(|(thread_id, thread_name)| {
+                json!({
+                    "name": "thread_name",
+                    "ph": "M",
+                    "pid": 1,
+                    "tid": thread_id,
+                    "args": { "name": thread_name },
+                })
+            })
+            .collect::<Vec<_>>();
+        trace_events.extend(spans.iter().map(|span| {
+            let mut args = span
+                .args
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect::<Map<_, _>>();
+            let name = match &span.item {
+                Some(item) => {
+                    args.insert("step".to_string(), span.name.into());
+                    item.as_str()
+                }
+                None => span.name,
+            };
+            json!({
+                "name": name,
+                "cat": span.event,
+                "ph": "X",
+                "pid": 1,
+                "tid": span.thread_id,
+                "ts": span.start.as_micros() as u64,
+                "dur": span.duration.as_micros() as u64,
+                "args": args,
+            })
+        }));
+        serde_json::to_writer(
+            writer,
+            &json!({
+                "traceEvents": trace_events,
+                "displayTimeUnit": "ms",
+            }),
+        )?;
+        Ok(())
+    }
+
+    fn elapsed(&self, instant: Instant) -> Duration {
+        instant.saturating_duration_since(self.trace.start)
+    }
+
+    fn thread_id(&self) -> u64 {
+        static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+        thread_local! {
+            static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+        }
+        let thread_id = THREAD_ID.with(|thread_id| *thread_id);
+        let mut threads = self.trace.threads.lock().unwrap();
+        if !threads.iter().any(|(id, _)| *id == thread_id) {
+            let current = thread::current();
+            let thread_name = current
+                .name()
+                .map_or_else(|| format!("thread {thread_id}"), str::to_string);
+            threads.push((thread_id, thread_name));
+        }
+        thread_id
+    }
+}
+
+impl Default for TraceLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PerfLogger for TraceLogger {
+    type PerfLogEvent = TraceLogEvent;
+    fn create_event(&self, name: &'static str) -> Self::PerfLogEvent {
+        TraceLogEvent {
+            logger: self.clone(),
+            name,
+            thread_id: self.thread_id(),
+            start: Instant::now(),
+            args: Default::default(),
+            spans: Default::default(),
+        }
+    }
+}
+
+/// An event of a `TraceLogger`. The spans of its timers are added to the
+/// trace when the event is dropped, with the values logged on the event.
+pub struct TraceLogEvent {
+    logger: TraceLogger,
+    name: &'static str,
+    thread_id: u64,
+    start: Instant,
+    args: Mutex<Vec<(&'static str, Value)>>,
+    spans: Mutex<Vec<TraceSpan>>,
+}
+
+pub struct TraceTimer {
+    name: &'static str,
+    thread_id: u64,
+    start: Instant,
+}
+
+impl TraceLogEvent {
+    fn record(&self, name: &'static str, item: Option<String>, thread_id: u64, start: Instant) {
+        self.spans.lock().unwrap().push(TraceSpan {
+            name,
+            event: self.name,
+            item,
+            args: vec![],
+            thread_id,
+            start: self.logger.elapsed(start),
+            duration: start.elapsed(),
+        });
+    }
+}
+
+impl PerfLogEvent for TraceLogEvent {
+    type Timer = TraceTimer;
+    fn number(&self, name: &'static str, number: usize) {
+        self.args.lock().unwrap().push((name, number.into()));
+    }
+    fn bool(&self, name: &'static str, value: bool) {
+        self.args.lock().unwrap().push((name, value.into()));
+    }
+    fn string(&self, name: &'static str, value: String) {
+        self.args.lock().unwrap().push((name, value.into()));
+    }
+    fn start(&self, name: &'static str) -> Self::Timer {
+        TraceTimer {
+            name,
+            thread_id: self.logger.thread_id(),
+            start: Instant::now(),
+        }
+    }
+    fn stop(&self, timer: Self::Timer) {
+        print_time(timer.name, timer.start);
+        self.record(timer.name, None, timer.thread_id, timer.start);
+    }
+    fn time_item<T, F, I>(&self, name: &'static str, item: I, f: F) -> T
+    where
+        F: FnOnce() -> T,
+        I: FnOnce() -> String,
+    {
+        let thread_id = self.logger.thread_id();
+        let start = Instant::now();
+        let res = f();
+        self.record(name, Some(item()), thread_id, start);
+        res
+    }
+    fn complete(self) {}
+}
+
+impl Drop for TraceLogEvent {
+    fn drop(&mut self) {
+        let args = std::mem::take(self.args.get_mut().unwrap());
+        let mut spans = std::mem::take(self.spans.get_mut().unwrap());
+        for span in &mut spans {
+            span.args = args.clone();
+        }
+        spans.push(TraceSpan {
+            name: self.name,
+            event: self.name,
+            item: None,
+            args,
+            thread_id: self.thread_id,
+            start: self.logger.elapsed(self.start),
+            duration: self.start.elapsed(),
+        });
+        self.logger.trace.spans.lock().unwrap().extend(spans);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_events_and_timers_as_spans() {
+        let logger = TraceLogger::new();
+        let event = logger.create_event("build_project");
+        event.string("project", "web".to_string());
+        event.time("build_schema_time", || {});
+        event.time_item("extract", || "src/App.js".to_string(), || {});
+        event.complete();
+
+        let spans = logger.spans();
+        assert_eq!(
+            spans
+                .iter()
+                .map(|span| (span.name, span.item.as_deref(), span.arg("project")))
+                .collect::<Vec<_>>(),
+            vec![
+                ("build_schema_time", None, Some("web")),
+                ("extract", Some("src/App.js"), Some("web")),
+                ("build_project", None, Some("web")),
+            ]
+        );
+        assert!(spans.iter().all(|span| span.event == "build_project"));
+
+        let mut trace = vec![];
+        logger.write_chrome_trace(&mut trace).unwrap();
+        let trace: Value = serde_json::from_slice(&trace).unwrap();
+        let trace_events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(trace_events[0]["ph"], "M");
+        assert_eq!(trace_events[2]["name"], "src/App.js");
+        assert_eq!(trace_events[2]["args"]["step"], "extract");
+        assert_eq!(trace_events[3]["ph"], "X");
+        assert_eq!(trace_events[3]["cat"], "build_project");
+    }
+}
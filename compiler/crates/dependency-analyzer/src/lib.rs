@@ -15,6 +15,9 @@ mod ir;
 mod minimized_executable;
 mod query_stats;
 mod schema_change_analyzer;
+mod schema_check;
+mod schema_usage;
+mod unused_client_schema;
 
 pub use ast::ReachableAst;
 pub use ast::get_definition_references;
@@ -25,5 +28,21 @@ pub use ir::ExecutableDefinitionNameVec;
 pub use ir::get_ir_definition_references;
 pub use ir::get_reachable_ir;
 pub use minimized_executable::MinProgram;
+pub use query_stats::OperationMetrics;
+pub use query_stats::OperationRegression;
+pub use query_stats::OperationStats;
 pub use query_stats::QueryStatsReport;
+pub use query_stats::compute_program_query_stats;
 pub use query_stats::compute_query_stats;
+pub use schema_check::ChangeSeverity;
+pub use schema_check::ClassifiedSchemaChange;
+pub use schema_check::FailingDefinition;
+pub use schema_check::SchemaCheckReport;
+pub use schema_usage::CoordinateUsage;
+pub use schema_usage::CoordinateUsageRow;
+pub use schema_usage::SchemaUsageOptions;
+pub use schema_usage::SchemaUsageReport;
+pub use unused_client_schema::UnusedClientSchemaElement;
+pub use unused_client_schema::UnusedClientSchemaKind;
+pub use unused_client_schema::UnusedClientSchemaReport;
+pub use unused_client_schema::UnusedClientSchemaRow;
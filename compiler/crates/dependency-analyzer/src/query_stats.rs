@@ -8,20 +8,65 @@
 use std::fmt;
 use std::fmt::Write;
 
+use common::NamedItem;
 use graphql_ir::ExecutableDefinitionName;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::Selection;
 use graphql_syntax::ExecutableDefinition;
 use graphql_syntax::OperationKind;
+use graphql_text_printer::print_full_operation;
+use relay_config::DeferStreamInterface;
+use relay_transforms::SplitOperationMetadata;
+use relay_transforms::get_resolver_fragment_dependency_name;
+use rustc_hash::FxHashSet;
+use schema::Schema;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::ExecutableDefinitionNameMap;
 use crate::ExecutableDefinitionNameSet;
+use crate::get_ir_definition_references;
 
 /// Per-operation fragment usage statistics.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OperationStats {
     pub name: String,
     pub kind: OperationKind,
     pub direct_fragment_count: usize,
     pub transitive_fragment_count: usize,
     pub max_fragment_depth: usize,
+    /// Size and feature usage of the compiled operation. Only available for
+    /// reports computed from compiled programs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<OperationMetrics>,
+}
+
+/// Size and feature usage statistics of a compiled operation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationMetrics {
+    /// Number of fields in the normalization AST.
+    pub normalized_field_count: usize,
+    /// Size in bytes of the printed operation text sent to the server.
+    pub text_size: usize,
+    pub defer_count: usize,
+    pub stream_count: usize,
+    /// Client extension fields selected by the operation and its fragments,
+    /// not counting resolvers.
+    pub client_field_count: usize,
+    pub resolver_count: usize,
+}
+
+/// An operation whose size grew by more than the allowed amount relative to a
+/// baseline report.
+pub struct OperationRegression {
+    pub name: String,
+    pub metric: &'static str,
+    pub baseline: usize,
+    pub current: usize,
 }
 
 /// Distribution statistics for a set of values.
@@ -35,6 +80,8 @@ struct Distribution {
 }
 
 /// Aggregated report of per-operation fragment usage across a project.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct QueryStatsReport {
     pub operations: Vec<OperationStats>,
     pub query_count: usize,
@@ -54,66 +101,194 @@ pub fn compute_query_stats(
     // DFS required to track longest paths through diamond-shaped graphs.
     let depth_cache = precompute_max_depths(dep_map);
 
-    let mut operations = Vec::new();
-    let mut query_count = 0usize;
-    let mut mutation_count = 0usize;
-    let mut subscription_count = 0usize;
-
-    for def in definitions {
-        if let ExecutableDefinition::Operation(op) = def {
+    let operations = definitions
+        .iter()
+        .filter_map(|def| match def {
+            ExecutableDefinition::Operation(op) => Some(op),
+            ExecutableDefinition::Fragment(_) => None,
+        })
+        .map(|op| {
             let name_str = op
                 .name
                 .as_ref()
                 .map(|n| n.value.to_string())
                 .unwrap_or_else(|| "<anonymous>".to_string());
+            operation_stats(name_str, op.operation_kind(), dep_map, &depth_cache)
+        })
+        .collect();
 
-            let kind = op.operation_kind();
+    QueryStatsReport::from_operations(operations)
+}
 
-            match kind {
-                OperationKind::Query => query_count += 1,
-                OperationKind::Mutation => mutation_count += 1,
-                OperationKind::Subscription => subscription_count += 1,
+/// Compute per-operation statistics from the programs of a compiled project.
+/// In addition to fragment usage, each operation gets `OperationMetrics`:
+/// field counts come from the `normalization` program, the text size from the
+/// `operation_text` program, and feature usage from the `source` program.
+/// Operations generated by the compiler (e.g. refetch queries) are included,
+/// split operations are not.
+pub fn compute_program_query_stats(
+    source: &Program,
+    normalization: &Program,
+    operation_text: &Program,
+    defer_stream_interface: &DeferStreamInterface,
+) -> QueryStatsReport {
+    let definitions: Vec<_> =
+        operation_text
+            .operations()
+            .map(|op| graphql_ir::ExecutableDefinition::Operation(op.as_ref().clone()))
+            .chain(operation_text.fragments().map(|fragment| {
+                graphql_ir::ExecutableDefinition::Fragment(fragment.as_ref().clone())
+            }))
+            .collect();
+    let dep_map = get_ir_definition_references(&operation_text.schema, &definitions);
+    let depth_cache = precompute_max_depths(&dep_map);
+
+    let operations = normalization
+        .operations()
+        .filter(|op| SplitOperationMetadata::find(&op.directives).is_none())
+        .map(|op| {
+            let name = op.name.item;
+            let mut stats = operation_stats(name.to_string(), op.kind, &dep_map, &depth_cache);
+
+            let mut feature_usage = FeatureUsage {
+                source,
+                defer_stream_interface,
+                visited_fragments: Default::default(),
+                metrics: OperationMetrics {
+                    normalized_field_count: count_fields(&op.selections),
+                    text_size: operation_text.operation(name).map_or(0, |text_op| {
+                        print_full_operation(operation_text, text_op, Default::default()).len()
+                    }),
+                    ..Default::default()
+                },
+            };
+            if let Some(source_op) = source
+                .operation(name)
+                .or_else(|| operation_text.operation(name))
+            {
+                feature_usage.visit_selections(&source_op.selections);
             }
+            stats.metrics = Some(feature_usage.metrics);
+            stats
+        })
+        .collect();
 
-            let op_def_name = ExecutableDefinitionName::OperationDefinitionName(
-                graphql_ir::OperationDefinitionName(intern::string_key::Intern::intern(
-                    name_str.as_str(),
-                )),
-            );
-
-            let direct_refs = dep_map.get(&op_def_name);
-            let direct_fragment_count = direct_refs.map_or(0, |refs| refs.len());
-
-            let transitive_fragment_count = count_transitive_fragments(dep_map, &op_def_name);
-            let max_fragment_depth = direct_refs.map_or(0, |refs| {
-                refs.iter()
-                    .map(|f| 1 + depth_cache.get(f).copied().unwrap_or(0))
-                    .max()
-                    .unwrap_or(0)
-            });
-
-            operations.push(OperationStats {
-                name: name_str,
-                kind,
-                direct_fragment_count,
-                transitive_fragment_count,
-                max_fragment_depth,
-            });
-        }
-    }
+    QueryStatsReport::from_operations(operations)
+}
 
-    // Sort by transitive fragment count descending, then by name ascending for ties
-    operations.sort_by(|a, b| {
-        b.transitive_fragment_count
-            .cmp(&a.transitive_fragment_count)
-            .then_with(|| a.name.cmp(&b.name))
+fn operation_stats(
+    name: String,
+    kind: OperationKind,
+    dep_map: &ExecutableDefinitionNameMap<ExecutableDefinitionNameSet>,
+    depth_cache: &ExecutableDefinitionNameMap<usize>,
+) -> OperationStats {
+    let op_def_name = ExecutableDefinitionName::OperationDefinitionName(OperationDefinitionName(
+        intern::string_key::Intern::intern(name.as_str()),
+    ));
+
+    let direct_refs = dep_map.get(&op_def_name);
+    let direct_fragment_count = direct_refs.map_or(0, |refs| refs.len());
+
+    let transitive_fragment_count = count_transitive_fragments(dep_map, &op_def_name);
+    let max_fragment_depth = direct_refs.map_or(0, |refs| {
+        refs.iter()
+            .map(|f| 1 + depth_cache.get(f).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0)
     });
 
-    QueryStatsReport {
-        operations,
-        query_count,
-        mutation_count,
-        subscription_count,
+    OperationStats {
+        name,
+        kind,
+        direct_fragment_count,
+        transitive_fragment_count,
+        max_fragment_depth,
+        metrics: None,
+    }
+}
+
+/// Count the fields of a normalization AST. Fragment spreads left in a
+/// normalization AST (e.g. for `@module`) are fetched separately, and are not
+/// followed.
+fn count_fields(selections: &[Selection]) -> usize {
+    selections
+        .iter()
+        .map(|selection| match selection {
+            Selection::ScalarField(_) => 1,
+            Selection::LinkedField(field) => 1 + count_fields(&field.selections),
+            Selection::InlineFragment(fragment) => count_fields(&fragment.selections),
+            Selection::Condition(condition) => count_fields(&condition.selections),
+            Selection::FragmentSpread(_) => 0,
+        })
+        .sum()
+}
+
+/// Collects `@defer`/`@stream`, client field and resolver usage of an
+/// operation, following its fragment spreads. Each fragment is counted once.
+struct FeatureUsage<'a> {
+    source: &'a Program,
+    defer_stream_interface: &'a DeferStreamInterface,
+    visited_fragments: FxHashSet<FragmentDefinitionName>,
+    metrics: OperationMetrics,
+}
+
+impl FeatureUsage<'_> {
+    fn visit_selections(&mut self, selections: &[Selection]) {
+        for selection in selections {
+            let directives = selection.directives();
+            if directives
+                .named(self.defer_stream_interface.defer_name)
+                .is_some()
+            {
+                self.metrics.defer_count += 1;
+            }
+            if directives
+                .named(self.defer_stream_interface.stream_name)
+                .is_some()
+            {
+                self.metrics.stream_count += 1;
+            }
+            match selection {
+                Selection::ScalarField(field) => self.visit_field(field.definition.item),
+                Selection::LinkedField(field) => {
+                    self.visit_field(field.definition.item);
+                    self.visit_selections(&field.selections);
+                }
+                Selection::InlineFragment(fragment) => self.visit_selections(&fragment.selections),
+                Selection::Condition(condition) => self.visit_selections(&condition.selections),
+                Selection::FragmentSpread(spread) => {
+                    if self.visited_fragments.insert(spread.fragment.item)
+                        && let Some(fragment) = self.source.fragment(spread.fragment.item)
+                    {
+                        self.visit_selections(&fragment.selections);
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_field(&mut self, field_id: schema::FieldID) {
+        let field = self.source.schema.field(field_id);
+        if !field.is_extension {
+            return;
+        }
+        if field
+            .directives
+            .named(*docblock_shared::RELAY_RESOLVER_DIRECTIVE_NAME)
+            .is_some()
+        {
+            self.metrics.resolver_count += 1;
+            // Resolvers read their own fragment, which is not part of the
+            // operation's selections.
+            if let Some(fragment_name) = get_resolver_fragment_dependency_name(field)
+                && self.visited_fragments.insert(fragment_name)
+                && let Some(fragment) = self.source.fragment(fragment_name)
+            {
+                self.visit_selections(&fragment.selections);
+            }
+        } else {
+            self.metrics.client_field_count += 1;
+        }
     }
 }
 
@@ -210,6 +385,23 @@ impl Distribution {
 }
 
 impl QueryStatsReport {
+    fn from_operations(mut operations: Vec<OperationStats>) -> Self {
+        // Sort by transitive fragment count descending, then by name ascending for ties
+        operations.sort_by(|a, b| {
+            b.transitive_fragment_count
+                .cmp(&a.transitive_fragment_count)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        let count = |kind| operations.iter().filter(|op| op.kind == kind).count();
+
+        QueryStatsReport {
+            query_count: count(OperationKind::Query),
+            mutation_count: count(OperationKind::Mutation),
+            subscription_count: count(OperationKind::Subscription),
+            operations,
+        }
+    }
+
     /// Format the report as a deterministic text string.
     /// `limit` controls how many operations appear in the ranked list;
     /// summary, distribution, and histogram always reflect the full dataset.
@@ -234,6 +426,19 @@ impl QueryStatsReport {
                 op.max_fragment_depth,
             )
             .unwrap();
+            if let Some(metrics) = &op.metrics {
+                writeln!(
+                    out,
+                    "    [fields: {}, text: {} bytes, defer: {}, stream: {}, client fields: {}, resolvers: {}]",
+                    metrics.normalized_field_count,
+                    metrics.text_size,
+                    metrics.defer_count,
+                    metrics.stream_count,
+                    metrics.client_field_count,
+                    metrics.resolver_count,
+                )
+                .unwrap();
+            }
         }
         if total > limit {
             writeln!(out, "... and {} more operations", total - limit).unwrap();
@@ -321,6 +526,112 @@ impl QueryStatsReport {
             .unwrap();
         }
     }
+
+    /// The header of the CSV rows written by `append_metrics_csv_rows`.
+    pub fn metrics_csv_header() -> &'static str {
+        "project,operation,kind,direct_fragments,transitive_fragments,max_fragment_depth,\
+normalized_fields,text_size,defer,stream,client_fields,resolvers"
+    }
+
+    /// Append CSV rows (without header) for all operations to the given string,
+    /// with the columns of `append_csv_rows` followed by the operation metrics.
+    /// Metric columns are left empty for operations without metrics.
+    pub fn append_metrics_csv_rows(&self, out: &mut String, project_name: &str) {
+        for op in &self.operations {
+            write!(
+                out,
+                "{},{},{},{},{},{},",
+                project_name,
+                op.name,
+                kind_str(op.kind),
+                op.direct_fragment_count,
+                op.transitive_fragment_count,
+                op.max_fragment_depth,
+            )
+            .unwrap();
+            match &op.metrics {
+                Some(metrics) => writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    metrics.normalized_field_count,
+                    metrics.text_size,
+                    metrics.defer_count,
+                    metrics.stream_count,
+                    metrics.client_field_count,
+                    metrics.resolver_count,
+                ),
+                None => writeln!(out, ",,,,,"),
+            }
+            .unwrap();
+        }
+    }
+
+    /// Returns the operations whose normalized field count or text size grew
+    /// by more than `max_growth_percent` relative to `baseline`. Operations
+    /// missing from either report, or without metrics, are not compared.
+    pub fn regressions_since(
+        &self,
+        baseline: &QueryStatsReport,
+        max_growth_percent: f64,
+    ) -> Vec<OperationRegression> {
+        let mut regressions = vec![];
+        for op in &self.operations {
+            let Some(metrics) = &op.metrics else {
+                continue;
+            };
+            let Some(baseline_metrics) = baseline
+                .operations
+                .iter()
+                .find(|baseline_op| baseline_op.name == op.name)
+                .and_then(|baseline_op| baseline_op.metrics.as_ref())
+            else {
+                continue;
+            };
+            for (metric, baseline_value, current_value) in [
+                (
+                    "normalized fields",
+                    baseline_metrics.normalized_field_count,
+                    metrics.normalized_field_count,
+                ),
+                ("text size", baseline_metrics.text_size, metrics.text_size),
+            ] {
+                if growth_percent(baseline_value, current_value) > max_growth_percent {
+                    regressions.push(OperationRegression {
+                        name: op.name.clone(),
+                        metric,
+                        baseline: baseline_value,
+                        current: current_value,
+                    });
+                }
+            }
+        }
+        regressions.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.metric.cmp(b.metric)));
+        regressions
+    }
+}
+
+fn growth_percent(baseline: usize, current: usize) -> f64 {
+    if current <= baseline {
+        0.0
+    } else if baseline == 0 {
+        f64::INFINITY
+    } else {
+        (current - baseline) as f64 / baseline as f64 * 100.0
+    }
+}
+
+impl fmt::Display for OperationRegression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} grew from {} to {} ({:+.1}%)",
+            self.name,
+            self.metric,
+            self.baseline,
+            self.current,
+            growth_percent(self.baseline, self.current),
+        )
+    }
 }
 
 fn kind_str(kind: OperationKind) -> &'static str {
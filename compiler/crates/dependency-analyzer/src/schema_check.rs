@@ -0,0 +1,1023 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Write;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::VariableDefinition;
+use graphql_ir::Visitor;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use rustc_hash::FxHashMap;
+use rustc_hash::FxHashSet;
+use schema::SDLSchema;
+use schema::Schema;
+use schema_coordinates::SchemaCoordinate;
+use schema_diff::definitions::ArgumentChange;
+use schema_diff::definitions::DefinitionChange;
+use schema_diff::definitions::DirectiveLocation;
+use schema_diff::definitions::SchemaChange;
+use schema_diff::definitions::Type;
+use schema_diff::definitions::TypeChange;
+use schema_diff::detect_changes_from_schemas;
+use serde::Serialize;
+
+use crate::SchemaUsageReport;
+use crate::schema_usage::definition_label;
+
+/// How a schema change affects the documents of a project.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeSeverity {
+    /// The documents and their generated types are unaffected.
+    Safe,
+    /// The documents keep working, but the data they receive or their
+    /// generated types change.
+    Dangerous,
+    /// The documents stop validating or are rejected by the server.
+    Breaking,
+}
+
+impl fmt::Display for ChangeSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeSeverity::Safe => write!(f, "safe"),
+            ChangeSeverity::Dangerous => write!(f, "dangerous"),
+            ChangeSeverity::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+/// A schema change with its severity for the documents of a project.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassifiedSchemaChange {
+    pub severity: ChangeSeverity,
+    /// The schema coordinate of the changed type, field, argument or value.
+    pub coordinate: String,
+    pub description: String,
+    /// Operations and fragments affected by the change, as
+    /// `Name (path/to/file)`.
+    pub affected: Vec<String>,
+}
+
+/// An operation or fragment that doesn't validate against the candidate
+/// schema.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailingDefinition {
+    pub definition: String,
+    pub messages: Vec<String>,
+}
+
+/// The changes between the schema of a project and a candidate schema,
+/// classified by how they affect the documents of the project.
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaCheckReport {
+    pub changes: Vec<ClassifiedSchemaChange>,
+    pub failing_definitions: Vec<FailingDefinition>,
+}
+
+impl SchemaCheckReport {
+    /// Classify the changes from the schema of `program` to `candidate`.
+    ///
+    /// `definitions` are the documents `program` was built from and
+    /// `candidate_errors` the errors of building them against `candidate`,
+    /// which are reported per failing definition. `no_future_proof_enums`
+    /// mirrors the typegen option of the project: without the
+    /// `%future added value` fallback, added enum values are dangerous.
+    pub fn from_program(
+        program: &Program,
+        candidate: &SDLSchema,
+        definitions: &[graphql_syntax::ExecutableDefinition],
+        candidate_errors: &[Diagnostic],
+        no_future_proof_enums: bool,
+    ) -> Self {
+        let mut classifier = ChangeClassifier {
+            candidate,
+            coordinate_usage: SchemaUsageReport::from_program(program),
+            type_usage: TypeUsage::from_program(program),
+            no_future_proof_enums,
+            changes: vec![],
+        };
+        if let SchemaChange::DefinitionChanges(changes) =
+            detect_changes_from_schemas(candidate, &program.schema)
+        {
+            for change in changes {
+                classifier.classify(change);
+            }
+        }
+        let mut changes = classifier.changes;
+        changes.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.coordinate.cmp(&b.coordinate))
+                .then_with(|| a.description.cmp(&b.description))
+        });
+
+        let mut failing: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for error in candidate_errors {
+            let location = error.location();
+            let definition = definitions
+                .iter()
+                .find(|definition| {
+                    let definition_location = definition.location();
+                    definition_location.source_location() == location.source_location()
+                        && definition_location.contains(location.span())
+                })
+                .map(|definition| match definition.name() {
+                    Some(name) => definition_label(name, definition.location()),
+                    None => definition_label("(anonymous)".intern(), definition.location()),
+                })
+                .unwrap_or_else(|| location.source_location().path().to_string());
+            failing
+                .entry(definition)
+                .or_default()
+                .insert(error.message().to_string());
+        }
+
+        SchemaCheckReport {
+            changes,
+            failing_definitions: failing
+                .into_iter()
+                .map(|(definition, messages)| FailingDefinition {
+                    definition,
+                    messages: messages.into_iter().collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether the candidate schema breaks any document: either a change is
+    /// breaking or a document doesn't validate against it.
+    pub fn has_breaking_changes(&self) -> bool {
+        !self.failing_definitions.is_empty()
+            || self
+                .changes
+                .iter()
+                .any(|change| change.severity == ChangeSeverity::Breaking)
+    }
+
+    fn count(&self, severity: ChangeSeverity) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.severity == severity)
+            .count()
+    }
+
+    /// Format the report as a deterministic text string.
+    pub fn format_report(&self) -> String {
+        let mut out = String::new();
+        for (severity, title) in [
+            (ChangeSeverity::Breaking, "Breaking changes"),
+            (ChangeSeverity::Dangerous, "Dangerous changes"),
+            (ChangeSeverity::Safe, "Safe changes"),
+        ] {
+            writeln!(out, "== {} ==", title).unwrap();
+            for change in self
+                .changes
+                .iter()
+                .filter(|change| change.severity == severity)
+            {
+                writeln!(out, "{}: {}", change.coordinate, change.description).unwrap();
+                for affected in &change.affected {
+                    writeln!(out, "  {}", affected).unwrap();
+                }
+            }
+            writeln!(out).unwrap();
+        }
+
+        writeln!(out, "== Documents failing validation ==").unwrap();
+        for failing in &self.failing_definitions {
+            writeln!(out, "{}", failing.definition).unwrap();
+            for message in &failing.messages {
+                writeln!(out, "  {}", message.replace('\n', "\n  ")).unwrap();
+            }
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "== Summary ==").unwrap();
+        writeln!(
+            out,
+            "Breaking: {}, dangerous: {}, safe: {}, failing documents: {}",
+            self.count(ChangeSeverity::Breaking),
+            self.count(ChangeSeverity::Dangerous),
+            self.count(ChangeSeverity::Safe),
+            self.failing_definitions.len()
+        )
+        .unwrap();
+
+        out
+    }
+}
+
+impl fmt::Display for SchemaCheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format_report())
+    }
+}
+
+struct ChangeClassifier<'a> {
+    candidate: &'a SDLSchema,
+    coordinate_usage: SchemaUsageReport,
+    type_usage: TypeUsage,
+    no_future_proof_enums: bool,
+    changes: Vec<ClassifiedSchemaChange>,
+}
+
+impl ChangeClassifier<'_> {
+    fn classify(&mut self, change: DefinitionChange) {
+        match change {
+            DefinitionChange::EnumAdded(name)
+            | DefinitionChange::UnionAdded(name)
+            | DefinitionChange::ScalarAdded(name)
+            | DefinitionChange::InputObjectAdded(name)
+            | DefinitionChange::InterfaceAdded(name)
+            | DefinitionChange::ObjectAdded(name) => {
+                self.push(
+                    ChangeSeverity::Safe,
+                    type_coordinate(name),
+                    "Type was added".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+            DefinitionChange::EnumRemoved(name)
+            | DefinitionChange::UnionRemoved(name)
+            | DefinitionChange::ScalarRemoved(name)
+            | DefinitionChange::InputObjectRemoved(name)
+            | DefinitionChange::InterfaceRemoved(name)
+            | DefinitionChange::ObjectRemoved { name, .. } => {
+                let affected = self.type_usage.any(name);
+                self.push(
+                    breaking_if_used(&affected),
+                    type_coordinate(name),
+                    "Type was removed".to_string(),
+                    affected,
+                );
+            }
+            DefinitionChange::EnumChanged {
+                name,
+                added,
+                removed,
+            } => self.classify_enum(name, added, removed),
+            DefinitionChange::UnionChanged {
+                name,
+                added,
+                removed,
+            } => {
+                for member in added {
+                    self.push(
+                        ChangeSeverity::Safe,
+                        type_coordinate(name),
+                        format!("Member `{}` was added", member),
+                        BTreeSet::new(),
+                    );
+                }
+                for member in removed {
+                    let affected = self.type_usage.output(name);
+                    self.push(
+                        dangerous_if_used(&affected),
+                        type_coordinate(name),
+                        format!(
+                            "Member `{}` was removed; documents selecting the union no longer receive it",
+                            member
+                        ),
+                        affected,
+                    );
+                }
+            }
+            DefinitionChange::InputObjectChanged {
+                name,
+                added,
+                removed,
+            } => self.classify_input_fields(name, added, removed),
+            DefinitionChange::InterfaceChanged {
+                name,
+                added,
+                removed,
+                changed,
+            } => self.classify_fields(name, added, removed, changed),
+            DefinitionChange::ObjectChanged {
+                name,
+                added,
+                removed,
+                changed,
+                interfaces_added,
+                interfaces_removed,
+            } => {
+                self.classify_fields(name, added, removed, changed);
+                for interface in interfaces_added {
+                    self.push(
+                        ChangeSeverity::Safe,
+                        type_coordinate(name),
+                        format!("Now implements `{}`", interface),
+                        BTreeSet::new(),
+                    );
+                }
+                for interface in interfaces_removed {
+                    let affected: BTreeSet<String> = self
+                        .type_usage
+                        .output(name)
+                        .intersection(&self.type_usage.output(interface))
+                        .cloned()
+                        .collect();
+                    self.push(
+                        dangerous_if_used(&affected),
+                        type_coordinate(name),
+                        format!("No longer implements `{}`", interface),
+                        affected,
+                    );
+                }
+            }
+            DefinitionChange::ScalarChanged { name, .. } => {
+                self.push(
+                    ChangeSeverity::Safe,
+                    type_coordinate(name),
+                    "`@specifiedBy` URL changed".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+            DefinitionChange::DirectiveAdded(name) => {
+                self.push(
+                    ChangeSeverity::Safe,
+                    directive_coordinate(name),
+                    "Directive was added".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+            // Directive usages aren't tracked per document: documents that
+            // use a removed or changed directive show up as failing validation.
+            DefinitionChange::DirectiveRemoved(name) => {
+                self.push(
+                    ChangeSeverity::Dangerous,
+                    directive_coordinate(name),
+                    "Directive was removed".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+            DefinitionChange::DirectiveChanged {
+                name,
+                added,
+                removed,
+                locations_added,
+                locations_removed,
+            } => self.classify_directive(name, added, removed, locations_added, locations_removed),
+            DefinitionChange::DefaultValueChanged {
+                coordinate,
+                previous,
+                current,
+            } => {
+                // Documents that select the field but don't pass the argument
+                // silently receive a different result.
+                let affected = match coordinate {
+                    SchemaCoordinate::Argument {
+                        parent_name,
+                        member_name,
+                        ..
+                    } => self.coordinate_users(SchemaCoordinate::Member {
+                        parent_name,
+                        member_name,
+                    }),
+                    SchemaCoordinate::Member { parent_name, .. } => {
+                        self.type_usage.input(parent_name)
+                    }
+                    _ => BTreeSet::new(),
+                };
+                self.push(
+                    dangerous_if_used(&affected),
+                    coordinate.to_string(),
+                    format!(
+                        "Default value changed from `{}` to `{}`",
+                        previous.as_deref().unwrap_or("none"),
+                        current.as_deref().unwrap_or("none")
+                    ),
+                    affected,
+                );
+            }
+            DefinitionChange::DeprecationAdded { coordinate, reason } => {
+                self.push(
+                    ChangeSeverity::Safe,
+                    coordinate.to_string(),
+                    match reason {
+                        Some(reason) => format!("Was deprecated: {}", reason),
+                        None => "Was deprecated".to_string(),
+                    },
+                    BTreeSet::new(),
+                );
+            }
+            DefinitionChange::DeprecationRemoved { coordinate } => {
+                self.push(
+                    ChangeSeverity::Safe,
+                    coordinate.to_string(),
+                    "Is no longer deprecated".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+        }
+    }
+
+    fn classify_directive(
+        &mut self,
+        name: StringKey,
+        added: Vec<TypeChange>,
+        removed: Vec<TypeChange>,
+        locations_added: Vec<DirectiveLocation>,
+        locations_removed: Vec<DirectiveLocation>,
+    ) {
+        let coordinate = directive_coordinate(name);
+        for location in locations_added {
+            self.push(
+                ChangeSeverity::Safe,
+                coordinate.clone(),
+                format!("Location `{}` was added", location),
+                BTreeSet::new(),
+            );
+        }
+        for location in locations_removed {
+            self.push(
+                ChangeSeverity::Dangerous,
+                coordinate.clone(),
+                format!("Location `{}` was removed", location),
+                BTreeSet::new(),
+            );
+        }
+        let (added, removed, changed) = pair_type_changes(added, removed);
+        for argument in added {
+            let severity = if is_non_null(&argument.type_) {
+                ChangeSeverity::Dangerous
+            } else {
+                ChangeSeverity::Safe
+            };
+            self.push(
+                severity,
+                directive_argument_coordinate(name, argument.name),
+                format!("Argument was added with type `{}`", argument.type_),
+                BTreeSet::new(),
+            );
+        }
+        for argument in removed {
+            self.push(
+                ChangeSeverity::Dangerous,
+                directive_argument_coordinate(name, argument.name),
+                "Argument was removed".to_string(),
+                BTreeSet::new(),
+            );
+        }
+        for (argument_name, current_type, candidate_type) in changed {
+            let severity = if accepts(&candidate_type, &current_type) {
+                ChangeSeverity::Safe
+            } else {
+                ChangeSeverity::Dangerous
+            };
+            self.push(
+                severity,
+                directive_argument_coordinate(name, argument_name),
+                format!(
+                    "Argument type changed from `{}` to `{}`",
+                    current_type, candidate_type
+                ),
+                BTreeSet::new(),
+            );
+        }
+    }
+
+    fn classify_enum(&mut self, name: StringKey, added: Vec<StringKey>, removed: Vec<StringKey>) {
+        for value in added {
+            let coordinate = member_coordinate(name, value);
+            let affected = self.type_usage.output(name);
+            if self.no_future_proof_enums && !affected.is_empty() {
+                self.push(
+                    ChangeSeverity::Dangerous,
+                    coordinate,
+                    "Value was added; generated types have no `%future added value` fallback, so exhaustive switches miss it".to_string(),
+                    affected,
+                );
+            } else {
+                self.push(
+                    ChangeSeverity::Safe,
+                    coordinate,
+                    "Value was added".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+        }
+        for value in removed {
+            let coordinate = member_coordinate(name, value);
+            let literal_users = self.literal_users(SchemaCoordinate::Member {
+                parent_name: name,
+                member_name: value,
+            });
+            if !literal_users.is_empty() {
+                self.push(
+                    ChangeSeverity::Breaking,
+                    coordinate,
+                    "Value was removed but is passed as a literal".to_string(),
+                    literal_users,
+                );
+            } else {
+                let affected = self.type_usage.output(name);
+                self.push(
+                    dangerous_if_used(&affected),
+                    coordinate,
+                    "Value was removed; generated types of documents selecting the enum change"
+                        .to_string(),
+                    affected,
+                );
+            }
+        }
+    }
+
+    fn classify_input_fields(
+        &mut self,
+        name: StringKey,
+        added: Vec<TypeChange>,
+        removed: Vec<TypeChange>,
+    ) {
+        let users = self.type_usage.input(name);
+        let (added, removed, changed) = pair_type_changes(added, removed);
+        for (field_name, current_type, candidate_type) in changed {
+            let coordinate = member_coordinate(name, field_name);
+            let description = format!(
+                "Type changed from `{}` to `{}`",
+                current_type, candidate_type
+            );
+            let severity = if accepts(&candidate_type, &current_type) {
+                ChangeSeverity::Safe
+            } else {
+                breaking_if_used(&users)
+            };
+            self.push(
+                severity,
+                coordinate,
+                description,
+                affected_if(severity, &users),
+            );
+        }
+        for field in added {
+            let coordinate = member_coordinate(name, field.name);
+            let has_default = match self.candidate.get_type(name) {
+                Some(schema::Type::InputObject(id)) => self
+                    .candidate
+                    .input_object(id)
+                    .fields
+                    .named(ArgumentName(field.name))
+                    .is_some_and(|field| field.default_value.is_some()),
+                _ => false,
+            };
+            if is_non_null(&field.type_) && !has_default {
+                self.push(
+                    breaking_if_used(&users),
+                    coordinate,
+                    format!("Required field of type `{}` was added", field.type_),
+                    users.clone(),
+                );
+            } else {
+                self.push(
+                    ChangeSeverity::Safe,
+                    coordinate,
+                    "Optional field was added".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+        }
+        for field in removed {
+            let coordinate = member_coordinate(name, field.name);
+            let literal_users = self.literal_users(SchemaCoordinate::Member {
+                parent_name: name,
+                member_name: field.name,
+            });
+            if !literal_users.is_empty() {
+                self.push(
+                    ChangeSeverity::Breaking,
+                    coordinate,
+                    "Field was removed but is passed as a literal".to_string(),
+                    literal_users,
+                );
+            } else {
+                self.push(
+                    dangerous_if_used(&users),
+                    coordinate,
+                    "Field was removed; variables of this type may still provide it".to_string(),
+                    users.clone(),
+                );
+            }
+        }
+    }
+
+    fn classify_fields(
+        &mut self,
+        name: StringKey,
+        added: Vec<TypeChange>,
+        removed: Vec<TypeChange>,
+        changed: Vec<ArgumentChange>,
+    ) {
+        let (added, removed, type_changed) = pair_type_changes(added, removed);
+        for field in added {
+            self.push(
+                ChangeSeverity::Safe,
+                member_coordinate(name, field.name),
+                "Field was added".to_string(),
+                BTreeSet::new(),
+            );
+        }
+        for field in removed {
+            let affected = self.coordinate_users(SchemaCoordinate::Member {
+                parent_name: name,
+                member_name: field.name,
+            });
+            self.push(
+                breaking_if_used(&affected),
+                member_coordinate(name, field.name),
+                "Field was removed".to_string(),
+                affected,
+            );
+        }
+        for (field_name, current_type, candidate_type) in type_changed {
+            let affected = self.coordinate_users(SchemaCoordinate::Member {
+                parent_name: name,
+                member_name: field_name,
+            });
+            let mut description = format!(
+                "Type changed from `{}` to `{}`",
+                current_type, candidate_type
+            );
+            let severity = if affected.is_empty() {
+                ChangeSeverity::Safe
+            } else if !same_ignoring_nullability(&current_type, &candidate_type) {
+                ChangeSeverity::Breaking
+            } else if accepts(&current_type, &candidate_type) {
+                description.push_str("; generated types become non-nullable");
+                ChangeSeverity::Safe
+            } else {
+                description.push_str("; generated types become nullable");
+                ChangeSeverity::Dangerous
+            };
+            self.push(
+                severity,
+                member_coordinate(name, field_name),
+                description,
+                affected,
+            );
+        }
+        for field in changed {
+            self.classify_arguments(name, field);
+        }
+    }
+
+    fn classify_arguments(&mut self, parent_name: StringKey, field: ArgumentChange) {
+        let field_users = self.coordinate_users(SchemaCoordinate::Member {
+            parent_name,
+            member_name: field.name,
+        });
+        let (added, removed, changed) = pair_type_changes(field.added, field.removed);
+        let argument_coordinate = |argument_name| {
+            SchemaCoordinate::Argument {
+                parent_name,
+                member_name: field.name,
+                argument_name,
+            }
+            .to_string()
+        };
+        for argument in added {
+            let has_default = self
+                .candidate
+                .get_type(parent_name)
+                .and_then(|type_| self.candidate.named_field(type_, field.name))
+                .and_then(|id| {
+                    self.candidate
+                        .field(id)
+                        .arguments
+                        .named(ArgumentName(argument.name))
+                        .map(|argument| argument.default_value.is_some())
+                })
+                .unwrap_or(false);
+            if is_non_null(&argument.type_) && !has_default {
+                self.push(
+                    breaking_if_used(&field_users),
+                    argument_coordinate(argument.name),
+                    format!("Required argument of type `{}` was added", argument.type_),
+                    field_users.clone(),
+                );
+            } else {
+                self.push(
+                    ChangeSeverity::Safe,
+                    argument_coordinate(argument.name),
+                    "Optional argument was added".to_string(),
+                    BTreeSet::new(),
+                );
+            }
+        }
+        for argument in removed {
+            let affected = self.coordinate_users(SchemaCoordinate::Argument {
+                parent_name,
+                member_name: field.name,
+                argument_name: argument.name,
+            });
+            self.push(
+                breaking_if_used(&affected),
+                argument_coordinate(argument.name),
+                "Argument was removed".to_string(),
+                affected,
+            );
+        }
+        for (argument_name, current_type, candidate_type) in changed {
+            let affected = self.coordinate_users(SchemaCoordinate::Argument {
+                parent_name,
+                member_name: field.name,
+                argument_name,
+            });
+            let severity = if accepts(&candidate_type, &current_type) {
+                ChangeSeverity::Safe
+            } else {
+                breaking_if_used(&affected)
+            };
+            self.push(
+                severity,
+                argument_coordinate(argument_name),
+                format!(
+                    "Type changed from `{}` to `{}`",
+                    current_type, candidate_type
+                ),
+                affected_if(severity, &affected),
+            );
+        }
+    }
+
+    fn coordinate_users(&self, coordinate: SchemaCoordinate) -> BTreeSet<String> {
+        self.coordinate_usage
+            .coordinates
+            .get(&coordinate)
+            .map(|usage| usage.used_by.clone())
+            .unwrap_or_default()
+    }
+
+    fn literal_users(&self, coordinate: SchemaCoordinate) -> BTreeSet<String> {
+        self.coordinate_usage
+            .coordinates
+            .get(&coordinate)
+            .map(|usage| usage.literal_used_by.clone())
+            .unwrap_or_default()
+    }
+
+    fn push(
+        &mut self,
+        severity: ChangeSeverity,
+        coordinate: String,
+        description: String,
+        affected: BTreeSet<String>,
+    ) {
+        self.changes.push(ClassifiedSchemaChange {
+            severity,
+            coordinate,
+            description,
+            affected: affected.into_iter().collect(),
+        });
+    }
+}
+
+fn breaking_if_used(affected: &BTreeSet<String>) -> ChangeSeverity {
+    if affected.is_empty() {
+        ChangeSeverity::Safe
+    } else {
+        ChangeSeverity::Breaking
+    }
+}
+
+fn dangerous_if_used(affected: &BTreeSet<String>) -> ChangeSeverity {
+    if affected.is_empty() {
+        ChangeSeverity::Safe
+    } else {
+        ChangeSeverity::Dangerous
+    }
+}
+
+/// Safe changes don't list the documents that merely refer to the coordinate.
+fn affected_if(severity: ChangeSeverity, users: &BTreeSet<String>) -> BTreeSet<String> {
+    if severity == ChangeSeverity::Safe {
+        BTreeSet::new()
+    } else {
+        users.clone()
+    }
+}
+
+fn type_coordinate(name: StringKey) -> String {
+    SchemaCoordinate::Type { name }.to_string()
+}
+
+fn directive_coordinate(name: StringKey) -> String {
+    SchemaCoordinate::Directive { name }.to_string()
+}
+
+fn directive_argument_coordinate(directive_name: StringKey, argument_name: StringKey) -> String {
+    SchemaCoordinate::DirectiveArgument {
+        directive_name,
+        argument_name,
+    }
+    .to_string()
+}
+
+fn member_coordinate(parent_name: StringKey, member_name: StringKey) -> String {
+    SchemaCoordinate::Member {
+        parent_name,
+        member_name,
+    }
+    .to_string()
+}
+
+/// A member whose type changed, as `(name, current type, candidate type)`.
+type ChangedType = (StringKey, Type, Type);
+
+/// Split the added and removed members of a definition into the members
+/// that were only added, only removed, or whose type changed.
+fn pair_type_changes(
+    added: Vec<TypeChange>,
+    removed: Vec<TypeChange>,
+) -> (Vec<TypeChange>, Vec<TypeChange>, Vec<ChangedType>) {
+    let mut removed: FxHashMap<StringKey, Type> = removed
+        .into_iter()
+        .map(|change| (change.name, change.type_))
+        .collect();
+    let mut only_added = vec![];
+    let mut changed = vec![];
+    for change in added {
+        match removed.remove(&change.name) {
+            Some(current_type) => changed.push((change.name, current_type, change.type_)),
+            None => only_added.push(change),
+        }
+    }
+    let only_removed = removed
+        .into_iter()
+        .map(|(name, type_)| TypeChange { name, type_ })
+        .collect();
+    (only_added, only_removed, changed)
+}
+
+/// Whether every value of type `old` is also a value of type `new`.
+fn accepts(new: &Type, old: &Type) -> bool {
+    match (new, old) {
+        (Type::NonNull(new), Type::NonNull(old)) => accepts(new, old),
+        (Type::NonNull(_), _) => false,
+        (new, Type::NonNull(old)) => accepts(new, old),
+        (Type::List(new), Type::List(old)) => accepts(new, old),
+        (Type::Named(new), Type::Named(old)) => new == old,
+        _ => false,
+    }
+}
+
+fn same_ignoring_nullability(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::NonNull(a), b) | (b, Type::NonNull(a)) => same_ignoring_nullability(a, b),
+        (Type::List(a), Type::List(b)) => same_ignoring_nullability(a, b),
+        (Type::Named(a), Type::Named(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn is_non_null(type_: &Type) -> bool {
+    matches!(type_, Type::NonNull(_))
+}
+
+/// The documents referring to each named type, either as the type of a
+/// selected field or type condition (output), or as the type of a variable
+/// or argument (input). Input object types are followed to the types of
+/// their fields.
+#[derive(Default)]
+struct TypeUsage {
+    output: FxHashMap<StringKey, BTreeSet<String>>,
+    input: FxHashMap<StringKey, BTreeSet<String>>,
+}
+
+impl TypeUsage {
+    fn from_program(program: &Program) -> Self {
+        let mut usage = Self::default();
+        let mut visitor = TypeUsageVisitor {
+            schema: &program.schema,
+            usage: &mut usage,
+            used_by: String::new(),
+            visited_input_objects: FxHashSet::default(),
+        };
+        visitor.visit_program(program);
+        usage
+    }
+
+    fn output(&self, name: StringKey) -> BTreeSet<String> {
+        self.output.get(&name).cloned().unwrap_or_default()
+    }
+
+    fn input(&self, name: StringKey) -> BTreeSet<String> {
+        self.input.get(&name).cloned().unwrap_or_default()
+    }
+
+    fn any(&self, name: StringKey) -> BTreeSet<String> {
+        let mut users = self.output(name);
+        users.extend(self.input(name));
+        users
+    }
+}
+
+struct TypeUsageVisitor<'a> {
+    schema: &'a SDLSchema,
+    usage: &'a mut TypeUsage,
+    used_by: String,
+    visited_input_objects: FxHashSet<StringKey>,
+}
+
+impl TypeUsageVisitor<'_> {
+    fn add_output(&mut self, type_: schema::Type) {
+        let name = self.schema.get_type_name(type_);
+        self.usage
+            .output
+            .entry(name)
+            .or_default()
+            .insert(self.used_by.clone());
+    }
+
+    fn add_input(&mut self, type_: schema::Type) {
+        let name = self.schema.get_type_name(type_);
+        self.usage
+            .input
+            .entry(name)
+            .or_default()
+            .insert(self.used_by.clone());
+        if let schema::Type::InputObject(id) = type_
+            && self.visited_input_objects.insert(name)
+        {
+            for field in self.schema.input_object(id).fields.iter() {
+                self.add_input(field.type_.inner());
+            }
+        }
+    }
+
+    fn add_arguments(&mut self, field_id: schema::FieldID, arguments: &[graphql_ir::Argument]) {
+        let field = self.schema.field(field_id);
+        for argument in arguments {
+            if let Some(definition) = field.arguments.named(argument.name.item) {
+                self.add_input(definition.type_.inner());
+            }
+        }
+    }
+
+    fn start_definition(&mut self, used_by: String, variables: &[VariableDefinition]) {
+        self.used_by = used_by;
+        self.visited_input_objects.clear();
+        for variable in variables {
+            self.add_input(variable.type_.inner());
+        }
+    }
+}
+
+impl Visitor for TypeUsageVisitor<'_> {
+    const NAME: &'static str = "DependencyAnalyzerTypeUsageVisitor";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_operation(&mut self, operation: &OperationDefinition) {
+        self.start_definition(
+            definition_label(operation.name.item.0, operation.name.location),
+            &operation.variable_definitions,
+        );
+        self.visit_selections(&operation.selections);
+    }
+
+    fn visit_fragment(&mut self, fragment: &FragmentDefinition) {
+        self.start_definition(
+            definition_label(fragment.name.item.0, fragment.name.location),
+            &fragment.variable_definitions,
+        );
+        self.add_output(fragment.type_condition);
+        self.visit_selections(&fragment.selections);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.add_output(self.schema.field(field.definition.item).type_.inner());
+        self.add_arguments(field.definition.item, &field.arguments);
+        self.default_visit_linked_field(field);
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.add_output(self.schema.field(field.definition.item).type_.inner());
+        self.add_arguments(field.definition.item, &field.arguments);
+    }
+
+    fn visit_inline_fragment(&mut self, fragment: &InlineFragment) {
+        if let Some(type_condition) = fragment.type_condition {
+            self.add_output(type_condition);
+        }
+        self.default_visit_inline_fragment(fragment);
+    }
+}
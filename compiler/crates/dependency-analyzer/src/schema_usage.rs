@@ -0,0 +1,555 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Write;
+
+use graphql_ir::ConstantValue;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::Value;
+use graphql_ir::VariableDefinition;
+use intern::Lookup;
+use intern::string_key::StringKey;
+use schema::EnumID;
+use schema::Field;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+use schema::definitions::Deprecation;
+use schema_coordinates::SchemaCoordinate;
+use serde::Serialize;
+
+/// Usage of a schema coordinate by the documents of one or more projects.
+#[derive(Default)]
+pub struct CoordinateUsage {
+    /// Number of selections, arguments, literal values and variable types
+    /// referring to the coordinate.
+    pub count: usize,
+    pub deprecated: bool,
+    pub deprecation_reason: Option<StringKey>,
+    /// Operations and fragments referring to the coordinate, as
+    /// `Name (path/to/file)`.
+    pub used_by: BTreeSet<String>,
+    /// The subset of `used_by` passing the enum value or input object field
+    /// as a literal, which breaks if it's removed from the schema.
+    pub literal_used_by: BTreeSet<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SchemaUsageOptions {
+    /// Also report the fields (including Relay Resolver fields), arguments
+    /// and enum values defined by client schema extensions.
+    pub include_client_extensions: bool,
+}
+
+/// Usage counts of the fields, field arguments, input object fields and enum
+/// values of a schema. Client schema extensions are not part of the report
+/// unless `SchemaUsageOptions::include_client_extensions` is set.
+///
+/// Enum values and input object fields are counted when they are passed as
+/// literals, and when they can be reached from the type of a variable, since
+/// the variable's value may contain any of them. Selecting a field of an enum
+/// type counts as a usage of all its values, and selecting a field of an
+/// interface also counts as a usage of that field on every object
+/// implementing the interface.
+#[derive(Default)]
+pub struct SchemaUsageReport {
+    pub coordinates: BTreeMap<SchemaCoordinate, CoordinateUsage>,
+}
+
+/// A row of the serialized report.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoordinateUsageRow {
+    pub coordinate: String,
+    pub count: usize,
+    pub deprecated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation_reason: Option<String>,
+    pub used_by: Vec<String>,
+}
+
+impl SchemaUsageReport {
+    /// Compute the usage of the schema of `program` by its definitions. Every
+    /// coordinate of the server schema is part of the report, so that unused
+    /// ones are reported with a count of 0.
+    pub fn from_program(program: &Program) -> Self {
+        Self::from_program_with_options(program, SchemaUsageOptions::default())
+    }
+
+    pub fn from_program_with_options(program: &Program, options: SchemaUsageOptions) -> Self {
+        let mut report = Self::default();
+        report.add_schema_coordinates(&program.schema, options);
+        for operation in program.operations() {
+            let used_by = definition_label(operation.name.item.0, operation.name.location);
+            let mut visitor = UsageVisitor {
+                schema: &program.schema,
+                report: &mut report,
+                options,
+                used_by,
+            };
+            visitor.visit_variable_definitions(&operation.variable_definitions);
+            visitor.visit_selections(&operation.selections);
+        }
+        for fragment in program.fragments() {
+            let used_by = definition_label(fragment.name.item.0, fragment.name.location);
+            let mut visitor = UsageVisitor {
+                schema: &program.schema,
+                report: &mut report,
+                options,
+                used_by,
+            };
+            visitor.visit_variable_definitions(&fragment.variable_definitions);
+            visitor.visit_selections(&fragment.selections);
+        }
+        report
+    }
+
+    /// Combine the usage of another report, e.g. of another project, into
+    /// this one.
+    pub fn merge(&mut self, other: SchemaUsageReport) {
+        for (coordinate, usage) in other.coordinates {
+            let entry = self.coordinates.entry(coordinate).or_default();
+            entry.count += usage.count;
+            entry.deprecated |= usage.deprecated;
+            entry.deprecation_reason = entry.deprecation_reason.or(usage.deprecation_reason);
+            entry.used_by.extend(usage.used_by);
+            entry.literal_used_by.extend(usage.literal_used_by);
+        }
+    }
+
+    fn add_schema_coordinates(&mut self, schema: &SDLSchema, options: SchemaUsageOptions) {
+        let include_extensions = options.include_client_extensions;
+        let fields = schema
+            .objects()
+            .filter(|object| include_extensions || !object.is_extension)
+            .flat_map(|object| &object.fields)
+            .chain(
+                schema
+                    .interfaces()
+                    .filter(|interface| include_extensions || !interface.is_extension)
+                    .flat_map(|interface| &interface.fields),
+            );
+        for field_id in fields {
+            let field = schema.field(*field_id);
+            let Some(parent_name) = field_parent_name(schema, field, options) else {
+                continue;
+            };
+            self.entry(
+                SchemaCoordinate::Member {
+                    parent_name,
+                    member_name: field.name.item,
+                },
+                field.deprecated(),
+            );
+            for argument in field.arguments.iter() {
+                self.entry(
+                    SchemaCoordinate::Argument {
+                        parent_name,
+                        member_name: field.name.item,
+                        argument_name: argument.name.item.0,
+                    },
+                    argument.deprecated(),
+                );
+            }
+        }
+        for input_object in schema
+            .input_objects()
+            .filter(|input_object| include_extensions || !input_object.is_extension)
+        {
+            for field in input_object.fields.iter() {
+                self.entry(
+                    SchemaCoordinate::Member {
+                        parent_name: input_object.name.item.0,
+                        member_name: field.name.item.0,
+                    },
+                    field.deprecated(),
+                );
+            }
+        }
+        for enum_ in schema
+            .enums()
+            .filter(|enum_| include_extensions || !enum_.is_extension)
+        {
+            for value in &enum_.values {
+                self.entry(
+                    SchemaCoordinate::Member {
+                        parent_name: enum_.name.item.0,
+                        member_name: value.value,
+                    },
+                    value.deprecated(),
+                );
+            }
+        }
+    }
+
+    fn entry(
+        &mut self,
+        coordinate: SchemaCoordinate,
+        deprecation: Option<Deprecation>,
+    ) -> &mut CoordinateUsage {
+        let entry = self.coordinates.entry(coordinate).or_default();
+        if let Some(deprecation) = deprecation {
+            entry.deprecated = true;
+            entry.deprecation_reason = deprecation.reason;
+        }
+        entry
+    }
+
+    /// The report as serializable rows, ordered by coordinate.
+    pub fn rows(&self) -> Vec<CoordinateUsageRow> {
+        self.coordinates
+            .iter()
+            .map(|(coordinate, usage)| CoordinateUsageRow {
+                coordinate: coordinate.to_string(),
+                count: usage.count,
+                deprecated: usage.deprecated,
+                deprecation_reason: usage.deprecation_reason.map(|reason| reason.to_string()),
+                used_by: usage.used_by.iter().cloned().collect(),
+            })
+            .collect()
+    }
+
+    /// The header of the CSV rows written by `append_csv_rows`.
+    pub fn csv_header() -> &'static str {
+        "coordinate,count,deprecated"
+    }
+
+    /// Append CSV rows (without header) for all coordinates to the given string.
+    pub fn append_csv_rows(&self, out: &mut String) {
+        for (coordinate, usage) in &self.coordinates {
+            writeln!(out, "{},{},{}", coordinate, usage.count, usage.deprecated).unwrap();
+        }
+    }
+
+    /// Format the report as a deterministic text string.
+    pub fn format_report(&self) -> String {
+        let mut out = String::new();
+        let total = self.coordinates.len();
+
+        writeln!(out, "== Usage by schema coordinate ==").unwrap();
+        for (coordinate, usage) in &self.coordinates {
+            write!(out, "{:>4} {}", usage.count, coordinate).unwrap();
+            if usage.deprecated {
+                write!(out, " (deprecated)").unwrap();
+            }
+            writeln!(out).unwrap();
+        }
+
+        let deprecated_in_use: Vec<_> = self
+            .coordinates
+            .iter()
+            .filter(|(_, usage)| usage.deprecated && usage.count > 0)
+            .collect();
+        writeln!(out).unwrap();
+        writeln!(out, "== Deprecated coordinates in use ==").unwrap();
+        for (coordinate, usage) in &deprecated_in_use {
+            write!(out, "{}: {} usages", coordinate, usage.count).unwrap();
+            if let Some(reason) = usage.deprecation_reason {
+                write!(out, " ({})", reason).unwrap();
+            }
+            writeln!(out).unwrap();
+            for used_by in &usage.used_by {
+                writeln!(out, "  {}", used_by).unwrap();
+            }
+        }
+
+        let used = self
+            .coordinates
+            .values()
+            .filter(|usage| usage.count > 0)
+            .count();
+        writeln!(out).unwrap();
+        writeln!(out, "== Summary ==").unwrap();
+        writeln!(
+            out,
+            "Used coordinates: {} of {} ({})",
+            used,
+            total,
+            format_pct(used, total)
+        )
+        .unwrap();
+        writeln!(out, "Unused coordinates: {}", total - used).unwrap();
+        writeln!(
+            out,
+            "Deprecated coordinates in use: {}",
+            deprecated_in_use.len()
+        )
+        .unwrap();
+
+        out
+    }
+}
+
+impl fmt::Display for SchemaUsageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format_report())
+    }
+}
+
+pub(crate) fn definition_label(name: StringKey, location: common::Location) -> String {
+    format!("{} ({})", name, location.source_location().path())
+}
+
+fn field_parent_name(
+    schema: &SDLSchema,
+    field: &Field,
+    options: SchemaUsageOptions,
+) -> Option<StringKey> {
+    if (field.is_extension && !options.include_client_extensions)
+        || field.name.item.lookup().starts_with("__")
+    {
+        return None;
+    }
+    field
+        .parent_type
+        .map(|parent_type| schema.get_type_name(parent_type))
+}
+
+fn format_pct(count: usize, total: usize) -> String {
+    if total == 0 {
+        "0.0%".to_string()
+    } else {
+        format!("{:.1}%", count as f64 / total as f64 * 100.0)
+    }
+}
+
+struct UsageVisitor<'a> {
+    schema: &'a SDLSchema,
+    report: &'a mut SchemaUsageReport,
+    options: SchemaUsageOptions,
+    used_by: String,
+}
+
+impl UsageVisitor<'_> {
+    fn record(&mut self, coordinate: SchemaCoordinate, deprecation: Option<Deprecation>) {
+        let used_by = self.used_by.clone();
+        let entry = self.report.entry(coordinate, deprecation);
+        entry.count += 1;
+        entry.used_by.insert(used_by);
+    }
+
+    fn record_literal(&mut self, coordinate: SchemaCoordinate, deprecation: Option<Deprecation>) {
+        let used_by = self.used_by.clone();
+        let entry = self.report.entry(coordinate, deprecation);
+        entry.count += 1;
+        entry.used_by.insert(used_by.clone());
+        entry.literal_used_by.insert(used_by);
+    }
+
+    /// Credit everything reachable from the variables' types. This includes
+    /// what their default values refer to.
+    fn visit_variable_definitions(&mut self, variable_definitions: &[VariableDefinition]) {
+        for variable_definition in variable_definitions {
+            self.visit_input_type(variable_definition.type_.inner(), &mut HashSet::new());
+        }
+    }
+
+    fn visit_input_type(&mut self, type_: Type, visited: &mut HashSet<Type>) {
+        if !visited.insert(type_) {
+            return;
+        }
+        match type_ {
+            Type::Enum(id) => self.visit_enum_values(id),
+            Type::InputObject(id) => {
+                let schema = self.schema;
+                let input_object = schema.input_object(id);
+                if input_object.is_extension && !self.options.include_client_extensions {
+                    return;
+                }
+                for field in input_object.fields.iter() {
+                    self.record(
+                        SchemaCoordinate::Member {
+                            parent_name: input_object.name.item.0,
+                            member_name: field.name.item.0,
+                        },
+                        field.deprecated(),
+                    );
+                    self.visit_input_type(field.type_.inner(), visited);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_enum_values(&mut self, id: EnumID) {
+        let enum_ = self.schema.enum_(id);
+        if enum_.is_extension && !self.options.include_client_extensions {
+            return;
+        }
+        for value in &enum_.values {
+            self.record(
+                SchemaCoordinate::Member {
+                    parent_name: enum_.name.item.0,
+                    member_name: value.value,
+                },
+                value.deprecated(),
+            );
+        }
+    }
+
+    fn visit_selections(&mut self, selections: &[Selection]) {
+        for selection in selections {
+            match selection {
+                Selection::ScalarField(field) => {
+                    self.visit_field(field.definition.item, &field.arguments);
+                }
+                Selection::LinkedField(field) => {
+                    self.visit_field(field.definition.item, &field.arguments);
+                    self.visit_selections(&field.selections);
+                }
+                Selection::InlineFragment(fragment) => self.visit_selections(&fragment.selections),
+                Selection::Condition(condition) => self.visit_selections(&condition.selections),
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    fn visit_field(&mut self, field_id: schema::FieldID, arguments: &[graphql_ir::Argument]) {
+        let schema = self.schema;
+        let field = schema.field(field_id);
+        if !self.record_field(field, arguments) {
+            return;
+        }
+        // The selection also reads the field of whichever object implements
+        // the interface at runtime.
+        if let Some(Type::Interface(id)) = field.parent_type {
+            for object_id in schema
+                .interface(id)
+                .recursively_implementing_objects(schema)
+            {
+                if let Some(implementation) =
+                    schema.named_field(Type::Object(object_id), field.name.item)
+                {
+                    self.record_field(schema.field(implementation), arguments);
+                }
+            }
+        }
+        for argument in arguments {
+            if let Some(definition) = field.arguments.named(argument.name.item) {
+                self.visit_value(&argument.value.item, &definition.type_);
+            }
+        }
+        if let Type::Enum(id) = field.type_.inner() {
+            self.visit_enum_values(id);
+        }
+    }
+
+    /// Records the usage of a field and of the given arguments of it, and
+    /// returns whether the field is part of the report.
+    fn record_field(&mut self, field: &Field, arguments: &[graphql_ir::Argument]) -> bool {
+        let Some(parent_name) = field_parent_name(self.schema, field, self.options) else {
+            return false;
+        };
+        self.record(
+            SchemaCoordinate::Member {
+                parent_name,
+                member_name: field.name.item,
+            },
+            field.deprecated(),
+        );
+        for argument in arguments {
+            let Some(definition) = field.arguments.named(argument.name.item) else {
+                continue;
+            };
+            self.record(
+                SchemaCoordinate::Argument {
+                    parent_name,
+                    member_name: field.name.item,
+                    argument_name: argument.name.item.0,
+                },
+                definition.deprecated(),
+            );
+        }
+        true
+    }
+
+    fn visit_value(&mut self, value: &Value, type_: &TypeReference<Type>) {
+        match value {
+            Value::Constant(value) => self.visit_constant_value(value, type_),
+            Value::List(items) => {
+                for item in items {
+                    self.visit_value(item, type_);
+                }
+            }
+            Value::Object(fields) => {
+                let Type::InputObject(id) = type_.inner() else {
+                    return;
+                };
+                let input_object = self.schema.input_object(id);
+                if input_object.is_extension && !self.options.include_client_extensions {
+                    return;
+                }
+                for field in fields {
+                    if let Some(definition) = input_object.fields.named(field.name.item) {
+                        self.record_literal(
+                            SchemaCoordinate::Member {
+                                parent_name: input_object.name.item.0,
+                                member_name: field.name.item.0,
+                            },
+                            definition.deprecated(),
+                        );
+                        self.visit_value(&field.value.item, &definition.type_);
+                    }
+                }
+            }
+            Value::Variable(_) => {}
+        }
+    }
+
+    fn visit_constant_value(&mut self, value: &ConstantValue, type_: &TypeReference<Type>) {
+        match (value, type_.inner()) {
+            (ConstantValue::Enum(value), Type::Enum(id)) => {
+                let enum_ = self.schema.enum_(id);
+                if enum_.is_extension && !self.options.include_client_extensions {
+                    return;
+                }
+                let deprecation = enum_
+                    .values
+                    .iter()
+                    .find(|enum_value| enum_value.value == *value)
+                    .and_then(|enum_value| enum_value.deprecated());
+                self.record_literal(
+                    SchemaCoordinate::Member {
+                        parent_name: enum_.name.item.0,
+                        member_name: *value,
+                    },
+                    deprecation,
+                );
+            }
+            (ConstantValue::List(items), _) => {
+                for item in items {
+                    self.visit_constant_value(item, type_);
+                }
+            }
+            (ConstantValue::Object(fields), Type::InputObject(id)) => {
+                let input_object = self.schema.input_object(id);
+                if input_object.is_extension && !self.options.include_client_extensions {
+                    return;
+                }
+                for field in fields {
+                    if let Some(definition) = input_object.fields.named(field.name.item) {
+                        self.record_literal(
+                            SchemaCoordinate::Member {
+                                parent_name: input_object.name.item.0,
+                                member_name: field.name.item.0,
+                            },
+                            definition.deprecated(),
+                        );
+                        self.visit_constant_value(&field.value.item, &definition.type_);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,317 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt;
+use std::fmt::Write;
+
+use common::Diagnostic;
+use common::DiagnosticTag;
+use common::Location;
+use common::NamedItem;
+use common::SourceLocationKey;
+use docblock_shared::RELAY_RESOLVER_DIRECTIVE_NAME;
+use docblock_shared::RELAY_RESOLVER_MODEL_DIRECTIVE_NAME;
+use docblock_shared::RELAY_RESOLVER_MODEL_GENERATED_ID_FIELD_DIRECTIVE_NAME;
+use docblock_shared::RELAY_RESOLVER_WEAK_OBJECT_DIRECTIVE;
+use graphql_ir::Program;
+use intern::Lookup;
+use intern::string_key::StringKey;
+use program_with_dependencies::ProgramWithDependencies;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema_coordinates::SchemaCoordinate;
+use schema_coordinates::SchemaElement;
+use schema_set::SchemaSet;
+use schema_set::SetType;
+use schema_set::UsedSchemaCollectionOptions;
+use serde::Serialize;
+use thiserror::Error;
+
+/// The kind of a client schema element that isn't used by any document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnusedClientSchemaKind {
+    Type,
+    Field,
+    EnumValue,
+    RelayResolver,
+}
+
+impl fmt::Display for UnusedClientSchemaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UnusedClientSchemaKind::Type => "type",
+            UnusedClientSchemaKind::Field => "field",
+            UnusedClientSchemaKind::EnumValue => "enum value",
+            UnusedClientSchemaKind::RelayResolver => "Relay Resolver",
+        })
+    }
+}
+
+pub struct UnusedClientSchemaElement {
+    pub kind: UnusedClientSchemaKind,
+    pub coordinate: SchemaCoordinate,
+    /// Where the element is defined. Enum values are located at their enum.
+    pub location: Location,
+}
+
+/// A row of the serialized report.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedClientSchemaRow {
+    pub kind: UnusedClientSchemaKind,
+    pub coordinate: String,
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+}
+
+/// The types, fields, enum values and Relay Resolvers defined by client schema
+/// extensions that aren't selected by any document of a program.
+///
+/// Fields of unused types are not reported on their own. Selecting a field of
+/// a client enum type uses all of its values.
+#[derive(Default)]
+pub struct UnusedClientSchemaReport {
+    pub elements: Vec<UnusedClientSchemaElement>,
+}
+
+#[derive(Clone, Debug, Error, Serialize)]
+#[serde(tag = "type")]
+enum UnusedClientSchemaMessage {
+    #[error("The client schema {kind} `{coordinate}` isn't used by any document.")]
+    Unused {
+        kind: UnusedClientSchemaKind,
+        coordinate: String,
+    },
+}
+
+impl UnusedClientSchemaReport {
+    /// Find the client schema elements of the schema of `program` that its
+    /// operations and fragments don't use. `program` is expected to contain
+    /// all documents of the project.
+    pub fn from_program(program: &Program) -> Self {
+        let schema = &program.schema;
+        let used = SchemaSet::from_ir(
+            &ProgramWithDependencies::from_full_program(schema, program),
+            UsedSchemaCollectionOptions {
+                include_implicit_output_enum_values: true,
+                ..UsedSchemaCollectionOptions::explicitly_used_outputs_and_implicit_inputs_without_directives_options()
+            },
+        )
+        .unwrap_or_default();
+
+        let mut report = Self::default();
+        report.add_unused_types(schema, &used);
+        report.elements.sort_by_cached_key(|element| {
+            (
+                element.location.source_location().path().to_string(),
+                element.location.span().start,
+                element.coordinate.to_string(),
+            )
+        });
+        report
+    }
+
+    fn add_unused_types(&mut self, schema: &SDLSchema, used: &SchemaSet) {
+        for type_ in schema.get_type_map().map(|(_, type_)| *type_) {
+            let element = SchemaElement::Type(type_);
+            let location = element
+                .definition_location(schema)
+                .unwrap_or(Location::generated());
+            let name = schema.get_type_name(type_);
+            let Some(used_type) = used.types.get(&name) else {
+                if is_extension_type(schema, type_)
+                    && location.source_location() != SourceLocationKey::Generated
+                {
+                    self.push(
+                        if is_resolver_model(schema, type_) {
+                            UnusedClientSchemaKind::RelayResolver
+                        } else {
+                            UnusedClientSchemaKind::Type
+                        },
+                        element,
+                        schema,
+                        location,
+                    );
+                }
+                continue;
+            };
+            match type_ {
+                Type::Object(_) | Type::Interface(_) => {
+                    let field_ids = match type_ {
+                        Type::Object(id) => &schema.object(id).fields,
+                        Type::Interface(id) => &schema.interface(id).fields,
+                        _ => unreachable!(),
+                    };
+                    for field_id in field_ids {
+                        let field = schema.field(*field_id);
+                        if !field.is_extension
+                            || field.name.location.source_location() == SourceLocationKey::Generated
+                            || field.name.item.lookup().starts_with("__")
+                            || field
+                                .directives
+                                .named(*RELAY_RESOLVER_MODEL_GENERATED_ID_FIELD_DIRECTIVE_NAME)
+                                .is_some()
+                            || has_field(used_type, field.name.item)
+                        {
+                            continue;
+                        }
+                        let kind = if field
+                            .directives
+                            .named(*RELAY_RESOLVER_DIRECTIVE_NAME)
+                            .is_some()
+                        {
+                            UnusedClientSchemaKind::RelayResolver
+                        } else {
+                            UnusedClientSchemaKind::Field
+                        };
+                        self.push(
+                            kind,
+                            SchemaElement::Field {
+                                parent_type: type_,
+                                field: *field_id,
+                            },
+                            schema,
+                            field.name.location,
+                        );
+                    }
+                }
+                Type::Enum(id) => {
+                    let enum_ = schema.enum_(id);
+                    let SetType::Enum(used_enum) = used_type else {
+                        continue;
+                    };
+                    if !enum_.is_extension
+                        || location.source_location() == SourceLocationKey::Generated
+                    {
+                        continue;
+                    }
+                    for value in &enum_.values {
+                        if !used_enum.values.contains_key(&value.value) {
+                            self.push(
+                                UnusedClientSchemaKind::EnumValue,
+                                SchemaElement::EnumValue {
+                                    enum_: id,
+                                    value: value.value,
+                                },
+                                schema,
+                                location,
+                            );
+                        }
+                    }
+                }
+                Type::InputObject(_) | Type::Scalar(_) | Type::Union(_) => {}
+            }
+        }
+    }
+
+    fn push(
+        &mut self,
+        kind: UnusedClientSchemaKind,
+        element: SchemaElement,
+        schema: &SDLSchema,
+        location: Location,
+    ) {
+        self.elements.push(UnusedClientSchemaElement {
+            kind,
+            coordinate: element.schema_coordinate(schema),
+            location,
+        });
+    }
+
+    /// Combine the unused elements of another report, e.g. of another
+    /// project, into this one.
+    pub fn merge(&mut self, other: UnusedClientSchemaReport) {
+        self.elements.extend(other.elements);
+    }
+
+    /// A hint diagnostic, marked as unnecessary, for every unused element.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.elements
+            .iter()
+            .map(|element| {
+                Diagnostic::hint(
+                    UnusedClientSchemaMessage::Unused {
+                        kind: element.kind,
+                        coordinate: element.coordinate.to_string(),
+                    },
+                    element.location,
+                    vec![DiagnosticTag::UNNECESSARY],
+                )
+            })
+            .collect()
+    }
+
+    /// The report as serializable rows. `line` returns the 1-based line of a
+    /// location, if its source is available.
+    pub fn rows(&self, line: impl Fn(Location) -> Option<u32>) -> Vec<UnusedClientSchemaRow> {
+        self.elements
+            .iter()
+            .map(|element| UnusedClientSchemaRow {
+                kind: element.kind,
+                coordinate: element.coordinate.to_string(),
+                file: element.location.source_location().path().to_string(),
+                line: line(element.location),
+            })
+            .collect()
+    }
+
+    /// Format the report as a deterministic text string, with one line per
+    /// unused element.
+    pub fn format_report(&self, line: impl Fn(Location) -> Option<u32>) -> String {
+        let mut out = String::new();
+        for row in self.rows(line) {
+            write!(out, "{}", row.file).unwrap();
+            if let Some(line) = row.line {
+                write!(out, ":{}", line).unwrap();
+            }
+            writeln!(out, ": unused {} `{}`", row.kind, row.coordinate).unwrap();
+        }
+        writeln!(
+            out,
+            "Unused client schema elements: {}",
+            self.elements.len()
+        )
+        .unwrap();
+        out
+    }
+}
+
+fn is_extension_type(schema: &SDLSchema, type_: Type) -> bool {
+    match type_ {
+        Type::Enum(id) => schema.enum_(id).is_extension,
+        // Input objects don't record whether they were defined by an extension.
+        Type::InputObject(_) => false,
+        Type::Interface(id) => schema.interface(id).is_extension,
+        Type::Object(id) => schema.object(id).is_extension,
+        Type::Scalar(id) => schema.scalar(id).is_extension,
+        Type::Union(id) => schema.union(id).is_extension,
+    }
+}
+
+fn is_resolver_model(schema: &SDLSchema, type_: Type) -> bool {
+    let Type::Object(id) = type_ else {
+        return false;
+    };
+    let directives = &schema.object(id).directives;
+    directives
+        .named(*RELAY_RESOLVER_MODEL_DIRECTIVE_NAME)
+        .is_some()
+        || directives
+            .named(*RELAY_RESOLVER_WEAK_OBJECT_DIRECTIVE)
+            .is_some()
+}
+
+fn has_field(used_type: &SetType, field_name: StringKey) -> bool {
+    match used_type {
+        SetType::Object(object) => object.fields.contains_key(&field_name),
+        SetType::Interface(interface) => interface.fields.contains_key(&field_name),
+        _ => false,
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use dependency_analyzer::QueryStatsReport;
+use dependency_analyzer::compute_program_query_stats;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use relay_config::DeferStreamInterface;
+use relay_test_schema::get_test_schema_with_extensions;
+use relay_transforms::inline_fragments;
+use schema::SDLSchema;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let [documents, extensions] = parts.as_slice() else {
+        panic!("Expected exactly one %extensions% section marker.")
+    };
+    let schema = get_test_schema_with_extensions(extensions);
+    let documents: Vec<_> = documents.split("%baseline%").collect();
+
+    let report = compute_report(fixture, &schema, documents[0]);
+    let mut output = report.format_report();
+    output.push('\n');
+    output.push_str(QueryStatsReport::metrics_csv_header());
+    output.push('\n');
+    report.append_metrics_csv_rows(&mut output, "test");
+
+    if let Some(baseline) = documents.get(1) {
+        let baseline = compute_report(fixture, &schema, baseline);
+        output.push_str("\n== Regressions (max growth: 10%) ==\n");
+        for regression in report.regressions_since(&baseline, 10.0) {
+            output.push_str(&format!("{regression}\n"));
+        }
+    }
+    Ok(output)
+}
+
+fn compute_report(fixture: &Fixture<'_>, schema: &Arc<SDLSchema>, text: &str) -> QueryStatsReport {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(text, source_location).unwrap();
+    let ir = build(schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(Arc::clone(schema), ir);
+    let normalization = inline_fragments(&program);
+    compute_program_query_stats(
+        &program,
+        &normalization,
+        &program,
+        &DeferStreamInterface::default(),
+    )
+}
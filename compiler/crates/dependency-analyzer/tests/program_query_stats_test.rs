@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<7aac820fb90fe79cba1738d2b59286b0>>
+ */
+
+mod program_query_stats;
+
+use program_query_stats::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn defer_stream_and_client_fields() {
+    let input = include_str!("program_query_stats/fixtures/defer-stream-and-client-fields.graphql");
+    let expected = include_str!("program_query_stats/fixtures/defer-stream-and-client-fields.expected");
+    test_fixture(transform_fixture, file!(), "defer-stream-and-client-fields.graphql", "program_query_stats/fixtures/defer-stream-and-client-fields.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn regression_against_baseline() {
+    let input = include_str!("program_query_stats/fixtures/regression-against-baseline.graphql");
+    let expected = include_str!("program_query_stats/fixtures/regression-against-baseline.expected");
+    test_fixture(transform_fixture, file!(), "regression-against-baseline.graphql", "program_query_stats/fixtures/regression-against-baseline.expected", input, expected).await;
+}
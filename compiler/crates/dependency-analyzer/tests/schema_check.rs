@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use dependency_analyzer::SchemaCheckReport;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use schema::build_schema;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%schema%").collect();
+    let [document, sdls] = parts.as_slice() else {
+        panic!("Expected exactly one %schema% section marker.")
+    };
+    let parts: Vec<_> = sdls.split("%candidate%").collect();
+    let [current_sdl, candidate_sdl] = parts.as_slice() else {
+        panic!("Expected exactly one %candidate% section marker after %schema%.")
+    };
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(document, source_location).unwrap();
+    let schema = Arc::new(build_schema(current_sdl).unwrap());
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(schema, ir);
+
+    let candidate = build_schema(candidate_sdl).unwrap();
+    let candidate_errors = build(&candidate, &ast.definitions)
+        .err()
+        .unwrap_or_default();
+    let no_future_proof_enums = fixture.file_name.contains("no-future-proof-enums");
+
+    let report = SchemaCheckReport::from_program(
+        &program,
+        &candidate,
+        &ast.definitions,
+        &candidate_errors,
+        no_future_proof_enums,
+    );
+    Ok(format!(
+        "{}\nHas breaking changes: {}\n",
+        report.format_report(),
+        report.has_breaking_changes()
+    ))
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<add799b68d64152ffee4400fad08cae3>>
+ */
+
+mod schema_check;
+
+use schema_check::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn enums_unions_and_inputs_no_future_proof_enums() {
+    let input = include_str!("schema_check/fixtures/enums-unions-and-inputs.no-future-proof-enums.graphql");
+    let expected = include_str!("schema_check/fixtures/enums-unions-and-inputs.no-future-proof-enums.expected");
+    test_fixture(transform_fixture, file!(), "enums-unions-and-inputs.no-future-proof-enums.graphql", "schema_check/fixtures/enums-unions-and-inputs.no-future-proof-enums.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn field_and_argument_changes() {
+    let input = include_str!("schema_check/fixtures/field-and-argument-changes.graphql");
+    let expected = include_str!("schema_check/fixtures/field-and-argument-changes.expected");
+    test_fixture(transform_fixture, file!(), "field-and-argument-changes.graphql", "schema_check/fixtures/field-and-argument-changes.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn safe_changes() {
+    let input = include_str!("schema_check/fixtures/safe-changes.graphql");
+    let expected = include_str!("schema_check/fixtures/safe-changes.expected");
+    test_fixture(transform_fixture, file!(), "safe-changes.graphql", "schema_check/fixtures/safe-changes.expected", input, expected).await;
+}
@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use dependency_analyzer::SchemaUsageReport;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use schema::build_schema_with_extensions_parallel;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%schema%").collect();
+    let [document, sdl] = parts.as_slice() else {
+        panic!("Expected exactly one %schema% section marker.")
+    };
+    let (server_sdl, extension_sdl) = sdl.split_once("%extensions%").unwrap_or((sdl, ""));
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(document, source_location).unwrap();
+    let schema = Arc::new(
+        build_schema_with_extensions_parallel(
+            &[(server_sdl, source_location)],
+            &[(extension_sdl, source_location)],
+        )
+        .unwrap(),
+    );
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(schema, ir);
+
+    let report = SchemaUsageReport::from_program(&program);
+    let mut output = report.format_report();
+    output.push('\n');
+    output.push_str(SchemaUsageReport::csv_header());
+    output.push('\n');
+    report.append_csv_rows(&mut output);
+    Ok(output)
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<2890556ddff002a2e8bbb581f68208df>>
+ */
+
+mod schema_usage;
+
+use schema_usage::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn client_extension_input_objects() {
+    let input = include_str!("schema_usage/fixtures/client-extension-input-objects.graphql");
+    let expected = include_str!("schema_usage/fixtures/client-extension-input-objects.expected");
+    test_fixture(transform_fixture, file!(), "client-extension-input-objects.graphql", "schema_usage/fixtures/client-extension-input-objects.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn enum_typed_selections() {
+    let input = include_str!("schema_usage/fixtures/enum-typed-selections.graphql");
+    let expected = include_str!("schema_usage/fixtures/enum-typed-selections.expected");
+    test_fixture(transform_fixture, file!(), "enum-typed-selections.graphql", "schema_usage/fixtures/enum-typed-selections.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fields_arguments_and_enum_values() {
+    let input = include_str!("schema_usage/fixtures/fields-arguments-and-enum-values.graphql");
+    let expected = include_str!("schema_usage/fixtures/fields-arguments-and-enum-values.expected");
+    test_fixture(transform_fixture, file!(), "fields-arguments-and-enum-values.graphql", "schema_usage/fixtures/fields-arguments-and-enum-values.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn interface_field_implementations() {
+    let input = include_str!("schema_usage/fixtures/interface-field-implementations.graphql");
+    let expected = include_str!("schema_usage/fixtures/interface-field-implementations.expected");
+    test_fixture(transform_fixture, file!(), "interface-field-implementations.graphql", "schema_usage/fixtures/interface-field-implementations.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn variable_input_types() {
+    let input = include_str!("schema_usage/fixtures/variable-input-types.graphql");
+    let expected = include_str!("schema_usage/fixtures/variable-input-types.expected");
+    test_fixture(transform_fixture, file!(), "variable-input-types.graphql", "schema_usage/fixtures/variable-input-types.expected", input, expected).await;
+}
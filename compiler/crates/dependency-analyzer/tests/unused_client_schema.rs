@@ -0,0 +1,33 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use common::TextSource;
+use dependency_analyzer::UnusedClientSchemaReport;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use relay_test_schema::get_test_schema_with_located_extensions;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let [document, extensions] = parts.as_slice() else {
+        panic!("Expected exactly one %extensions% section marker.")
+    };
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let ast = parse_executable(document, source_location).unwrap();
+    let schema = get_test_schema_with_located_extensions(extensions, source_location);
+    let ir = build(&schema, &ast.definitions).unwrap();
+    let program = Program::from_definitions(schema, ir);
+
+    // Locations of the extensions are relative to the extensions section.
+    let text_source = TextSource::from_whole_document(*extensions);
+    let report = UnusedClientSchemaReport::from_program(&program);
+    Ok(report
+        .format_report(|location| Some(text_source.to_span_range(location.span()).start.line + 1)))
+}
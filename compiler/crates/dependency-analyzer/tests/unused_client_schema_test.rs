@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<387b53a22086f6191d00ac5fa675ffbb>>
+ */
+
+mod unused_client_schema;
+
+use unused_client_schema::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn all_used() {
+    let input = include_str!("unused_client_schema/fixtures/all-used.graphql");
+    let expected = include_str!("unused_client_schema/fixtures/all-used.expected");
+    test_fixture(transform_fixture, file!(), "all-used.graphql", "unused_client_schema/fixtures/all-used.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn client_extensions() {
+    let input = include_str!("unused_client_schema/fixtures/client-extensions.graphql");
+    let expected = include_str!("unused_client_schema/fixtures/client-extensions.expected");
+    test_fixture(transform_fixture, file!(), "client-extensions.graphql", "unused_client_schema/fixtures/client-extensions.expected", input, expected).await;
+}
@@ -0,0 +1,207 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Locates the JavaScript embedded in single-file components and MDX
+//! documents.
+
+use std::ops::Range;
+
+/// Formats of files that embed JavaScript, by file extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EmbeddingFormat {
+    /// Vue and Svelte components: `<script>` blocks.
+    Component,
+    /// Astro components: the `---` frontmatter and `<script>` blocks.
+    Astro,
+    /// Markdown and MDX: JavaScript code fences and MDX `import`/`export`
+    /// blocks.
+    Markdown,
+}
+
+impl EmbeddingFormat {
+    pub(crate) fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "vue" | "svelte" => Some(Self::Component),
+            "astro" => Some(Self::Astro),
+            "md" | "mdx" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    /// Byte ranges of the embedded JavaScript in `input`, in order.
+    pub(crate) fn script_ranges(self, input: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        match self {
+            Self::Component => script_blocks(input, 0, &mut ranges),
+            Self::Astro => {
+                let rest = match frontmatter(input) {
+                    Some(frontmatter) => {
+                        let end = frontmatter.end;
+                        ranges.push(frontmatter);
+                        end
+                    }
+                    None => 0,
+                };
+                script_blocks(input, rest, &mut ranges);
+            }
+            Self::Markdown => markdown_blocks(input, &mut ranges),
+        }
+        ranges
+    }
+}
+
+/// Contents of the `<script>` elements of an HTML-like document, skipping
+/// comments.
+fn script_blocks(input: &str, start: usize, ranges: &mut Vec<Range<usize>>) {
+    let mut rest = start;
+    while let Some(found) = input[rest..].find('<') {
+        let tag_start = rest + found;
+        let tag = &input[tag_start..];
+        if tag.starts_with("<!--") {
+            rest = tag
+                .find("-->")
+                .map_or(input.len(), |end| tag_start + end + 3);
+            continue;
+        }
+        if !is_script_start_tag(tag) {
+            rest = tag_start + 1;
+            continue;
+        }
+        let Some(tag_end) = tag.find('>') else {
+            return;
+        };
+        rest = tag_start + tag_end + 1;
+        if tag[..tag_end].ends_with('/') {
+            // `<script src="..." />` has no contents.
+            continue;
+        }
+        let end = input[rest..]
+            .find("</script")
+            .map_or(input.len(), |end| rest + end);
+        ranges.push(rest..end);
+        rest = end;
+    }
+}
+
+fn is_script_start_tag(tag: &str) -> bool {
+    tag.starts_with("<script")
+        && matches!(
+            tag.as_bytes().get(7),
+            Some(b'>' | b'/' | b' ' | b'\t' | b'\n' | b'\r')
+        )
+}
+
+/// The contents of the `---` fences at the start of an Astro component.
+fn frontmatter(input: &str) -> Option<Range<usize>> {
+    let mut leading_lines = lines(input).skip_while(|(_, line)| line.trim().is_empty());
+    let (_, open) = leading_lines.next()?;
+    if open.trim() != "---" {
+        return None;
+    }
+    let start = leading_lines.next()?.0;
+    let end = lines(input)
+        .skip_while(|(offset, _)| *offset < start)
+        .find(|(_, line)| line.trim() == "---")
+        .map_or(input.len(), |(offset, _)| offset);
+    Some(start..end)
+}
+
+/// JavaScript code fences, and `import`/`export` blocks of MDX documents.
+fn markdown_blocks(input: &str, ranges: &mut Vec<Range<usize>>) {
+    let mut open_fence: Option<(Fence<'_>, Option<usize>)> = None;
+    let mut esm_block: Option<usize> = None;
+    for (offset, line) in lines(input) {
+        if let Some((fence, start)) = &open_fence {
+            if fence.is_closed_by(line) {
+                if let Some(start) = *start {
+                    ranges.push(start..offset);
+                }
+                open_fence = None;
+            }
+            continue;
+        }
+        if let Some(start) = esm_block {
+            if !line.trim().is_empty() {
+                continue;
+            }
+            ranges.push(start..offset);
+            esm_block = None;
+        }
+        if let Some(fence) = Fence::open(line) {
+            let start = fence.is_javascript().then_some(offset + line.len());
+            open_fence = Some((fence, start));
+        } else if line.starts_with("import ") || line.starts_with("export ") {
+            esm_block = Some(offset);
+        }
+    }
+    if let Some((_, Some(start))) = open_fence {
+        ranges.push(start..input.len());
+    }
+    if let Some(start) = esm_block {
+        ranges.push(start..input.len());
+    }
+}
+
+/// An opening code fence of a Markdown document.
+struct Fence<'a> {
+    marker: u8,
+    length: usize,
+    info: &'a str,
+}
+
+impl<'a> Fence<'a> {
+    fn open(line: &'a str) -> Option<Self> {
+        let (marker, length, rest) = fence_marker(line)?;
+        if marker == b'`' && rest.contains('`') {
+            return None;
+        }
+        Some(Self {
+            marker,
+            length,
+            info: rest.trim(),
+        })
+    }
+
+    fn is_closed_by(&self, line: &str) -> bool {
+        fence_marker(line).is_some_and(|(marker, length, rest)| {
+            marker == self.marker && length >= self.length && rest.trim().is_empty()
+        })
+    }
+
+    fn is_javascript(&self) -> bool {
+        let language = self.info.split_whitespace().next().unwrap_or_default();
+        matches!(
+            language.to_ascii_lowercase().as_str(),
+            "js" | "jsx" | "ts" | "tsx" | "javascript" | "typescript"
+        )
+    }
+}
+
+/// The marker character, its length and the rest of a line starting with at
+/// least three backticks or tildes, indented by up to three spaces.
+fn fence_marker(line: &str) -> Option<(u8, usize, &str)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let line = &line[indent..];
+    let marker = *line.as_bytes().first()?;
+    if marker != b'`' && marker != b'~' {
+        return None;
+    }
+    let length = line.len() - line.trim_start_matches(marker as char).len();
+    (length >= 3).then(|| (marker, length, &line[length..]))
+}
+
+/// The lines of `input` with their byte offsets, including line terminators.
+fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line))
+    })
+}
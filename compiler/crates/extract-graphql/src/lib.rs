@@ -9,11 +9,15 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+mod embedded;
+
 use std::iter::Peekable;
+use std::path::Path;
 use std::str::CharIndices;
 
 use common::TextSource;
 use docblock_syntax::DocblockSource;
+use embedded::EmbeddingFormat;
 use graphql_syntax::GraphQLSource;
 
 #[derive(Clone)]
@@ -36,6 +40,34 @@ impl JavaScriptSourceFeature {
             JavaScriptSourceFeature::Docblock(docblock_source) => docblock_source.to_text_source(),
         }
     }
+
+    /// Moves the feature from an embedded script starting at `line_index` and
+    /// `column_index` to its position in the enclosing file.
+    fn offset_by(self, line_index: usize, column_index: usize) -> Self {
+        let text_source = self.text_source();
+        let column_index = if text_source.line_index == 0 {
+            text_source.column_index + column_index
+        } else {
+            text_source.column_index
+        };
+        let line_index = text_source.line_index + line_index;
+        match self {
+            JavaScriptSourceFeature::GraphQL(graphql_source) => {
+                JavaScriptSourceFeature::GraphQL(GraphQLSource::new(
+                    graphql_source.to_text_source().text,
+                    line_index,
+                    column_index,
+                ))
+            }
+            JavaScriptSourceFeature::Docblock(docblock_source) => {
+                JavaScriptSourceFeature::Docblock(DocblockSource::new(
+                    docblock_source.to_text_source().text,
+                    line_index,
+                    column_index,
+                ))
+            }
+        }
+    }
 }
 
 /// A wrapper around a peekable char iterator that tracks
@@ -250,6 +282,42 @@ pub fn extract(input: &str) -> Vec<JavaScriptSourceFeature> {
     res
 }
 
+/// Extract graphql`text` literals and Relay Resolver docblock comments from a
+/// source file. Vue, Svelte and Astro components (`.vue`, `.svelte`, `.astro`)
+/// are searched within their `<script>` blocks and Astro frontmatter,
+/// Markdown and MDX documents (`.md`, `.mdx`) within JavaScript code fences
+/// and `import`/`export` blocks. Other files are searched as JavaScript.
+pub fn extract_from_file(path: &Path, input: &str) -> Vec<JavaScriptSourceFeature> {
+    let format = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(EmbeddingFormat::from_extension);
+    let Some(format) = format else {
+        return extract(input);
+    };
+    if !input.contains("graphql") && !contains_resolver_tag(input) {
+        return Vec::new();
+    }
+    let mut position = CharReader::new(input);
+    let mut res = Vec::new();
+    for range in format.script_ranges(input) {
+        while position
+            .chars
+            .peek()
+            .is_some_and(|(index, _)| *index < range.start)
+        {
+            position.next();
+        }
+        let (line_index, column_index) = (position.line_index, position.column_index);
+        res.extend(
+            extract(&input[range])
+                .into_iter()
+                .map(|feature| feature.offset_by(line_index, column_index)),
+        );
+    }
+    res
+}
+
 pub fn consume_escaped_char(it: &mut CharReader<'_>) {
     it.next();
 }
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<3c2cb388d73f2904eee16c3b407de258>>
+ * @generated SignedSource<<8189540bbd6cf17f8153dc4d1ac671e8>>
  */
 
 mod extract;
@@ -40,6 +40,13 @@ async fn division() {
     test_fixture(transform_fixture, file!(), "division.js", "extract/fixtures/division.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn docs() {
+    let input = include_str!("extract/fixtures/docs.mdx");
+    let expected = include_str!("extract/fixtures/docs.expected");
+    test_fixture(transform_fixture, file!(), "docs.mdx", "extract/fixtures/docs.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn inline() {
     let input = include_str!("extract/fixtures/inline.js");
@@ -61,6 +68,13 @@ async fn no_graphql() {
     test_fixture(transform_fixture, file!(), "no_graphql.js", "extract/fixtures/no_graphql.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn page() {
+    let input = include_str!("extract/fixtures/page.astro");
+    let expected = include_str!("extract/fixtures/page.expected");
+    test_fixture(transform_fixture, file!(), "page.astro", "extract/fixtures/page.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn quote_in_jsx() {
     let input = include_str!("extract/fixtures/quote_in_jsx.js");
@@ -103,6 +117,13 @@ async fn slashes() {
     test_fixture(transform_fixture, file!(), "slashes.js", "extract/fixtures/slashes.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn svelte_component() {
+    let input = include_str!("extract/fixtures/svelte_component.svelte");
+    let expected = include_str!("extract/fixtures/svelte_component.expected");
+    test_fixture(transform_fixture, file!(), "svelte_component.svelte", "extract/fixtures/svelte_component.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn tabbed() {
     let input = include_str!("extract/fixtures/tabbed.js");
@@ -117,6 +138,13 @@ async fn template_literal() {
     test_fixture(transform_fixture, file!(), "template_literal.js", "extract/fixtures/template_literal.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn vue_component() {
+    let input = include_str!("extract/fixtures/vue_component.vue");
+    let expected = include_str!("extract/fixtures/vue_component.expected");
+    test_fixture(transform_fixture, file!(), "vue_component.vue", "extract/fixtures/vue_component.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn with_space() {
     let input = include_str!("extract/fixtures/with_space.js");
@@ -32,6 +32,7 @@ pub use build::build_directive;
 pub use build::build_ir as build;
 pub use build::build_ir_in_relay_mode;
 pub use build::build_ir_with_extra_features;
+pub use build::build_type_annotation;
 pub use constants::ARGUMENT_DEFINITION;
 pub use ir::*;
 pub use program::Program;
@@ -107,7 +107,8 @@ impl OperationDefinition {
     Ord,
     PartialOrd,
     Hash,
-    serde::Serialize
+    serde::Serialize,
+    serde::Deserialize
 )]
 pub enum OperationKind {
     Query,
@@ -10,6 +10,8 @@ use std::process::Output;
 
 use thiserror::Error;
 
+use crate::static_js::StaticEvalError;
+
 #[derive(Debug)]
 pub struct ConfigError {
     pub path: PathBuf,
@@ -43,4 +45,12 @@ pub enum ErrorCode {
 
     #[error("Error running node: {}", String::from_utf8_lossy(&output.stderr))]
     NodeExecuteError { output: Output },
+
+    #[error(
+        "Only configs exporting static values can be loaded without Node: {error}. Use a JSON config, or a `.js` or `.cjs` config and install Node."
+    )]
+    JsStaticEvalError { error: StaticEvalError },
+
+    #[error("Invalid config value: {error}")]
+    JsInvalidValue { error: serde_json::Error },
 }
@@ -7,6 +7,7 @@
 
 mod error;
 mod loader;
+mod static_js;
 
 use std::fmt::Display;
 use std::path::Path;
@@ -20,6 +21,7 @@ use loader::Loader;
 use loader::PackageJsonLoader;
 use loader::YamlLoader;
 use serde::Deserialize;
+pub use static_js::StaticEvalError;
 
 #[derive(Debug)]
 pub struct Config<T> {
@@ -48,6 +50,31 @@ impl Display for LoaderSource {
 }
 
 pub fn load<T>(dir: &Path, sources: &[LoaderSource]) -> Result<Option<Config<T>>, ConfigError>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    let loaders = create_loaders(sources);
+    for search_dir in dir.ancestors() {
+        if let Some(config) = load_from_loaders(search_dir, &loaders)? {
+            return Ok(Some(config));
+        }
+    }
+    Ok(None)
+}
+
+/// Like `load`, but only looks for the config in `dir` and not in its
+/// ancestors.
+pub fn load_in_dir<T>(
+    dir: &Path,
+    sources: &[LoaderSource],
+) -> Result<Option<Config<T>>, ConfigError>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+{
+    load_from_loaders(dir, &create_loaders(sources))
+}
+
+fn create_loaders<T>(sources: &[LoaderSource]) -> Vec<(String, Box<dyn Loader<T> + '_>)>
 where
     T: for<'de> Deserialize<'de> + 'static,
 {
@@ -71,30 +98,33 @@ where
             }
         }
     }
+    loaders
+}
 
-    for search_dir in dir.ancestors() {
-        for (file_name, loader) in &loaders {
-            let file_path = search_dir.join(file_name);
-            if file_path.exists() {
-                match loader.load(&file_path) {
-                    Ok(None) => {}
-                    Ok(Some(value)) => {
-                        return Ok(Some(Config {
-                            path: file_path,
-                            value,
-                        }));
-                    }
-                    Err(code) => {
-                        return Err(ConfigError {
-                            path: file_path,
-                            code,
-                        });
-                    }
-                };
-            }
+fn load_from_loaders<T>(
+    dir: &Path,
+    loaders: &[(String, Box<dyn Loader<T> + '_>)],
+) -> Result<Option<Config<T>>, ConfigError> {
+    for (file_name, loader) in loaders {
+        let file_path = dir.join(file_name);
+        if file_path.exists() {
+            match loader.load(&file_path) {
+                Ok(None) => {}
+                Ok(Some(value)) => {
+                    return Ok(Some(Config {
+                        path: file_path,
+                        value,
+                    }));
+                }
+                Err(code) => {
+                    return Err(ConfigError {
+                        path: file_path,
+                        code,
+                    });
+                }
+            };
         }
     }
-
     Ok(None)
 }
 
@@ -114,6 +144,10 @@ where
             LoaderSource::Js(format!(".{}.rc.cjs", name)),
             LoaderSource::Js(format!("{}.config.js", name)),
             LoaderSource::Js(format!("{}.config.cjs", name)),
+            LoaderSource::Js(format!("{}.config.mjs", name)),
+            LoaderSource::Js(format!("{}.config.ts", name)),
+            LoaderSource::Js(format!("{}.config.mts", name)),
+            LoaderSource::Js(format!("{}.config.cts", name)),
         ],
     )
 }
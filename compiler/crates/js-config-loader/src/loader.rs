@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::ffi::OsStr;
+use std::fs;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
@@ -14,6 +16,7 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::ErrorCode;
+use crate::static_js;
 
 pub trait Loader<T> {
     fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode>;
@@ -59,15 +62,34 @@ impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for JsonLoader {
     }
 }
 
+/// Loads JS and TS configs. The static subset of JS and TS is evaluated
+/// in-process; CommonJS configs with dynamic code are evaluated with Node
+/// when it is installed.
 pub struct JsLoader;
 impl<T: for<'de> Deserialize<'de> + 'static> Loader<T> for JsLoader {
     fn load(&self, path: &Path) -> Result<Option<T>, ErrorCode> {
-        let output = Command::new("node")
+        let source = fs::read_to_string(path).unwrap();
+        let error = match static_js::evaluate(&source) {
+            Ok(value) => {
+                return serde_json::from_value(value)
+                    .map(Some)
+                    .map_err(|error| ErrorCode::JsInvalidValue { error });
+            }
+            Err(error) => error,
+        };
+        let is_commonjs = matches!(path.extension().and_then(OsStr::to_str), Some("js" | "cjs"));
+        if !is_commonjs {
+            return Err(ErrorCode::JsStaticEvalError { error });
+        }
+
+        let Ok(output) = Command::new("node")
             .arg("-e")
             .arg(r#"process.stdout.write(JSON.stringify(require(process.argv[1])))"#)
             .arg(path)
             .output()
-            .expect("failed to execute process. Make sure you have Node installed.");
+        else {
+            return Err(ErrorCode::JsStaticEvalError { error });
+        };
 
         if output.status.success() {
             let value = serde_json::from_slice::<T>(&output.stdout);
@@ -0,0 +1,714 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Evaluation of the static subset of JS and TS config files without Node.
+//!
+//! A config file is supported when it exports, with `module.exports =`,
+//! `export default` or `export =`, a value built from object and array
+//! literals, strings, template literals without substitutions, numbers,
+//! booleans, `null` and `undefined`. Values may reference (and spread) the
+//! `const`, `let` or `var` declarations of the file. Type-only imports,
+//! type annotations of declarations and `as`/`satisfies` expressions are
+//! ignored, so typed TS configs are supported too. Anything else, like
+//! function calls, `require` or `process.env`, is dynamic code and is
+//! reported as an error.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+/// Why a config file couldn't be evaluated statically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticEvalError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for StaticEvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.message, self.line, self.column)
+    }
+}
+
+/// Evaluates the exported value of the config file `source`.
+pub fn evaluate(source: &str) -> Result<Value, StaticEvalError> {
+    let tokens = tokenize(source)?;
+    Evaluator {
+        tokens,
+        index: 0,
+        bindings: HashMap::new(),
+    }
+    .evaluate_module()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Str(String),
+    Number(Number),
+    Punct(&'static str),
+    Eof,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Ident(name) => write!(f, "`{name}`"),
+            TokenKind::Str(_) => write!(f, "string"),
+            TokenKind::Number(number) => write!(f, "`{number}`"),
+            TokenKind::Punct(punct) => write!(f, "`{punct}`"),
+            TokenKind::Eof => write!(f, "end of file"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+/// Punctuators, longest first so that `...` isn't read as three `.`.
+const PUNCTUATORS: &[&str] = &[
+    "...", "=>", "==", "!=", "&&", "||", "??", "?.", "{", "}", "[", "]", "(", ")", ",", ":", ";",
+    "=", ".", "<", ">", "+", "-", "*", "/", "%", "!", "?", "&", "|", "^", "~", "@",
+];
+
+struct Scanner<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    rest: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.chars.next()?;
+        self.rest = &self.rest[ch.len_utf8()..];
+        if ch == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(ch)
+    }
+
+    fn error(&self, message: impl Into<String>) -> StaticEvalError {
+        StaticEvalError {
+            message: message.into(),
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, StaticEvalError> {
+    let mut scanner = Scanner {
+        chars: source.chars().peekable(),
+        rest: source,
+        line: 1,
+        column: 1,
+    };
+    // A shebang line is allowed before the module.
+    if scanner.rest.starts_with("#!") {
+        while scanner.peek().is_some_and(|ch| ch != '\n') {
+            scanner.bump();
+        }
+    }
+    let mut tokens = vec![];
+    loop {
+        let (line, column) = (scanner.line, scanner.column);
+        let Some(ch) = scanner.peek() else {
+            tokens.push(Token {
+                kind: TokenKind::Eof,
+                line,
+                column,
+            });
+            return Ok(tokens);
+        };
+        let kind = if ch.is_whitespace() {
+            scanner.bump();
+            continue;
+        } else if scanner.rest.starts_with("//") {
+            while scanner.peek().is_some_and(|ch| ch != '\n') {
+                scanner.bump();
+            }
+            continue;
+        } else if scanner.rest.starts_with("/*") {
+            scanner.bump();
+            scanner.bump();
+            while !scanner.rest.starts_with("*/") {
+                if scanner.bump().is_none() {
+                    return Err(scanner.error("Unterminated comment"));
+                }
+            }
+            scanner.bump();
+            scanner.bump();
+            continue;
+        } else if ch == '"' || ch == '\'' || ch == '`' {
+            TokenKind::Str(scan_string(&mut scanner)?)
+        } else if ch.is_ascii_digit()
+            || (ch == '.' && scanner.rest[1..].starts_with(|ch: char| ch.is_ascii_digit()))
+        {
+            TokenKind::Number(scan_number(&mut scanner)?)
+        } else if ch == '_' || ch == '$' || ch.is_alphabetic() {
+            let mut name = String::new();
+            while let Some(ch) = scanner
+                .peek()
+                .filter(|ch| *ch == '_' || *ch == '$' || ch.is_alphanumeric())
+            {
+                name.push(ch);
+                scanner.bump();
+            }
+            TokenKind::Ident(name)
+        } else if let Some(punct) = PUNCTUATORS
+            .iter()
+            .find(|punct| scanner.rest.starts_with(**punct))
+        {
+            for _ in 0..punct.len() {
+                scanner.bump();
+            }
+            TokenKind::Punct(punct)
+        } else {
+            return Err(scanner.error(format!("Unexpected character `{ch}`")));
+        };
+        tokens.push(Token { kind, line, column });
+    }
+}
+
+fn scan_string(scanner: &mut Scanner<'_>) -> Result<String, StaticEvalError> {
+    let quote = scanner.bump().unwrap();
+    let mut value = String::new();
+    loop {
+        match scanner.bump() {
+            None => return Err(scanner.error("Unterminated string")),
+            Some('\n') if quote != '`' => return Err(scanner.error("Unterminated string")),
+            Some(ch) if ch == quote => return Ok(value),
+            Some('$') if quote == '`' && scanner.peek() == Some('{') => {
+                return Err(scanner.error(
+                    "Template literals with substitutions are dynamic code and are not supported",
+                ));
+            }
+            Some('\\') => match scanner.bump() {
+                None => return Err(scanner.error("Unterminated string")),
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => value.push('\r'),
+                Some('b') => value.push('\u{8}'),
+                Some('f') => value.push('\u{c}'),
+                Some('v') => value.push('\u{b}'),
+                Some('0') => value.push('\0'),
+                // A line continuation.
+                Some('\n') => {}
+                Some('u') => value.push(scan_unicode_escape(scanner)?),
+                Some(ch) => value.push(ch),
+            },
+            Some(ch) => value.push(ch),
+        }
+    }
+}
+
+fn scan_unicode_escape(scanner: &mut Scanner<'_>) -> Result<char, StaticEvalError> {
+    let mut digits = String::new();
+    if scanner.peek() == Some('{') {
+        scanner.bump();
+        while let Some(ch) = scanner.bump() {
+            if ch == '}' {
+                break;
+            }
+            digits.push(ch);
+        }
+    } else {
+        for _ in 0..4 {
+            digits.extend(scanner.bump());
+        }
+    }
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| scanner.error(format!("Invalid unicode escape `\\u{digits}`")))
+}
+
+fn scan_number(scanner: &mut Scanner<'_>) -> Result<Number, StaticEvalError> {
+    let mut text = String::new();
+    while let Some(ch) = scanner.peek() {
+        let is_exponent_sign =
+            (ch == '+' || ch == '-') && (text.ends_with('e') || text.ends_with('E'));
+        if !(ch.is_ascii_alphanumeric() || ch == '.' || ch == '_' || is_exponent_sign) {
+            break;
+        }
+        if ch != '_' {
+            text.push(ch);
+        }
+        scanner.bump();
+    }
+    let number = if let Some(hex) = text.strip_prefix("0x").or(text.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16).ok().map(Number::from)
+    } else if let Ok(integer) = text.parse::<i64>() {
+        Some(Number::from(integer))
+    } else {
+        text.parse::<f64>().ok().and_then(Number::from_f64)
+    };
+    number.ok_or_else(|| scanner.error(format!("Invalid number `{text}`")))
+}
+
+struct Evaluator {
+    tokens: Vec<Token>,
+    index: usize,
+    bindings: HashMap<String, Option<Value>>,
+}
+
+impl Evaluator {
+    fn peek(&self) -> &TokenKind {
+        &self.tokens[self.index].kind
+    }
+
+    fn peek_at(&self, offset: usize) -> &TokenKind {
+        let index = (self.index + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].kind
+    }
+
+    fn next(&mut self) -> TokenKind {
+        let kind = self.tokens[self.index].kind.clone();
+        if self.index + 1 < self.tokens.len() {
+            self.index += 1;
+        }
+        kind
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), TokenKind::Punct(p) if *p == punct)
+    }
+
+    fn is_ident(&self, name: &str) -> bool {
+        matches!(self.peek(), TokenKind::Ident(n) if n == name)
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let is_punct = self.is_punct(punct);
+        if is_punct {
+            self.next();
+        }
+        is_punct
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<(), StaticEvalError> {
+        if self.eat_punct(punct) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("expected `{punct}`")))
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> StaticEvalError {
+        let token = &self.tokens[self.index];
+        StaticEvalError {
+            message: message.into(),
+            line: token.line,
+            column: token.column,
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> StaticEvalError {
+        self.error(format!("Unexpected {}, {}", self.peek(), expected))
+    }
+
+    fn evaluate_module(mut self) -> Result<Value, StaticEvalError> {
+        let mut exported = None;
+        loop {
+            match self.peek().clone() {
+                TokenKind::Eof => break,
+                TokenKind::Punct(";") => {
+                    self.next();
+                }
+                // Directives like `'use strict'`.
+                TokenKind::Str(_) => {
+                    self.next();
+                    self.end_statement()?;
+                }
+                TokenKind::Ident(keyword) => match keyword.as_str() {
+                    "import" if matches!(self.peek_at(1), TokenKind::Ident(name) if name == "type") =>
+                    {
+                        self.skip_type_import()?;
+                    }
+                    "import" => {
+                        return Err(self.error(
+                            "Imports are dynamic code and are not supported, only `import type` is",
+                        ));
+                    }
+                    "const" | "let" | "var" => self.declaration()?,
+                    "module" => {
+                        self.next();
+                        self.expect_punct(".")?;
+                        if !self.is_ident("exports") {
+                            return Err(self.unexpected("expected `module.exports`"));
+                        }
+                        self.next();
+                        self.expect_punct("=")?;
+                        exported = Some(self.export_value()?);
+                    }
+                    "export" => {
+                        self.next();
+                        if self.is_ident("default") {
+                            self.next();
+                            exported = Some(self.export_value()?);
+                        } else if self.eat_punct("=") {
+                            exported = Some(self.export_value()?);
+                        } else if self.is_ident("type") || self.is_ident("interface") {
+                            return Err(self.error(
+                                "Type declarations are not supported, import the types instead",
+                            ));
+                        } else {
+                            self.declaration()?;
+                        }
+                    }
+                    _ => {
+                        return Err(self.error(format!(
+                            "Unsupported statement starting with `{keyword}`, only declarations and `module.exports =` or `export default` are supported"
+                        )));
+                    }
+                },
+                _ => {
+                    return Err(self.unexpected(
+                        "only declarations and `module.exports =` or `export default` are supported",
+                    ));
+                }
+            }
+        }
+        exported.ok_or_else(|| {
+            self.error("No config is exported with `module.exports =` or `export default`")
+        })
+    }
+
+    fn export_value(&mut self) -> Result<Value, StaticEvalError> {
+        let value = self.expression()?;
+        self.end_statement()?;
+        Ok(value.unwrap_or(Value::Null))
+    }
+
+    fn end_statement(&mut self) -> Result<(), StaticEvalError> {
+        if self.eat_punct(";") || matches!(self.peek(), TokenKind::Eof) {
+            return Ok(());
+        }
+        // Automatic semicolon insertion: the next statement starts on a new
+        // line.
+        let previous_line = self.tokens[self.index - 1].line;
+        if self.tokens[self.index].line > previous_line {
+            Ok(())
+        } else {
+            Err(self.unexpected("only static values are supported"))
+        }
+    }
+
+    /// Skips `import type { Config } from 'relay-compiler';`.
+    fn skip_type_import(&mut self) -> Result<(), StaticEvalError> {
+        while !matches!(self.peek(), TokenKind::Str(_)) {
+            if matches!(self.peek(), TokenKind::Eof) {
+                return Err(self.unexpected("expected the module of the type import"));
+            }
+            self.next();
+        }
+        self.next();
+        self.end_statement()
+    }
+
+    fn declaration(&mut self) -> Result<(), StaticEvalError> {
+        self.next();
+        loop {
+            let TokenKind::Ident(name) = self.next() else {
+                return Err(self.error("Destructuring declarations are not supported"));
+            };
+            if self.eat_punct(":") {
+                self.skip_type(&["="])?;
+            }
+            let value = if self.eat_punct("=") {
+                self.expression()?
+            } else {
+                None
+            };
+            self.bindings.insert(name, value);
+            if !self.eat_punct(",") {
+                return self.end_statement();
+            }
+        }
+    }
+
+    /// Skips a type annotation, up to one of `terminators` or a closing
+    /// bracket that isn't part of the type.
+    fn skip_type(&mut self, terminators: &[&str]) -> Result<(), StaticEvalError> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                TokenKind::Eof => return Err(self.unexpected("expected the end of the type")),
+                TokenKind::Punct(punct) => {
+                    let punct = *punct;
+                    if depth == 0
+                        && (terminators.contains(&punct)
+                            || [",", ";", ")", "]", "}"].contains(&punct))
+                    {
+                        return Ok(());
+                    }
+                    match punct {
+                        "{" | "[" | "(" | "<" => depth += 1,
+                        "}" | "]" | ")" | ">" => depth = depth.saturating_sub(1),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+            self.next();
+        }
+    }
+
+    /// Evaluates an expression, `None` being `undefined`.
+    fn expression(&mut self) -> Result<Option<Value>, StaticEvalError> {
+        let value = self.primary()?;
+        while self.is_ident("as") || self.is_ident("satisfies") {
+            self.next();
+            self.skip_type(&[])?;
+        }
+        if self.is_punct("(") || self.is_punct(".") || self.is_punct("?.") || self.is_punct("[") {
+            return Err(
+                self.error("Calls and member accesses are dynamic code and are not supported")
+            );
+        }
+        Ok(value)
+    }
+
+    fn primary(&mut self) -> Result<Option<Value>, StaticEvalError> {
+        match self.peek().clone() {
+            TokenKind::Punct("{") => self.object().map(Some),
+            TokenKind::Punct("[") => self.array().map(Some),
+            TokenKind::Punct("(") => {
+                self.next();
+                let value = self.expression()?;
+                self.expect_punct(")")?;
+                Ok(value)
+            }
+            TokenKind::Punct("-") => {
+                self.next();
+                match self.next() {
+                    TokenKind::Number(number) => {
+                        let negated = if let Some(integer) = number.as_i64() {
+                            Number::from(-integer)
+                        } else {
+                            Number::from_f64(-number.as_f64().unwrap_or_default())
+                                .ok_or_else(|| self.error("Invalid number"))?
+                        };
+                        Ok(Some(Value::Number(negated)))
+                    }
+                    _ => Err(self.error("Only numbers can be negated")),
+                }
+            }
+            TokenKind::Str(value) => {
+                self.next();
+                Ok(Some(Value::String(value)))
+            }
+            TokenKind::Number(number) => {
+                self.next();
+                Ok(Some(Value::Number(number)))
+            }
+            TokenKind::Ident(name) => {
+                let value = match name.as_str() {
+                    "true" => Some(Value::Bool(true)),
+                    "false" => Some(Value::Bool(false)),
+                    "null" => Some(Value::Null),
+                    "undefined" => None,
+                    "require" => {
+                        return Err(self.error("`require` is dynamic code and is not supported"));
+                    }
+                    "function" | "async" | "class" | "new" => {
+                        return Err(
+                            self.error(format!("`{name}` is dynamic code and is not supported"))
+                        );
+                    }
+                    _ => match self.bindings.get(&name) {
+                        Some(value) => value.clone(),
+                        None => {
+                            return Err(self.error(format!(
+                                "`{name}` is not a constant declared in the config file, dynamic code is not supported"
+                            )));
+                        }
+                    },
+                };
+                self.next();
+                Ok(value)
+            }
+            _ => Err(self.unexpected("expected a static value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, StaticEvalError> {
+        self.expect_punct("{")?;
+        let mut object = Map::new();
+        while !self.eat_punct("}") {
+            if self.eat_punct("...") {
+                match self.expression()? {
+                    Some(Value::Object(spread)) => object.extend(spread),
+                    None | Some(Value::Null) => {}
+                    Some(_) => return Err(self.error("Only objects can be spread in an object")),
+                }
+            } else {
+                let key = match self.next() {
+                    TokenKind::Ident(key) | TokenKind::Str(key) => key,
+                    TokenKind::Number(number) => number.to_string(),
+                    TokenKind::Punct("[") => {
+                        return Err(self.error("Computed keys are not supported"));
+                    }
+                    _ => {
+                        self.index -= 1;
+                        return Err(self.unexpected("expected a key"));
+                    }
+                };
+                let value = if self.eat_punct(":") {
+                    self.expression()?
+                } else if self.is_punct(",") || self.is_punct("}") {
+                    // A shorthand property.
+                    self.index -= 1;
+                    self.primary()?
+                } else if self.is_punct("(") {
+                    return Err(self.error("Methods are dynamic code and are not supported"));
+                } else {
+                    return Err(self.unexpected("expected `:`"));
+                };
+                match value {
+                    Some(value) => {
+                        object.insert(key, value);
+                    }
+                    None => {
+                        object.remove(&key);
+                    }
+                }
+            }
+            if !self.eat_punct(",") {
+                self.expect_punct("}")?;
+                break;
+            }
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn array(&mut self) -> Result<Value, StaticEvalError> {
+        self.expect_punct("[")?;
+        let mut array = vec![];
+        while !self.eat_punct("]") {
+            if self.eat_punct("...") {
+                match self.expression()? {
+                    Some(Value::Array(spread)) => array.extend(spread),
+                    _ => return Err(self.error("Only arrays can be spread in an array")),
+                }
+            } else {
+                array.push(self.expression()?.unwrap_or(Value::Null));
+            }
+            if !self.eat_punct(",") {
+                self.expect_punct("]")?;
+                break;
+            }
+        }
+        Ok(Value::Array(array))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn evaluates_typed_config() {
+        let source = r#"
+            // @ts-check
+            import type {Config} from 'relay-compiler';
+
+            const common = {language: 'typescript', noFutureProofEnums: true};
+            const excludes: Array<string> = ["**/node_modules/**", '**/__mocks__/**'];
+
+            const config: Config = {
+              ...common,
+              src: './src',
+              schema: `./schema.graphql`,
+              excludes: [...excludes, "**/__generated__/**"],
+              customScalarTypes: {'URL': 'string', 'Int64': "number"},
+              persistConfig: undefined,
+              eagerEsModules: true,
+            };
+
+            export default config satisfies Config;
+        "#;
+        assert_eq!(
+            evaluate(source).unwrap(),
+            json!({
+                "language": "typescript",
+                "noFutureProofEnums": true,
+                "src": "./src",
+                "schema": "./schema.graphql",
+                "excludes": ["**/node_modules/**", "**/__mocks__/**", "**/__generated__/**"],
+                "customScalarTypes": {"URL": "string", "Int64": "number"},
+                "eagerEsModules": true,
+            })
+        );
+    }
+
+    #[test]
+    fn evaluates_commonjs_config() {
+        let source = r#"
+            'use strict';
+            /**
+             * @type {import('relay-compiler').Config}
+             */
+            module.exports = {
+              src: "./src",
+              schema: "./schema.graphql",
+              featureFlags: {max_depth: 1e2, ratio: -0.5,},
+            }
+        "#;
+        assert_eq!(
+            evaluate(source).unwrap(),
+            json!({
+                "src": "./src",
+                "schema": "./schema.graphql",
+                "featureFlags": {"max_depth": 100.0, "ratio": -0.5},
+            })
+        );
+    }
+
+    #[test]
+    fn reports_dynamic_code() {
+        let error = |source: &str| evaluate(source).unwrap_err().to_string();
+        assert_eq!(
+            error("module.exports = require('./base');"),
+            "`require` is dynamic code and is not supported at 1:18"
+        );
+        assert_eq!(
+            error("module.exports = {\n  src: process.env.SRC,\n};"),
+            "`process` is not a constant declared in the config file, dynamic code is not supported at 2:8"
+        );
+        assert_eq!(
+            error("const path = 'src';\nexport default {src: `./${path}`};"),
+            "Template literals with substitutions are dynamic code and are not supported at 2:26"
+        );
+        assert_eq!(
+            error("import base from './base';\nexport default base;"),
+            "Imports are dynamic code and are not supported, only `import type` is at 1:1"
+        );
+        assert_eq!(
+            error("const schema = 'schema.graphql';"),
+            "No config is exported with `module.exports =` or `export default` at 1:33"
+        );
+    }
+}
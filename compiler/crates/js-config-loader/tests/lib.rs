@@ -173,6 +173,122 @@ fn config_js_invalid_js() {
     }
 }
 
+#[test]
+fn config_cjs() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    let dir_f = dir.path().join("a/b/c/d/e/f");
+    create_dir_all(&dir_f).unwrap();
+
+    std::fs::write(
+        dir_d.join("foo.config.cjs"),
+        r#"
+        'use strict';
+        const name = 'correct';
+        module.exports = { name };
+        "#,
+    )
+    .unwrap();
+
+    let config = search::<TestConfig>("foo", &dir_f).unwrap().unwrap();
+    assert_eq!(config.value.name, "correct");
+    assert_eq!(config.path.file_name().unwrap(), "foo.config.cjs");
+}
+
+#[test]
+fn config_mjs() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    let dir_f = dir.path().join("a/b/c/d/e/f");
+    create_dir_all(&dir_f).unwrap();
+
+    std::fs::write(
+        dir_d.join("foo.config.mjs"),
+        r#"
+        /** @type {import('foo').Config} */
+        export default {
+          name: "correct",
+        };
+        "#,
+    )
+    .unwrap();
+
+    let config = search::<TestConfig>("foo", &dir_f).unwrap().unwrap();
+    assert_eq!(config.value.name, "correct");
+    assert_eq!(config.path.file_name().unwrap(), "foo.config.mjs");
+}
+
+#[test]
+fn config_ts() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    let dir_f = dir.path().join("a/b/c/d/e/f");
+    create_dir_all(&dir_f).unwrap();
+
+    std::fs::write(
+        dir_d.join("foo.config.ts"),
+        r#"
+        import type {Config} from 'foo';
+
+        const config: Config = {
+          name: `correct`,
+        };
+
+        export default config satisfies Config;
+        "#,
+    )
+    .unwrap();
+
+    let config = search::<TestConfig>("foo", &dir_f).unwrap().unwrap();
+    assert_eq!(config.value.name, "correct");
+    assert_eq!(config.path.file_name().unwrap(), "foo.config.ts");
+}
+
+#[test]
+fn config_ts_dynamic() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    let dir_f = dir.path().join("a/b/c/d/e/f");
+    create_dir_all(&dir_f).unwrap();
+
+    std::fs::write(
+        dir_d.join("foo.config.ts"),
+        "export default {\n  name: process.env.NAME,\n};\n",
+    )
+    .unwrap();
+
+    match search::<TestConfig>("foo", &dir_f).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsStaticEvalError { error },
+            path,
+        } => {
+            assert_eq!(path.file_name().unwrap(), "foo.config.ts");
+            assert_eq!((error.line, error.column), (2, 9));
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
+#[test]
+fn config_ts_invalid_value() {
+    let dir = tempdir().unwrap();
+    let dir_d = dir.path().join("a/b/c/d");
+    let dir_f = dir.path().join("a/b/c/d/e/f");
+    create_dir_all(&dir_f).unwrap();
+
+    std::fs::write(dir_d.join("foo.config.ts"), "export default {name: 1};").unwrap();
+
+    match search::<TestConfig>("foo", &dir_f).unwrap_err() {
+        ConfigError {
+            code: ErrorCode::JsInvalidValue { error },
+            path: _,
+        } => {
+            assert!(error.is_data());
+        }
+        other => panic!("incorrect error: {:?}", other),
+    }
+}
+
 #[test]
 fn unsupported_file() {
     let dir = tempdir().unwrap();
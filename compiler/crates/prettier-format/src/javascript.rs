@@ -0,0 +1,750 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Prettier-compatible formatting of generated JavaScript and TypeScript.
+//!
+//! The formatter doesn't parse JavaScript, it reflows the bracketed groups
+//! (`{}`, `{||}`, `[]` and `()`) of the code with Prettier's algorithm:
+//! a group is printed on one line when it fits in the print width, and
+//! otherwise with one element per line. Like in Prettier, objects that
+//! start with a line break stay expanded. Strings are requoted with the
+//! preferred quotes, and trailing commas are added to or removed from
+//! expanded groups. Line breaks inside of the elements of a group, like
+//! between statements, are kept, so code without brackets isn't reflowed.
+//! This is enough for the code generated by Relay, which only needs
+//! reformatting, and not arbitrary JavaScript.
+
+/// Where to print trailing commas in expanded groups, like Prettier's
+/// `trailingComma`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrailingComma {
+    /// In arrays, objects, imports and exports, and function parameters and
+    /// arguments.
+    All,
+    /// In arrays, objects, imports and exports.
+    Es5,
+    /// Nowhere.
+    None,
+}
+
+/// The subset of Prettier options supported by [`format_javascript`].
+#[derive(Clone, Debug)]
+pub struct JavaScriptFormatOptions {
+    pub print_width: usize,
+    pub single_quote: bool,
+    pub trailing_comma: TrailingComma,
+}
+
+impl Default for JavaScriptFormatOptions {
+    fn default() -> Self {
+        Self {
+            print_width: 80,
+            single_quote: false,
+            trailing_comma: TrailingComma::All,
+        }
+    }
+}
+
+/// Format generated JavaScript, Flow or TypeScript code with Prettier's
+/// layout for the given options.
+pub fn format_javascript(source: &str, options: &JavaScriptFormatOptions) -> String {
+    let tokens = tokenize(source, options);
+    let nodes = parse_nodes(tokens);
+    let mut docs = vec![];
+    sequence_docs(&nodes, options, &mut docs);
+    let mut output = Printer::new(options.print_width).print(&docs);
+    if source.ends_with('\n') {
+        output.push('\n');
+    }
+    output
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TokenKind {
+    Word,
+    Str,
+    BlockComment,
+    LineComment,
+    Open,
+    Close,
+    Comma,
+    Semicolon,
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    space_before: bool,
+    newlines_before: usize,
+}
+
+fn tokenize(source: &str, options: &JavaScriptFormatOptions) -> Vec<Token> {
+    let bytes = source.as_bytes();
+    let mut tokens = vec![];
+    let mut index = 0;
+    let mut space_before = false;
+    let mut newlines_before = 0;
+    let mut open_exact_objects = 0usize;
+    while index < bytes.len() {
+        let rest = &source[index..];
+        let ch = bytes[index];
+        if ch.is_ascii_whitespace() {
+            space_before = true;
+            if ch == b'\n' {
+                newlines_before += 1;
+            }
+            index += 1;
+            continue;
+        }
+        let (kind, length) = if rest.starts_with("//") {
+            (
+                TokenKind::LineComment,
+                rest.find('\n').unwrap_or(rest.len()),
+            )
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            (
+                TokenKind::BlockComment,
+                body.find("*/").map_or(rest.len(), |end| end + 4),
+            )
+        } else if ch == b'"' || ch == b'\'' {
+            (TokenKind::Str, string_length(rest))
+        } else if ch == b'`' {
+            (TokenKind::Word, template_length(rest))
+        } else if rest.starts_with("{|") {
+            open_exact_objects += 1;
+            (TokenKind::Open, 2)
+        } else if rest.starts_with("|}") && open_exact_objects > 0 {
+            open_exact_objects -= 1;
+            (TokenKind::Close, 2)
+        } else if matches!(ch, b'{' | b'[' | b'(') {
+            (TokenKind::Open, 1)
+        } else if matches!(ch, b'}' | b']' | b')') {
+            (TokenKind::Close, 1)
+        } else if ch == b',' {
+            (TokenKind::Comma, 1)
+        } else if ch == b';' {
+            (TokenKind::Semicolon, 1)
+        } else {
+            let length = rest
+                .char_indices()
+                .find(|(offset, ch)| {
+                    ch.is_whitespace()
+                        || "{}[](),;\"'`".contains(*ch)
+                        || rest[*offset..].starts_with("//")
+                        || rest[*offset..].starts_with("/*")
+                        || (rest[*offset..].starts_with("|}") && open_exact_objects > 0)
+                })
+                .map_or(rest.len(), |(offset, _)| offset);
+            (TokenKind::Word, length)
+        };
+        let text = &rest[..length];
+        tokens.push(Token {
+            kind,
+            text: if kind == TokenKind::Str {
+                requote(text, options.single_quote)
+            } else {
+                text.to_string()
+            },
+            space_before,
+            newlines_before,
+        });
+        space_before = false;
+        newlines_before = 0;
+        index += length;
+    }
+    tokens
+}
+
+/// The length of the string literal at the start of `source`.
+fn string_length(source: &str) -> usize {
+    let quote = source.as_bytes()[0];
+    let mut escaped = false;
+    for (offset, ch) in source.bytes().enumerate().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if ch == b'\\' {
+            escaped = true;
+        } else if ch == quote || ch == b'\n' {
+            return offset + 1;
+        }
+    }
+    source.len()
+}
+
+/// The length of the template literal at the start of `source`, including
+/// its substitutions.
+fn template_length(source: &str) -> usize {
+    let mut escaped = false;
+    let mut depth = 0usize;
+    for (offset, ch) in source.bytes().enumerate().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if ch == b'\\' {
+            escaped = true;
+        } else if depth == 0 && ch == b'`' {
+            return offset + 1;
+        } else if ch == b'{' && (depth > 0 || source.as_bytes()[offset - 1] == b'$') {
+            depth += 1;
+        } else if depth > 0 && ch == b'}' {
+            depth -= 1;
+        }
+    }
+    source.len()
+}
+
+/// Requote the string literal `literal` with the preferred quotes, unless
+/// the other quotes need fewer escapes, like Prettier.
+fn requote(literal: &str, single_quote: bool) -> String {
+    let quote = literal.chars().next().unwrap();
+    let Some(content) = literal[1..].strip_suffix(quote) else {
+        return literal.to_string();
+    };
+    let mut chars = content.chars();
+    let mut unescaped = vec![];
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            match chars.next() {
+                Some(next @ ('"' | '\'')) => unescaped.push((next, false)),
+                Some(next) => {
+                    unescaped.push(('\\', true));
+                    unescaped.push((next, true));
+                }
+                None => unescaped.push(('\\', true)),
+            }
+        } else {
+            unescaped.push((ch, false));
+        }
+    }
+    let double_quotes = unescaped.iter().filter(|(ch, _)| *ch == '"').count();
+    let single_quotes = unescaped.iter().filter(|(ch, _)| *ch == '\'').count();
+    let quote = match (single_quote, double_quotes.cmp(&single_quotes)) {
+        (true, std::cmp::Ordering::Less) => '"',
+        (true, _) => '\'',
+        (false, std::cmp::Ordering::Greater) => '\'',
+        (false, _) => '"',
+    };
+    let mut requoted = String::with_capacity(literal.len());
+    requoted.push(quote);
+    for (ch, is_escape) in unescaped {
+        if ch == quote && !is_escape {
+            requoted.push('\\');
+        }
+        requoted.push(ch);
+    }
+    requoted.push(quote);
+    requoted
+}
+
+#[derive(Debug)]
+enum Node {
+    Token(Token),
+    Group {
+        open: Token,
+        children: Vec<Node>,
+        close: Option<Token>,
+    },
+}
+
+impl Node {
+    fn first_token(&self) -> &Token {
+        match self {
+            Node::Token(token) => token,
+            Node::Group { open, .. } => open,
+        }
+    }
+
+    fn kind(&self) -> Option<TokenKind> {
+        match self {
+            Node::Token(token) => Some(token.kind),
+            Node::Group { .. } => None,
+        }
+    }
+}
+
+fn parse_nodes(tokens: Vec<Token>) -> Vec<Node> {
+    let mut stack: Vec<(Token, Vec<Node>)> = vec![];
+    let mut nodes = vec![];
+    for token in tokens {
+        match token.kind {
+            TokenKind::Open => stack.push((token, std::mem::take(&mut nodes))),
+            TokenKind::Close => match stack.pop() {
+                Some((open, parent_nodes)) => {
+                    let children = std::mem::replace(&mut nodes, parent_nodes);
+                    nodes.push(Node::Group {
+                        open,
+                        children,
+                        close: Some(token),
+                    });
+                }
+                None => nodes.push(Node::Token(Token {
+                    kind: TokenKind::Word,
+                    ..token
+                })),
+            },
+            _ => nodes.push(Node::Token(token)),
+        }
+    }
+    while let Some((open, parent_nodes)) = stack.pop() {
+        let children = std::mem::replace(&mut nodes, parent_nodes);
+        nodes.push(Node::Group {
+            open,
+            children,
+            close: None,
+        });
+    }
+    nodes
+}
+
+#[derive(Debug)]
+enum Doc {
+    Text(String),
+    /// A space, or a line break when the group breaks.
+    Line,
+    /// Nothing, or a line break when the group breaks.
+    SoftLine,
+    HardLine,
+    Indent(Vec<Doc>),
+    Group {
+        docs: Vec<Doc>,
+        should_break: bool,
+    },
+    /// Text printed only when the group breaks.
+    IfBreak(&'static str),
+}
+
+/// Append the docs of a sequence of nodes, keeping the line breaks between
+/// them. Returns whether the sequence contains a line break.
+fn sequence_docs(nodes: &[Node], options: &JavaScriptFormatOptions, docs: &mut Vec<Doc>) -> bool {
+    let mut has_line_break = false;
+    for (index, node) in nodes.iter().enumerate() {
+        let token = node.first_token();
+        if index > 0 {
+            if token.newlines_before > 0 {
+                docs.push(Doc::HardLine);
+                if token.newlines_before > 1 {
+                    docs.push(Doc::HardLine);
+                }
+                has_line_break = true;
+            } else if token.space_before {
+                docs.push(Doc::Text(" ".to_string()));
+            }
+        }
+        let previous = index.checked_sub(1).map(|index| &nodes[index]);
+        has_line_break |= node_docs(node, previous, options, docs);
+        if node.kind() == Some(TokenKind::LineComment) {
+            has_line_break = true;
+        }
+    }
+    has_line_break
+}
+
+/// Append the docs of a node. Returns whether it contains a line break.
+fn node_docs(
+    node: &Node,
+    previous: Option<&Node>,
+    options: &JavaScriptFormatOptions,
+    docs: &mut Vec<Doc>,
+) -> bool {
+    let (open, children, close) = match node {
+        Node::Token(token) => {
+            docs.push(Doc::Text(token.text.clone()));
+            return token.kind == TokenKind::BlockComment && token.text.contains('\n');
+        }
+        Node::Group {
+            open,
+            children,
+            close,
+        } => (open, children, close),
+    };
+    let close_text = close.as_ref().map_or("", |close| close.text.as_str());
+    if children.is_empty() {
+        docs.push(Doc::Text(format!("{}{}", open.text, close_text)));
+        return false;
+    }
+
+    let is_object = open.text.starts_with('{');
+    let separator = if children
+        .iter()
+        .any(|child| child.kind() == Some(TokenKind::Comma))
+    {
+        Some(TokenKind::Comma)
+    } else if children
+        .iter()
+        .any(|child| child.kind() == Some(TokenKind::Semicolon))
+    {
+        Some(TokenKind::Semicolon)
+    } else {
+        None
+    };
+    let mut elements: Vec<&[Node]> = match separator {
+        Some(separator) => children
+            .split(|child| child.kind() == Some(separator))
+            .collect(),
+        None => vec![children],
+    };
+    let has_trailing_separator = elements.len() > 1 && elements.last().unwrap().is_empty();
+    if has_trailing_separator {
+        elements.pop();
+    }
+    if elements.iter().any(|element| element.is_empty()) {
+        // Holes, like in `[a, , b]`, are printed as they are.
+        elements = vec![children];
+    }
+
+    let mut has_line_break = false;
+    let mut element_docs = vec![];
+    for (index, element) in elements.iter().enumerate() {
+        if index > 0 {
+            let separator = if separator == Some(TokenKind::Comma) {
+                ","
+            } else {
+                ";"
+            };
+            element_docs.push(Doc::Text(separator.to_string()));
+            element_docs.push(Doc::Line);
+            if element[0].first_token().newlines_before > 1 {
+                element_docs.push(Doc::SoftLine);
+                has_line_break = true;
+            }
+        }
+        has_line_break |= sequence_docs(element, options, &mut element_docs);
+    }
+    let ends_with_line_comment =
+        elements.last().unwrap().last().unwrap().kind() == Some(TokenKind::LineComment);
+    has_line_break |= ends_with_line_comment;
+
+    // Like in Prettier, objects with a line break after `{` stay expanded.
+    let should_break =
+        has_line_break || (is_object && children[0].first_token().newlines_before > 0);
+
+    if elements.len() == 1 && !has_trailing_separator && open.text == "(" {
+        docs.push(Doc::Text(open.text.clone()));
+        docs.append(&mut element_docs);
+        docs.push(Doc::Text(close_text.to_string()));
+        return has_line_break;
+    }
+
+    // A single element of an array or object is followed by a comma when
+    // the group breaks, but not a single statement of a block.
+    let separator = separator.or_else(|| {
+        let element = elements[0];
+        let is_item = open.text == "["
+            || (is_object
+                && (element.len() == 1
+                    || element.iter().any(|node| {
+                        node.kind() == Some(TokenKind::Word)
+                            && (node.first_token().text.ends_with(':')
+                                || node.first_token().text.starts_with("..."))
+                    })));
+        is_item.then_some(TokenKind::Comma)
+    });
+    let trailing_separator = match separator {
+        Some(TokenKind::Comma) => {
+            let is_call = is_call_arguments(previous, open);
+            let is_rest = elements
+                .last()
+                .unwrap()
+                .first()
+                .is_some_and(|node| node.first_token().text.starts_with("..."));
+            let allowed = match options.trailing_comma {
+                TrailingComma::All => is_object || open.text == "[" || (is_call && !is_rest),
+                TrailingComma::Es5 => is_object || open.text == "[",
+                TrailingComma::None => false,
+            };
+            (allowed && !ends_with_line_comment).then_some(Doc::IfBreak(","))
+        }
+        Some(_) if has_trailing_separator => Some(Doc::IfBreak(";")),
+        _ => None,
+    };
+    element_docs.extend(trailing_separator);
+
+    let line = || if is_object { Doc::Line } else { Doc::SoftLine };
+    let mut indented = vec![line()];
+    indented.append(&mut element_docs);
+    docs.push(Doc::Group {
+        docs: vec![
+            Doc::Text(open.text.clone()),
+            Doc::Indent(indented),
+            if ends_with_line_comment {
+                Doc::HardLine
+            } else {
+                line()
+            },
+            Doc::Text(close_text.to_string()),
+        ],
+        should_break,
+    });
+    should_break
+}
+
+/// Whether the group opened by `open` holds the parameters or arguments of a
+/// function, which only get trailing commas with `TrailingComma::All`.
+fn is_call_arguments(previous: Option<&Node>, open: &Token) -> bool {
+    if open.text != "(" || open.space_before {
+        return false;
+    }
+    match previous {
+        Some(Node::Token(token)) => {
+            token.kind == TokenKind::Word
+                && token
+                    .text
+                    .ends_with(|ch: char| ch.is_alphanumeric() || ch == '_' || ch == '$')
+                && !matches!(
+                    token.text.as_str(),
+                    "if" | "for" | "while" | "switch" | "catch" | "return" | "typeof" | "as"
+                )
+        }
+        Some(Node::Group { .. }) => true,
+        None => false,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Break,
+    Flat,
+}
+
+struct Printer {
+    width: usize,
+    output: String,
+    column: usize,
+}
+
+impl Printer {
+    fn new(width: usize) -> Self {
+        Self {
+            width,
+            output: String::new(),
+            column: 0,
+        }
+    }
+
+    fn print(mut self, docs: &[Doc]) -> String {
+        let mut stack: Vec<(usize, Mode, &Doc)> =
+            docs.iter().rev().map(|doc| (0, Mode::Break, doc)).collect();
+        while let Some((indent, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Text(text) => self.text(text),
+                Doc::Line if mode == Mode::Flat => self.text(" "),
+                Doc::SoftLine if mode == Mode::Flat => {}
+                Doc::Line | Doc::SoftLine | Doc::HardLine => self.newline(indent),
+                Doc::Indent(docs) => {
+                    stack.extend(docs.iter().rev().map(|doc| (indent + 2, mode, doc)));
+                }
+                Doc::Group { docs, should_break } => {
+                    let mode = if *should_break {
+                        Mode::Break
+                    } else if mode == Mode::Flat || self.fits((indent, Mode::Flat, doc), &stack) {
+                        Mode::Flat
+                    } else {
+                        Mode::Break
+                    };
+                    stack.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+                }
+                Doc::IfBreak(text) => {
+                    if mode == Mode::Break {
+                        self.text(text);
+                    }
+                }
+            }
+        }
+        let trimmed_length = self.output.trim_end().len();
+        self.output.truncate(trimmed_length);
+        self.output
+    }
+
+    fn text(&mut self, text: &str) {
+        self.output.push_str(text);
+        match text.rfind('\n') {
+            Some(index) => self.column = text[index + 1..].chars().count(),
+            None => self.column += text.chars().count(),
+        }
+    }
+
+    fn newline(&mut self, indent: usize) {
+        let trimmed_length = self.output.trim_end_matches(' ').len();
+        self.output.truncate(trimmed_length);
+        self.output.push('\n');
+        self.output.extend(std::iter::repeat_n(' ', indent));
+        self.column = indent;
+    }
+
+    /// Whether `next` fits in flat mode on the rest of the current line,
+    /// together with what follows it up to the next possible line break.
+    fn fits(&self, next: (usize, Mode, &Doc), rest: &[(usize, Mode, &Doc)]) -> bool {
+        let mut remaining = self.width as isize - self.column as isize;
+        let mut commands = vec![(next.1, next.2)];
+        let mut rest_index = rest.len();
+        loop {
+            if remaining < 0 {
+                return false;
+            }
+            let Some((mode, doc)) = commands.pop() else {
+                if rest_index == 0 {
+                    return true;
+                }
+                rest_index -= 1;
+                commands.push((rest[rest_index].1, rest[rest_index].2));
+                continue;
+            };
+            match doc {
+                Doc::Text(text) => {
+                    if let Some(index) = text.find('\n') {
+                        return remaining >= text[..index].chars().count() as isize;
+                    }
+                    remaining -= text.chars().count() as isize;
+                }
+                Doc::Line | Doc::SoftLine if mode == Mode::Break => return true,
+                Doc::Line => remaining -= 1,
+                Doc::SoftLine => {}
+                Doc::HardLine => return true,
+                Doc::Indent(docs) => commands.extend(docs.iter().rev().map(|doc| (mode, doc))),
+                Doc::Group { docs, should_break } => {
+                    let mode = if *should_break { Mode::Break } else { mode };
+                    commands.extend(docs.iter().rev().map(|doc| (mode, doc)));
+                }
+                Doc::IfBreak(text) => {
+                    if mode == Mode::Break {
+                        remaining -= text.len() as isize;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(source: &str, options: JavaScriptFormatOptions) -> String {
+        format_javascript(source, &options)
+    }
+
+    #[test]
+    fn reflows_groups_to_the_print_width() {
+        let source = r#"import {ReaderFragment} from 'relay-runtime';
+
+var node = {
+  "argumentDefinitions": [],
+  "path": [
+    "viewer",
+    "actor"
+  ],
+  "selections": [
+    {
+      "kind": "ScalarField",
+      "name": "id"
+    }
+  ],
+  "text": "query Q {\n  id\n}\n",
+  "args": f(a, b)
+};
+"#;
+        assert_eq!(
+            format(source, JavaScriptFormatOptions::default()),
+            r#"import { ReaderFragment } from "relay-runtime";
+
+var node = {
+  "argumentDefinitions": [],
+  "path": ["viewer", "actor"],
+  "selections": [
+    {
+      "kind": "ScalarField",
+      "name": "id",
+    },
+  ],
+  "text": "query Q {\n  id\n}\n",
+  "args": f(a, b),
+};
+"#
+        );
+        assert_eq!(
+            format(
+                source,
+                JavaScriptFormatOptions {
+                    print_width: 20,
+                    single_quote: true,
+                    trailing_comma: TrailingComma::None,
+                }
+            ),
+            r#"import {
+  ReaderFragment
+} from 'relay-runtime';
+
+var node = {
+  'argumentDefinitions': [],
+  'path': [
+    'viewer',
+    'actor'
+  ],
+  'selections': [
+    {
+      'kind': 'ScalarField',
+      'name': 'id'
+    }
+  ],
+  'text': 'query Q {\n  id\n}\n',
+  'args': f(a, b)
+};
+"#
+        );
+    }
+
+    #[test]
+    fn keeps_comments_and_types() {
+        let source = r#"/**
+ * @flow
+ */
+
+// A comment.
+export type Q$data = {|
+  +viewer: ?{|
+    +name: ?string,
+  |},
+|};
+
+module.exports = ((node/*: any*/)/*: Query<
+  Q$variables,
+  Q$data,
+>*/);
+"#;
+        assert_eq!(
+            format(
+                source,
+                JavaScriptFormatOptions {
+                    trailing_comma: TrailingComma::Es5,
+                    ..Default::default()
+                }
+            ),
+            source
+        );
+        assert_eq!(
+            format(
+                source,
+                JavaScriptFormatOptions {
+                    trailing_comma: TrailingComma::None,
+                    ..Default::default()
+                }
+            ),
+            source
+                .replace("?string,", "?string")
+                .replace("|},\n|}", "|}\n|}")
+        );
+    }
+
+    #[test]
+    fn requotes_strings_with_fewest_escapes() {
+        assert_eq!(requote(r#"'a'"#, false), r#""a""#);
+        assert_eq!(requote(r#"'a"b'"#, false), r#"'a"b'"#);
+        assert_eq!(requote(r#""it's""#, true), r#""it's""#);
+        assert_eq!(requote(r#""a\'b\n""#, true), r#""a'b\n""#);
+        assert_eq!(requote(r#"'\\'"#, false), r#""\\""#);
+    }
+}
@@ -9,13 +9,19 @@
 //!
 //! This module provides functionality to format GraphQL source files
 //! and JavaScript files containing graphql template literals using
-//! the Rust prettier-style printing utilities.
+//! the Rust prettier-style printing utilities, and generated JavaScript and
+//! TypeScript code.
+
+mod javascript;
 
 use common::SourceLocationKey;
 use extract_graphql::JavaScriptSourceFeature;
 use extract_graphql::extract;
 use graphql_syntax::parse_document;
 use graphql_text_printer::prettier_print_document;
+pub use javascript::JavaScriptFormatOptions;
+pub use javascript::TrailingComma;
+pub use javascript::format_javascript;
 
 /// Format a GraphQL source string using prettier-compatible formatting.
 ///
@@ -24,6 +24,27 @@ pub enum Error {
     #[error("Unable to run relay codemod. Error details: \n{details}")]
     CodemodError { details: String },
 
+    #[error("Unable to produce the report. Error details: \n{details}")]
+    ReportFailed { details: String },
+
+    #[error("Unable to convert the schema to a flatbuffer. Error details: \n{details}")]
+    SchemaFlatBufferFailed { details: String },
+
+    #[error("Unable to compose the subgraph schemas:\n{details}")]
+    SchemaComposeFailed { details: String },
+
+    #[error("Unable to resolve schema coordinates:\n{details}")]
+    SchemaCoordinateFailed { details: String },
+
+    #[error("Operations grew by more than the allowed amount:\n{details}")]
+    StatsRegression { details: String },
+
+    #[error("The candidate schema has breaking changes for projects:\n{details}")]
+    BreakingSchemaChanges { details: String },
+
+    #[error("The schema isn't a subset of the service schema for projects:\n{details}")]
+    SubsetSchemaViolations { details: String },
+
     #[cfg(unix)]
     #[error("Daemon command failed")]
     DaemonCommandFailed,
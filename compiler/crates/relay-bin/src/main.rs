@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::collections::BTreeMap;
 use std::env;
 use std::env::current_dir;
 #[cfg(unix)]
@@ -16,30 +17,49 @@ use std::sync::Arc;
 use clap::Parser;
 use clap::ValueEnum;
 use common::ConsoleLogger;
+use common::PerfLogger;
+use common::SourceLocationKey;
+use common::Span;
+use common::TextSource;
+use common::TraceLogger;
+use dependency_analyzer::QueryStatsReport;
+use dependency_analyzer::SchemaUsageOptions;
+use dependency_analyzer::SchemaUsageReport;
+use dependency_analyzer::UnusedClientSchemaReport;
+use dependency_analyzer::compute_program_query_stats;
 use intern::string_key::Intern;
 use log::error;
 use log::info;
 use relay_codemod::AvailableCodemod;
 use relay_codemod::run_codemod;
+use relay_compiler::ArtifactCacheConfig;
 #[cfg(unix)]
 use relay_compiler::DeferredArtifactCache;
 #[cfg(unix)]
 use relay_compiler::DeferredArtifactWriter;
 use relay_compiler::FileSourceKind;
+use relay_compiler::FsSourceReader;
 use relay_compiler::LocalPersister;
 #[cfg(unix)]
 use relay_compiler::NoopArtifactWriter;
 use relay_compiler::OperationPersister;
 use relay_compiler::PersistConfig;
 use relay_compiler::ProjectName;
+use relay_compiler::RelayPrograms;
 use relay_compiler::RemotePersister;
+use relay_compiler::SchemaLocation;
 use relay_compiler::build_project::artifact_writer::ArtifactValidationWriter;
 use relay_compiler::build_project::generate_extra_artifacts::default_generate_extra_artifacts_fn;
 use relay_compiler::compiler::Compiler;
+use relay_compiler::compiler_fingerprint;
+use relay_compiler::compose_subgraph_sources;
 use relay_compiler::config::Config;
 use relay_compiler::config::ConfigFile;
 use relay_compiler::errors::Error as CompilerError;
+use relay_compiler::explain;
 use relay_compiler::get_programs;
+use relay_compiler::print_compiler_error;
+use relay_compiler::schema_check::check_candidate_schema;
 #[cfg(unix)]
 use relay_compiler::server_daemon;
 #[cfg(unix)]
@@ -48,18 +68,30 @@ use relay_compiler::server_daemon::protocol::DaemonRequest;
 use relay_compiler::server_daemon::protocol::DaemonResponse;
 #[cfg(unix)]
 use relay_compiler::server_daemon::socket::ServerConfig as DaemonServerConfig;
+use relay_compiler::source_for_location;
 #[cfg(unix)]
 use relay_compiler::status_reporter::BuildStatus;
 #[cfg(unix)]
 use relay_compiler::status_reporter::NoopStatusReporter;
 use relay_compiler::subschema_extraction::compile_and_extract_subschema;
+use relay_compiler::subset_schema_check::check_subset_schemas;
+use relay_compiler::trace_summary;
 use relay_lsp::DummyExtraDataProvider;
 use relay_lsp::FieldDefinitionSourceInfo;
 use relay_lsp::FieldSchemaInfo;
 use relay_lsp::LSPExtraDataProvider;
 use relay_lsp::start_language_server;
 use schema::SDLSchema;
+use schema_coordinates::SchemaElement;
+use schema_coordinates::parse_schema_coordinate;
+use schema_coordinates::resolve_schema_coordinate;
+use schema_coordinates::schema_coordinate_at;
 use schema_documentation::SchemaDocumentationLoader;
+use schema_print::DocumentationFormat;
+use schema_print::DocumentationOptions;
+use schema_print::IntrospectionOptions;
+use schema_print::print_documentation;
+use schema_print::print_introspection;
 use simplelog::ColorChoice;
 use simplelog::ConfigBuilder as SimpleLogConfigBuilder;
 use simplelog::LevelFilter;
@@ -135,10 +167,36 @@ struct CompileCommand {
     repersist: bool,
 
     /// Disable watchman and use directory traversal to find source files.
-    /// Watch mode is not supported without watchman.
-    #[clap(long, conflicts_with = "watch")]
+    /// In watch mode, changes are then detected with the file system
+    /// notifications of the operating system.
+    #[clap(long)]
     no_watchman: bool,
 
+    /// Keep the compiler state of the last successful build in this directory
+    /// and only process the files that changed since. Only used when
+    /// watchman isn't, and not in watch mode.
+    #[clap(long)]
+    local_cache_dir: Option<PathBuf>,
+
+    /// Keep a content-addressed cache of the artifacts of each project in
+    /// this directory. Projects whose config, schema and sources match a
+    /// cached build have their artifacts and persisted ids restored instead
+    /// of being compiled. Not used in watch mode.
+    #[clap(long, conflicts_with = "watch")]
+    artifact_cache_dir: Option<PathBuf>,
+
+    /// Share the artifact cache through this HTTP store: entries missing from
+    /// the cache directory are fetched with `GET <url>/<key>`, and new
+    /// entries are uploaded with `PUT <url>/<key>`.
+    #[clap(long, requires = "artifact_cache_dir")]
+    artifact_cache_url: Option<String>,
+
+    /// Record the events and timers of the build in this file, in the Chrome
+    /// Trace Event format (open it in `chrome://tracing` or Perfetto), and
+    /// print the slowest transforms and files.
+    #[clap(long, conflicts_with = "watch")]
+    trace: Option<PathBuf>,
+
     /// Verbosity level
     #[clap(long, value_enum, default_value = "verbose")]
     output: OutputKind,
@@ -154,9 +212,10 @@ struct CompileCommand {
     /// `--daemon=false` to force an in-process build. When the working
     /// directory is mid-rebase / mid-merge, the build falls back to
     /// in-process automatically. Not compatible with `--watch`,
-    /// `--validate`, `--repersist`, `--no-watchman`, or inline-config
-    /// flags (`--src`, `--schema`, `--artifactDirectory`) — these all
-    /// imply per-build behavior the daemon's in-memory state can't honor.
+    /// `--validate`, `--repersist`, `--no-watchman`, `--localCacheDir`,
+    /// `--artifactCacheDir`, `--trace`, or inline-config flags (`--src`, `--schema`,
+    /// `--artifactDirectory`) — these all imply per-build behavior the
+    /// daemon's in-memory state can't honor.
     /// Unix-only.
     #[cfg(unix)]
     #[clap(
@@ -166,6 +225,9 @@ struct CompileCommand {
             "validate",
             "repersist",
             "no_watchman",
+            "local_cache_dir",
+            "artifact_cache_dir",
+            "trace",
             "src",
             "schema",
             "artifact_directory",
@@ -239,6 +301,343 @@ struct CompareDocumentIRCommand {
     schema_paths: Vec<String>,
 }
 
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Report per-operation size and field usage statistics, optionally comparing them against a baseline report."
+)]
+struct StatsCommand {
+    /// Report only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be reported.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Format of the report
+    #[clap(long, value_enum, default_value = "text")]
+    format: ReportFormat,
+
+    /// Write the report to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+
+    /// Path to a baseline report, written with `--format json`. Exits with a
+    /// non-zero code if the normalized field count or text size of an
+    /// operation grew by more than `--maxGrowth` percent since the baseline.
+    #[clap(long)]
+    baseline: Option<PathBuf>,
+
+    /// Allowed growth of an operation, in percent, when comparing against
+    /// `--baseline`
+    #[clap(long, default_value = "10", requires = "baseline")]
+    max_growth: f64,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Report how often each schema field, argument, input field and enum value is used by the documents of all projects, including deprecated ones."
+)]
+struct SchemaUsageCommand {
+    /// Report only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be reported.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Format of the report
+    #[clap(long, value_enum, default_value = "text")]
+    format: ReportFormat,
+
+    /// Write the report to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Report the client schema extension types, fields, enum values and Relay Resolvers that aren't used by any document of a project."
+)]
+struct UnusedClientSchemaCommand {
+    /// Report only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be reported.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Format of the report
+    #[clap(long, value_enum, default_value = "text")]
+    format: UnusedClientSchemaFormat,
+
+    /// Write the report to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Check a candidate server schema against the documents of all projects, reporting each change as breaking, dangerous or safe. Exits with an error if any change is breaking."
+)]
+struct SchemaCheckCommand {
+    /// Check only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be checked.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Path to the candidate server schema: a file or a directory of `.graphql` files
+    #[clap(long)]
+    schema: PathBuf,
+
+    /// Format of the report
+    #[clap(long, value_enum, default_value = "text")]
+    format: SchemaCheckFormat,
+
+    /// Write the report to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Print the full compiled schema of a project, including client schema extensions and Relay Resolvers, as a GraphQL introspection result in JSON."
+)]
+struct SchemaIntrospectionCommand {
+    /// The project whose schema is printed. Required if the config has more
+    /// than one project.
+    #[clap(long, short)]
+    project: Option<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Only print the server schema, without client schema extensions and
+    /// Relay Resolvers.
+    #[clap(long)]
+    exclude_client_extensions: bool,
+
+    /// Write the introspection result to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Convert an SDL schema into a flatbuffer schema, which projects can load with the `schemaFlatBuffer` option."
+)]
+struct SchemaFlatBufferCommand {
+    /// Path to the server schema: a file or a directory of `.graphql` files
+    #[clap(long)]
+    schema: PathBuf,
+
+    /// Path of the flatbuffer schema file to write
+    #[clap(long)]
+    out: PathBuf,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Generate static documentation of the schema of a project, including client schema extensions and Relay Resolvers, with one page per type linking to the operations and fragments that use each field."
+)]
+struct SchemaDocsCommand {
+    /// The project whose schema is documented. Required if the config has
+    /// more than one project.
+    #[clap(long, short)]
+    project: Option<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Format of the generated pages
+    #[clap(long, value_enum, default_value = "html")]
+    format: SchemaDocsFormat,
+
+    /// Only document the server schema, without client schema extensions and
+    /// Relay Resolvers.
+    #[clap(long)]
+    exclude_client_extensions: bool,
+
+    /// Directory to write the documentation to
+    #[clap(long)]
+    out: PathBuf,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Resolve schema coordinates such as `Type.field(argument:)` against the schema of a project, or print the schema coordinate referenced at a position in an operation or fragment."
+)]
+struct SchemaCoordinateCommand {
+    /// The project whose schema is used. Required if the config has more
+    /// than one project.
+    #[clap(long, short)]
+    project: Option<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Schema coordinate to resolve. Prints the kind of the schema element and
+    /// the location of its definition. Can be passed multiple times.
+    #[clap(long, required_unless_present = "at")]
+    resolve: Vec<String>,
+
+    /// Position in a source file as `path:line:column`, both 1-based. Prints the
+    /// schema coordinate of the field, argument or directive at that position.
+    /// Can be passed multiple times.
+    #[clap(long)]
+    at: Vec<String>,
+
+    /// Disable watchman and use directory traversal to find source files.
+    #[clap(long)]
+    no_watchman: bool,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Compose the subgraph schemas configured with `schemaSubgraphs` and print the supergraph schema."
+)]
+struct SchemaComposeCommand {
+    /// The project whose subgraphs are composed. Required if the config has
+    /// more than one project.
+    #[clap(long, short)]
+    project: Option<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Write the supergraph schema to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Check that the schema of each project is still a subset of the service schema, without rewriting it. Exits with an error if any project's schema isn't a subset."
+)]
+struct SchemaSubsetCommand {
+    /// Check only this project. You can pass this argument multiple times.
+    /// If excluded, all projects will be checked.
+    #[clap(name = "project", long, short)]
+    projects: Vec<String>,
+
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// Path to the service schema: a file or a directory of `.graphql` files
+    #[clap(long)]
+    service_schema: PathBuf,
+
+    /// Format of the report
+    #[clap(long, value_enum, default_value = "text")]
+    format: SchemaCheckFormat,
+
+    /// Write the report to this file instead of stdout
+    #[clap(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+#[clap(
+    rename_all = "camel_case",
+    about = "Explain how the config applies to a file: the file group, projects and schema it belongs to, and the filters leaving it out. Or print the resolved config of a project as JSON, including the values inherited from its base project."
+)]
+struct ExplainCommand {
+    /// Compile using this config file. If not provided, searches for a config in
+    /// package.json under the `relay` key or `relay.config.json` files among other up
+    /// from the current working directory.
+    config: Option<PathBuf>,
+
+    /// File to explain, relative to the current working directory
+    #[clap(long, required_unless_present = "project", conflicts_with = "project")]
+    file: Option<PathBuf>,
+
+    /// Project whose resolved config is printed
+    #[clap(long, short)]
+    project: Option<String>,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum SchemaDocsFormat {
+    Html,
+    Markdown,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum SchemaCheckFormat {
+    Text,
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum UnusedClientSchemaFormat {
+    Text,
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum ReportFormat {
+    Text,
+    Json,
+    Csv,
+}
+
 #[derive(clap::Subcommand)]
 enum Commands {
     Compiler(CompileCommand),
@@ -247,6 +646,17 @@ enum Commands {
     Codemod(CodemodCommand),
     ExperimentalRegenerateSubSchema(UpdateSchemaCommand),
     ExperimentalCompareDocumentIR(CompareDocumentIRCommand),
+    Stats(StatsCommand),
+    SchemaUsage(SchemaUsageCommand),
+    UnusedClientSchema(UnusedClientSchemaCommand),
+    SchemaCheck(SchemaCheckCommand),
+    SchemaIntrospection(SchemaIntrospectionCommand),
+    SchemaFlatBuffer(SchemaFlatBufferCommand),
+    SchemaDocs(SchemaDocsCommand),
+    SchemaCoordinate(SchemaCoordinateCommand),
+    SchemaCompose(SchemaComposeCommand),
+    SchemaSubset(SchemaSubsetCommand),
+    Explain(ExplainCommand),
     /// Manage the compiler daemon server.
     ///
     /// The daemon keeps an in-memory compiler state for fast incremental
@@ -383,6 +793,19 @@ async fn main() {
         Commands::ExperimentalCompareDocumentIR(command) => {
             handle_compare_document_ir_command(command)
         }
+        Commands::Stats(command) => handle_stats_command(command).await,
+        Commands::SchemaUsage(command) => handle_schema_usage_command(command).await,
+        Commands::UnusedClientSchema(command) => handle_unused_client_schema_command(command).await,
+        Commands::SchemaCheck(command) => handle_schema_check_command(command).await,
+        Commands::SchemaIntrospection(command) => {
+            handle_schema_introspection_command(command).await
+        }
+        Commands::SchemaFlatBuffer(command) => handle_schema_flat_buffer_command(command),
+        Commands::SchemaDocs(command) => handle_schema_docs_command(command).await,
+        Commands::SchemaCoordinate(command) => handle_schema_coordinate_command(command).await,
+        Commands::SchemaCompose(command) => handle_schema_compose_command(command),
+        Commands::SchemaSubset(command) => handle_schema_subset_command(command),
+        Commands::Explain(command) => handle_explain_command(command),
         #[cfg(unix)]
         Commands::Server(opt) => handle_server_command(opt).await,
     };
@@ -526,6 +949,666 @@ fn handle_compare_document_ir_command(command: CompareDocumentIRCommand) -> Resu
     Ok(())
 }
 
+async fn handle_stats_command(command: StatsCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_project_flag(&mut config, &command.projects)?;
+    let (programs, _, config) = load_programs(config, command.no_watchman).await?;
+
+    let reports: BTreeMap<String, QueryStatsReport> = programs
+        .iter()
+        .map(|(project_name, programs)| {
+            let project_config = &config.projects[project_name];
+            let report = compute_program_query_stats(
+                &programs.source,
+                &programs.normalization,
+                &programs.operation_text,
+                &project_config.schema_config.defer_stream_interface,
+            );
+            (project_name.to_string(), report)
+        })
+        .collect();
+
+    let output = match command.format {
+        ReportFormat::Text => reports
+            .iter()
+            .map(|(project_name, report)| format!("# {}\n{}", project_name, report))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ReportFormat::Json => {
+            let json =
+                serde_json::to_string_pretty(&reports).map_err(|err| Error::ReportFailed {
+                    details: format!("{}", err),
+                })?;
+            format!("{}\n", json)
+        }
+        ReportFormat::Csv => {
+            let mut csv = format!("{}\n", QueryStatsReport::metrics_csv_header());
+            for (project_name, report) in &reports {
+                report.append_metrics_csv_rows(&mut csv, project_name);
+            }
+            csv
+        }
+    };
+    write_report(command.out, output)?;
+
+    if let Some(baseline_path) = command.baseline {
+        let baseline: BTreeMap<String, QueryStatsReport> = std::fs::read_to_string(&baseline_path)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| serde_json::from_str(&contents).map_err(|err| err.to_string()))
+            .map_err(|err| Error::ReportFailed {
+                details: format!(
+                    "Failed to read baseline {}: {}",
+                    baseline_path.to_string_lossy(),
+                    err
+                ),
+            })?;
+        let regressions = reports
+            .iter()
+            .filter_map(|(project_name, report)| {
+                baseline.get(project_name).map(|baseline| {
+                    (
+                        project_name,
+                        report.regressions_since(baseline, command.max_growth),
+                    )
+                })
+            })
+            .flat_map(|(project_name, regressions)| {
+                regressions
+                    .into_iter()
+                    .map(move |regression| format!("  [{}] {}", project_name, regression))
+            })
+            .collect::<Vec<_>>();
+        if !regressions.is_empty() {
+            return Err(Error::StatsRegression {
+                details: regressions.join("\n"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_schema_usage_command(command: SchemaUsageCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_project_flag(&mut config, &command.projects)?;
+    let (programs, _, _) = load_programs(config, command.no_watchman).await?;
+
+    let mut report = SchemaUsageReport::default();
+    for programs in programs.values() {
+        report.merge(SchemaUsageReport::from_program(&programs.source));
+    }
+
+    let output = match command.format {
+        ReportFormat::Text => report.format_report(),
+        ReportFormat::Json => {
+            let json = serde_json::to_string_pretty(&report.rows()).map_err(|err| {
+                Error::ReportFailed {
+                    details: format!("{}", err),
+                }
+            })?;
+            format!("{}\n", json)
+        }
+        ReportFormat::Csv => {
+            let mut csv = format!("{}\n", SchemaUsageReport::csv_header());
+            report.append_csv_rows(&mut csv);
+            csv
+        }
+    };
+    write_report(command.out, output)
+}
+
+async fn handle_unused_client_schema_command(
+    command: UnusedClientSchemaCommand,
+) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_project_flag(&mut config, &command.projects)?;
+    let root_dir = config.root_dir.clone();
+    let (programs, _, _) = load_programs(config, command.no_watchman).await?;
+
+    let mut report = UnusedClientSchemaReport::default();
+    for programs in programs.values() {
+        report.merge(UnusedClientSchemaReport::from_program(&programs.source));
+    }
+
+    let line = |location: common::Location| {
+        let source = source_for_location(&root_dir, location.source_location(), &FsSourceReader)?;
+        Some(
+            source
+                .text_source()
+                .to_span_range(location.span())
+                .start
+                .line
+                + 1,
+        )
+    };
+    let output = match command.format {
+        UnusedClientSchemaFormat::Text => report.format_report(line),
+        UnusedClientSchemaFormat::Json => {
+            let json = serde_json::to_string_pretty(&report.rows(line)).map_err(|err| {
+                Error::ReportFailed {
+                    details: format!("{}", err),
+                }
+            })?;
+            format!("{}\n", json)
+        }
+    };
+    write_report(command.out, output)
+}
+
+async fn handle_schema_check_command(command: SchemaCheckCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_project_flag(&mut config, &command.projects)?;
+    config.file_source_config = file_source_kind(command.no_watchman);
+    let reports = check_candidate_schema(config, &command.schema)
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })?;
+
+    let output = match command.format {
+        SchemaCheckFormat::Text => {
+            let mut text = String::new();
+            for (project_name, report) in &reports {
+                text.push_str(&format!("# {}\n{}\n", project_name, report));
+            }
+            text
+        }
+        SchemaCheckFormat::Json => {
+            let json =
+                serde_json::to_string_pretty(&reports).map_err(|err| Error::ReportFailed {
+                    details: format!("{}", err),
+                })?;
+            format!("{}\n", json)
+        }
+    };
+    write_report(command.out, output)?;
+
+    let broken: Vec<String> = reports
+        .iter()
+        .filter(|(_, report)| report.has_breaking_changes())
+        .map(|(project_name, _)| format!("  {}", project_name))
+        .collect();
+    if broken.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::BreakingSchemaChanges {
+            details: broken.join("\n"),
+        })
+    }
+}
+
+fn handle_schema_subset_command(command: SchemaSubsetCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_project_flag(&mut config, &command.projects)?;
+    let reports = check_subset_schemas(&config, &command.service_schema).map_err(|err| {
+        Error::CompilerError {
+            details: format!("{}", err),
+        }
+    })?;
+
+    let output = match command.format {
+        SchemaCheckFormat::Text => {
+            let mut text = String::new();
+            for (project_name, report) in &reports {
+                text.push_str(&format!("# {}\n{}\n", project_name, report));
+            }
+            text
+        }
+        SchemaCheckFormat::Json => {
+            let json =
+                serde_json::to_string_pretty(&reports).map_err(|err| Error::ReportFailed {
+                    details: format!("{}", err),
+                })?;
+            format!("{}\n", json)
+        }
+    };
+    write_report(command.out, output)?;
+
+    let invalid: Vec<String> = reports
+        .iter()
+        .filter(|(_, report)| !report.is_valid_subset())
+        .map(|(project_name, _)| format!("  {}", project_name))
+        .collect();
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::SubsetSchemaViolations {
+            details: invalid.join("\n"),
+        })
+    }
+}
+
+async fn handle_schema_introspection_command(
+    command: SchemaIntrospectionCommand,
+) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_single_project_flag(&mut config, command.project)?;
+    let (programs, _, _) = load_programs(config, command.no_watchman).await?;
+
+    let options = IntrospectionOptions {
+        include_client_extensions: !command.exclude_client_extensions,
+    };
+    let output = programs
+        .values()
+        .map(|programs| {
+            format!(
+                "{}\n",
+                print_introspection(&programs.source.schema, options)
+            )
+        })
+        .collect();
+    write_report(command.out, output)
+}
+
+async fn handle_schema_docs_command(command: SchemaDocsCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_single_project_flag(&mut config, command.project)?;
+    let (programs, _, _) = load_programs(config, command.no_watchman).await?;
+
+    let include_client_extensions = !command.exclude_client_extensions;
+    let options = DocumentationOptions {
+        format: match command.format {
+            SchemaDocsFormat::Html => DocumentationFormat::Html,
+            SchemaDocsFormat::Markdown => DocumentationFormat::Markdown,
+        },
+        include_client_extensions,
+    };
+    for programs in programs.values() {
+        let references = SchemaUsageReport::from_program_with_options(
+            &programs.source,
+            SchemaUsageOptions {
+                include_client_extensions,
+            },
+        )
+        .coordinates
+        .into_iter()
+        .map(|(coordinate, usage)| (coordinate, usage.used_by))
+        .collect();
+        for page in print_documentation(&programs.source.schema, &references, options) {
+            let path = command.out.join(&page.path);
+            let write_failed = |err: std::io::Error| Error::ReportFailed {
+                details: format!("Failed to write {}: {}", path.to_string_lossy(), err),
+            };
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(write_failed)?;
+            }
+            std::fs::write(&path, page.content).map_err(write_failed)?;
+        }
+    }
+    Ok(())
+}
+
+async fn handle_schema_coordinate_command(command: SchemaCoordinateCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_single_project_flag(&mut config, command.project)?;
+    let root_dir = config.root_dir.clone();
+    let (programs, _, _) = load_programs(config, command.no_watchman).await?;
+    let program = &programs
+        .values()
+        .next()
+        .ok_or_else(|| Error::ProjectFilterError {
+            details: "No project to resolve schema coordinates in.".to_string(),
+        })?
+        .source;
+    let schema = &program.schema;
+
+    let mut output = String::new();
+    let mut failures = vec![];
+    for input in &command.resolve {
+        let element = parse_schema_coordinate(input)
+            .map_err(|_| format!("`{}` is not a valid schema coordinate.", input))
+            .and_then(|coordinate| {
+                resolve_schema_coordinate(schema, &coordinate).map_err(|err| err.to_string())
+            });
+        match element {
+            Ok(element) => {
+                let kind = match element {
+                    SchemaElement::Type(_) => "type",
+                    SchemaElement::Field { .. } => "field",
+                    SchemaElement::FieldArgument { .. } => "argument",
+                    SchemaElement::InputField { .. } => "input-field",
+                    SchemaElement::EnumValue { .. } => "enum-value",
+                    SchemaElement::Directive(_) => "directive",
+                    SchemaElement::DirectiveArgument { .. } => "directive-argument",
+                };
+                let location = element
+                    .definition_location(schema)
+                    .and_then(|location| print_source_position(&root_dir, location))
+                    .unwrap_or_else(|| "-".to_string());
+                output.push_str(&format!(
+                    "{}\t{}\t{}\n",
+                    element.schema_coordinate(schema),
+                    kind,
+                    location
+                ));
+            }
+            Err(err) => failures.push(err),
+        }
+    }
+    for position in &command.at {
+        match location_at_source_position(&root_dir, position) {
+            Ok(location) => match schema_coordinate_at(program, location) {
+                Some(coordinate) => output.push_str(&format!("{}\n", coordinate)),
+                None => failures.push(format!(
+                    "No field, argument or directive found at `{}`.",
+                    position
+                )),
+            },
+            Err(err) => failures.push(err),
+        }
+    }
+
+    print!("{}", output);
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::SchemaCoordinateFailed {
+            details: failures.join("\n"),
+        })
+    }
+}
+
+/// Print `location` as `path:line:column`, 1-based like compiler errors.
+fn print_source_position(root_dir: &Path, location: common::Location) -> Option<String> {
+    let source = source_for_location(root_dir, location.source_location(), &FsSourceReader)?;
+    let range = source.text_source().to_span_range(location.span());
+    Some(format!(
+        "{}:{}:{}",
+        location.source_location().path(),
+        range.start.line + 1,
+        range.start.character + 1
+    ))
+}
+
+/// Parse a `path:line:column` position into the location of the GraphQL
+/// source (standalone file or embedded literal) that contains it.
+fn location_at_source_position(
+    root_dir: &Path,
+    position: &str,
+) -> Result<common::Location, String> {
+    let invalid = || {
+        format!(
+            "`{}` is not a position of the form path:line:column.",
+            position
+        )
+    };
+    let mut parts = position.rsplitn(3, ':');
+    let column = parts.next().and_then(|column| column.parse::<usize>().ok());
+    let line = parts.next().and_then(|line| line.parse::<usize>().ok());
+    let (path, line, column) = match (parts.next(), line, column) {
+        (Some(path), Some(line), Some(column)) if line > 0 && column > 0 => {
+            (PathBuf::from(path), line - 1, column - 1)
+        }
+        _ => return Err(invalid()),
+    };
+    let absolute_path = current_dir().map_err(|err| err.to_string())?.join(&path);
+    let relative_path = absolute_path
+        .strip_prefix(root_dir)
+        .map_err(|_| format!("`{}` is not inside of the project root.", path.display()))?
+        .to_string_lossy()
+        .to_string();
+    let contents = std::fs::read_to_string(&absolute_path)
+        .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+
+    let (source_location, text_source) = if absolute_path
+        .extension()
+        .is_some_and(|extension| extension == "graphql")
+    {
+        (
+            SourceLocationKey::standalone(&relative_path),
+            TextSource::from_whole_document(contents),
+        )
+    } else {
+        extract_graphql::extract_from_file(&absolute_path, &contents)
+            .into_iter()
+            .enumerate()
+            .find_map(|(index, feature)| {
+                let text_source = feature.to_text_source();
+                let range = text_source.to_range();
+                let after_start =
+                    (line, column) >= (range.start.line as usize, range.start.character as usize);
+                let before_end =
+                    (line, column) <= (range.end.line as usize, range.end.character as usize);
+                (after_start && before_end).then(|| {
+                    (
+                        SourceLocationKey::embedded(&relative_path, index),
+                        text_source,
+                    )
+                })
+            })
+            .ok_or_else(|| format!("No GraphQL literal found at `{}`.", position))?
+    };
+
+    // Spans are byte offsets relative to the start of the GraphQL source.
+    let mut offset = 0;
+    for (index, text) in text_source.text.split_inclusive('\n').enumerate() {
+        let current_line = text_source.line_index + index;
+        if current_line == line {
+            let first_column = if index == 0 {
+                text_source.column_index
+            } else {
+                0
+            };
+            let character = column.checked_sub(first_column).ok_or_else(invalid)?;
+            offset += text
+                .char_indices()
+                .nth(character)
+                .map_or(text.len(), |(byte_offset, _)| byte_offset);
+            return Ok(common::Location::new(
+                source_location,
+                Span::new(offset as u32, offset as u32),
+            ));
+        }
+        offset += text.len();
+    }
+    Err(invalid())
+}
+
+fn handle_schema_flat_buffer_command(command: SchemaFlatBufferCommand) -> Result<(), Error> {
+    let conversion_failed = |details: String| Error::SchemaFlatBufferFailed { details };
+    let read_failed = |error: std::io::Error| {
+        conversion_failed(format!("{}: {}", command.schema.display(), error))
+    };
+    let mut paths = vec![];
+    if std::fs::metadata(&command.schema)
+        .map_err(read_failed)?
+        .is_dir()
+    {
+        for entry in std::fs::read_dir(&command.schema).map_err(read_failed)? {
+            let path = entry.map_err(read_failed)?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "graphql")
+            {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+    } else {
+        paths.push(command.schema.clone());
+    }
+    let sources = paths
+        .iter()
+        .map(|path| {
+            let text = std::fs::read_to_string(path).map_err(read_failed)?;
+            Ok((
+                text,
+                SourceLocationKey::standalone(&path.display().to_string()),
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    // Only the server schema is serialized: client schema extensions and the
+    // Relay directives are layered over the flatbuffer when it is loaded.
+    let schema = schema::build_schema_with_extensions_parallel::<_, &str>(&sources, &[]).map_err(
+        |diagnostics| {
+            conversion_failed(
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.print_without_source())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        },
+    )?;
+    let bytes = schema::serialize_as_flatbuffer(&schema);
+    // Compilers may have the current file memory-mapped: replace it rather
+    // than truncating it under them.
+    let temp_path = command.out.with_file_name(format!(
+        ".{}.{}.tmp",
+        command
+            .out
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy(),
+        std::process::id()
+    ));
+    std::fs::write(&temp_path, bytes)
+        .and_then(|_| std::fs::rename(&temp_path, &command.out))
+        .map_err(|error| {
+            let _ = std::fs::remove_file(&temp_path);
+            conversion_failed(format!("{}: {}", command.out.display(), error))
+        })
+}
+
+fn handle_schema_compose_command(command: SchemaComposeCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let mut config = get_config(command.config)?;
+    set_single_project_flag(&mut config, command.project)?;
+    let project_config =
+        config
+            .enabled_projects()
+            .next()
+            .ok_or_else(|| Error::ProjectFilterError {
+                details: "No project to compose the schema of.".to_string(),
+            })?;
+    let SchemaLocation::Subgraphs(subgraphs) = &project_config.schema_location else {
+        return Err(Error::SchemaComposeFailed {
+            details: format!(
+                "Project `{}` doesn't configure `schemaSubgraphs`.",
+                project_config.name
+            ),
+        });
+    };
+    let sources = subgraphs
+        .iter()
+        .map(|path| {
+            let text = std::fs::read_to_string(config.root_dir.join(path)).map_err(|error| {
+                Error::SchemaComposeFailed {
+                    details: format!("{}: {}", path.display(), error),
+                }
+            })?;
+            Ok((
+                text,
+                SourceLocationKey::standalone(&path.display().to_string()),
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let sources = sources
+        .iter()
+        .map(|(text, location_key)| (text.as_str(), *location_key))
+        .collect::<Vec<_>>();
+
+    let supergraph = compose_subgraph_sources(&sources)
+        .and_then(|supergraph| supergraph.printed_base_and_client_schema())
+        .map_err(|errors| Error::SchemaComposeFailed {
+            details: print_compiler_error(
+                &config.root_dir,
+                CompilerError::DiagnosticsError { errors },
+            ),
+        })?;
+    write_report(command.out, supergraph.0)
+}
+
+/// Restrict the config to `project`, which can only be omitted when the
+/// config has a single project.
+fn set_single_project_flag(config: &mut Config, project: Option<String>) -> Result<(), Error> {
+    match project {
+        Some(project) => set_project_flag(config, &vec![project]),
+        None if config.projects.len() > 1 => Err(Error::ProjectFilterError {
+            details: format!(
+                "The config has more than one project, pass one of them with --project: {}.",
+                config
+                    .projects
+                    .keys()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn handle_explain_command(command: ExplainCommand) -> Result<(), Error> {
+    configure_logger(OutputKind::QuietWithErrors, TerminalMode::Stderr);
+    let config = get_config(command.config)?;
+    if let Some(project) = command.project {
+        let project_name = ProjectName::from(project.intern());
+        let project_config =
+            config
+                .projects
+                .get(&project_name)
+                .ok_or_else(|| Error::ProjectFilterError {
+                    details: format!(
+                        "Project `{}` not found, available projects: {}.",
+                        project_name,
+                        config
+                            .projects
+                            .keys()
+                            .map(|name| name.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                })?;
+        let json = explain::project_config_json(&config, project_config);
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        return Ok(());
+    }
+
+    let file = command.file.unwrap();
+    let absolute_path = current_dir()
+        .expect("Unable to get current working directory.")
+        .join(&file);
+    // Resolve symlinks when the file exists, like the root directory.
+    let absolute_path = absolute_path.canonicalize().unwrap_or(absolute_path);
+    let root_dir = config
+        .root_dir
+        .canonicalize()
+        .unwrap_or_else(|_| config.root_dir.clone());
+    let path = absolute_path
+        .strip_prefix(&root_dir)
+        .map_err(|_| Error::ReportFailed {
+            details: format!(
+                "{} is outside of the root directory {}.",
+                file.display(),
+                root_dir.display()
+            ),
+        })?;
+    print!("{}", explain::explain_file(&config, path));
+    Ok(())
+}
+
+fn write_report(out: Option<PathBuf>, report: String) -> Result<(), Error> {
+    match out {
+        Some(path) => std::fs::write(&path, report).map_err(|err| Error::ReportFailed {
+            details: format!("Failed to write {}: {}", path.to_string_lossy(), err),
+        }),
+        None => {
+            print!("{}", report);
+            Ok(())
+        }
+    }
+}
+
 async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
     configure_logger(command.output, TerminalMode::Mixed);
 
@@ -559,22 +1642,41 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
 
     apply_default_cli_extensions(&mut config);
 
-    config.file_source_config = if should_use_watchman(command.no_watchman) {
-        FileSourceKind::Watchman
-    } else {
-        FileSourceKind::WalkDir
+    config.file_source_config = match file_source_kind(command.no_watchman) {
+        FileSourceKind::WalkDir if command.watch => FileSourceKind::NativeWatch,
+        file_source_kind => file_source_kind,
     };
     config.repersist_operations = command.repersist;
-
-    if command.watch && !matches!(&config.file_source_config, FileSourceKind::Watchman) {
-        return Err(Error::CompilerError {
-            details: "Cannot run relay in watch mode if `watchman` is not available. Install watchman or remove the --watch flag.".to_string(),
-        });
+    config.local_cache_dir = command.local_cache_dir;
+    config.artifact_cache = command.artifact_cache_dir.map(|dir| ArtifactCacheConfig {
+        dir,
+        url: command.artifact_cache_url,
+        compiler_version: compiler_fingerprint(),
+    });
+
+    match command.trace {
+        Some(trace_path) => {
+            let perf_logger = TraceLogger::new();
+            let result = run_compiler(config, Arc::new(perf_logger.clone()), false).await;
+            write_trace(&trace_path, &perf_logger)?;
+            result?;
+        }
+        None => run_compiler(config, Arc::new(ConsoleLogger), command.watch).await?,
     }
 
-    let compiler = Compiler::new(Arc::new(config), Arc::new(ConsoleLogger));
+    info!("Done.");
+    Ok(())
+}
 
-    if command.watch {
+async fn run_compiler<TPerfLogger: PerfLogger + 'static>(
+    mut config: Config,
+    perf_logger: Arc<TPerfLogger>,
+    watch: bool,
+) -> Result<(), Error> {
+    config.memory_map_flat_buffer_schemas = !watch;
+    let compiler = Compiler::new(Arc::new(config), perf_logger);
+
+    if watch {
         compiler.watch().await.map_err(|err| Error::CompilerError {
             details: format!("{:?}", err),
         })?;
@@ -586,8 +1688,24 @@ async fn handle_compiler_command(command: CompileCommand) -> Result<(), Error> {
                 details: format!("{}", err),
             })?;
     }
+    Ok(())
+}
 
-    info!("Done.");
+/// Write the Chrome trace of a build and print its slowest transforms and
+/// files.
+fn write_trace(trace_path: &Path, perf_logger: &TraceLogger) -> Result<(), Error> {
+    let write_failed = |err: std::io::Error| Error::CompilerError {
+        details: format!("Failed to write {}: {}", trace_path.display(), err),
+    };
+    let file = std::fs::File::create(trace_path).map_err(write_failed)?;
+    perf_logger
+        .write_chrome_trace(std::io::BufWriter::new(file))
+        .map_err(write_failed)?;
+    info!(
+        "Wrote the trace of the build to {}.\n{}",
+        trace_path.display(),
+        trace_summary(&perf_logger.spans(), 10).trim_end()
+    );
     Ok(())
 }
 
@@ -676,6 +1794,26 @@ async fn handle_lsp_command(command: LspCommand) -> Result<(), Error> {
     Ok(())
 }
 
+/// Watchman when it is available, or else walking the file system.
+fn file_source_kind(no_watchman_flag: bool) -> FileSourceKind {
+    if should_use_watchman(no_watchman_flag) {
+        FileSourceKind::Watchman
+    } else {
+        FileSourceKind::WalkDir
+    }
+}
+
+/// Compile the programs of the projects of `config`, reading the files with
+/// Watchman when it is available.
+async fn load_programs(mut config: Config, no_watchman_flag: bool) -> Result<RelayPrograms, Error> {
+    config.file_source_config = file_source_kind(no_watchman_flag);
+    get_programs(config, Arc::new(ConsoleLogger))
+        .await
+        .map_err(|err| Error::CompilerError {
+            details: format!("{}", err),
+        })
+}
+
 /// Check if `watchman` is available.
 /// Returns `false` if `no_watchman_flag` is set (via `--noWatchman` CLI flag),
 /// or if the `FORCE_NO_WATCHMAN` environment variable is set,
@@ -690,14 +1828,6 @@ fn should_use_watchman(no_watchman_flag: bool) -> bool {
         .is_ok()
 }
 
-/// Compiler version string used by the daemon for client/server version checks.
-#[cfg(unix)]
-fn compiler_version() -> String {
-    option_env!("CARGO_PKG_VERSION")
-        .unwrap_or("unknown")
-        .to_string()
-}
-
 #[cfg(unix)]
 async fn handle_server_command(opt: ServerOpt) -> Result<(), Error> {
     configure_logger(OutputKind::Verbose, TerminalMode::Mixed);
@@ -781,7 +1911,7 @@ async fn handle_server_command(opt: ServerOpt) -> Result<(), Error> {
             if matches!(&request, DaemonRequest::Write { .. })
                 && let Some(response) =
                     server_daemon::send_request(&socket_path, DaemonRequest::Version).await
-                && server_daemon::has_version_mismatch(&response, &compiler_version())
+                && server_daemon::has_version_mismatch(&response, &compiler_fingerprint())
             {
                 server_daemon::restart_daemon(
                     &socket_path,
@@ -853,7 +1983,7 @@ async fn start_server_foreground(
         perf_logger,
         artifact_cache,
         build_status,
-        compiler_version: compiler_version(),
+        compiler_version: compiler_fingerprint(),
         flush_writer_factory: None,
     })
     .await
@@ -925,7 +2055,7 @@ async fn try_daemon_build(command: &CompileCommand) -> Result<bool, Error> {
     let (socket_path, _outcome) = server_daemon::ensure_daemon_running(
         &config_path,
         &command.projects,
-        &compiler_version(),
+        &compiler_fingerprint(),
         &extra_args,
         &[],
     )
@@ -17,8 +17,11 @@ use std::sync::Arc;
 
 use common::SourceLocationKey;
 use content::generate_fragment;
+use content::generate_native_fragment_models;
+use content::generate_native_operation_models;
 use content::generate_operation;
 use content::generate_resolvers_schema_module_content;
+use content::generate_schema_type_module_content;
 use content::generate_split_operation;
 use content::generate_updatable_query;
 use graphql_ir::FragmentDefinition;
@@ -27,6 +30,7 @@ use relay_codegen::Printer;
 use relay_codegen::QueryID;
 use relay_typegen::FragmentLocations;
 use schema::SDLSchema;
+use schema::Type;
 
 use self::content::generate_preloadable_query_parameters;
 use crate::config::Config;
@@ -63,6 +67,8 @@ pub enum ArtifactContent {
         no_optional_fields_in_raw_response_type: bool,
     },
     ResolversSchema,
+    /// Shared type module of an input object or custom scalar.
+    SchemaType(Type),
     Generic {
         content: Vec<u8>,
     },
@@ -84,7 +90,32 @@ impl ArtifactContent {
             } else {
                 false
             };
+        let is_native = project_config.typegen_config.language.is_native();
         match self {
+            ArtifactContent::Operation {
+                normalization_operation,
+                typegen_operation,
+                ..
+            } if is_native => generate_native_operation_models(
+                config,
+                project_config,
+                printer,
+                schema,
+                normalization_operation,
+                typegen_operation,
+                fragment_locations,
+            )
+            .unwrap(),
+            ArtifactContent::Fragment {
+                typegen_fragment, ..
+            } if is_native => generate_native_fragment_models(
+                config,
+                project_config,
+                schema,
+                typegen_fragment,
+                fragment_locations,
+            )
+            .unwrap(),
             ArtifactContent::Operation {
                 normalization_operation,
                 reader_operation,
@@ -172,6 +203,9 @@ impl ArtifactContent {
                 generate_resolvers_schema_module_content(config, project_config, printer, schema)
                     .unwrap()
             }
+            ArtifactContent::SchemaType(type_) => {
+                generate_schema_type_module_content(config, project_config, schema, *type_).unwrap()
+            }
             ArtifactContent::Generic { content } => content.clone(),
         }
     }
@@ -27,8 +27,11 @@ use relay_typegen::TypegenLanguage;
 use relay_typegen::generate_fragment_type_exports_section;
 use relay_typegen::generate_named_validator_export;
 use relay_typegen::generate_operation_type_exports_section;
+use relay_typegen::generate_schema_type_module;
 use relay_typegen::generate_split_operation_type_exports_section;
 use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
 use signedsource::SIGNING_TOKEN;
 
 use super::super::ArtifactGeneratedTypes;
@@ -147,7 +150,7 @@ pub fn generate_preloadable_query_parameters(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -270,7 +273,7 @@ pub fn generate_updatable_query(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Query Node Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -467,7 +470,10 @@ pub fn generate_operation(
                     )?;
                 }
             }
-            TypegenLanguage::JavaScript | TypegenLanguage::TypeScript => {
+            TypegenLanguage::JavaScript
+            | TypegenLanguage::TypeScript
+            | TypegenLanguage::Kotlin
+            | TypegenLanguage::Swift => {
                 if project_config.typegen_config.eager_es_modules {
                     writeln!(
                         section,
@@ -500,7 +506,7 @@ pub fn generate_operation(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -613,7 +619,7 @@ pub fn generate_split_operation(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -784,7 +790,7 @@ fn generate_read_only_fragment(
     content_sections.push(ContentSection::Generic(section));
     // -- End Fragment Node Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
 }
 
 fn generate_assignable_fragment(
@@ -888,7 +894,7 @@ fn generate_assignable_fragment(
     content_sections.push(ContentSection::Generic(section));
     // -- End Export Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
 }
 
 fn write_variable_value_with_type(
@@ -903,7 +909,7 @@ fn write_variable_value_with_type(
         TypegenLanguage::Flow => {
             writeln!(section, "var {variable_name}/*: {type_}*/ = {value};")
         }
-        TypegenLanguage::TypeScript => {
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
             writeln!(section, "const {variable_name}: {type_} = {value};")
         }
     }
@@ -920,6 +926,7 @@ fn generate_disable_lint_section(language: &TypegenLanguage) -> Result<GenericSe
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             writeln!(section, "/* eslint-disable */")?;
         }
+        TypegenLanguage::Kotlin | TypegenLanguage::Swift => {}
     }
     Ok(section)
 }
@@ -927,7 +934,7 @@ fn generate_disable_lint_section(language: &TypegenLanguage) -> Result<GenericSe
 fn generate_use_strict_section(language: &TypegenLanguage) -> Result<GenericSection, FmtError> {
     let mut section = GenericSection::default();
     match language {
-        TypegenLanguage::TypeScript => {}
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {}
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
             writeln!(section, "'use strict';")?;
         }
@@ -943,7 +950,7 @@ fn write_import_type_from(
 ) -> FmtResult {
     let language = &project_config.typegen_config.language;
     match language {
-        TypegenLanguage::JavaScript => Ok(()),
+        TypegenLanguage::JavaScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => Ok(()),
         TypegenLanguage::Flow => writeln!(section, "import type {{ {type_} }} from '{from}';"),
         TypegenLanguage::TypeScript => writeln!(
             section,
@@ -969,7 +976,7 @@ pub fn write_export_generated_node(
         (TypegenLanguage::Flow, None) | (TypegenLanguage::JavaScript, _) => {
             variable_node.to_string()
         }
-        (TypegenLanguage::TypeScript, _) => {
+        (TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift, _) => {
             // TODO: Support force_type for TypeScript
             variable_node.to_string()
         }
@@ -1004,7 +1011,9 @@ pub fn generate_docblock_section(
     if project_config.typegen_config.language == TypegenLanguage::Flow {
         writeln!(section, "@flow")?;
     }
-    writeln!(section, "@lightSyntaxTransform")?;
+    if !project_config.typegen_config.language.is_native() {
+        writeln!(section, "@lightSyntaxTransform")?;
+    }
     if project_config
         .feature_flags
         .emit_nogrep_annotation
@@ -1036,7 +1045,7 @@ fn write_source_hash(
                 writeln!(section, "  (node/*:: as any*/).hash = \"{source_hash}\";")?
             }
             TypegenLanguage::JavaScript => writeln!(section, "  node.hash = \"{source_hash}\";")?,
-            TypegenLanguage::TypeScript => {
+            TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
                 writeln!(section, "  (node as any).hash = \"{source_hash}\";")?
             }
         };
@@ -1047,7 +1056,7 @@ fn write_source_hash(
                 writeln!(section, "(node/*:: as any*/).hash = \"{source_hash}\";")?
             }
             TypegenLanguage::JavaScript => writeln!(section, "node.hash = \"{source_hash}\";")?,
-            TypegenLanguage::TypeScript => {
+            TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
                 writeln!(section, "(node as any).hash = \"{source_hash}\";")?
             }
         };
@@ -1161,5 +1170,129 @@ pub fn generate_resolvers_schema_module_content(
     content_sections.push(ContentSection::Generic(section));
     // -- End Exports Section --
 
-    content_sections.into_signed_bytes()
+    content_sections.into_signed_bytes(project_config)
+}
+
+pub fn generate_schema_type_module_content(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    type_: Type,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        schema.get_type_name(type_),
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Disable Lint Section --
+    content_sections.push(ContentSection::Generic(generate_disable_lint_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Disable Lint Section --
+
+    // -- Begin Use Strict Section --
+    content_sections.push(ContentSection::Generic(generate_use_strict_section(
+        &project_config.typegen_config.language,
+    )?));
+    // -- End Use Strict Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "/*::")?;
+    }
+    write!(
+        section,
+        "{}",
+        generate_schema_type_module(type_, schema, project_config)
+    )?;
+    if project_config.typegen_config.language == TypegenLanguage::Flow {
+        writeln!(section, "*/")?;
+    }
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    content_sections.into_signed_bytes(project_config)
+}
+
+/// Generates the response models of an operation for a native language.
+/// Unlike JS artifacts, these contain no runtime nodes, only types.
+pub fn generate_native_operation_models(
+    config: &Config,
+    project_config: &ProjectConfig,
+    printer: &mut Printer<'_>,
+    schema: &SDLSchema,
+    normalization_operation: &OperationDefinition,
+    typegen_operation: &OperationDefinition,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        normalization_operation.name.item.0,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    write!(
+        section,
+        "{}",
+        generate_operation_type_exports_section(
+            typegen_operation,
+            normalization_operation,
+            schema,
+            project_config,
+            fragment_locations,
+            printer.print_provided_variables(schema, normalization_operation),
+        )
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    content_sections.into_signed_bytes(project_config)
+}
+
+/// Generates the data models of a fragment for a native language.
+pub fn generate_native_fragment_models(
+    config: &Config,
+    project_config: &ProjectConfig,
+    schema: &SDLSchema,
+    typegen_fragment: &FragmentDefinition,
+    fragment_locations: &FragmentLocations,
+) -> Result<Vec<u8>, FmtError> {
+    let mut content_sections = ContentSections::default();
+    // -- Begin Docblock Section --
+    content_sections.push(ContentSection::Docblock(generate_docblock_section(
+        config,
+        project_config,
+        vec![],
+        typegen_fragment.name.item.0,
+    )?));
+    // -- End Docblock Section --
+
+    // -- Begin Types Section --
+    let mut section = GenericSection::default();
+    write!(
+        section,
+        "{}",
+        generate_fragment_type_exports_section(
+            typegen_fragment,
+            schema,
+            project_config,
+            fragment_locations,
+        )
+    )?;
+    content_sections.push(ContentSection::Generic(section));
+    // -- End Types Section --
+
+    content_sections.into_signed_bytes(project_config)
 }
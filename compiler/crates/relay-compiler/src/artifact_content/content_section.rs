@@ -9,6 +9,12 @@ use std::fmt::Error as FmtError;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
 
+use prettier_format_lib::JavaScriptFormatOptions;
+use prettier_format_lib::TrailingComma;
+use prettier_format_lib::format_javascript;
+use relay_config::PrettierConfig;
+use relay_config::ProjectConfig;
+use relay_config::TrailingCommaConfig;
 use signedsource::sign_file;
 
 pub enum ContentSection {
@@ -119,14 +125,28 @@ impl ContentSections {
         }
     }
 
-    pub fn into_signed_bytes(self) -> Result<Vec<u8>, FmtError> {
-        Ok(sign_file(
-            self.0
-                .into_iter()
-                .collect::<Vec<String>>()
-                .join("\n")
-                .as_str(),
-        )
-        .into_bytes())
+    /// Join the sections, formatting JavaScript and TypeScript with the
+    /// `prettier` options of the project before signing them.
+    pub fn into_signed_bytes(self, project_config: &ProjectConfig) -> Result<Vec<u8>, FmtError> {
+        let content = self.0.into_iter().collect::<Vec<String>>().join("\n");
+        let content = match &project_config.prettier {
+            Some(prettier) if !project_config.typegen_config.language.is_native() => {
+                format_javascript(&content, &format_options(prettier))
+            }
+            _ => content,
+        };
+        Ok(sign_file(&content).into_bytes())
+    }
+}
+
+fn format_options(prettier: &PrettierConfig) -> JavaScriptFormatOptions {
+    JavaScriptFormatOptions {
+        print_width: prettier.print_width,
+        single_quote: prettier.single_quote,
+        trailing_comma: match prettier.trailing_comma {
+            TrailingCommaConfig::All => TrailingComma::All,
+            TrailingCommaConfig::Es5 => TrailingComma::Es5,
+            TrailingCommaConfig::None => TrailingComma::None,
+        },
     }
 }
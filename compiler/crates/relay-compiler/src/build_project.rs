@@ -13,6 +13,7 @@
 //! * Reading the schema from the specified location
 //! * Processing the GraphQL documents in the project
 //! * Generating the necessary artifacts (e.g. generated files)
+pub mod artifact_cache;
 mod artifact_generated_types;
 pub mod artifact_writer;
 mod build_ir;
@@ -35,10 +36,12 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::time::Instant;
 
+use artifact_cache::ArtifactCacheRecorder;
 pub use artifact_generated_types::ArtifactGeneratedTypes;
 use build_ir::BuildIRResult;
 pub use build_ir::SourceHashes;
 pub use build_schema::build_schema;
+pub use build_schema::compose_subgraph_sources;
 use common::Diagnostic;
 use common::DirectiveName;
 use common::PerfLogEvent;
@@ -82,11 +85,13 @@ use schema_diff::check::SchemaChangeSafety;
 pub use source_control::source_control_for_root;
 pub use validate::AdditionalValidations;
 pub use validate::validate;
+pub use validate::validate_normalization;
 pub use validate::validate_reader;
 
 use self::log_program_stats::print_stats;
 pub use self::project_asts::ProjectAstData;
 pub use self::project_asts::ProjectAsts;
+pub use self::project_asts::find_duplicate_definitions;
 pub use self::project_asts::find_duplicates;
 pub use self::project_asts::get_project_asts;
 use super::artifact_content;
@@ -280,6 +285,21 @@ pub fn validate_reader_program(
     result
 }
 
+// OK(Vec<Diagnostic>) = Compilation can continue
+// Err(Vec<Diagnostic>) = Compilation must stop here
+pub fn validate_normalization_program(
+    project_config: &ProjectConfig,
+    program: &Program,
+    log_event: &impl PerfLogEvent,
+) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    let timer = log_event.start("validate_normalization_time");
+    let result = validate_normalization(program, project_config).map(|result| result.diagnostics);
+
+    log_event.stop(timer);
+
+    result
+}
+
 /// Apply various chains of transforms to create a set of output programs.
 pub fn transform_program(
     project_config: &ProjectConfig,
@@ -406,6 +426,7 @@ pub fn build_programs(
         return Err(BuildProjectFailure::Cancelled);
     }
     let base_fragment_names = Arc::new(base_fragment_names);
+    let custom_transforms = config.all_custom_transforms();
     let validate_and_transform_all_timer = log_event.start("validate_and_transform_all_time");
     let validation_results = programs
         .into_par_iter()
@@ -426,7 +447,7 @@ pub fn build_programs(
                             Arc::clone(&base_fragment_names),
                             Arc::clone(&perf_logger),
                             log_event,
-                            config.custom_transforms.as_ref(),
+                            custom_transforms.as_ref(),
                             config.transferrable_refetchable_query_directives.clone(),
                         )
                     },
@@ -441,12 +462,19 @@ pub fn build_programs(
                     &programs.reader,
                     log_event,
                 )?);
+                diagnostics.extend(validate_normalization_program(
+                    project_config,
+                    &programs.normalization,
+                    log_event,
+                )?);
 
                 Ok((programs, diagnostics))
             },
         )
         .collect::<Vec<_>>();
     log_event.stop(validate_and_transform_all_timer);
+    // Taken even if the build fails, so they aren't reported by a later build.
+    let plugin_warnings = config.transform_plugins.take_warnings(project_name);
 
     let merge_programs_timer = log_event.start("combine_build_programs_time");
     let results: Vec<(Programs, Vec<Diagnostic>)> =
@@ -458,7 +486,7 @@ pub fn build_programs(
         })?;
 
     let len = results.len();
-    let (programs, diagnostics) = results.into_iter().fold(
+    let (programs, mut diagnostics) = results.into_iter().fold(
         (Vec::with_capacity(len), vec![]),
         |(mut programs, mut diagnostics), (temp_programs, temp_diagnostics)| {
             programs.push(temp_programs);
@@ -466,6 +494,7 @@ pub fn build_programs(
             (programs, diagnostics)
         },
     );
+    diagnostics.extend(plugin_warnings);
     log_event.stop(merge_programs_timer);
 
     Ok(WithDiagnostics {
@@ -605,6 +634,8 @@ pub async fn commit_project(
     // Pre-spawned Eden hash map RPC handle, started earlier in compiler.rs
     // to overlap with build_commit_state_time processing.
     hash_map_handle: Option<get_artifacts_file_hash_map::ArtifactHashMapHandle>,
+    // Records the artifacts of a full build for the artifact cache.
+    artifact_cache_recorder: Option<&ArtifactCacheRecorder>,
 ) -> Result<ArtifactMap, BuildProjectFailure> {
     let log_event = perf_logger.create_event("commit_project");
     log_event.string("project", project_config.name.to_string());
@@ -642,6 +673,9 @@ pub async fn commit_project(
         )
         .await?;
         log_event.stop(persist_operations_timer);
+        if let Some(artifact_cache_recorder) = artifact_cache_recorder {
+            artifact_cache_recorder.record_persisted_operations(config, project_config, &artifacts);
+        }
     }
 
     if source_control_update_status.is_started() {
@@ -712,6 +746,7 @@ pub async fn commit_project(
                 &fragment_locations,
                 &artifacts_file_hash_map,
                 &log_event,
+                artifact_cache_recorder,
             )?;
             for artifact in &artifacts {
                 if !existing_artifacts.remove(&artifact.path) {
@@ -749,6 +784,7 @@ pub async fn commit_project(
                 &fragment_locations,
                 &artifacts_file_hash_map,
                 &log_event,
+                None,
             )?;
             artifacts.into_par_iter().for_each(|artifact| {
                 current_paths_map.insert(artifact);
@@ -850,6 +886,7 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
     fragment_locations: &FragmentLocations,
     artifacts_file_hash_map: &Option<FxHashMap<String, Option<String>>>,
     log_event: &impl PerfLogEvent,
+    artifact_cache_recorder: Option<&ArtifactCacheRecorder>,
 ) -> Result<(), BuildProjectFailure> {
     // Cumulative CPU time summed across all parallel rayon threads (not
     // wall-clock). Accumulated in microseconds (not millis) because
@@ -880,16 +917,26 @@ fn write_artifacts<F: Fn() -> bool + Sync + Send>(
                 let path = config.root_dir.join(&artifact.path);
 
                 let codegen_start = Instant::now();
-                let content = artifact.content.as_bytes(
-                    config,
-                    project_config,
-                    printer,
-                    schema,
-                    artifact.source_file,
-                    fragment_locations,
+                let content = log_event.time_item(
+                    "generate_artifact",
+                    || artifact.path.display().to_string(),
+                    || {
+                        artifact.content.as_bytes(
+                            config,
+                            project_config,
+                            printer,
+                            schema,
+                            artifact.source_file,
+                            fragment_locations,
+                        )
+                    },
                 );
                 chunk_codegen_us += codegen_start.elapsed().as_micros() as usize;
 
+                if let Some(artifact_cache_recorder) = artifact_cache_recorder {
+                    artifact_cache_recorder.record(&artifact.path, &content);
+                }
+
                 let file_hash = match artifact.path.to_str() {
                     Some(key) => artifacts_file_hash_map
                         .as_ref()
@@ -0,0 +1,714 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A content-addressed cache of the artifacts of projects, shared by the
+//! builds of the same sources, like the CI jobs of a commit.
+//!
+//! The key of a project is a hash of the compiler fingerprint, the resolved
+//! project config and the schema and source files of the project and its
+//! base project. When a full build finds an entry for the key, the cached
+//! artifacts (with their persisted ids) are written instead of compiling the
+//! project, the operations of the changed artifacts are persisted again and
+//! the diagnostics of the build are reported again. Otherwise the project is
+//! compiled and its artifacts are stored.
+//! Entries are kept in a local directory, and optionally in an HTTP store
+//! that is read on a local miss and written with every new entry. The cache
+//! never fails a build: errors reading or writing it are only logged.
+
+use std::fs;
+use std::io::Write;
+use std::iter;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use bytes::Bytes;
+use common::Diagnostic;
+use common::DiagnosticSeverity;
+use common::DiagnosticTag;
+use common::Location;
+use fnv::FnvHashSet;
+use futures::future::join_all;
+use http::Method;
+use http::Request;
+use http::StatusCode;
+use http_body_util::BodyExt as _;
+use http_body_util::Full;
+use hyper_tls::HttpsConnector;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use log::debug;
+use log::warn;
+use relay_codegen::QueryID;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::artifact_map::ArtifactMap;
+use crate::artifact_map::ArtifactRecord;
+use crate::artifact_map::ArtifactSourceKey;
+use crate::build_project::Artifact;
+use crate::build_project::ArtifactContent;
+use crate::compiler_state::ArtifactMapKind;
+use crate::compiler_state::CompilerState;
+use crate::compiler_state::IncrementalSources;
+use crate::compiler_state::Source;
+use crate::config::ArtifactForPersister;
+use crate::config::Config;
+use crate::config::ProjectConfig;
+use crate::errors::BuildProjectError;
+use crate::explain::project_config_json;
+
+type StoreResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Where the artifact cache keeps its entries.
+#[derive(Clone, Debug)]
+pub struct ArtifactCacheConfig {
+    /// Directory of the entries.
+    pub dir: PathBuf,
+    /// Base URL of an HTTP store shared with other machines. Entries are
+    /// read with `GET <url>/<key>` and written with `PUT <url>/<key>`.
+    pub url: Option<String>,
+    /// Fingerprint of the compiler, see `compiler_fingerprint`. Entries of
+    /// other builds of the compiler are never used.
+    pub compiler_version: String,
+}
+
+/// The artifacts of a project, as written to disk, the entries of its
+/// artifact map, and what the build persisted and reported.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ArtifactCacheEntry {
+    artifacts: Vec<CachedArtifact>,
+    artifact_map: Vec<(ArtifactSourceKey, Vec<ArtifactRecord>)>,
+    persisted_operations: Vec<CachedPersistedOperation>,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedArtifact {
+    path: PathBuf,
+    content: String,
+}
+
+/// An operation the build gave to the persister, by its artifact path.
+#[derive(Serialize, Deserialize)]
+struct CachedPersistedOperation {
+    path: PathBuf,
+    text: String,
+    override_schema: Option<String>,
+}
+
+/// A diagnostic of the build, with its messages printed.
+#[derive(Serialize, Deserialize)]
+struct CachedDiagnostic {
+    severity: DiagnosticSeverity,
+    message: String,
+    location: Location,
+    tags: Vec<DiagnosticTag>,
+    related_information: Vec<(String, Location)>,
+}
+
+impl CachedDiagnostic {
+    fn new(diagnostic: &Diagnostic) -> Self {
+        Self {
+            severity: diagnostic.severity(),
+            message: diagnostic.message().to_string(),
+            location: diagnostic.location(),
+            tags: diagnostic.tags(),
+            related_information: diagnostic
+                .related_information()
+                .iter()
+                .map(|related| (related.message.to_string(), related.location))
+                .collect(),
+        }
+    }
+
+    fn into_diagnostic(self) -> Diagnostic {
+        let diagnostic = match self.severity {
+            DiagnosticSeverity::WARNING => {
+                Diagnostic::warning(self.message, self.location, self.tags)
+            }
+            DiagnosticSeverity::INFORMATION => {
+                Diagnostic::info(self.message, self.location, self.tags)
+            }
+            DiagnosticSeverity::HINT => Diagnostic::hint(self.message, self.location, self.tags),
+            _ => Diagnostic::error(self.message, self.location),
+        };
+        self.related_information
+            .into_iter()
+            .fold(diagnostic, |diagnostic, (message, location)| {
+                diagnostic.annotate(message, location)
+            })
+    }
+}
+
+impl ArtifactCacheEntry {
+    pub(crate) fn artifact_count(&self) -> usize {
+        self.artifacts.len()
+    }
+}
+
+/// Collects the content of the artifacts written by a build, for the entry of
+/// the project.
+#[derive(Default)]
+pub struct ArtifactCacheRecorder {
+    artifacts: Mutex<Vec<CachedArtifact>>,
+    persisted_operations: Mutex<Vec<CachedPersistedOperation>>,
+    has_binary_content: AtomicBool,
+}
+
+impl ArtifactCacheRecorder {
+    pub(crate) fn record(&self, path: &Path, content: &[u8]) {
+        match String::from_utf8(content.to_vec()) {
+            Ok(content) => self.artifacts.lock().unwrap().push(CachedArtifact {
+                path: path.to_path_buf(),
+                content,
+            }),
+            Err(_) => self.has_binary_content.store(true, Ordering::Relaxed),
+        }
+    }
+
+    /// Record the operations of the artifacts that were persisted.
+    pub(crate) fn record_persisted_operations(
+        &self,
+        config: &Config,
+        project_config: &ProjectConfig,
+        artifacts: &[Artifact],
+    ) {
+        let mut persisted_operations = self.persisted_operations.lock().unwrap();
+        for artifact in artifacts {
+            if let ArtifactContent::Operation {
+                normalization_operation,
+                text: Some(text),
+                id_and_text_hash: Some(QueryID::Persisted { .. }),
+                ..
+            } = &artifact.content
+            {
+                persisted_operations.push(CachedPersistedOperation {
+                    path: artifact.path.clone(),
+                    text: text.clone(),
+                    override_schema: config
+                        .custom_override_schema_determinator
+                        .as_ref()
+                        .and_then(|determinator| {
+                            determinator(project_config, normalization_operation)
+                        }),
+                });
+            }
+        }
+    }
+
+    /// The entry of the recorded artifacts, unless some of them can't be
+    /// cached.
+    pub(crate) fn into_entry(
+        self,
+        artifact_map: &ArtifactMap,
+        diagnostics: &[Diagnostic],
+    ) -> Option<ArtifactCacheEntry> {
+        if self.has_binary_content.into_inner() {
+            debug!("Not caching artifacts, some of them aren't UTF-8.");
+            return None;
+        }
+        let mut artifacts = self.artifacts.into_inner().unwrap();
+        artifacts.sort_by(|a, b| a.path.cmp(&b.path));
+        let mut artifact_map = artifact_map
+            .0
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        artifact_map.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut persisted_operations = self.persisted_operations.into_inner().unwrap();
+        persisted_operations.sort_by(|a, b| a.path.cmp(&b.path));
+        Some(ArtifactCacheEntry {
+            artifacts,
+            artifact_map,
+            persisted_operations,
+            diagnostics: diagnostics.iter().map(CachedDiagnostic::new).collect(),
+        })
+    }
+}
+
+/// The cache key of a project, from the compiler version, the resolved
+/// configs, the transform plugins and the current sources of the project and
+/// its base project.
+pub(crate) fn cache_key(
+    config: &Config,
+    cache_config: &ArtifactCacheConfig,
+    project_config: &ProjectConfig,
+    compiler_state: &CompilerState,
+) -> String {
+    let mut hasher = Sha256::new();
+    hash_json(&mut hasher, &cache_config.compiler_version);
+    hash_json(&mut hasher, &config.header);
+    hash_json(&mut hasher, &config.codegen_command);
+    hash_json(&mut hasher, &config.is_dev_variable_name);
+    hash_json(&mut hasher, &config.transform_plugins.cache_key_json());
+    let base_config = project_config
+        .base
+        .and_then(|base| config.projects.get(&base));
+    for project_config in iter::once(project_config).chain(base_config) {
+        let name = project_config.name;
+        hash_json(&mut hasher, &project_config_json(config, project_config));
+        hash_sources(&mut hasher, compiler_state.graphql_sources.get(&name));
+        hash_sources(&mut hasher, compiler_state.schemas.get(&name));
+        hash_sources(&mut hasher, compiler_state.extensions.get(&name));
+        hash_sources(&mut hasher, compiler_state.docblocks.get(&name));
+        hash_sources(&mut hasher, compiler_state.full_sources.get(&name));
+        hash_sources(&mut hasher, compiler_state.compact_schemas.get(&name));
+        hash_sources(&mut hasher, compiler_state.flat_buffer_schemas.get(&name));
+    }
+    hex::encode(hasher.finalize())
+}
+
+fn hash_json(hasher: &mut Sha256, value: &impl Serialize) {
+    serde_json::to_writer(&mut *hasher, value).unwrap();
+    hasher.update(b"\n");
+}
+
+fn hash_sources<V: Source + Clone + Serialize>(
+    hasher: &mut Sha256,
+    sources: Option<&IncrementalSources<V>>,
+) {
+    let sources = sources.map_or_else(Vec::new, |sources| sources.get_all_non_empty());
+    hash_json(hasher, &sources);
+}
+
+/// The entry of `key`, from the local directory or else the HTTP store.
+pub(crate) async fn fetch(
+    cache_config: &ArtifactCacheConfig,
+    key: &str,
+) -> Option<ArtifactCacheEntry> {
+    let local_path = cache_config.dir.join(key);
+    let contents = match fs::read(&local_path) {
+        Ok(contents) => contents,
+        Err(_) => {
+            let url = cache_config.url.as_ref()?;
+            match http_get(&format!("{}/{}", url.trim_end_matches('/'), key)).await {
+                Ok(Some(contents)) => {
+                    if let Err(err) = write_local(cache_config, key, &contents) {
+                        warn!("Unable to write the artifact cache entry {key}: {err}");
+                    }
+                    contents
+                }
+                Ok(None) => return None,
+                Err(err) => {
+                    warn!("Unable to fetch the artifact cache entry {key}: {err}");
+                    return None;
+                }
+            }
+        }
+    };
+    match decode(&contents) {
+        Ok(entry) => Some(entry),
+        Err(err) => {
+            warn!("Ignoring the invalid artifact cache entry {key}: {err}");
+            None
+        }
+    }
+}
+
+/// Store the entry of `key` in the local directory and the HTTP store.
+pub(crate) async fn store(
+    cache_config: &ArtifactCacheConfig,
+    key: &str,
+    entry: &ArtifactCacheEntry,
+) {
+    let result: StoreResult<()> = async {
+        let contents = encode(entry)?;
+        write_local(cache_config, key, &contents)?;
+        if let Some(url) = &cache_config.url {
+            http_put(&format!("{}/{}", url.trim_end_matches('/'), key), contents).await?;
+        }
+        Ok(())
+    }
+    .await;
+    if let Err(err) = result {
+        warn!("Unable to store the artifact cache entry {key}: {err}");
+    }
+}
+
+/// Write the artifacts of a cache entry, and remove the other artifacts of a
+/// project that wasn't built before. Like a build, the operations of the
+/// artifacts that weren't up to date on disk are given to the persister.
+/// Returns the artifact map and the diagnostics of the entry.
+pub(crate) async fn restore(
+    config: &Config,
+    project_config: &ProjectConfig,
+    entry: ArtifactCacheEntry,
+    artifact_map: Option<&ArtifactMapKind>,
+) -> Result<(ArtifactMap, Vec<Diagnostic>), BuildProjectError> {
+    let mut existing_artifacts = match artifact_map {
+        Some(ArtifactMapKind::Unconnected(existing_artifacts)) => existing_artifacts.clone(),
+        _ => Default::default(),
+    };
+    let mut changed_artifacts = FnvHashSet::default();
+    for artifact in entry.artifacts {
+        existing_artifacts.remove(&artifact.path);
+        let path = config.root_dir.join(&artifact.path);
+        let content = artifact.content.into_bytes();
+        if !fs::read(&path).is_ok_and(|existing| existing == content) {
+            changed_artifacts.insert(artifact.path);
+        }
+        if config.artifact_writer.should_write(&path, &content, None)? {
+            config.artifact_writer.write(path, content)?;
+        }
+    }
+    for remaining_artifact in existing_artifacts {
+        config
+            .artifact_writer
+            .remove(config.root_dir.join(remaining_artifact))?;
+    }
+    let persisted_operations = entry
+        .persisted_operations
+        .into_iter()
+        .filter(|operation| changed_artifacts.contains(&operation.path))
+        .collect::<Vec<_>>();
+    if !persisted_operations.is_empty()
+        && let Some(operation_persister) = config
+            .create_operation_persister
+            .as_ref()
+            .and_then(|create_fn| create_fn(project_config))
+    {
+        let results = join_all(persisted_operations.into_iter().map(|operation| {
+            operation_persister.persist_artifact(ArtifactForPersister {
+                text: operation.text,
+                relative_path: operation.path,
+                override_schema: operation.override_schema,
+            })
+        }))
+        .await;
+        operation_persister
+            .finalize()
+            .map_err(|error| BuildProjectError::PersistErrors {
+                errors: vec![error],
+                project_name: project_config.name,
+            })?;
+        let errors = results
+            .into_iter()
+            .filter_map(Result::err)
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(BuildProjectError::PersistErrors {
+                errors,
+                project_name: project_config.name,
+            });
+        }
+    }
+    Ok((
+        ArtifactMap(entry.artifact_map.into_iter().collect()),
+        entry
+            .diagnostics
+            .into_iter()
+            .map(CachedDiagnostic::into_diagnostic)
+            .collect(),
+    ))
+}
+
+fn encode(entry: &ArtifactCacheEntry) -> StoreResult<Vec<u8>> {
+    let json = serde_json::to_vec(entry)?;
+    Ok(zstd::encode_all(json.as_slice(), 3)?)
+}
+
+fn decode(contents: &[u8]) -> StoreResult<ArtifactCacheEntry> {
+    let json = zstd::decode_all(contents)?;
+    Ok(serde_json::from_slice(&json)?)
+}
+
+/// Write an entry to a temporary file first, so that a concurrent build
+/// never reads a partial entry.
+fn write_local(cache_config: &ArtifactCacheConfig, key: &str, contents: &[u8]) -> StoreResult<()> {
+    fs::create_dir_all(&cache_config.dir)?;
+    let temp_path = cache_config
+        .dir
+        .join(format!("{}.{}.tmp", key, std::process::id()));
+    fs::File::create(&temp_path)?.write_all(contents)?;
+    fs::rename(&temp_path, cache_config.dir.join(key))?;
+    Ok(())
+}
+
+async fn http_get(url: &str) -> StoreResult<Option<Vec<u8>>> {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(url)
+        .body(Full::new(Bytes::new()))?;
+    let client = Client::builder(TokioExecutor::new()).build(HttpsConnector::new());
+    let response = client.request(request).await?;
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        return Err(format!("GET {url} responded with {status}").into());
+    }
+    Ok(Some(
+        response.into_body().collect().await?.to_bytes().to_vec(),
+    ))
+}
+
+async fn http_put(url: &str, contents: Vec<u8>) -> StoreResult<()> {
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(url)
+        .header("content-type", "application/octet-stream")
+        .body(Full::new(Bytes::from(contents)))?;
+    let client = Client::builder(TokioExecutor::new()).build(HttpsConnector::new());
+    let status = client.request(request).await?.status();
+    if !status.is_success() {
+        return Err(format!("PUT {url} responded with {status}").into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    use common::NoopPerfLogger;
+    use relay_config::PersistConfig;
+
+    use super::*;
+    use crate::LocalPersister;
+    use crate::OperationPersister;
+    use crate::compiler::Compiler;
+    use crate::config::FileSourceKind;
+    use crate::errors::Error;
+    use crate::status_reporter::StatusReporter;
+
+    /// Records the reported diagnostics and artifact cache lookups.
+    #[derive(Clone, Default)]
+    struct RecordingStatusReporter {
+        diagnostics: Arc<Mutex<Vec<String>>>,
+        lookups: Arc<Mutex<Vec<(usize, usize)>>>,
+    }
+
+    impl StatusReporter for RecordingStatusReporter {
+        fn build_starts(&self) {}
+
+        fn build_completes(&self, diagnostics: &[Diagnostic]) {
+            self.diagnostics
+                .lock()
+                .unwrap()
+                .extend(diagnostics.iter().map(|diagnostic| diagnostic.to_string()));
+        }
+
+        fn build_errors(&self, error: &Error) {
+            panic!("Unexpected build error: {error}");
+        }
+
+        fn artifact_cache_lookups(&self, hits: usize, misses: usize) {
+            self.lookups.lock().unwrap().push((hits, misses));
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    async fn build_with_cache(
+        root_dir: &Path,
+        cache_dir: &Path,
+        status_reporter: &RecordingStatusReporter,
+    ) -> CompilerState {
+        let mut config = Config::search(root_dir).unwrap();
+        config.file_source_config = FileSourceKind::WalkDir;
+        config.create_operation_persister = Some(Box::new(|project_config| {
+            project_config.persist.as_ref().map(
+                |persist_config| -> Box<dyn OperationPersister + Send + Sync> {
+                    match persist_config {
+                        PersistConfig::Local(local_config) => {
+                            Box::new(LocalPersister::new(local_config.clone()))
+                        }
+                        PersistConfig::Remote(_) => unreachable!(),
+                    }
+                },
+            )
+        }));
+        config.artifact_cache = Some(ArtifactCacheConfig {
+            dir: cache_dir.to_path_buf(),
+            url: None,
+            compiler_version: "test".to_string(),
+        });
+        config.status_reporter = Box::new(status_reporter.clone());
+        let compiler = Compiler::new(Arc::new(config), Arc::new(NoopPerfLogger));
+        let compiler_state = compiler.compile().await.unwrap();
+        status_reporter.build_completes(&[]);
+        compiler_state
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    fn generated_artifacts(root_dir: &Path) -> BTreeMap<PathBuf, String> {
+        fs::read_dir(root_dir.join("src/__generated__"))
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let content = fs::read_to_string(&path).unwrap();
+                (path, content)
+            })
+            .collect()
+    }
+
+    /// A second checkout of the same sources, like another CI job, is
+    /// restored from the entry of the first build.
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[tokio::test]
+    async fn restores_a_full_build_from_the_cache() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let root_dir = root_dir.path();
+        let cache_dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root_dir.join("src")).unwrap();
+        fs::write(root_dir.join("operations.json"), "{}").unwrap();
+        fs::write(
+            root_dir.join("relay.config.json"),
+            serde_json::json!({
+                "sources": { "src": "default" },
+                "projects": {
+                    "default": {
+                        "language": "javascript",
+                        "schema": "schema.graphql",
+                        "persist": { "file": root_dir.join("operations.json") },
+                        "queryLimits": { "maxDepth": 1, "level": "warning" }
+                    }
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        fs::write(
+            root_dir.join("schema.graphql"),
+            "type Query { me: User }\ntype User { name: String, friend: User }\n",
+        )
+        .unwrap();
+        fs::write(
+            root_dir.join("src/Profile.js"),
+            "graphql`query ProfileQuery { me { friend { name } } }`;\n",
+        )
+        .unwrap();
+
+        let status_reporter = RecordingStatusReporter::default();
+        let first_state = build_with_cache(root_dir, cache_dir.path(), &status_reporter).await;
+        let first_artifacts = generated_artifacts(root_dir);
+        let first_operations = fs::read_to_string(root_dir.join("operations.json")).unwrap();
+        let first_diagnostics = status_reporter.diagnostics.lock().unwrap().split_off(0);
+        assert_eq!(first_diagnostics.len(), 1);
+
+        fs::remove_dir_all(root_dir.join("src/__generated__")).unwrap();
+        fs::write(root_dir.join("operations.json"), "{}").unwrap();
+        let second_state = build_with_cache(root_dir, cache_dir.path(), &status_reporter).await;
+
+        assert_eq!(
+            *status_reporter.lookups.lock().unwrap(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(generated_artifacts(root_dir), first_artifacts);
+        assert_eq!(
+            fs::read_to_string(root_dir.join("operations.json")).unwrap(),
+            first_operations
+        );
+        assert_eq!(
+            format!("{:?}", second_state.artifacts),
+            format!("{:?}", first_state.artifacts)
+        );
+        assert_eq!(
+            *status_reporter.diagnostics.lock().unwrap(),
+            first_diagnostics
+        );
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[tokio::test]
+    async fn misses_the_cache_when_a_transform_plugin_changes() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let root_dir = root_dir.path();
+        let cache_dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root_dir.join("src")).unwrap();
+        let write_config = |plugin_script: &str| {
+            fs::write(
+                root_dir.join("relay.config.json"),
+                serde_json::json!({
+                    "sources": { "src": "default" },
+                    "projects": {
+                        "default": { "language": "javascript", "schema": "schema.graphql" }
+                    },
+                    "transformPlugins": [{ "command": "sh", "args": ["-c", plugin_script] }]
+                })
+                .to_string(),
+            )
+            .unwrap();
+        };
+        fs::write(
+            root_dir.join("schema.graphql"),
+            "type Query { name: String }
+",
+        )
+        .unwrap();
+        fs::write(
+            root_dir.join("src/Name.js"),
+            "graphql`query NameQuery { name }`;\n",
+        )
+        .unwrap();
+        let plugin_script = r#"read request
+echo '{"protocolVersion": 2, "hooks": []}'
+while read request; do :; done"#;
+
+        let status_reporter = RecordingStatusReporter::default();
+        write_config(plugin_script);
+        build_with_cache(root_dir, cache_dir.path(), &status_reporter).await;
+        build_with_cache(root_dir, cache_dir.path(), &status_reporter).await;
+        write_config(&format!("{plugin_script}\n# v2"));
+        build_with_cache(root_dir, cache_dir.path(), &status_reporter).await;
+
+        assert_eq!(
+            *status_reporter.lookups.lock().unwrap(),
+            vec![(0, 1), (1, 0), (0, 1)]
+        );
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[tokio::test]
+    async fn stores_and_fetches_local_entries() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache_config = ArtifactCacheConfig {
+            dir: temp_dir.path().to_path_buf(),
+            url: None,
+            compiler_version: "test".to_string(),
+        };
+        assert!(fetch(&cache_config, "key").await.is_none());
+
+        let recorder = ArtifactCacheRecorder::default();
+        recorder.record(
+            Path::new("__generated__/B.graphql.js"),
+            b"/* B */".as_slice(),
+        );
+        recorder.record(
+            Path::new("__generated__/A.graphql.js"),
+            b"/* A */".as_slice(),
+        );
+        let entry = recorder.into_entry(&ArtifactMap::default(), &[]).unwrap();
+        store(&cache_config, "key", &entry).await;
+
+        let entry = fetch(&cache_config, "key").await.unwrap();
+        assert_eq!(
+            entry
+                .artifacts
+                .iter()
+                .map(|artifact| (artifact.path.to_str().unwrap(), artifact.content.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("__generated__/A.graphql.js", "/* A */"),
+                ("__generated__/B.graphql.js", "/* B */"),
+            ]
+        );
+    }
+
+    #[test]
+    fn binary_artifacts_are_not_cached() {
+        let recorder = ArtifactCacheRecorder::default();
+        recorder.record(Path::new("schema.bin"), &[0xff, 0xfe]);
+        assert!(recorder.into_entry(&ArtifactMap::default(), &[]).is_none());
+    }
+}
@@ -57,6 +57,12 @@ pub trait ArtifactWriter {
     fn content_matches_last_write(&self, _path: &Path) -> bool {
         false
     }
+
+    /// Whether the writer changes the artifacts on disk. Writers that only
+    /// check the artifacts, like the one of `--validate`, return `false`.
+    fn writes_artifacts(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Default)]
@@ -244,6 +250,10 @@ impl ArtifactWriter for ArtifactValidationWriter {
             Err(Error::ArtifactsValidationError { error: output })
         }
     }
+
+    fn writes_artifacts(&self) -> bool {
+        false
+    }
 }
 
 fn write_outdated_artifacts(output: &mut String, title: &str, artifacts: &DashSet<PathBuf>) {
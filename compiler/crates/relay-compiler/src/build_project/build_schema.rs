@@ -16,6 +16,8 @@ use common::PerfLogEvent;
 use common::SourceLocationKey;
 use fnv::FnvHashMap;
 use graphql_syntax::SchemaDocument;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
 use relay_config::ProjectName;
 use relay_config::SchemaLocation;
 use relay_docblock::extend_schema_with_resolver_type_system_definition;
@@ -23,6 +25,10 @@ use relay_docblock::validate_resolver_schema;
 use schema::SDLSchema;
 use schema::SchemaDocuments;
 use schema::parse_schema_with_extensions_parallel;
+use schema_set::SchemaSet;
+use schema_set::Subgraph;
+use schema_set::ToSDLDefinition;
+use schema_set::compose_subgraphs;
 use schema_validate_lib::SchemaValidationOptions;
 use schema_validate_lib::validate;
 
@@ -133,10 +139,15 @@ fn build_schema_impl(
     }
 
     let extensions = get_extension_sources(compiler_state, project_config);
+    let flat_buffer_path = match &project_config.schema_location {
+        SchemaLocation::FlatBufferFile(path) => Some(config.root_dir.join(path)),
+        _ => None,
+    };
 
     // Parse the server and extension schema text. If pre-parsed server ASTs
     // are available in the cache (shared across projects with the same schema
     // source), reuse them and only parse the project-specific extensions.
+    // Flatbuffer schemas are loaded instead of parsed.
     let cached_server_asts = schema_location_sdl_path(&project_config.schema_location)
         .and_then(|path| compiler_state.parsed_server_asts_cache.get(path));
     log_event.number(
@@ -144,7 +155,18 @@ fn build_schema_impl(
         cached_server_asts.is_some() as usize,
     );
 
-    let (server_asts, mut extension_asts) = if let Some(cached) = cached_server_asts {
+    let (server_asts, mut extension_asts) = if flat_buffer_path.is_some() {
+        log_event.time("parse_schema_extensions_only_time", || {
+            parse_extension_documents(&extensions).map(|ext_docs| (Arc::new(vec![]), ext_docs))
+        })?
+    } else if let SchemaLocation::Subgraphs(_) = &project_config.schema_location {
+        log_event.time("compose_subgraphs_time", || {
+            let schema_sources = get_schema_sources(compiler_state, project_config);
+            let supergraph = compose_subgraph_sources(&schema_sources)?;
+            let server = vec![schema::builtins()?, supergraph.to_sdl_definition()];
+            parse_extension_documents(&extensions).map(|ext_docs| (Arc::new(server), ext_docs))
+        })?
+    } else if let Some(cached) = cached_server_asts {
         log_event.time("parse_schema_extensions_only_time", || {
             parse_extension_documents(&extensions).map(|ext_docs| (Arc::clone(cached), ext_docs))
         })?
@@ -177,8 +199,13 @@ fn build_schema_impl(
 
     // Now that all the named types have been collected, we can build
     // the normalized schema. All names should be able to be resolved.
-    let mut schema = log_event.time("build_schema_time", || {
-        relay_schema::build_schema_with_extensions_from_asts(&server_asts, extension_asts)
+    let mut schema = log_event.time("build_schema_time", || match &flat_buffer_path {
+        Some(path) => relay_schema::build_schema_with_extensions_from_flat_buffer_file(
+            path,
+            extension_asts,
+            config.memory_map_flat_buffer_schemas,
+        ),
+        None => relay_schema::build_schema_with_extensions_from_asts(&server_asts, extension_asts),
     })?;
 
     // Now that the normalized schema has been built we can add fields to existing types by name.
@@ -205,12 +232,49 @@ fn build_schema_impl(
 }
 
 /// Returns the SDL schema path for File/Directory schema locations,
-/// or None for CompactFile schemas (which use a different code path).
+/// or None for CompactFile and FlatBufferFile schemas, which aren't parsed,
+/// and for Subgraphs, which are composed before being built.
 fn schema_location_sdl_path(location: &SchemaLocation) -> Option<&Path> {
     match location {
         SchemaLocation::File(path) | SchemaLocation::Directory(path) => Some(path),
-        SchemaLocation::CompactFile(_) => None,
+        SchemaLocation::CompactFile(_)
+        | SchemaLocation::FlatBufferFile(_)
+        | SchemaLocation::Subgraphs(_) => None,
+    }
+}
+
+/// Parse the SDL of every subgraph and compose them into the supergraph
+/// schema. Subgraphs are named after their file.
+pub fn compose_subgraph_sources(
+    subgraph_sdls: &[(&str, SourceLocationKey)],
+) -> DiagnosticsResult<SchemaSet> {
+    let mut errors = vec![];
+    let mut documents = vec![];
+    for (sdl, location_key) in subgraph_sdls {
+        match graphql_syntax::parse_schema_document(sdl, *location_key) {
+            Ok(document) => documents.push((subgraph_name(*location_key), document)),
+            Err(diagnostics) => errors.extend(diagnostics),
+        }
     }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let subgraphs = documents
+        .iter()
+        .map(|(name, document)| Subgraph {
+            name: *name,
+            document,
+        })
+        .collect::<Vec<_>>();
+    compose_subgraphs(&subgraphs)
+}
+
+fn subgraph_name(location_key: SourceLocationKey) -> StringKey {
+    let path = Path::new(location_key.path());
+    path.file_stem()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .intern()
 }
 
 /// Parse extension SDL documents independently of server schema.
@@ -17,6 +17,7 @@ use graphql_ir::OperationDefinitionName;
 use graphql_text_printer::PrinterOptions;
 use graphql_text_printer::compute_operation_text;
 use graphql_text_printer::precompute_fragment_texts;
+use indexmap::IndexMap;
 use intern::Lookup;
 use intern::string_key::StringKey;
 use rayon::prelude::*;
@@ -30,6 +31,10 @@ use relay_transforms::RawResponseGenerationMode;
 use relay_transforms::RefetchableDerivedFromMetadata;
 use relay_transforms::SplitOperationMetadata;
 use relay_transforms::UPDATABLE_DIRECTIVE;
+use relay_typegen::TypegenLanguage;
+use relay_typegen::collect_schema_types;
+use schema::Schema;
+use schema::Type;
 
 pub use super::artifact_content::ArtifactContent;
 use super::build_ir::SourceHashes;
@@ -81,7 +86,7 @@ pub fn generate_artifacts(
             )
         })
         .collect();
-    group_operations(programs).into_values().map(|operations| {
+    let mut artifacts: Vec<Artifact> = group_operations(programs).into_values().map(|operations| {
             if let Some(normalization) = operations.normalization {
                 // We have a normalization AST... so we'll move forward with that
                 if let Some(metadata) = SplitOperationMetadata::find(&normalization.directives)
@@ -226,7 +231,20 @@ pub fn generate_artifacts(
                 _ => vec![],
             }
         )
-        .collect()
+        // Native projects only get the response models of their operations
+        // and fragments.
+        .filter(|artifact| {
+            !project_config.typegen_config.language.is_native()
+                || matches!(
+                    artifact.content,
+                    ArtifactContent::Operation { .. } | ArtifactContent::Fragment { .. }
+                )
+        })
+        .collect();
+    let schema_type_artifacts =
+        generate_schema_type_artifacts(project_config, programs, &artifacts);
+    artifacts.extend(schema_type_artifacts);
+    artifacts
 }
 
 fn generate_normalization_artifact(
@@ -423,3 +441,73 @@ fn generate_resolvers_schema_module_artifact(path: PathBuf) -> Artifact {
         source_file: SourceLocationKey::generated(),
     }
 }
+
+/// Generates the shared type modules of the input objects and custom scalars
+/// used by the project, when `schemaTypesOutput` is configured.
+///
+/// A module is keyed by the sources of every artifact that references its
+/// type rather than by the schema: an incremental build only sees the dirty
+/// definitions, and the artifact map keeps a module as long as any source
+/// still references it.
+fn generate_schema_type_artifacts(
+    project_config: &ProjectConfig,
+    programs: &Programs,
+    artifacts: &[Artifact],
+) -> Vec<Artifact> {
+    if project_config.typegen_config.schema_types_output.is_none()
+        || project_config.typegen_config.language == TypegenLanguage::JavaScript
+        || project_config.typegen_config.language.is_native()
+    {
+        return vec![];
+    }
+    let schema = &programs.typegen.schema;
+    let mut source_keys_by_type: IndexMap<Type, Vec<ArtifactSourceKey>> = IndexMap::new();
+    for artifact in artifacts {
+        let (operations, fragments): (Vec<&OperationDefinition>, Vec<&FragmentDefinition>) =
+            match &artifact.content {
+                ArtifactContent::Operation {
+                    normalization_operation,
+                    typegen_operation,
+                    ..
+                } => (vec![normalization_operation, typegen_operation], vec![]),
+                ArtifactContent::UpdatableQuery {
+                    typegen_operation, ..
+                } => (vec![typegen_operation], vec![]),
+                ArtifactContent::SplitOperation {
+                    normalization_operation,
+                    typegen_operation,
+                    ..
+                } => (
+                    std::iter::once(normalization_operation)
+                        .chain(typegen_operation)
+                        .map(AsRef::as_ref)
+                        .collect(),
+                    vec![],
+                ),
+                ArtifactContent::Fragment {
+                    typegen_fragment, ..
+                } => (vec![], vec![typegen_fragment]),
+                _ => continue,
+            };
+        for type_ in collect_schema_types(schema, operations, fragments, project_config) {
+            let source_keys = source_keys_by_type.entry(type_).or_default();
+            for source_key in &artifact.artifact_source_keys {
+                if !source_keys.contains(source_key) {
+                    source_keys.push(source_key.clone());
+                }
+            }
+        }
+    }
+    source_keys_by_type.sort_by_cached_key(|type_, _| schema.get_type_name(*type_));
+    source_keys_by_type
+        .into_iter()
+        .map(|(type_, artifact_source_keys)| Artifact {
+            artifact_source_keys,
+            path: project_config
+                .path_for_schema_type_module(schema.get_type_name(type_))
+                .unwrap(),
+            content: ArtifactContent::SchemaType(type_),
+            source_file: SourceLocationKey::generated(),
+        })
+        .collect()
+}
@@ -181,11 +181,19 @@ fn find_changed_names(
 pub fn find_duplicates(
     asts: &[ExecutableDefinition],
     base_asts: &[ExecutableDefinition],
+) -> Result<(), Vec<Diagnostic>> {
+    find_duplicate_definitions(asts.iter().chain(base_asts))
+}
+
+/// Report the definitions with the name of a previous definition, like the
+/// definitions of different workspace packages.
+pub fn find_duplicate_definitions<'a>(
+    definitions_iter: impl IntoIterator<Item = &'a ExecutableDefinition>,
 ) -> Result<(), Vec<Diagnostic>> {
     let mut definitions = FnvHashMap::default();
 
     let mut errors = Vec::new();
-    for def in asts.iter().chain(base_asts) {
+    for def in definitions_iter {
         if let Some(name) = def.name_identifier()
             && let Some(prev_def) = definitions.insert(name.value, def)
         {
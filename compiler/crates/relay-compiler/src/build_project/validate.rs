@@ -30,6 +30,7 @@ use relay_transforms::validate_no_double_underscore_alias;
 use relay_transforms::validate_no_inline_fragments_with_raw_response_type;
 use relay_transforms::validate_no_unselectable_selections;
 use relay_transforms::validate_operation_variables;
+use relay_transforms::validate_query_limits;
 use relay_transforms::validate_relay_directives;
 use relay_transforms::validate_resolver_fragments;
 use relay_transforms::validate_static_args;
@@ -62,6 +63,23 @@ pub fn validate_reader(
     transform_errors(output, project_config)
 }
 
+/// Perform validations on the program after it has been transformed for
+/// normalization, i.e. on the selections that are sent to the server.
+pub fn validate_normalization(
+    program: &Program,
+    project_config: &ProjectConfig,
+) -> DiagnosticsResult<WithDiagnostics<()>> {
+    let output = try_all(vec![
+        if let Some(query_limits) = &project_config.query_limits {
+            validate_query_limits(program, query_limits)
+        } else {
+            Ok(())
+        },
+    ]);
+
+    transform_errors(output, project_config)
+}
+
 pub fn validate(
     program: &Program,
     project_config: &ProjectConfig,
@@ -22,11 +22,13 @@ use common::PerfLogEvent;
 use common::PerfLogger;
 use common::WithDiagnostics;
 use docblock_shared::ResolverSourceHash;
+use fnv::FnvHashMap;
 use futures::future::join_all;
 use graphql_watchman::WatchmanFileSourceSubscriptionNextChange;
 use log::debug;
 use log::info;
 use rayon::prelude::*;
+use relay_config::ProjectName;
 use tokio::sync::Notify;
 use tokio::task;
 use tokio::task::JoinHandle;
@@ -34,14 +36,18 @@ use tokio::task::JoinHandle;
 use crate::FileSourceResult;
 use crate::artifact_map::ArtifactSourceKey;
 use crate::build_project::BuildProjectFailure;
+use crate::build_project::artifact_cache;
+use crate::build_project::artifact_cache::ArtifactCacheRecorder;
 use crate::build_project::build_project;
 use crate::build_project::commit_project;
+use crate::build_project::find_duplicate_definitions;
 use crate::build_project::get_artifacts_file_hash_map::spawn_artifact_hash_map_prefetch;
 use crate::compiler_state::ArtifactMapKind;
 use crate::compiler_state::CompilerState;
 use crate::compiler_state::DocblockSources;
 use crate::compiler_state::FullSources;
 use crate::config::Config;
+use crate::errors::BuildProjectError;
 use crate::errors::Error;
 use crate::errors::Result;
 use crate::file_source::FileSource;
@@ -91,6 +97,7 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
             let diagnostics = self
                 .build_projects(&mut compiler_state, &setup_event)
                 .await?;
+            file_source.complete_build(&compiler_state);
 
             Ok((compiler_state, diagnostics))
         }
@@ -291,7 +298,8 @@ impl<TPerfLogger: PerfLogger> Compiler<TPerfLogger> {
                                     WatchmanFileSourceSubscriptionNextChange::None => {}
                                 }
                             }
-                            Ok(FileSourceSubscriptionNextChange::Test(file_source_changes)) => {
+                            Ok(FileSourceSubscriptionNextChange::NativeWatch(file_source_changes))
+                            | Ok(FileSourceSubscriptionNextChange::Test(file_source_changes)) => {
                                 pending_file_source_changes
                                     .write()
                                     .unwrap()
@@ -661,6 +669,21 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
         return Err(Error::Cancelled);
     }
 
+    // Definitions of workspace packages may be used by the same app, so their
+    // names must be unique across packages.
+    if !config.workspace_packages.is_empty() {
+        setup_event.time("find_workspace_duplicates_time", || {
+            find_duplicate_definitions(
+                config
+                    .workspace_packages
+                    .keys()
+                    .filter_map(|project_name| graphql_asts.get(project_name))
+                    .flat_map(|asts| asts.get_all_executable_definitions()),
+            )
+            .map_err(|errors| Error::DiagnosticsError { errors })
+        })?;
+    }
+
     // Pre-parse unique server schemas shared by multiple projects.
     // This avoids redundant parsing when many projects (e.g. intern_www,
     // facebook_www, oculus_www) all reference the same schema source.
@@ -676,6 +699,13 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
         compiler_state.parsed_server_asts_cache.len(),
     );
 
+    let (artifact_cache_keys, restored_diagnostics) =
+        restore_cached_artifacts(&config, setup_event, compiler_state)
+            .await
+            .map_err(|error| Error::BuildProjectsErrors {
+                errors: vec![error],
+            })?;
+
     let build_results: Vec<_> = config
         .par_enabled_projects()
         .filter(|project_config| {
@@ -686,6 +716,11 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
             }
             compiler_state.project_has_pending_changes(project_config.name)
         })
+        .filter(|project_config| {
+            artifact_cache_keys
+                .get(&project_config.name)
+                .is_none_or(|key| key.is_some())
+        })
         .map(|project_config| {
             build_project(
                 &config,
@@ -754,6 +789,7 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
             .unwrap_or_default();
 
         let source_control_update_status = Arc::clone(&compiler_state.source_control_update_status);
+        let artifact_cache_key = artifact_cache_keys.get(&project_name).cloned().flatten();
         handles.push(task::spawn(async move {
             // Spawn the Eden hash map RPC inside this per-project tokio task so
             // (a) the synchronous artifact-path extraction parallelizes across
@@ -776,6 +812,9 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
                 )
             };
             let project_config = &config.projects[&project_name];
+            let artifact_cache_recorder = artifact_cache_key
+                .as_ref()
+                .map(|_| ArtifactCacheRecorder::default());
             let artifact_map = commit_project(
                 &config,
                 project_config,
@@ -788,8 +827,18 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
                 dirty_artifact_paths,
                 source_control_update_status,
                 hash_map_handle,
+                artifact_cache_recorder.as_ref(),
             )
             .await?;
+            if let (Some(key), Some(artifact_cache_recorder), Some(cache_config)) = (
+                artifact_cache_key,
+                artifact_cache_recorder,
+                &config.artifact_cache,
+            ) && let Some(entry) =
+                artifact_cache_recorder.into_entry(&artifact_map, &diagnostics)
+            {
+                artifact_cache::store(cache_config, &key, &entry).await;
+            }
             Ok(((project_name, artifact_map, schema), diagnostics))
         }));
     }
@@ -797,7 +846,7 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
 
     let commit_all_projects_timer = setup_event.start("commit_all_projects_time");
     let mut build_cancelled_during_commit = false;
-    let mut all_diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut all_diagnostics: Vec<Diagnostic> = restored_diagnostics;
     for commit_result in join_all(handles).await {
         let commit_result: std::result::Result<std::result::Result<_, _>, _> = commit_result;
         let mut inner_result = commit_result.map_err(|e| Error::JoinError {
@@ -835,6 +884,79 @@ async fn build_projects<TPerfLogger: PerfLogger + 'static>(
     Ok(all_diagnostics)
 }
 
+/// Look up the enabled projects of a full build in the artifact cache, and
+/// restore the artifacts of the projects found. Returns the cache keys of the
+/// projects looked up, `None` for the restored projects, which aren't built,
+/// and the diagnostics of the restored projects.
+async fn restore_cached_artifacts(
+    config: &Config,
+    setup_event: &impl PerfLogEvent,
+    compiler_state: &mut CompilerState,
+) -> std::result::Result<
+    (FnvHashMap<ProjectName, Option<String>>, Vec<Diagnostic>),
+    BuildProjectError,
+> {
+    let mut cache_keys = FnvHashMap::default();
+    let mut diagnostics = Vec::new();
+    let Some(cache_config) = &config.artifact_cache else {
+        return Ok((cache_keys, diagnostics));
+    };
+    // Repersisting operations is the point of such builds.
+    if config.repersist_operations {
+        return Ok((cache_keys, diagnostics));
+    }
+    let timer = setup_event.start("restore_cached_artifacts_time");
+    let mut hits = 0;
+    for project_config in config.enabled_projects() {
+        let is_full_build = match compiler_state.artifacts.0.get(&project_config.name) {
+            Some(artifact_map) => matches!(**artifact_map, ArtifactMapKind::Unconnected(_)),
+            None => true,
+        };
+        if !is_full_build || !compiler_state.project_has_pending_changes(project_config.name) {
+            continue;
+        }
+        let key = artifact_cache::cache_key(config, cache_config, project_config, compiler_state);
+        match artifact_cache::fetch(cache_config, &key).await {
+            Some(entry) => {
+                info!(
+                    "[{}] restored {} artifacts from the artifact cache",
+                    project_config.name,
+                    entry.artifact_count()
+                );
+                let (artifact_map, restored_diagnostics) = artifact_cache::restore(
+                    config,
+                    project_config,
+                    entry,
+                    compiler_state
+                        .artifacts
+                        .0
+                        .get(&project_config.name)
+                        .map(Arc::as_ref),
+                )
+                .await?;
+                diagnostics.extend(restored_diagnostics);
+                compiler_state.artifacts.0.insert(
+                    project_config.name,
+                    Arc::new(ArtifactMapKind::Mapping(artifact_map)),
+                );
+                cache_keys.insert(project_config.name, None);
+                hits += 1;
+            }
+            None => {
+                cache_keys.insert(project_config.name, Some(key));
+            }
+        }
+    }
+    setup_event.stop(timer);
+    let misses = cache_keys.len() - hits;
+    setup_event.number("artifact_cache_hits", hits);
+    setup_event.number("artifact_cache_misses", misses);
+    if !cache_keys.is_empty() {
+        config.status_reporter.artifact_cache_lookups(hits, misses);
+    }
+    Ok((cache_keys, diagnostics))
+}
+
 /// Get the list of removed docblock sources.
 fn get_removed_docblock_artifact_source_keys(
     docblock_sources: Option<&DocblockSources>,
@@ -0,0 +1,54 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fs::File;
+use std::io;
+use std::sync::OnceLock;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+static COMPILER_FINGERPRINT: OnceLock<String> = OnceLock::new();
+
+/// Identifies the running compiler binary: its package version and a hash of
+/// its contents. Caches and the daemon compare it so that the output of
+/// another build of the compiler, even of the same version, is never used,
+/// while copies of the same binary on different machines share it. The binary
+/// is hashed once per process.
+pub fn compiler_fingerprint() -> String {
+    COMPILER_FINGERPRINT
+        .get_or_init(|| {
+            let binary = std::env::current_exe()
+                .and_then(File::open)
+                .and_then(|mut file| {
+                    let mut hasher = Sha256::new();
+                    io::copy(&mut file, &mut hasher)?;
+                    Ok(hex::encode(hasher.finalize()))
+                })
+                .unwrap_or_default();
+            format!("{}-{}", env!("CARGO_PKG_VERSION"), binary)
+        })
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_hashes_the_binary_contents() {
+        let contents = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+        assert_eq!(
+            compiler_fingerprint(),
+            format!(
+                "{}-{}",
+                env!("CARGO_PKG_VERSION"),
+                hex::encode(Sha256::digest(&contents))
+            )
+        );
+    }
+}
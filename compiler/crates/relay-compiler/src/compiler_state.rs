@@ -266,6 +266,9 @@ pub type SchemaSources = IncrementalSources<String>;
 pub type DocblockSources = IncrementalSources<Vec<LocatedDocblockSource>>;
 pub type FullSources = IncrementalSources<String>;
 pub type CompactSchemaSources = IncrementalSources<Vec<u8>>;
+/// Flatbuffer schemas are memory-mapped when the schema is built, so only a
+/// fingerprint (size and modification time) of the file is tracked.
+pub type FlatBufferSchemaSources = IncrementalSources<String>;
 
 impl Source for String {
     fn is_empty(&self) -> bool {
@@ -347,6 +350,8 @@ pub struct CompilerState {
     pub source_control_update_status: Arc<SourceControlUpdateStatus>,
     #[serde(default)]
     pub compact_schemas: FnvHashMap<ProjectName, CompactSchemaSources>,
+    #[serde(default)]
+    pub flat_buffer_schemas: FnvHashMap<ProjectName, FlatBufferSchemaSources>,
     /// Cache of pre-parsed server schema ASTs, keyed by schema location path.
     /// Populated before the parallel project build loop so that multiple projects
     /// sharing the same schema source can reuse the parsed ASTs instead of
@@ -433,6 +438,7 @@ enum FileSourceIntermediateResult {
     ),
     Schema(ProjectSet, FnvHashMap<PathBuf, String>, Vec<PathBuf>),
     CompactSchema(ProjectSet, FnvHashMap<PathBuf, Vec<u8>>, Vec<PathBuf>),
+    FlatBufferSchema(ProjectSet, FnvHashMap<PathBuf, String>, Vec<PathBuf>),
     Extension(ProjectSet, FnvHashMap<PathBuf, String>, Vec<PathBuf>),
     Generated(ProjectName, FnvHashSet<PathBuf>),
     Ignore,
@@ -495,6 +501,15 @@ impl CompilerState {
                         entry.merge_pending_sources(&added);
                     }
                 }
+                FileSourceIntermediateResult::FlatBufferSchema(project_set, added, removed) => {
+                    for project_name in project_set {
+                        let entry = result.flat_buffer_schemas.entry(project_name).or_default();
+                        for source in &removed {
+                            entry.pending.insert(source.clone(), "".to_string());
+                        }
+                        entry.merge_pending_sources(&added);
+                    }
+                }
                 FileSourceIntermediateResult::Extension(project_set, added, removed) => {
                     for project_name in project_set {
                         let entry = result.extensions.entry(project_name).or_default();
@@ -533,6 +548,10 @@ impl CompilerState {
                 .compact_schemas
                 .get(&project_name)
                 .is_some_and(|sources| !sources.pending.is_empty())
+            || self
+                .flat_buffer_schemas
+                .get(&project_name)
+                .is_some_and(|sources| !sources.pending.is_empty())
             || self.has_pending_non_schema_changes(project_name)
     }
 
@@ -548,7 +567,15 @@ impl CompilerState {
             (None, None) => true,
             _ => false,
         };
-        sdl_match && compact_match
+        let flat_buffer_match = match (
+            self.flat_buffer_schemas.get(&a),
+            self.flat_buffer_schemas.get(&b),
+        ) {
+            (Some(a), Some(b)) => a.pending == b.pending,
+            (None, None) => true,
+            _ => false,
+        };
+        sdl_match && compact_match && flat_buffer_match
     }
 
     /// Whether a project has pending extension, docblock, or full_source changes.
@@ -578,6 +605,10 @@ impl CompilerState {
                 .compact_schemas
                 .values()
                 .any(|sources| !sources.processed.is_empty())
+            || self
+                .flat_buffer_schemas
+                .values()
+                .any(|sources| !sources.processed.is_empty())
             || self
                 .extensions
                 .values()
@@ -652,6 +683,16 @@ impl CompilerState {
             log_event.string("has_breaking_schema_change", "full_source".to_owned());
             return SchemaChangeSafety::Unsafe;
         }
+        if let Some(flat_buffer_sources) = self.flat_buffer_schemas.get(&project_name)
+            && !flat_buffer_sources.pending.is_empty()
+        {
+            // The previous flatbuffer is not retained, so its changes can't be diffed.
+            log_event.string(
+                "has_breaking_schema_change",
+                "flat_buffer_schema".to_owned(),
+            );
+            return SchemaChangeSafety::Unsafe;
+        }
         if let Some(compact_sources) = self.compact_schemas.get(&project_name)
             && !compact_sources.pending.is_empty()
         {
@@ -789,6 +830,16 @@ impl CompilerState {
                             entry.merge_pending_sources(&added);
                         }
                     }
+                    FileSourceIntermediateResult::FlatBufferSchema(project_set, added, removed) => {
+                        has_changed = true;
+                        for project_name in project_set {
+                            let entry = self.flat_buffer_schemas.entry(project_name).or_default();
+                            for source in &removed {
+                                entry.pending.insert(source.clone(), "".to_string());
+                            }
+                            entry.merge_pending_sources(&added);
+                        }
+                    }
                     FileSourceIntermediateResult::Extension(project_set, added, removed) => {
                         has_changed = true;
                         for project_name in project_set {
@@ -833,6 +884,9 @@ impl CompilerState {
         for sources in self.compact_schemas.values_mut() {
             sources.commit_pending_sources();
         }
+        for sources in self.flat_buffer_schemas.values_mut() {
+            sources.commit_pending_sources();
+        }
         for sources in self.extensions.values_mut() {
             sources.commit_pending_sources();
         }
@@ -1037,6 +1091,34 @@ fn process_intermediate_compact_schema_change(
     Ok((added_sources, removed_sources))
 }
 
+fn process_intermediate_flat_buffer_schema_change(
+    file_source_changes: &FileSourceResult,
+    files: Vec<File>,
+) -> Result<(IncrementalSourceSet<String>, Vec<PathBuf>)> {
+    let mut removed_sources = vec![];
+    let mut added_sources = FnvHashMap::default();
+    for file in files {
+        let file_name = file.name.clone();
+        if file.exists {
+            let absolute_path = file.absolute_path(file_source_changes.resolved_root());
+            let metadata =
+                std::fs::metadata(&absolute_path).map_err(|err| Error::ReadFileError {
+                    file: absolute_path,
+                    source: err,
+                })?;
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+                .map_or(0, |duration| duration.as_nanos());
+            added_sources.insert(file_name, format!("{}:{}", metadata.len(), modified));
+        } else {
+            removed_sources.push(file_name);
+        }
+    }
+    Ok((added_sources, removed_sources))
+}
+
 fn process_categorized_sources(
     categorized: HashMap<FileGroup, Vec<File>>,
     config: &Config,
@@ -1082,6 +1164,15 @@ fn process_categorized_sources(
                     removed,
                 ))
             }
+            FileGroup::FlatBufferSchema { project_set } => {
+                let (added, removed) =
+                    process_intermediate_flat_buffer_schema_change(file_source_changes, files)?;
+                Ok(FileSourceIntermediateResult::FlatBufferSchema(
+                    project_set,
+                    added,
+                    removed,
+                ))
+            }
             FileGroup::Extension { project_set } => {
                 let (added, removed) =
                     process_intermediate_schema_change(file_source_changes, files)?;
@@ -1116,7 +1207,12 @@ fn extract_sources(
         .par_iter()
         .map(|file| {
             if file.exists {
-                match extract_javascript_features_from_file(file_source_changes, file, config) {
+                let features = log_event.time_item(
+                    "extract_graphql_strings_from_file",
+                    || file.name.display().to_string(),
+                    || extract_javascript_features_from_file(file_source_changes, file, config),
+                );
+                match features {
                     Ok(features) => Ok((file, features)),
                     Err(err) => Err(err),
                 }
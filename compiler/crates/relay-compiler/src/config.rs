@@ -26,8 +26,6 @@ use common::ScalarName;
 use dunce::canonicalize;
 use fnv::FnvBuildHasher;
 use fnv::FnvHashSet;
-use globset::Glob;
-use globset::GlobSetBuilder;
 use graphql_ir::OperationDefinition;
 use graphql_ir::Program;
 use indexmap::IndexMap;
@@ -40,20 +38,25 @@ use regex::Regex;
 use relay_config::CustomType;
 use relay_config::DiagnosticReportConfig;
 pub use relay_config::ExtraArtifactsConfig;
+use relay_config::GlobPatterns;
 use relay_config::JsModuleFormat;
 pub use relay_config::LocalPersistConfig;
 use relay_config::ModuleImportConfig;
 pub use relay_config::PersistConfig;
+use relay_config::PrettierConfig;
 pub use relay_config::ProjectConfig;
 use relay_config::ProjectName;
+use relay_config::QueryLimitsConfig;
 pub use relay_config::RemotePersistConfig;
 use relay_config::ResolversSchemaModuleConfig;
 use relay_config::SchemaConfig;
 pub use relay_config::SchemaLocation;
+use relay_config::TransformPluginConfig;
 use relay_config::TypegenConfig;
 pub use relay_config::TypegenLanguage;
 use relay_docblock::DocblockIr;
 use relay_saved_state_loader::SavedStateLoader;
+use relay_transform_plugins::TransformPlugins;
 use relay_transforms::CustomTransformsConfig;
 use schemars::JsonSchema;
 use schemars::SchemaGenerator;
@@ -64,6 +67,7 @@ use serde::Deserializer;
 use serde::Serialize;
 use serde::de::Error as DeError;
 use serde_json::Value;
+use serde_json::json;
 use sha1::Digest;
 use sha1::Sha1;
 use tokio::sync::broadcast;
@@ -71,6 +75,7 @@ use watchman_client::pdu::ScmAwareClockData;
 
 use crate::GraphQLAsts;
 use crate::build_project::AdditionalValidations;
+use crate::build_project::artifact_cache::ArtifactCacheConfig;
 use crate::build_project::artifact_writer::ArtifactFileWriter;
 use crate::build_project::artifact_writer::ArtifactWriter;
 use crate::build_project::generate_extra_artifacts::GenerateExtraArtifactsFn;
@@ -87,6 +92,10 @@ use crate::status_reporter::BuildStatus;
 use crate::status_reporter::ConsoleStatusReporter;
 use crate::status_reporter::StatusReporter;
 
+mod workspaces;
+
+pub use workspaces::WorkspacesConfig;
+
 pub type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
 
 type PostArtifactsWriter = Box<
@@ -351,6 +360,12 @@ pub struct Config {
     /// Do not reuse persist ids from artifacts even if the text hash matches.
     pub repersist_operations: bool,
 
+    /// Memory-map flatbuffer schemas instead of reading them. Only for a
+    /// compiler running a single build: watch mode, the daemon and the LSP
+    /// keep their schemas across builds, and a mapped file rewritten in the
+    /// meantime would crash them.
+    pub memory_map_flat_buffer_schemas: bool,
+
     pub saved_state_config: Option<ScmAwareClockData>,
     pub saved_state_loader: Option<Box<dyn SavedStateLoader + Send + Sync>>,
     pub saved_state_version: String,
@@ -379,10 +394,28 @@ pub struct Config {
     /// Type of file source to use in the Compiler
     pub file_source_config: FileSourceKind,
 
+    /// Directory of an on-disk cache of the compiler state, used by
+    /// `FileSourceKind::WalkDir` builds to only process the files that
+    /// changed since the last successful build.
+    pub local_cache_dir: Option<PathBuf>,
+
+    /// Content-addressed cache of the artifacts of projects, used by full
+    /// builds to restore the artifacts of unchanged projects instead of
+    /// compiling them.
+    pub artifact_cache: Option<ArtifactCacheConfig>,
+
+    /// Directories of the packages of the projects discovered with
+    /// `workspaces`, relative to the root directory.
+    pub workspace_packages: FnvIndexMap<ProjectName, PathBuf>,
+
     /// A set of custom transform functions, that can be applied before,
     /// and after each major transformation step (common, operations, etc)
     /// in the `apply_transforms(...)`.
     pub custom_transforms: Option<CustomTransformsConfig>,
+    /// The configured transform plugins, which run after `custom_transforms`,
+    /// see `all_custom_transforms`.
+    /// Keeps the warnings the plugins report until a build takes them.
+    pub transform_plugins: TransformPlugins,
     pub custom_override_schema_determinator: Option<CustomOverrideSchemaDeterminator>,
     pub export_persisted_query_ids_to_file: Option<PathBuf>,
 
@@ -419,6 +452,10 @@ pub enum FileSourceKind {
     /// This can be used to replace watchman queries
     External(PathBuf),
     WalkDir,
+    /// Directory traversal for the initial state, then the notification APIs
+    /// of the operating system (e.g. inotify or FSEvents) for watch mode without
+    /// Watchman.
+    NativeWatch,
     /// Test file source for testing the daemon. Allows external test code to push
     /// file changes and trigger builds without requiring Watchman.
     Test(TestFileSourceConfig),
@@ -531,11 +568,14 @@ impl Config {
         }
     }
 
-    fn default_loader_sources() -> [LoaderSource; 3] {
+    fn default_loader_sources() -> [LoaderSource; 6] {
         [
             LoaderSource::PackageJson("relay".to_string()),
             LoaderSource::Json("relay.config.json".to_string()),
             LoaderSource::Js("relay.config.js".to_string()),
+            LoaderSource::Js("relay.config.mjs".to_string()),
+            LoaderSource::Js("relay.config.cjs".to_string()),
+            LoaderSource::Js("relay.config.ts".to_string()),
         ]
     }
 
@@ -558,17 +598,19 @@ impl Config {
             );
         }
 
-        let loader = if config_path.extension() == Some(OsStr::new("js")) {
-            LoaderSource::Js(config_path.display().to_string())
-        } else if config_path.extension() == Some(OsStr::new("json")) {
-            LoaderSource::Json(config_path.display().to_string())
-        } else {
-            return Err(Error::ConfigError {
-                details: format!(
-                    "Invalid file extension. Expected `.js` or `.json`. Provided file \"{}\".",
-                    config_path.display()
-                ),
-            });
+        let loader = match config_path.extension().and_then(OsStr::to_str) {
+            Some("js" | "mjs" | "cjs" | "ts") => {
+                LoaderSource::Js(config_path.display().to_string())
+            }
+            Some("json") => LoaderSource::Json(config_path.display().to_string()),
+            _ => {
+                return Err(Error::ConfigError {
+                    details: format!(
+                        "Invalid file extension. Expected `.js`, `.mjs`, `.cjs`, `.ts` or `.json`. Provided file \"{}\".",
+                        config_path.display()
+                    ),
+                });
+            }
         };
         Self::load_config(
             &current_dir().expect("Unable to get current working directory."),
@@ -639,7 +681,7 @@ impl Config {
             ConfigFile::SingleProject(_) => false,
         };
 
-        let config_file = match config_file {
+        let mut config_file = match config_file {
             ConfigFile::MultiProject(config) => *config,
             ConfigFile::SingleProject(config) => {
                 config.create_multi_project_config(&config_path)?
@@ -648,12 +690,29 @@ impl Config {
 
         let config_file_dir = config_path.parent().unwrap();
 
-        let root_dir = if let Some(config_root) = config_file.root {
+        let root_dir = if let Some(config_root) = config_file.root.take() {
             canonicalize(config_file_dir.join(config_root)).unwrap()
         } else {
             config_file_dir.to_owned()
         };
 
+        let workspace_packages = match config_file.workspaces.take() {
+            Some(workspaces) => {
+                let workspace_packages =
+                    workspaces::add_workspace_projects(&root_dir, &workspaces, &mut config_file)?;
+                if config_file.projects.is_empty() {
+                    return Err(Error::ConfigError {
+                        details: "No projects are configured, and no package of the workspace has a Relay config.".to_string(),
+                    });
+                }
+                // Package configs aren't part of the config file.
+                serde_json::to_writer(&mut hash, &config_file.sources).unwrap();
+                serde_json::to_writer(&mut hash, &config_file.projects).unwrap();
+                workspace_packages
+            }
+            None => Default::default(),
+        };
+
         let MultiProjectConfigFile {
             feature_flags: config_file_feature_flags,
             projects,
@@ -666,16 +725,37 @@ impl Config {
                     config_file_project.schema,
                     config_file_project.schema_dir,
                     config_file_project.schema_compact,
+                    config_file_project.schema_flat_buffer,
+                    config_file_project.schema_subgraphs,
                 ) {
-                    (Some(schema_file), None, None) => Ok(SchemaLocation::File(
+                    (Some(schema_file), None, None, None, None) => Ok(SchemaLocation::File(
                         normalize_relative_path(&root_dir, &schema_file),
                     )),
-                    (None, Some(schema_dir), None) => Ok(SchemaLocation::Directory(
+                    (None, Some(schema_dir), None, None, None) => Ok(SchemaLocation::Directory(
                         normalize_relative_path(&root_dir, &schema_dir),
                     )),
-                    (None, None, Some(schema_compact)) => Ok(SchemaLocation::CompactFile(
-                        normalize_relative_path(&root_dir, &schema_compact),
-                    )),
+                    (None, None, Some(schema_compact), None, None) => {
+                        Ok(SchemaLocation::CompactFile(normalize_relative_path(
+                            &root_dir,
+                            &schema_compact,
+                        )))
+                    }
+                    (None, None, None, Some(schema_flat_buffer), None) => {
+                        Ok(SchemaLocation::FlatBufferFile(normalize_relative_path(
+                            &root_dir,
+                            &schema_flat_buffer,
+                        )))
+                    }
+                    (None, None, None, None, Some(schema_subgraphs))
+                        if !schema_subgraphs.is_empty() =>
+                    {
+                        Ok(SchemaLocation::Subgraphs(
+                            schema_subgraphs
+                                .iter()
+                                .map(|subgraph| normalize_relative_path(&root_dir, subgraph))
+                                .collect(),
+                        ))
+                    }
                     _ => Err(Error::ConfigFileValidation {
                         config_path: config_path.clone(),
                         validation_errors: vec![
@@ -710,32 +790,31 @@ impl Config {
                         }],
                     })?;
 
-                let excludes_extensions_set = match &config_file_project.excludes_extensions {
-                    Some(extensions) => {
-                        let mut builder = GlobSetBuilder::new();
-                        for ext in extensions {
-                            match Glob::new(ext) {
-                                Ok(glob) => {
-                                    builder.add(glob);
-                                }
-                                Err(e) => {
-                                    return Err(Error::ConfigFileValidation {
-                                        config_path: config_path.clone(),
-                                        validation_errors: vec![
-                                            ConfigValidationError::InvalidGlobPattern {
-                                                field: "excludesExtensions".to_string(),
-                                                pattern: ext.clone(),
-                                                reason: e.to_string(),
-                                            },
-                                        ],
-                                    });
-                                }
-                            }
-                        }
-                        Some(builder.build().unwrap())
-                    }
-                    None => None,
-                };
+                let excludes_extensions_set = config_file_project
+                    .excludes_extensions
+                    .map(GlobPatterns::new)
+                    .transpose()
+                    .map_err(|(pattern, error)| Error::ConfigFileValidation {
+                        config_path: config_path.clone(),
+                        validation_errors: vec![ConfigValidationError::InvalidGlobPattern {
+                            field: "excludesExtensions".to_string(),
+                            pattern,
+                            reason: error.to_string(),
+                        }],
+                    })?;
+
+                let excludes_set = config_file_project
+                    .excludes
+                    .map(GlobPatterns::new)
+                    .transpose()
+                    .map_err(|(pattern, error)| Error::ConfigFileValidation {
+                        config_path: config_path.clone(),
+                        validation_errors: vec![ConfigValidationError::InvalidGlobPattern {
+                            field: "excludes".to_string(),
+                            pattern,
+                            reason: error.to_string(),
+                        }],
+                    })?;
 
                 let project_config = ProjectConfig {
                     name: project_name,
@@ -749,6 +828,8 @@ impl Config {
                     extra_artifacts_config: None,
                     extra: config_file_project.extra,
                     excludes_extensions: excludes_extensions_set,
+                    excludes: excludes_set,
+                    additional_file_extensions: config_file_project.additional_file_extensions,
                     output: config_file_project.output,
                     extra_artifacts_output: config_file_project.extra_artifacts_output,
                     shard_output: config_file_project.shard_output,
@@ -771,6 +852,9 @@ impl Config {
                     module_import_config: config_file_project.module_import_config,
                     diagnostic_report_config: config_file_project.diagnostic_report_config,
                     resolvers_schema_module: config_file_project.resolvers_schema_module,
+                    query_limits: config_file_project.query_limits,
+                    prettier: config_file_project.prettier,
+                    report_unused_client_schema: config_file_project.report_unused_client_schema,
                     codegen_command: config_file_project.codegen_command,
                     get_custom_path_for_artifact: None,
                 };
@@ -778,6 +862,8 @@ impl Config {
             })
             .collect::<Result<FnvIndexMap<_, _>>>()?;
 
+        let transform_plugins = TransformPlugins::new(&config_file.transform_plugins, &root_dir);
+
         let config = Self {
             name: config_file.name,
             artifact_writer: Box::new(ArtifactFileWriter::new(
@@ -815,12 +901,17 @@ impl Config {
             additional_validations: None,
             is_dev_variable_name: config_file.is_dev_variable_name,
             file_source_config: FileSourceKind::Watchman,
+            local_cache_dir: None,
+            artifact_cache: None,
+            workspace_packages,
             custom_transforms: None,
+            transform_plugins,
             custom_override_schema_determinator: None,
             export_persisted_query_ids_to_file: None,
             initialize_resources: None,
             update_compiler_state_from_saved_state: None,
             has_schema_change_incremental_build: false,
+            memory_map_flat_buffer_schemas: false,
             custom_extract_relay_resolvers: None,
             should_extract_full_source: None,
             transferrable_refetchable_query_directives: vec![],
@@ -861,6 +952,20 @@ impl Config {
         signals.check().await
     }
 
+    /// The `custom_transforms` followed by the transforms running the
+    /// transform plugins.
+    pub fn all_custom_transforms(&self) -> Option<CustomTransformsConfig> {
+        let plugin_transforms = self.transform_plugins.custom_transforms_config();
+        match (&self.custom_transforms, plugin_transforms) {
+            (Some(custom_transforms), Some(plugin_transforms)) => {
+                Some(custom_transforms.merge(&plugin_transforms))
+            }
+            (custom_transforms, plugin_transforms) => {
+                custom_transforms.clone().or(plugin_transforms)
+            }
+        }
+    }
+
     /// Iterator over projects that are enabled.
     pub fn enabled_projects(&self) -> impl Iterator<Item = &ProjectConfig> {
         self.projects
@@ -940,12 +1045,19 @@ impl Config {
 
         for (_, project) in &self.projects {
             match &project.schema_location {
-                SchemaLocation::CompactFile(schema_file) | SchemaLocation::File(schema_file) => {
+                SchemaLocation::CompactFile(schema_file)
+                | SchemaLocation::FlatBufferFile(schema_file)
+                | SchemaLocation::File(schema_file) => {
                     validator.assert_is_included_schema_file(schema_file);
                 }
                 SchemaLocation::Directory(schema_dir) => {
                     validator.assert_is_included_schema_dir(schema_dir);
                 }
+                SchemaLocation::Subgraphs(subgraphs) => {
+                    for subgraph in subgraphs {
+                        validator.assert_is_included_schema_file(subgraph);
+                    }
+                }
             }
 
             // Validate schema extensions
@@ -1017,11 +1129,12 @@ impl Config {
     pub fn get_schema_file_paths(&self) -> Vec<PathBuf> {
         self.projects
             .values()
-            .filter_map(|project_config| match &project_config.schema_location {
-                SchemaLocation::File(schema_file) | SchemaLocation::CompactFile(schema_file) => {
-                    Some(schema_file.clone())
-                }
-                SchemaLocation::Directory(_) => None,
+            .flat_map(|project_config| match &project_config.schema_location {
+                SchemaLocation::File(schema_file)
+                | SchemaLocation::CompactFile(schema_file)
+                | SchemaLocation::FlatBufferFile(schema_file) => vec![schema_file.clone()],
+                SchemaLocation::Subgraphs(subgraphs) => subgraphs.clone(),
+                SchemaLocation::Directory(_) => vec![],
             })
             .collect()
     }
@@ -1031,7 +1144,10 @@ impl Config {
         self.projects
             .values()
             .filter_map(|project_config| match &project_config.schema_location {
-                SchemaLocation::File(_) | SchemaLocation::CompactFile(_) => None,
+                SchemaLocation::File(_)
+                | SchemaLocation::CompactFile(_)
+                | SchemaLocation::FlatBufferFile(_)
+                | SchemaLocation::Subgraphs(_) => None,
                 SchemaLocation::Directory(schema_dir) => Some(schema_dir.clone()),
             })
             .collect()
@@ -1182,6 +1298,52 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_transform_plugins_run_after_custom_transforms() {
+        let mut config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": { "src": "test" },
+                    "projects": {
+                        "test": { "schema": "schema.graphql", "language": "typescript" }
+                    },
+                    "transformPlugins": [{ "name": "plugin", "command": "./plugin" }]
+                }
+            "#,
+        )
+        .unwrap();
+        config.custom_transforms = Some(CustomTransformsConfig {
+            common_transforms: Some(relay_transforms::CustomTransforms {
+                apply_before: vec![relay_transforms::CustomTransform::new(
+                    "custom",
+                    |_, program, _| Ok(program.clone()),
+                )],
+                apply_after: vec![],
+            }),
+            reader_transforms: None,
+            operation_transforms: None,
+            normalization_transforms: None,
+            operation_text_transforms: None,
+            typegen_transforms: None,
+        });
+
+        let all_custom_transforms = config.all_custom_transforms().unwrap();
+        let names = |transforms: &[relay_transforms::CustomTransform]| {
+            transforms
+                .iter()
+                .map(|transform| transform.name.to_string())
+                .collect::<Vec<_>>()
+        };
+        let common_transforms = all_custom_transforms.common_transforms.unwrap();
+        assert_eq!(
+            names(&common_transforms.apply_before),
+            vec!["custom", "plugin"]
+        );
+        assert_eq!(names(&common_transforms.apply_after), vec!["plugin"]);
+        let typegen_transforms = all_custom_transforms.typegen_transforms.unwrap();
+        assert_eq!(names(&typegen_transforms.apply_before), vec!["plugin"]);
+    }
+
     #[test]
     fn test_restart_signals_missing_config_returns_none() {
         // Pointing at a path that doesn't exist must not panic — production
@@ -1403,6 +1565,14 @@ pub struct MultiProjectConfigFile {
     /// Configuration of projects to compile.
     projects: FnvIndexMap<ProjectName, ConfigFileProject>,
 
+    /// Add a project for every package of a package-manager workspace with a
+    /// single-project Relay config, in a `relay.config.*` file or under the
+    /// `relay` key of its `package.json`. Projects are named after their
+    /// package, and the paths of a package config are relative to the package.
+    /// With `workspaces`, `sources` and `projects` may be omitted.
+    #[serde(default)]
+    workspaces: Option<WorkspacesConfig>,
+
     /// Enable and disable experimental or legacy behaviors.
     /// WARNING! These are not stable and may change at any time.
     #[serde(default)]
@@ -1417,6 +1587,10 @@ pub struct MultiProjectConfigFile {
 
     /// Opt out of source control checks/integration.
     no_source_control: Option<bool>,
+
+    /// Plugins with custom transforms and validations, run in order.
+    #[serde(default)]
+    transform_plugins: Vec<TransformPluginConfig>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -1447,6 +1621,12 @@ pub struct SingleProjectConfigFile {
     /// List of files or directories with schema extensions.
     pub schema_extensions: Vec<PathBuf>,
 
+    /// Extensions of source files to extract GraphQL from, besides `js` and
+    /// `jsx`, and `ts` and `tsx` for TypeScript projects, e.g. `vue`, `svelte`,
+    /// `astro` or `mdx`.
+    #[serde(default)]
+    pub additional_file_extensions: Vec<String>,
+
     #[serde(flatten)]
     pub typegen_config: TypegenConfig,
 
@@ -1486,10 +1666,29 @@ pub struct SingleProjectConfigFile {
     #[serde(default)]
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
 
+    /// Limits on the cost and depth of operations, checked at compile time.
+    #[serde(default)]
+    pub query_limits: Option<QueryLimitsConfig>,
+
+    /// Format the generated JavaScript and TypeScript artifacts in-process
+    /// with a subset of the options of Prettier.
+    #[serde(default)]
+    pub prettier: Option<PrettierConfig>,
+
+    /// Report client schema extension types, fields, enum values and Relay
+    /// Resolvers that aren't used by any document as hints in the language
+    /// server.
+    #[serde(default)]
+    pub report_unused_client_schema: bool,
+
     /// Opt out of source control checks/integration.
     #[serde(default)]
     pub no_source_control: Option<bool>,
 
+    /// Plugins with custom transforms and validations, run in order.
+    #[serde(default)]
+    pub transform_plugins: Vec<TransformPluginConfig>,
+
     /// A placeholder for allowing extra information in the config file
     #[serde(default)]
     pub extra: serde_json::Value,
@@ -1505,6 +1704,7 @@ impl Default for SingleProjectConfigFile {
             artifact_directory: Default::default(),
             excludes: get_default_excludes(),
             schema_extensions: vec![],
+            additional_file_extensions: vec![],
             schema_config: Default::default(),
             typegen_config: Default::default(),
             persist_config: None,
@@ -1515,7 +1715,11 @@ impl Default for SingleProjectConfigFile {
             feature_flags: None,
             module_import_config: Default::default(),
             resolvers_schema_module: Default::default(),
+            query_limits: None,
+            prettier: None,
+            report_unused_client_schema: false,
             no_source_control: Some(false),
+            transform_plugins: vec![],
             extra: Default::default(),
         }
     }
@@ -1563,7 +1767,7 @@ impl SingleProjectConfigFile {
             .ok_or(ConfigValidationError::CommonPathNotFound)
     }
 
-    fn create_multi_project_config(self, config_path: &Path) -> Result<MultiProjectConfigFile> {
+    fn create_multi_project_config(mut self, config_path: &Path) -> Result<MultiProjectConfigFile> {
         let current_dir = std::env::current_dir().unwrap();
         let common_root_dir = self.get_common_root(current_dir.clone()).map_err(|err| {
             Error::ConfigFileValidation {
@@ -1572,57 +1776,75 @@ impl SingleProjectConfigFile {
             }
         })?;
 
-        let project_config = ConfigFileProject {
-            output: self.artifact_directory.map(|dir| {
-                normalize_path_from_config(current_dir.clone(), common_root_dir.clone(), dir)
-            }),
-            schema: Some(normalize_path_from_config(
-                current_dir.clone(),
-                common_root_dir.clone(),
-                self.schema,
-            )),
-            schema_config: self.schema_config,
-            schema_extensions: self
-                .schema_extensions
-                .iter()
-                .map(|path| {
-                    normalize_path_from_config(
-                        current_dir.clone(),
-                        common_root_dir.clone(),
-                        path.clone(),
-                    )
-                })
-                .collect(),
-            persist: self.persist_config,
-            typegen_config: self.typegen_config,
-            js_module_format: self.js_module_format,
-            feature_flags: self.feature_flags,
-            module_import_config: self.module_import_config,
-            relativize_js_module_paths: self.relativize_js_module_paths,
-            resolvers_schema_module: self.resolvers_schema_module,
-            extra: self.extra,
-            ..Default::default()
-        };
-
-        let mut projects = FnvIndexMap::default();
-        projects.insert(self.project_name, project_config);
+        let transform_plugins = std::mem::take(&mut self.transform_plugins)
+            .into_iter()
+            .map(|plugin| {
+                if plugin.is_relative_command() {
+                    TransformPluginConfig {
+                        command: current_dir.join(&plugin.command),
+                        ..plugin
+                    }
+                } else {
+                    plugin
+                }
+            })
+            .collect();
 
         let mut sources = FnvIndexMap::default();
-        let src = normalize_path_from_config(current_dir, common_root_dir.clone(), self.src);
-
+        let src = normalize_path_from_config(
+            current_dir.clone(),
+            common_root_dir.clone(),
+            std::mem::take(&mut self.src),
+        );
         sources.insert(src, ProjectSet::of(self.project_name));
 
+        let project_name = self.project_name;
+        let excludes = std::mem::take(&mut self.excludes);
+        let is_dev_variable_name = self.is_dev_variable_name.take();
+        let codegen_command = self.codegen_command.take();
+        let no_source_control = self.no_source_control.take();
+        let project_config = self.into_config_file_project(|path| {
+            normalize_path_from_config(current_dir.clone(), common_root_dir.clone(), path)
+        });
+
+        let mut projects = FnvIndexMap::default();
+        projects.insert(project_name, project_config);
+
         Ok(MultiProjectConfigFile {
             root: Some(common_root_dir),
             projects,
             sources,
-            excludes: self.excludes,
-            is_dev_variable_name: self.is_dev_variable_name,
-            codegen_command: self.codegen_command,
-            no_source_control: self.no_source_control,
+            excludes,
+            is_dev_variable_name,
+            codegen_command,
+            no_source_control,
+            transform_plugins,
             ..Default::default()
         })
     }
+
+    /// The project options of the config, with paths mapped by `normalize`.
+    fn into_config_file_project(self, normalize: impl Fn(PathBuf) -> PathBuf) -> ConfigFileProject {
+        ConfigFileProject {
+            output: self.artifact_directory.map(&normalize),
+            schema: Some(normalize(self.schema)),
+            schema_config: self.schema_config,
+            schema_extensions: self.schema_extensions.into_iter().map(&normalize).collect(),
+            additional_file_extensions: self.additional_file_extensions,
+            persist: self.persist_config,
+            typegen_config: self.typegen_config,
+            js_module_format: self.js_module_format,
+            feature_flags: self.feature_flags,
+            module_import_config: self.module_import_config,
+            relativize_js_module_paths: self.relativize_js_module_paths,
+            resolvers_schema_module: self.resolvers_schema_module,
+            query_limits: self.query_limits,
+            prettier: self.prettier,
+            report_unused_client_schema: self.report_unused_client_schema,
+            extra: self.extra,
+            ..Default::default()
+        }
+    }
 }
 
 /// Relay's configuration file. Supports a single project config for simple use
@@ -1655,7 +1877,16 @@ impl ConfigFile {
 
 impl<'de> Deserialize<'de> for ConfigFile {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
-        let value = Value::deserialize(deserializer)?;
+        let mut value = Value::deserialize(deserializer)?;
+        // A config with `workspaces` may get all of its projects from the
+        // packages of the workspace.
+        if let Some(object) = value.as_object_mut()
+            && object.contains_key("workspaces")
+        {
+            for key in ["sources", "projects"] {
+                object.entry(key).or_insert_with(|| json!({}));
+            }
+        }
         match MultiProjectConfigFile::deserialize(value.clone()) {
             Ok(config) => Ok(ConfigFile::MultiProject(Box::new(config))),
             Err(multi_project_error) => match SingleProjectConfigFile::deserialize(value) {
@@ -1701,6 +1932,19 @@ pub struct ConfigFileProject {
     /// Some projects may need to exclude files with certain extensions.
     excludes_extensions: Option<Vec<String>>,
 
+    /// Glob patterns, relative to the root, of files of the sources of the
+    /// project that should not be part of this project. Unlike the top-level
+    /// `excludes`, they don't apply to the other projects.
+    excludes: Option<Vec<String>>,
+
+    /// Extensions of source files to extract GraphQL from, besides `js` and
+    /// `jsx`, and `ts` and `tsx` for TypeScript projects. GraphQL is extracted
+    /// from the `<script>` blocks of `vue`, `svelte` and `astro` components,
+    /// the JavaScript code fences of `md` and `mdx` documents, and the whole
+    /// contents of other files.
+    #[serde(default)]
+    additional_file_extensions: Vec<String>,
+
     /// If `output` is provided and `shard_output` is `true`, shard the files
     /// by putting them under `{output_dir}/{source_relative_path}`
     #[serde(default)]
@@ -1720,6 +1964,10 @@ pub struct ConfigFileProject {
     schema: Option<PathBuf>,
     schema_dir: Option<PathBuf>,
     schema_compact: Option<PathBuf>,
+    schema_flat_buffer: Option<PathBuf>,
+    /// The SDL files of the subgraphs composed into the schema served by a
+    /// gateway.
+    schema_subgraphs: Option<Vec<PathBuf>>,
 
     /// Schema name, if differs from project name.
     /// If schema name is unset, the project name will be used as schema name.
@@ -1782,6 +2030,21 @@ pub struct ConfigFileProject {
     #[serde(default)]
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
 
+    /// Limits on the cost and depth of operations, checked at compile time.
+    #[serde(default)]
+    pub query_limits: Option<QueryLimitsConfig>,
+
+    /// Format the generated JavaScript and TypeScript artifacts in-process
+    /// with a subset of the options of Prettier.
+    #[serde(default)]
+    pub prettier: Option<PrettierConfig>,
+
+    /// Report client schema extension types, fields, enum values and Relay
+    /// Resolvers that aren't used by any document as hints in the language
+    /// server.
+    #[serde(default)]
+    pub report_unused_client_schema: bool,
+
     /// Name of the command that runs the relay compiler. This will be added at
     /// the top of generated code to let readers know how to regenerate the file.
     #[serde(default)]
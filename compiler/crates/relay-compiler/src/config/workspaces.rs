@@ -0,0 +1,336 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Discovery of the Relay configs of the packages of a package-manager
+//! workspace (npm, yarn or pnpm workspaces).
+//!
+//! Every package with a single-project config, in a `relay.config.*` file or
+//! under the `relay` key of its `package.json`, becomes a project of the root
+//! config named after the package. The paths of a package config are
+//! relative to the package directory, so its source directory, artifact
+//! directory and schema may also point outside of the package, like to a
+//! schema shared by all packages.
+
+use std::fs;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use intern::string_key::Intern;
+use log::warn;
+use relay_config::ProjectName;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use yaml_rust2::Yaml;
+use yaml_rust2::YamlLoader;
+
+use super::Config;
+use super::ConfigFile;
+use super::FnvIndexMap;
+use super::MultiProjectConfigFile;
+use super::get_default_excludes;
+use crate::compiler_state::ProjectSet;
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// Packages of a package-manager workspace to discover Relay configs in.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum WorkspacesConfig {
+    /// With `true`, the packages are the ones of the `workspaces` of the
+    /// `package.json` in the root directory (npm and yarn), or of the
+    /// `packages` of its `pnpm-workspace.yaml`.
+    Discover(bool),
+    /// Glob patterns of package directories, relative to the root directory.
+    /// Patterns starting with `!` exclude directories.
+    Packages(Vec<String>),
+}
+
+/// Add a project and its source directory to `config_file` for every package
+/// of the workspace with a Relay config. Returns the directories of the
+/// packages, relative to the root directory, by project name.
+pub(super) fn add_workspace_projects(
+    root_dir: &Path,
+    workspaces: &WorkspacesConfig,
+    config_file: &mut MultiProjectConfigFile,
+) -> Result<FnvIndexMap<ProjectName, PathBuf>> {
+    let patterns = match workspaces {
+        WorkspacesConfig::Discover(false) => return Ok(Default::default()),
+        WorkspacesConfig::Discover(true) => package_manager_patterns(root_dir)?,
+        WorkspacesConfig::Packages(patterns) => patterns.clone(),
+    };
+
+    let mut packages = FnvIndexMap::default();
+    for package_dir in package_dirs(root_dir, &patterns)? {
+        let absolute_dir = root_dir.join(&package_dir);
+        let found = js_config_loader::load_in_dir::<ConfigFile>(
+            &absolute_dir,
+            &Config::default_loader_sources(),
+        )
+        .map_err(|error| Error::ConfigError {
+            details: format!("Error loading the config of workspace package: {error}"),
+        })?;
+        let Some(found) = found else {
+            continue;
+        };
+        let mut package_config = match found.value {
+            ConfigFile::SingleProject(package_config) => package_config,
+            ConfigFile::MultiProject(_) => {
+                return Err(Error::ConfigError {
+                    details: format!(
+                        "The config `{}` of a workspace package must be a single-project config.",
+                        found.path.display()
+                    ),
+                });
+            }
+        };
+
+        let project_name = package_name(&absolute_dir)
+            .unwrap_or_else(|| package_dir.to_string_lossy().to_string());
+        let project_name = ProjectName::from(project_name.intern());
+        if config_file.projects.contains_key(&project_name) {
+            return Err(Error::ConfigError {
+                details: format!(
+                    "The workspace package `{}` and another project are both named `{}`.",
+                    package_dir.display(),
+                    project_name
+                ),
+            });
+        }
+        if package_config.is_dev_variable_name.is_some()
+            || package_config.codegen_command.is_some()
+            || package_config.no_source_control.is_some()
+            || !package_config.transform_plugins.is_empty()
+        {
+            warn!(
+                "`isDevVariableName`, `codegenCommand`, `noSourceControl` and `transformPlugins` are only read from the root config, ignoring them in `{}`.",
+                found.path.display()
+            );
+        }
+
+        let src = package_path(
+            root_dir,
+            &package_dir,
+            std::mem::take(&mut package_config.src),
+        );
+        let excludes = std::mem::take(&mut package_config.excludes);
+        let mut project_config = package_config
+            .into_config_file_project(|path| package_path(root_dir, &package_dir, path));
+        // The excludes of the root config apply to all sources, the other
+        // excludes of the package only to its project.
+        let default_excludes = get_default_excludes();
+        let package_excludes = excludes
+            .into_iter()
+            .filter(|exclude| !default_excludes.contains(exclude))
+            .map(|exclude| format!("{}/{}", package_dir.display(), exclude))
+            .collect::<Vec<_>>();
+        if !package_excludes.is_empty() {
+            project_config.excludes = Some(package_excludes);
+        }
+
+        match config_file.sources.get_mut(&src) {
+            Some(project_set) => project_set.insert(project_name),
+            None => {
+                config_file
+                    .sources
+                    .insert(src, ProjectSet::of(project_name));
+            }
+        }
+        config_file.projects.insert(project_name, project_config);
+        packages.insert(project_name, package_dir);
+    }
+    Ok(packages)
+}
+
+/// The package patterns of the npm, yarn or pnpm workspace in `root_dir`.
+fn package_manager_patterns(root_dir: &Path) -> Result<Vec<String>> {
+    let package_json_path = root_dir.join("package.json");
+    if let Ok(contents) = fs::read_to_string(&package_json_path) {
+        let package_json: Value =
+            serde_json::from_str(&contents).map_err(|error| Error::ConfigError {
+                details: format!(
+                    "Failed to parse `{}`: {}",
+                    package_json_path.display(),
+                    error
+                ),
+            })?;
+        let workspaces = match &package_json["workspaces"] {
+            Value::Object(workspaces) => workspaces.get("packages"),
+            workspaces => Some(workspaces),
+        };
+        if let Some(Value::Array(patterns)) = workspaces {
+            return Ok(patterns
+                .iter()
+                .filter_map(|pattern| pattern.as_str().map(str::to_string))
+                .collect());
+        }
+    }
+    let pnpm_workspace_path = root_dir.join("pnpm-workspace.yaml");
+    if let Ok(contents) = fs::read_to_string(&pnpm_workspace_path) {
+        return pnpm_workspace_patterns(&contents).map_err(|error| Error::ConfigError {
+            details: format!(
+                "Failed to parse `{}`: {}",
+                pnpm_workspace_path.display(),
+                error
+            ),
+        });
+    }
+    Err(Error::ConfigError {
+        details: format!(
+            "No workspace packages are defined in `{}` or `pnpm-workspace.yaml`.",
+            package_json_path.display()
+        ),
+    })
+}
+
+/// The `packages` of a `pnpm-workspace.yaml`.
+fn pnpm_workspace_patterns(contents: &str) -> std::result::Result<Vec<String>, String> {
+    let documents = YamlLoader::load_from_str(contents).map_err(|error| error.to_string())?;
+    let Some(document) = documents.first() else {
+        return Ok(vec![]);
+    };
+    match &document["packages"] {
+        Yaml::BadValue | Yaml::Null => Ok(vec![]),
+        Yaml::Array(packages) => packages
+            .iter()
+            .map(|package| {
+                package
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or_else(|| "`packages` must be a list of strings".to_string())
+            })
+            .collect(),
+        _ => Err("`packages` must be a list of strings".to_string()),
+    }
+}
+
+/// The package directories matching `patterns`, relative to `root_dir`.
+fn package_dirs(root_dir: &Path, patterns: &[String]) -> Result<Vec<PathBuf>> {
+    let invalid_pattern = |pattern: &str, error: glob::PatternError| Error::ConfigError {
+        details: format!("Invalid workspace package pattern `{pattern}`: {error}"),
+    };
+    let mut excludes = vec![];
+    for pattern in patterns {
+        if let Some(exclude) = pattern.strip_prefix('!') {
+            excludes.push(
+                glob::Pattern::new(normalize_pattern(exclude))
+                    .map_err(|error| invalid_pattern(pattern, error))?,
+            );
+        }
+    }
+    let mut package_dirs = vec![];
+    for pattern in patterns.iter().filter(|pattern| !pattern.starts_with('!')) {
+        let absolute_pattern = root_dir.join(normalize_pattern(pattern));
+        let paths = glob::glob(&absolute_pattern.to_string_lossy())
+            .map_err(|error| invalid_pattern(pattern, error))?;
+        for path in paths.flatten() {
+            let Ok(package_dir) = path.strip_prefix(root_dir) else {
+                continue;
+            };
+            if path.is_dir()
+                && !package_dir
+                    .components()
+                    .any(|component| component.as_os_str() == "node_modules")
+                && !excludes
+                    .iter()
+                    .any(|exclude| exclude.matches_path(package_dir))
+                && !package_dirs.iter().any(|dir| dir == package_dir)
+            {
+                package_dirs.push(package_dir.to_path_buf());
+            }
+        }
+    }
+    package_dirs.sort();
+    Ok(package_dirs)
+}
+
+fn normalize_pattern(pattern: &str) -> &str {
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+    pattern.strip_suffix('/').unwrap_or(pattern)
+}
+
+/// The `name` in the `package.json` of a package.
+fn package_name(package_dir: &Path) -> Option<String> {
+    let contents = fs::read_to_string(package_dir.join("package.json")).ok()?;
+    let package_json: Value = serde_json::from_str(&contents).ok()?;
+    package_json["name"].as_str().map(str::to_string)
+}
+
+/// A path of the config of the package in `package_dir`, relative to the
+/// root directory.
+fn package_path(root_dir: &Path, package_dir: &Path, path: PathBuf) -> PathBuf {
+    let path = match path.strip_prefix(root_dir) {
+        Ok(path) => path.to_path_buf(),
+        Err(_) => package_dir.join(path),
+    };
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_pnpm_workspace_packages() {
+        assert_eq!(
+            pnpm_workspace_patterns(
+                r#"
+# All packages but the tests
+packages:
+  - "packages/*"
+  - 'apps/**' # nested apps
+  - "!**/test/**"
+catalog:
+  react: ^19.0.0
+"#
+            )
+            .unwrap(),
+            vec!["packages/*", "apps/**", "!**/test/**"]
+        );
+        assert_eq!(
+            pnpm_workspace_patterns("packages: ['packages/*', \"tools/#cli\"] # flow style")
+                .unwrap(),
+            vec!["packages/*", "tools/#cli"]
+        );
+        assert_eq!(
+            pnpm_workspace_patterns("# No packages yet\n").unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(pnpm_workspace_patterns("packages: [packages/*").is_err());
+        assert!(pnpm_workspace_patterns("packages: packages/*").is_err());
+    }
+
+    #[test]
+    fn resolves_package_paths_from_the_root() {
+        let root_dir = Path::new("/repo");
+        let package_dir = Path::new("packages/web");
+        assert_eq!(
+            package_path(root_dir, package_dir, "./src".into()),
+            PathBuf::from("packages/web/src")
+        );
+        assert_eq!(
+            package_path(root_dir, package_dir, "../../schema.graphql".into()),
+            PathBuf::from("schema.graphql")
+        );
+        assert_eq!(
+            package_path(root_dir, package_dir, "/repo/schema/schema.graphql".into()),
+            PathBuf::from("schema/schema.graphql")
+        );
+    }
+}
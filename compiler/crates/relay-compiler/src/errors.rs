@@ -115,6 +115,18 @@ pub enum Error {
     #[error("Watchman query returned no results.")]
     EmptyQueryResult,
 
+    #[error(
+        "Unable to watch `{path}` for changes: {source}. If the limit of watched directories was reached, raise `fs.inotify.max_user_watches`."
+    )]
+    FileWatch {
+        path: PathBuf,
+        #[serde(skip_serializing)]
+        source: std::io::Error,
+    },
+
+    #[error("The file system watcher stopped unexpectedly.")]
+    FileWatchStopped,
+
     #[error("Failed to read file: `{file}`.")]
     FileRead {
         file: PathBuf,
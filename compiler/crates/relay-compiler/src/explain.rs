@@ -0,0 +1,496 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Explains how the config applies to a file or a project: which projects
+//! own a file and why it may be left out of them, and the resolved config of
+//! a project.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::path::Path;
+use std::path::PathBuf;
+
+use relay_config::ProjectName;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::compiler_state::ProjectSet;
+use crate::config::Config;
+use crate::config::ProjectConfig;
+use crate::config::SchemaLocation;
+use crate::file_source::FileCategorizer;
+use crate::file_source::FileFilter;
+use crate::file_source::FileGroup;
+
+/// How the config applies to a file, see `explain_file`.
+pub struct FileExplanation<'config> {
+    config: &'config Config,
+    path: PathBuf,
+    file_group: Result<FileGroup, Cow<'static, str>>,
+    /// The `excludes` glob leaving the file out of the sources.
+    excluded_by: Option<String>,
+    /// Projects of the source directory of the file, with the project setting
+    /// (`excludesExtensions` or `excludes`) and its glob leaving the file out
+    /// of the project.
+    excluded_from_projects: Vec<(ProjectName, &'static str, String)>,
+    /// Projects of the source directory of the file without its extension
+    /// among their source file extensions.
+    unexpected_extension_projects: Vec<ProjectName>,
+}
+
+/// Explain which group the file categorizer puts a file into, the projects
+/// and schemas it belongs to, and the filters leaving it out of them. The
+/// path is relative to the root directory of the config.
+pub fn explain_file<'config>(config: &'config Config, path: &Path) -> FileExplanation<'config> {
+    let categorizer = FileCategorizer::from_config(config);
+    let file_group = categorizer.categorize(path, config);
+    let excluded_by = FileFilter::from_config(config)
+        .matching_exclude(path)
+        .map(str::to_string);
+
+    let source_projects = categorizer
+        .source_project_set(path)
+        .map_or_else(Vec::new, |project_set| {
+            project_set.iter().copied().collect::<Vec<_>>()
+        });
+    let excluded_from_projects = source_projects
+        .iter()
+        .filter_map(|project_name| {
+            let project_config = &config.projects[project_name];
+            [
+                ("excludesExtensions", &project_config.excludes_extensions),
+                ("excludes", &project_config.excludes),
+            ]
+            .into_iter()
+            .find_map(|(setting, excludes)| {
+                let pattern = excludes.as_ref()?.matching_pattern(path)?;
+                Some((*project_name, setting, pattern.to_string()))
+            })
+        })
+        .collect();
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let unexpected_extension_projects = source_projects
+        .iter()
+        .filter(|project_name| {
+            !extension.is_some_and(|extension| {
+                config.projects[*project_name]
+                    .source_file_extensions()
+                    .contains(&extension)
+            })
+        })
+        .copied()
+        .collect();
+
+    FileExplanation {
+        config,
+        path: path.to_path_buf(),
+        file_group,
+        excluded_by,
+        excluded_from_projects,
+        unexpected_extension_projects,
+    }
+}
+
+impl FileExplanation<'_> {
+    /// The projects the file belongs to.
+    pub fn projects(&self) -> Vec<ProjectName> {
+        match &self.file_group {
+            Ok(FileGroup::Generated { project_name }) => vec![*project_name],
+            Ok(
+                FileGroup::Schema { project_set }
+                | FileGroup::Extension { project_set }
+                | FileGroup::Source { project_set }
+                | FileGroup::CompactSchema { project_set }
+                | FileGroup::FlatBufferSchema { project_set },
+            ) => project_set.iter().copied().collect(),
+            Ok(FileGroup::Ignore) | Err(_) => vec![],
+        }
+    }
+
+    /// Whether the compiler reads the file.
+    pub fn is_compiled(&self) -> bool {
+        self.excluded_by.is_none() && !self.projects().is_empty()
+    }
+}
+
+impl fmt::Display for FileExplanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "File `{}`:", self.path.display())?;
+        match &self.file_group {
+            Ok(file_group) => writeln!(f, "  Group: {}", describe_file_group(file_group))?,
+            Err(reason) => writeln!(f, "  Group: none, {reason}")?,
+        }
+        if let Some(pattern) = &self.excluded_by {
+            writeln!(
+                f,
+                "  Left out of the sources by the `excludes` glob `{pattern}`."
+            )?;
+        }
+        for (project_name, setting, pattern) in &self.excluded_from_projects {
+            writeln!(
+                f,
+                "  Left out of project `{project_name}` by its `{setting}` glob `{pattern}`."
+            )?;
+        }
+        for project_name in &self.unexpected_extension_projects {
+            writeln!(
+                f,
+                "  Not a source file of project `{project_name}`, which has the extensions {}.",
+                self.config.projects[project_name]
+                    .source_file_extensions()
+                    .join(", ")
+            )?;
+        }
+        for project_name in self.projects() {
+            let project_config = &self.config.projects[&project_name];
+            write!(
+                f,
+                "  Project `{project_name}`: {}",
+                describe_schema_location(&project_config.schema_location)
+            )?;
+            if !project_config.schema_extensions.is_empty() {
+                write!(
+                    f,
+                    ", schema extensions {}",
+                    join_paths(&project_config.schema_extensions)
+                )?;
+            }
+            if let Some(base) = project_config.base {
+                write!(f, ", base project `{base}`")?;
+            }
+            if !project_config.enabled {
+                write!(f, ", disabled")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn describe_file_group(file_group: &FileGroup) -> String {
+    match file_group {
+        FileGroup::Generated { project_name } => {
+            format!("generated file of project `{project_name}`")
+        }
+        FileGroup::Schema { project_set } => {
+            format!("schema of {}", describe_projects(project_set))
+        }
+        FileGroup::Extension { project_set } => {
+            format!("schema extension of {}", describe_projects(project_set))
+        }
+        FileGroup::Source { project_set } => {
+            format!("source file of {}", describe_projects(project_set))
+        }
+        FileGroup::CompactSchema { project_set } => {
+            format!("compact schema of {}", describe_projects(project_set))
+        }
+        FileGroup::FlatBufferSchema { project_set } => {
+            format!("flatbuffer schema of {}", describe_projects(project_set))
+        }
+        FileGroup::Ignore => "ignored file in a generated directory".to_string(),
+    }
+}
+
+fn describe_projects(project_set: &ProjectSet) -> String {
+    let names = project_set
+        .iter()
+        .map(|project_name| format!("`{project_name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if project_set.has_multiple_projects() {
+        format!("projects {names}")
+    } else {
+        format!("project {names}")
+    }
+}
+
+fn describe_schema_location(schema_location: &SchemaLocation) -> String {
+    match schema_location {
+        SchemaLocation::File(path) => format!("schema `{}`", path.display()),
+        SchemaLocation::Directory(path) => format!("schema directory `{}`", path.display()),
+        SchemaLocation::CompactFile(path) => format!("compact schema `{}`", path.display()),
+        SchemaLocation::FlatBufferFile(path) => {
+            format!("flatbuffer schema `{}`", path.display())
+        }
+        SchemaLocation::Subgraphs(paths) => format!("schema subgraphs {}", join_paths(paths)),
+    }
+}
+
+fn join_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| format!("`{}`", path.display()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The resolved config of a project as JSON, in the shape of the project
+/// config in the config file. Paths are relative to the root directory. The
+/// sources and schema extensions of the base project are listed under
+/// `inherited`, and `featureFlags` falls back to the top-level flags of the
+/// config file.
+pub fn project_config_json(config: &Config, project_config: &ProjectConfig) -> Value {
+    let mut json = json!({
+        "name": project_config.name,
+        "enabled": project_config.enabled,
+        "base": project_config.base,
+        "sources": project_sources(config, project_config.name),
+    });
+    let object = json.as_object_mut().unwrap();
+    match &project_config.schema_location {
+        SchemaLocation::File(path) => object.insert("schema".to_string(), json!(path)),
+        SchemaLocation::Directory(path) => object.insert("schemaDir".to_string(), json!(path)),
+        SchemaLocation::CompactFile(path) => {
+            object.insert("schemaCompact".to_string(), json!(path))
+        }
+        SchemaLocation::FlatBufferFile(path) => {
+            object.insert("schemaFlatBuffer".to_string(), json!(path))
+        }
+        SchemaLocation::Subgraphs(paths) => {
+            object.insert("schemaSubgraphs".to_string(), json!(paths))
+        }
+    };
+    object.extend([
+        ("schemaName".to_string(), json!(project_config.schema_name)),
+        (
+            "schemaExtensions".to_string(),
+            json!(project_config.schema_extensions),
+        ),
+        (
+            "schemaConfig".to_string(),
+            json!(project_config.schema_config),
+        ),
+        ("output".to_string(), json!(project_config.output)),
+        (
+            "extraArtifactsOutput".to_string(),
+            json!(project_config.extra_artifacts_output),
+        ),
+        (
+            "shardOutput".to_string(),
+            json!(project_config.shard_output),
+        ),
+        (
+            "shardStripRegex".to_string(),
+            json!(
+                project_config
+                    .shard_strip_regex
+                    .as_ref()
+                    .map(|regex| regex.as_str())
+            ),
+        ),
+        (
+            "excludesExtensions".to_string(),
+            json!(
+                project_config
+                    .excludes_extensions
+                    .as_ref()
+                    .map(|excludes| excludes.patterns())
+            ),
+        ),
+        (
+            "excludes".to_string(),
+            json!(
+                project_config
+                    .excludes
+                    .as_ref()
+                    .map(|excludes| excludes.patterns())
+            ),
+        ),
+        (
+            "sourceFileExtensions".to_string(),
+            json!(project_config.source_file_extensions()),
+        ),
+    ]);
+    if let Value::Object(typegen_config) = json!(project_config.typegen_config) {
+        object.extend(typegen_config);
+    }
+    object.extend([
+        ("persist".to_string(), json!(project_config.persist)),
+        (
+            "variableNamesComment".to_string(),
+            json!(project_config.variable_names_comment),
+        ),
+        (
+            "testPathRegex".to_string(),
+            json!(
+                project_config
+                    .test_path_regex
+                    .as_ref()
+                    .map(|regex| regex.as_str())
+            ),
+        ),
+        (
+            "featureFlags".to_string(),
+            json!(project_config.feature_flags),
+        ),
+        ("rollout".to_string(), json!(project_config.rollout)),
+        (
+            "jsModuleFormat".to_string(),
+            json!(project_config.js_module_format),
+        ),
+        (
+            "relativizeJsModulePaths".to_string(),
+            json!(project_config.relativize_js_module_paths),
+        ),
+        (
+            "moduleImportConfig".to_string(),
+            json!(project_config.module_import_config),
+        ),
+        (
+            "diagnosticReportConfig".to_string(),
+            json!(project_config.diagnostic_report_config),
+        ),
+        (
+            "resolversSchemaModule".to_string(),
+            json!(project_config.resolvers_schema_module),
+        ),
+        (
+            "queryLimits".to_string(),
+            json!(project_config.query_limits),
+        ),
+        ("prettier".to_string(), json!(project_config.prettier)),
+        (
+            "reportUnusedClientSchema".to_string(),
+            json!(project_config.report_unused_client_schema),
+        ),
+        (
+            "codegenCommand".to_string(),
+            json!(project_config.codegen_command),
+        ),
+        ("extra".to_string(), project_config.extra.clone()),
+    ]);
+    if let Some(base_config) = project_config
+        .base
+        .and_then(|base| config.projects.get(&base))
+    {
+        object.insert(
+            "inherited".to_string(),
+            json!({
+                "sources": project_sources(config, base_config.name),
+                "schemaExtensions": base_config.schema_extensions,
+            }),
+        );
+    }
+    json
+}
+
+/// The source and generated source directories of a project.
+fn project_sources(config: &Config, project_name: ProjectName) -> Vec<&Path> {
+    config
+        .sources
+        .iter()
+        .chain(&config.generated_sources)
+        .filter(|(_, project_set)| project_set.iter().any(|name| *name == project_name))
+        .map(|(path, _)| path.as_path())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use intern::string_key::Intern;
+
+    use super::*;
+
+    fn create_test_config() -> Config {
+        Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src/js": ["web", "native"],
+                        "src/base": "base"
+                    },
+                    "excludes": ["**/__mocks__/**"],
+                    "featureFlags": {
+                        "enable_fragment_argument_transform": true
+                    },
+                    "projects": {
+                        "base": {
+                            "schema": "graphql/schema.graphql",
+                            "schemaExtensions": ["graphql/base_extensions"],
+                            "language": "flow"
+                        },
+                        "web": {
+                            "base": "base",
+                            "schema": "graphql/schema.graphql",
+                            "language": "flow",
+                            "excludesExtensions": ["*.native.js"]
+                        },
+                        "native": {
+                            "schema": "graphql/schema.graphql",
+                            "language": "typescript",
+                            "excludes": ["src/js/web/**"]
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn explains_files() {
+        let config = create_test_config();
+        assert_eq!(
+            explain_file(&config, Path::new("src/js/App.native.js")).to_string(),
+            "File `src/js/App.native.js`:
+  Group: source file of project `native`
+  Left out of project `web` by its `excludesExtensions` glob `*.native.js`.
+  Project `native`: schema `graphql/schema.graphql`
+"
+        );
+        assert_eq!(
+            explain_file(&config, Path::new("src/js/web/App.js")).to_string(),
+            "File `src/js/web/App.js`:
+  Group: source file of project `web`
+  Left out of project `native` by its `excludes` glob `src/js/web/**`.
+  Project `web`: schema `graphql/schema.graphql`, base project `base`
+"
+        );
+        assert_eq!(
+            explain_file(&config, Path::new("src/js/App.ts")).to_string(),
+            "File `src/js/App.ts`:
+  Group: none, Invalid extension for a generated file.
+  Not a source file of project `web`, which has the extensions js, jsx.
+"
+        );
+        let explanation = explain_file(&config, Path::new("src/js/__mocks__/App.js"));
+        assert!(!explanation.is_compiled());
+        assert_eq!(
+            explanation.to_string(),
+            "File `src/js/__mocks__/App.js`:
+  Group: source file of projects `web`, `native`
+  Left out of the sources by the `excludes` glob `**/__mocks__/**`.
+  Project `web`: schema `graphql/schema.graphql`, base project `base`
+  Project `native`: schema `graphql/schema.graphql`
+"
+        );
+    }
+
+    #[test]
+    fn prints_resolved_project_configs() {
+        let config = create_test_config();
+        let json = project_config_json(
+            &config,
+            &config.projects[&ProjectName::from("web".intern())],
+        );
+        assert_eq!(json["sources"], json!(["src/js"]));
+        assert_eq!(json["schema"], "graphql/schema.graphql");
+        assert_eq!(json["language"], "flow");
+        assert_eq!(json["excludesExtensions"], json!(["*.native.js"]));
+        assert_eq!(
+            json["featureFlags"]["enable_fragment_argument_transform"],
+            true
+        );
+        assert_eq!(
+            json["inherited"],
+            json!({
+                "sources": ["src/base"],
+                "schemaExtensions": ["graphql/base_extensions"],
+            })
+        );
+    }
+}
@@ -15,6 +15,8 @@ mod extract_graphql;
 mod file_categorizer;
 mod file_filter;
 mod file_group;
+mod local_cache;
+mod native_watch_file_source;
 mod read_file_to_string;
 mod source_control_update_status;
 mod walk_dir_file_source;
@@ -29,6 +31,7 @@ use common::PerfLogger;
 use external_file_source::ExternalFileSource;
 pub use file_categorizer::FileCategorizer;
 pub use file_categorizer::categorize_files;
+pub(crate) use file_filter::FileFilter;
 pub use file_group::FileGroup;
 use graphql_watchman::WatchmanFileSourceResult;
 use graphql_watchman::WatchmanFileSourceSubscription;
@@ -52,6 +55,8 @@ pub use self::extract_graphql::LocatedJavascriptSourceFeatures;
 pub use self::extract_graphql::SourceReader;
 pub use self::extract_graphql::extract_javascript_features_from_file;
 pub use self::extract_graphql::source_for_location;
+use self::native_watch_file_source::NativeWatchFileSource;
+pub use self::native_watch_file_source::NativeWatchFileSourceSubscription;
 use self::walk_dir_file_source::WalkDirFileSource;
 pub use self::walk_dir_file_source::WalkDirFileSourceResult;
 use crate::compiler_state::CompilerState;
@@ -65,6 +70,7 @@ pub enum FileSource {
     Watchman(WatchmanFileSource),
     External(ExternalFileSource),
     WalkDir(WalkDirFileSource),
+    NativeWatch(NativeWatchFileSource),
     Test(TestFileSource),
 }
 
@@ -113,6 +119,9 @@ impl FileSource {
             FileSourceKind::WalkDir => {
                 Ok(Self::WalkDir(WalkDirFileSource::new(Arc::clone(config))))
             }
+            FileSourceKind::NativeWatch => Ok(Self::NativeWatch(NativeWatchFileSource::new(
+                Arc::clone(config),
+            ))),
             FileSourceKind::Test(_) => Ok(Self::Test(TestFileSource {
                 config: Arc::clone(config),
                 walk_dir_source: WalkDirFileSource::new(Arc::clone(config)),
@@ -147,12 +156,23 @@ impl FileSource {
                 }
             }
             Self::WalkDir(file_source) => file_source.create_compiler_state(perf_logger),
+            Self::NativeWatch(file_source) => file_source.create_compiler_state(perf_logger),
             Self::Test(file_source) => file_source
                 .walk_dir_source
                 .create_compiler_state(perf_logger),
         }
     }
 
+    /// Save the state of a successful build for the next build, if the file
+    /// source supports it. Failing to save is not an error of the build.
+    pub fn complete_build(&self, compiler_state: &CompilerState) {
+        if let Self::WalkDir(file_source) = self
+            && let Err(err) = file_source.save_local_cache(compiler_state)
+        {
+            warn!("Unable to save the local cache: {err}");
+        }
+    }
+
     pub async fn subscribe(
         self,
         perf_logger_event: &impl PerfLogEvent,
@@ -168,6 +188,14 @@ impl FileSource {
                     FileSourceSubscription::Watchman(watchman_subscription),
                 ))
             }
+            Self::NativeWatch(file_source) => {
+                let (compiler_state, subscription) =
+                    file_source.subscribe(perf_logger_event, perf_logger)?;
+                Ok((
+                    compiler_state,
+                    FileSourceSubscription::NativeWatch(subscription),
+                ))
+            }
             Self::Test(file_source) => {
                 // Use WalkDir for initial state
                 let compiler_state = file_source
@@ -254,6 +282,7 @@ impl FileSourceResult {
 
 pub enum FileSourceSubscription {
     Watchman(WatchmanFileSourceSubscription), // Oss(OssFileSourceSubscription),
+    NativeWatch(NativeWatchFileSourceSubscription),
     Test(TestFileSourceSubscription),
 }
 
@@ -281,6 +310,11 @@ impl FileSourceSubscription {
                     },
                 )
             }
+            Self::NativeWatch(native_watch_subscription) => {
+                Ok(FileSourceSubscriptionNextChange::NativeWatch(
+                    native_watch_subscription.next_change().await?,
+                ))
+            }
             Self::Test(test_subscription) => {
                 match test_subscription.receiver.recv().await {
                     Ok(TestFileSourceEvent::FileChanged) => {
@@ -316,6 +350,9 @@ impl FileSourceSubscription {
 #[derive(Debug)]
 pub enum FileSourceSubscriptionNextChange {
     Watchman(Box<WatchmanFileSourceSubscriptionNextChange>),
+    /// Files changed since the last change, found by the native file system
+    /// watcher
+    NativeWatch(WalkDirFileSourceResult),
     /// Test file source notification with rescanned files
     Test(WalkDirFileSourceResult),
     /// Test: source control update started (mirrors watchman hg.update enter)
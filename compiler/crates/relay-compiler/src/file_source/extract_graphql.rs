@@ -56,14 +56,14 @@ impl SourceReader for FsSourceReader {
 }
 
 /// Reads and extracts `graphql` tagged literals and Relay-specific docblocks
-/// from a JavaScript file.
+/// from a JavaScript file, or the scripts embedded in a component or MDX file.
 pub fn extract_javascript_features_from_file(
     file_source_result: &FileSourceResult,
     file: &File,
     config: &Config,
 ) -> Result<LocatedJavascriptSourceFeatures> {
     let contents = read_file_to_string(file_source_result, file)?;
-    let features = extract_graphql::extract(&contents);
+    let features = extract_graphql::extract_from_file(&file.name, &contents);
     let mut graphql_sources = Vec::new();
     let mut docblock_sources = Vec::new();
     let extract_full_source_for_docblock = match &config.should_extract_full_source {
@@ -110,7 +110,7 @@ pub fn source_for_location(
         SourceLocationKey::Embedded { path, index } => {
             let absolute_path = root_dir.join(path.lookup());
             let contents = source_reader.read_file_to_string(&absolute_path).ok()?;
-            let file_sources = extract_graphql::extract(&contents);
+            let file_sources = extract_graphql::extract_from_file(&absolute_path, &contents);
             file_sources.into_iter().nth(index.into())
         }
         SourceLocationKey::Standalone { path } => {
@@ -19,7 +19,6 @@ use fnv::FnvHashSet;
 use log::warn;
 use rayon::iter::IntoParallelRefIterator;
 use relay_config::ProjectName;
-use relay_typegen::TypegenLanguage;
 
 use super::File;
 use super::FileGroup;
@@ -81,7 +80,8 @@ pub fn categorize_files(
                             FileGroup::Source { project_set }
                             | FileGroup::Schema { project_set }
                             | FileGroup::Extension { project_set }
-                            | FileGroup::CompactSchema { project_set } => !project_set
+                            | FileGroup::CompactSchema { project_set }
+                            | FileGroup::FlatBufferSchema { project_set } => !project_set
                                 .iter()
                                 .any(|name| relevant_projects.contains(name)),
                             FileGroup::Ignore => false,
@@ -137,7 +137,7 @@ fn categorize_non_watchman_files(
 /// Watchman into what kind of files they are, such as source files of a
 /// specific source file group or generated files from some project.
 pub struct FileCategorizer {
-    source_language: HashMap<ProjectName, TypegenLanguage>,
+    source_extensions: HashMap<ProjectName, Vec<String>>,
     extensions_mapping: PathMapping<ProjectSet>,
     default_generated_dir: &'static OsStr,
     generated_dir_mapping: PathMapping<ProjectName>,
@@ -145,6 +145,7 @@ pub struct FileCategorizer {
     source_mapping: PathMapping<ProjectSet>,
     schema_file_mapping: HashMap<PathBuf, ProjectSet>,
     compact_schema_file_mapping: HashMap<PathBuf, ProjectSet>,
+    flat_buffer_schema_file_mapping: HashMap<PathBuf, ProjectSet>,
     schema_dir_mapping: PathMapping<ProjectSet>,
 }
 
@@ -182,6 +183,7 @@ impl FileCategorizer {
 
         let mut schema_file_mapping: HashMap<PathBuf, ProjectSet> = Default::default();
         let mut compact_schema_file_mapping: HashMap<PathBuf, ProjectSet> = Default::default();
+        let mut flat_buffer_schema_file_mapping: HashMap<PathBuf, ProjectSet> = Default::default();
         let mut schema_dir_mapping_map: HashMap<PathBuf, ProjectSet> = Default::default();
         for (&project_name, project_config) in &config.projects {
             match &project_config.schema_location {
@@ -191,12 +193,26 @@ impl FileCategorizer {
                         .and_modify(|project_set| project_set.insert(project_name))
                         .or_insert_with(|| ProjectSet::of(project_name));
                 }
+                SchemaLocation::FlatBufferFile(schema_file) => {
+                    flat_buffer_schema_file_mapping
+                        .entry(schema_file.clone())
+                        .and_modify(|project_set| project_set.insert(project_name))
+                        .or_insert_with(|| ProjectSet::of(project_name));
+                }
                 SchemaLocation::File(schema_file) => {
                     schema_file_mapping
                         .entry(schema_file.clone())
                         .and_modify(|project_set| project_set.insert(project_name))
                         .or_insert_with(|| ProjectSet::of(project_name));
                 }
+                SchemaLocation::Subgraphs(subgraphs) => {
+                    for subgraph in subgraphs {
+                        schema_file_mapping
+                            .entry(subgraph.clone())
+                            .and_modify(|project_set| project_set.insert(project_name))
+                            .or_insert_with(|| ProjectSet::of(project_name));
+                    }
+                }
                 SchemaLocation::Directory(directory) => {
                     schema_dir_mapping_map
                         .entry(directory.clone())
@@ -220,22 +236,28 @@ impl FileCategorizer {
                 generated_dir_mapping.push((extra_artifacts_output.clone(), project_name));
             }
         }
-        let source_language: HashMap<ProjectName, TypegenLanguage> = config
+        let source_extensions: HashMap<ProjectName, Vec<String>> = config
             .projects
             .iter()
             .map(|(project_name, project_config)| {
-                (*project_name, project_config.typegen_config.language)
+                let extensions = project_config
+                    .source_file_extensions()
+                    .into_iter()
+                    .map(String::from)
+                    .collect();
+                (*project_name, extensions)
             })
             .collect::<HashMap<_, _>>();
 
         Self {
-            source_language,
+            source_extensions,
             extensions_mapping: PathMapping::new(extensions_map.into_iter().collect()),
             default_generated_dir,
             generated_dir_mapping: PathMapping::new(generated_dir_mapping),
             generated_sources,
             schema_file_mapping,
             compact_schema_file_mapping,
+            flat_buffer_schema_file_mapping,
             schema_dir_mapping: PathMapping::new(schema_dir_mapping),
             source_mapping: PathMapping::new(source_mapping),
         }
@@ -247,12 +269,18 @@ impl FileCategorizer {
     pub fn categorize(&self, path: &Path, config: &Config) -> Result<FileGroup, Cow<'static, str>> {
         let extension = path.extension();
 
-        // Check if this is a compact schema file (matched by exact path, not extension)
+        // Check if this is a compact or flatbuffer schema file (matched by exact
+        // path, not extension)
         if let Some(project_set) = self.compact_schema_file_mapping.get(path) {
             return Ok(FileGroup::CompactSchema {
                 project_set: project_set.clone(),
             });
         }
+        if let Some(project_set) = self.flat_buffer_schema_file_mapping.get(path) {
+            return Ok(FileGroup::FlatBufferSchema {
+                project_set: project_set.clone(),
+            });
+        }
 
         let in_generated_sources = self
             .generated_sources
@@ -277,7 +305,7 @@ impl FileCategorizer {
 
         let extension = extension.ok_or(Cow::Borrowed("Got unexpected path without extension."))?;
 
-        if is_source_code_extension(extension) {
+        if self.is_source_extension(extension) {
             let project_set = self
                 .source_mapping
                 .find(path)
@@ -330,6 +358,12 @@ impl FileCategorizer {
         }
     }
 
+    /// The projects of the source directory of a path, before filtering them
+    /// by their `excludesExtensions`.
+    pub fn source_project_set(&self, path: &Path) -> Option<ProjectSet> {
+        self.source_mapping.find(path)
+    }
+
     fn is_valid_extension_for_project_set(
         &self,
         project_set: &ProjectSet,
@@ -337,15 +371,28 @@ impl FileCategorizer {
         path: &Path,
     ) -> bool {
         for project_name in project_set.iter() {
-            if let Some(language) = self.source_language.get(project_name)
-                && !is_valid_source_code_extension(language, extension)
+            if let Some(extensions) = self.source_extensions.get(project_name)
+                && !extensions
+                    .iter()
+                    .any(|expected| extension == expected.as_str())
             {
-                warn!("Unexpected file `{path:?}` for language `{language:?}`.");
+                warn!("Unexpected file `{path:?}` for project `{project_name}`.");
                 return false;
             }
         }
         true
     }
+
+    /// Whether the extension is a JavaScript extension or one of the
+    /// additional file extensions of some project.
+    fn is_source_extension(&self, extension: &OsStr) -> bool {
+        is_source_code_extension(extension)
+            || self
+                .source_extensions
+                .values()
+                .flatten()
+                .any(|expected| extension == expected.as_str())
+    }
 }
 
 struct PathMapping<T: Clone>(Vec<(PathBuf, T)>);
@@ -379,11 +426,13 @@ fn filter_projects_for_path(
         .iter()
         .filter(|project_name| {
             if let Some(project_config) = config.projects.get(*project_name) {
-                if let Some(glob_set) = &project_config.excludes_extensions {
-                    !glob_set.is_match(path)
-                } else {
-                    true
-                }
+                [
+                    &project_config.excludes_extensions,
+                    &project_config.excludes,
+                ]
+                .into_iter()
+                .flatten()
+                .all(|glob_set| !glob_set.is_match(path))
             } else {
                 true
             }
@@ -416,15 +465,6 @@ fn is_extra_extensions(extension: &OsStr) -> bool {
     extension == "php" || extension == "json"
 }
 
-fn is_valid_source_code_extension(typegen_language: &TypegenLanguage, extension: &OsStr) -> bool {
-    match typegen_language {
-        TypegenLanguage::TypeScript => is_source_code_extension(extension),
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-            extension == "js" || extension == "jsx"
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use intern::string_key::Intern;
@@ -444,7 +484,10 @@ mod tests {
                         "src/custom_overlapping": ["with_custom_generated_dir", "overlapping_generated_dir"],
                         "src/react_native.native.js": ["public"],
                         "src/component.react.native.js": ["public"],
-                        "src/flatbuffer": "flatbuffer_project"
+                        "src/flatbuffer": "flatbuffer_project",
+                        "src/mapped_flatbuffer": "mapped_flatbuffer_project",
+                        "src/gateway": "gateway_project",
+                        "src/svelte": "svelte_project"
                     },
                     "generatedSources": {
                         "src/resolver_codegen/__generated__": "public"
@@ -477,6 +520,19 @@ mod tests {
                         "flatbuffer_project": {
                             "schemaCompact": "schema/fb_schema.bin",
                             "language": "flow"
+                        },
+                        "mapped_flatbuffer_project": {
+                            "schemaFlatBuffer": "schema/schema.fb",
+                            "language": "flow"
+                        },
+                        "gateway_project": {
+                            "schemaSubgraphs": ["subgraphs/accounts.graphql", "subgraphs/reviews.graphql"],
+                            "language": "flow"
+                        },
+                        "svelte_project": {
+                            "schema": "graphql/svelte.graphql",
+                            "language": "typescript",
+                            "additionalFileExtensions": ["svelte", "mdx"]
                         }
                     }
                 }
@@ -599,6 +655,22 @@ mod tests {
                 project_set: ProjectSet::of("flatbuffer_project".intern().into()),
             },
         );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("schema/schema.fb"), &config)
+                .unwrap(),
+            FileGroup::FlatBufferSchema {
+                project_set: ProjectSet::of("mapped_flatbuffer_project".intern().into()),
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("subgraphs/reviews.graphql"), &config)
+                .unwrap(),
+            FileGroup::Schema {
+                project_set: ProjectSet::of("gateway_project".intern().into()),
+            },
+        );
     }
 
     #[test]
@@ -623,6 +695,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_additional_file_extensions() {
+        let config = create_test_config();
+        let categorizer = FileCategorizer::from_config(&config);
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/svelte/App.svelte"), &config)
+                .unwrap(),
+            FileGroup::Source {
+                project_set: ProjectSet::of("svelte_project".intern().into()),
+            },
+        );
+        assert_eq!(
+            categorizer
+                .categorize(&PathBuf::from("src/svelte/docs/Button.mdx"), &config)
+                .unwrap(),
+            FileGroup::Source {
+                project_set: ProjectSet::of("svelte_project".intern().into()),
+            },
+        );
+        assert_eq!(
+            categorizer.categorize(&PathBuf::from("src/js/App.svelte"), &config),
+            Err(Cow::Borrowed("Invalid extension for a generated file."))
+        );
+    }
+
     #[test]
     fn test_categorize_errors() {
         let config = create_test_config();
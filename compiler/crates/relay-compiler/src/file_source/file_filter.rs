@@ -55,6 +55,17 @@ impl FileFilter {
                     .iter()
                     .any(|exclude| exclude.matches_path(path)))
     }
+
+    /// The `excludes` glob that makes a file in the sources irrelevant.
+    pub fn matching_exclude(&self, path: &Path) -> Option<&str> {
+        if self.extra_roots.iter().any(|root| path.starts_with(root)) {
+            return None;
+        }
+        self.excludes
+            .iter()
+            .find(|exclude| exclude.matches_path(path))
+            .map(|exclude| exclude.as_str())
+    }
 }
 
 // Get roots for extensions, schemas and output dirs
@@ -76,8 +87,10 @@ fn get_extra_roots(config: &Config, enabled_projects: &FnvHashSet<ProjectName>)
         }
         match &project_config.schema_location {
             SchemaLocation::CompactFile(path)
+            | SchemaLocation::FlatBufferFile(path)
             | SchemaLocation::File(path)
             | SchemaLocation::Directory(path) => roots.push(path),
+            SchemaLocation::Subgraphs(paths) => roots.extend(paths),
         }
     }
     unify_roots(roots)
@@ -26,6 +26,9 @@ pub enum FileGroup {
     CompactSchema {
         project_set: ProjectSet,
     },
+    FlatBufferSchema {
+        project_set: ProjectSet,
+    },
     /// Files, that are located in the generated directory, but not created
     /// by relay compiler (utility files: .gitkeep, README, etc.) and should
     /// be ignored
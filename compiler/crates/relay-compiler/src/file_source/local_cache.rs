@@ -0,0 +1,575 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! An on-disk cache of the compiler state for builds without Watchman.
+//!
+//! After a successful build, the compiler state is saved together with a
+//! manifest holding a fingerprint (modification time, size and content hash)
+//! of every file the state was built from. The next build compares the files
+//! on disk against the manifest and only merges the changed ones into the
+//! cached state, so unchanged documents and their artifacts are reused.
+//! Artifacts that were changed or deleted since are generated again. Schema
+//! changes are handled like any other incremental change. The cache is
+//! discarded when the compiler binary or the resolved config changed,
+//! including command line options like `--project` or `--repersist`.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use common::PerfLogEvent;
+use common::PerfLogger;
+use dashmap::DashSet;
+use fnv::FnvBuildHasher;
+use fnv::FnvHashMap;
+use fnv::FnvHashSet;
+use log::info;
+use log::warn;
+use md5::Digest;
+use md5::Md5;
+use rayon::prelude::*;
+use relay_config::ProjectName;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::File;
+use super::FileSourceResult;
+use super::walk_dir_file_source::WalkDirFileSourceResult;
+use crate::compiler_fingerprint::compiler_fingerprint;
+use crate::compiler_state::ArtifactMapKind;
+use crate::compiler_state::CompilerState;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::Result;
+use crate::explain::project_config_json;
+
+const STATE_FILE_NAME: &str = "compiler_state";
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct FileFingerprint {
+    modified_nanos: u128,
+    size: u64,
+    content_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LocalCacheManifest {
+    compiler_version: String,
+    config_version: String,
+    /// Fingerprints of the input files by path relative to the root dir.
+    files: FnvHashMap<PathBuf, FileFingerprint>,
+    /// Fingerprints of the artifacts of the cached state. Generated
+    /// directories are usually excluded from the input files, so artifacts
+    /// that were changed or deleted since are detected from these.
+    artifacts: FnvHashMap<PathBuf, FileFingerprint>,
+}
+
+pub(super) struct LocalCache {
+    dir: PathBuf,
+    /// Fingerprints of the files the current build started from, saved with
+    /// the compiler state once the build succeeded.
+    fingerprints: Mutex<FnvHashMap<PathBuf, FileFingerprint>>,
+    /// Artifacts of the cached state, which the current build may delete.
+    cached_artifact_paths: Mutex<FnvHashSet<PathBuf>>,
+}
+
+impl LocalCache {
+    pub(super) fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            fingerprints: Default::default(),
+            cached_artifact_paths: Default::default(),
+        }
+    }
+
+    /// Create the compiler state for `files`: from the cached state and the
+    /// files that changed since it was saved if the cache is valid, otherwise
+    /// from scratch.
+    pub(super) fn create_compiler_state(
+        &self,
+        config: &Config,
+        files: Vec<File>,
+        setup_event: &impl PerfLogEvent,
+        perf_logger: &impl PerfLogger,
+    ) -> Result<CompilerState> {
+        let manifest = self.read_manifest(config);
+        let fingerprints = setup_event.time("local_cache_fingerprint_files_time", || {
+            fingerprint_files(&config.root_dir, &files, manifest.as_ref())
+        });
+
+        let cached_state = manifest.and_then(|manifest| {
+            let state_path = self.dir.join(STATE_FILE_NAME);
+            match CompilerState::deserialize_from_file(&state_path) {
+                Ok(state) => Some((manifest, state)),
+                Err(err) => {
+                    warn!("Ignoring the local cache: {err}");
+                    None
+                }
+            }
+        });
+        let compiler_state = match cached_state {
+            Some((manifest, mut compiler_state)) => {
+                let artifact_paths = artifact_paths(&compiler_state);
+                for (project_name, paths) in &artifact_paths {
+                    let dirty_paths: DashSet<PathBuf, FnvBuildHasher> = paths
+                        .par_iter()
+                        .filter(|path| {
+                            let cached = manifest.artifacts.get(*path);
+                            fingerprint_file(&config.root_dir.join(path), cached).as_ref() != cached
+                        })
+                        .cloned()
+                        .collect();
+                    if !dirty_paths.is_empty() {
+                        compiler_state
+                            .dirty_artifact_paths
+                            .insert(*project_name, dirty_paths);
+                    }
+                }
+                *self.cached_artifact_paths.lock().unwrap() =
+                    artifact_paths.into_values().flatten().collect();
+                let changed_files = changed_files(&manifest.files, &fingerprints);
+                info!(
+                    "Using the local cache at `{}`, {} files changed.",
+                    self.dir.display(),
+                    changed_files.len()
+                );
+                setup_event.number("local_cache_changed_files", changed_files.len());
+                if !changed_files.is_empty() {
+                    compiler_state
+                        .pending_file_source_changes
+                        .write()
+                        .unwrap()
+                        .push(FileSourceResult::WalkDir(WalkDirFileSourceResult {
+                            files: changed_files,
+                            resolved_root: config.root_dir.clone(),
+                        }));
+                    compiler_state.merge_file_source_changes(config, perf_logger, true)?;
+                }
+                compiler_state
+            }
+            None => CompilerState::from_file_source_changes(
+                config,
+                &FileSourceResult::WalkDir(WalkDirFileSourceResult {
+                    files,
+                    resolved_root: config.root_dir.clone(),
+                }),
+                setup_event,
+                perf_logger,
+            )?,
+        };
+        *self.fingerprints.lock().unwrap() = fingerprints;
+        Ok(compiler_state)
+    }
+
+    /// Save the state of a successful build. Artifacts written or deleted by
+    /// the build are fingerprinted again, so that they don't count as changed
+    /// next time.
+    pub(super) fn save(&self, config: &Config, compiler_state: &CompilerState) -> Result<()> {
+        let mut files = std::mem::take(&mut *self.fingerprints.lock().unwrap());
+        let artifacts: FnvHashMap<PathBuf, FileFingerprint> = artifact_paths(compiler_state)
+            .into_values()
+            .flatten()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .filter_map(|path| {
+                let fingerprint = fingerprint_file(&config.root_dir.join(&path), None)?;
+                Some((path, fingerprint))
+            })
+            .collect();
+        // Artifacts are only among the input files if generated directories
+        // aren't excluded.
+        for path in std::mem::take(&mut *self.cached_artifact_paths.lock().unwrap())
+            .iter()
+            .chain(artifacts.keys())
+        {
+            if files.contains_key(path) {
+                match artifacts.get(path) {
+                    Some(fingerprint) => files.insert(path.clone(), fingerprint.clone()),
+                    None => files.remove(path),
+                };
+            }
+        }
+
+        fs::create_dir_all(&self.dir).map_err(|source| Error::WriteFileError {
+            file: self.dir.clone(),
+            source,
+        })?;
+        // The manifest is written last, and removed first, so that an
+        // interrupted write leaves no manifest and the cache is ignored.
+        let manifest_path = self.dir.join(MANIFEST_FILE_NAME);
+        if manifest_path.exists() {
+            fs::remove_file(&manifest_path).map_err(|source| Error::WriteFileError {
+                file: manifest_path.clone(),
+                source,
+            })?;
+        }
+        compiler_state.serialize_to_file(&self.dir.join(STATE_FILE_NAME))?;
+        let manifest = LocalCacheManifest {
+            compiler_version: compiler_fingerprint(),
+            config_version: config_version(config),
+            files,
+            artifacts,
+        };
+        let contents = serde_json::to_vec(&manifest).map_err(|source| Error::SerdeError {
+            file: manifest_path.clone(),
+            source,
+        })?;
+        fs::write(&manifest_path, contents).map_err(|source| Error::WriteFileError {
+            file: manifest_path,
+            source,
+        })
+    }
+
+    /// The manifest of the cache, if there is one that was written by this
+    /// compiler for the current config.
+    fn read_manifest(&self, config: &Config) -> Option<LocalCacheManifest> {
+        let contents = fs::read(self.dir.join(MANIFEST_FILE_NAME)).ok()?;
+        let manifest: LocalCacheManifest = match serde_json::from_slice(&contents) {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                warn!("Ignoring the local cache, its manifest is invalid: {err}");
+                return None;
+            }
+        };
+        if manifest.compiler_version != compiler_fingerprint() {
+            info!("Ignoring the local cache, it was written by another compiler version.");
+            return None;
+        }
+        if manifest.config_version != config_version(config) {
+            info!("Ignoring the local cache, the config changed.");
+            return None;
+        }
+        Some(manifest)
+    }
+}
+
+/// A hash of the resolved config: the config file together with the command
+/// line options applied to it, and the transform plugins.
+fn config_version(config: &Config) -> String {
+    let projects = config
+        .projects
+        .values()
+        .map(|project_config| project_config_json(config, project_config))
+        .collect::<Vec<_>>();
+    let resolved = serde_json::json!({
+        "savedStateVersion": config.saved_state_version,
+        "excludes": config.excludes,
+        "header": config.header,
+        "codegenCommand": config.codegen_command,
+        "isDevVariableName": config.is_dev_variable_name,
+        "compileEverything": config.compile_everything,
+        "repersistOperations": config.repersist_operations,
+        "writesArtifacts": config.artifact_writer.writes_artifacts(),
+        "transformPlugins": config.transform_plugins.cache_key_json(),
+        "projects": projects,
+    });
+    let mut md5 = Md5::new();
+    md5.update(resolved.to_string());
+    hex::encode(md5.finalize())
+}
+
+fn fingerprint_files(
+    root_dir: &Path,
+    files: &[File],
+    manifest: Option<&LocalCacheManifest>,
+) -> FnvHashMap<PathBuf, FileFingerprint> {
+    files
+        .par_iter()
+        .filter_map(|file| {
+            let previous = manifest.and_then(|manifest| manifest.files.get(&file.name));
+            let fingerprint = fingerprint_file(&root_dir.join(&file.name), previous)?;
+            Some((file.name.clone(), fingerprint))
+        })
+        .collect()
+}
+
+/// Fingerprint the file at `path`. The content is only hashed if its
+/// modification time or size differ from the `previous` fingerprint.
+fn fingerprint_file(path: &Path, previous: Option<&FileFingerprint>) -> Option<FileFingerprint> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified_nanos = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_nanos();
+    let size = metadata.len();
+    if let Some(previous) = previous
+        && previous.modified_nanos == modified_nanos
+        && previous.size == size
+    {
+        return Some(previous.clone());
+    }
+    let mut md5 = Md5::new();
+    md5.update(fs::read(path).ok()?);
+    Some(FileFingerprint {
+        modified_nanos,
+        size,
+        content_hash: hex::encode(md5.finalize()),
+    })
+}
+
+/// The files whose content differs from the cached fingerprints, including
+/// removed files.
+fn changed_files(
+    cached: &FnvHashMap<PathBuf, FileFingerprint>,
+    current: &FnvHashMap<PathBuf, FileFingerprint>,
+) -> Vec<File> {
+    let mut files = current
+        .iter()
+        .filter(|(name, fingerprint)| {
+            cached.get(*name).map(|cached| &cached.content_hash) != Some(&fingerprint.content_hash)
+        })
+        .map(|(name, _)| File {
+            name: name.clone(),
+            exists: true,
+        })
+        .chain(
+            cached
+                .keys()
+                .filter(|name| !current.contains_key(*name))
+                .map(|name| File {
+                    name: name.clone(),
+                    exists: false,
+                }),
+        )
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    files
+}
+
+fn artifact_paths(compiler_state: &CompilerState) -> FnvHashMap<ProjectName, FnvHashSet<PathBuf>> {
+    let mut result = FnvHashMap::default();
+    for (project_name, artifact_map) in &compiler_state.artifacts.0 {
+        let paths: &mut FnvHashSet<PathBuf> = result.entry(*project_name).or_default();
+        match artifact_map.as_ref() {
+            ArtifactMapKind::Unconnected(unconnected) => paths.extend(unconnected.iter().cloned()),
+            ArtifactMapKind::Mapping(mapping) => {
+                for entry in mapping.0.iter() {
+                    paths.extend(entry.value().iter().map(|record| record.path.clone()));
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common::NoopPerfLogger;
+    use relay_config::TransformPluginConfig;
+    use relay_transform_plugins::TransformPlugins;
+
+    use super::*;
+    use crate::build_project::artifact_writer::ArtifactValidationWriter;
+    use crate::compiler::Compiler;
+    use crate::config::FileSourceKind;
+
+    fn fingerprint(content_hash: &str) -> FileFingerprint {
+        FileFingerprint {
+            modified_nanos: 0,
+            size: 0,
+            content_hash: content_hash.to_string(),
+        }
+    }
+
+    #[test]
+    fn changed_files_compares_content_hashes() {
+        let cached = [
+            ("same.js", fingerprint("a")),
+            ("changed.js", fingerprint("b")),
+            ("removed.js", fingerprint("c")),
+        ]
+        .into_iter()
+        .map(|(name, fingerprint)| (PathBuf::from(name), fingerprint))
+        .collect();
+        let current = [
+            ("same.js", fingerprint("a")),
+            ("changed.js", fingerprint("B")),
+            ("added.js", fingerprint("d")),
+        ]
+        .into_iter()
+        .map(|(name, fingerprint)| (PathBuf::from(name), fingerprint))
+        .collect();
+
+        let files = changed_files(&cached, &current)
+            .into_iter()
+            .map(|file| (file.name.to_string_lossy().to_string(), file.exists))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            vec![
+                ("added.js".to_string(), true),
+                ("changed.js".to_string(), true),
+                ("removed.js".to_string(), false),
+            ]
+        );
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn fingerprint_reuses_hash_of_unmodified_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("Foo.js");
+        fs::write(&path, "graphql`fragment Foo on User { id }`").unwrap();
+
+        let fingerprint = fingerprint_file(&path, None).unwrap();
+        let previous = FileFingerprint {
+            content_hash: "previous".to_string(),
+            ..fingerprint.clone()
+        };
+        assert_eq!(
+            fingerprint_file(&path, Some(&previous))
+                .unwrap()
+                .content_hash,
+            "previous"
+        );
+
+        let modified = FileFingerprint {
+            size: fingerprint.size + 1,
+            ..previous
+        };
+        assert_eq!(
+            fingerprint_file(&path, Some(&modified)).unwrap(),
+            fingerprint
+        );
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    fn local_cache_config(root_dir: &Path, cache_dir: &Path) -> Config {
+        let mut config = Config::search(root_dir).unwrap();
+        config.file_source_config = FileSourceKind::WalkDir;
+        config.local_cache_dir = Some(cache_dir.to_path_buf());
+        config
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    async fn build(root_dir: &Path, cache_dir: &Path) -> String {
+        let config = local_cache_config(root_dir, cache_dir);
+        Compiler::new(Arc::new(config), Arc::new(NoopPerfLogger))
+            .compile()
+            .await
+            .unwrap();
+        fs::read_to_string(root_dir.join("src/__generated__/ProfileQuery.graphql.ts")).unwrap()
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    fn write_config(root_dir: &Path, exclude_undefined: bool) {
+        fs::write(
+            root_dir.join("relay.config.json"),
+            serde_json::json!({
+                "sources": { "src": "default" },
+                "projects": {
+                    "default": {
+                        "language": "typescript",
+                        "schema": "schema.graphql",
+                        "typescriptExcludeUndefinedFromNullableUnion": exclude_undefined
+                    }
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[tokio::test]
+    async fn rebuilds_after_schema_and_config_changes() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let root_dir = root_dir.path();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache_dir = cache_dir.path();
+        fs::create_dir_all(root_dir.join("src")).unwrap();
+        write_config(root_dir, false);
+        fs::write(
+            root_dir.join("schema.graphql"),
+            "type Query { me: User }\ntype User { name: String }\n",
+        )
+        .unwrap();
+        fs::write(
+            root_dir.join("src/Profile.ts"),
+            "graphql`query ProfileQuery { me { name } }`;\n",
+        )
+        .unwrap();
+
+        let artifact = build(root_dir, cache_dir).await;
+        assert!(artifact.contains("readonly name: string | null | undefined;"));
+
+        // A schema change is merged into the cached state.
+        fs::write(
+            root_dir.join("schema.graphql"),
+            "type Query { me: User }\ntype User { name: Int }\n",
+        )
+        .unwrap();
+        let config = local_cache_config(root_dir, cache_dir);
+        assert!(
+            LocalCache::new(cache_dir.to_path_buf())
+                .read_manifest(&config)
+                .is_some()
+        );
+        let artifact = build(root_dir, cache_dir).await;
+        assert!(artifact.contains("readonly name: number | null | undefined;"));
+
+        // Command line options invalidate the cache.
+        let mut config = local_cache_config(root_dir, cache_dir);
+        config.repersist_operations = true;
+        assert!(
+            LocalCache::new(cache_dir.to_path_buf())
+                .read_manifest(&config)
+                .is_none()
+        );
+        let mut config = local_cache_config(root_dir, cache_dir);
+        for project_config in config.projects.values_mut() {
+            project_config.enabled = false;
+        }
+        assert!(
+            LocalCache::new(cache_dir.to_path_buf())
+                .read_manifest(&config)
+                .is_none()
+        );
+        let mut config = local_cache_config(root_dir, cache_dir);
+        config.artifact_writer = Box::<ArtifactValidationWriter>::default();
+        assert!(
+            LocalCache::new(cache_dir.to_path_buf())
+                .read_manifest(&config)
+                .is_none()
+        );
+
+        // So does a plugin change.
+        let mut config = local_cache_config(root_dir, cache_dir);
+        config.transform_plugins = TransformPlugins::new(
+            &[TransformPluginConfig {
+                name: None,
+                command: "./plugin".into(),
+                args: vec![],
+                timeout_seconds: 60,
+            }],
+            root_dir,
+        );
+        assert!(
+            LocalCache::new(cache_dir.to_path_buf())
+                .read_manifest(&config)
+                .is_none()
+        );
+
+        // So does a config change.
+        write_config(root_dir, true);
+        let config = local_cache_config(root_dir, cache_dir);
+        assert!(
+            LocalCache::new(cache_dir.to_path_buf())
+                .read_manifest(&config)
+                .is_none()
+        );
+        let artifact = build(root_dir, cache_dir).await;
+        assert!(artifact.contains("readonly name: number | null;"));
+    }
+}
@@ -0,0 +1,477 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! A file source for watch mode without Watchman. Changes are reported by the
+//! notification APIs of the operating system (inotify on Linux, FSEvents on
+//! macOS, through the `notify` crate) and turned into the same file lists as
+//! a directory traversal, so that they can be categorized and merged like any
+//! other file source result.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::PerfLogEvent;
+use common::PerfLogger;
+use log::warn;
+use notify::RecommendedWatcher;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use super::File;
+use super::FileSourceResult;
+use super::walk_dir_file_source::WalkDirFileSource;
+use super::walk_dir_file_source::WalkDirFileSourceResult;
+use crate::compiler_state::CompilerState;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::Result;
+
+/// How long to wait for further events before reporting a change, so that
+/// e.g. saving many files at once or switching branches triggers one build.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+/// The longest a change is held back while events keep arriving, so that a
+/// steady stream of events, e.g. from a process writing logs into a watched
+/// directory, doesn't delay the build indefinitely.
+const MAX_DEBOUNCE_DURATION: Duration = Duration::from_secs(1);
+
+/// A change observed by the watcher thread.
+#[derive(Debug, PartialEq)]
+enum WatchEvent {
+    /// The file or directory at this absolute path was created, modified,
+    /// removed, or moved to or from this path.
+    Changed(PathBuf),
+    /// Events were dropped by the operating system, e.g. because its queue
+    /// overflowed, so every file needs to be checked again.
+    Overflow,
+}
+
+pub struct NativeWatchFileSource {
+    config: Arc<Config>,
+    walk_dir_source: WalkDirFileSource,
+}
+
+impl NativeWatchFileSource {
+    pub fn new(config: Arc<Config>) -> Self {
+        let walk_dir_source = WalkDirFileSource::new(Arc::clone(&config));
+        Self {
+            config,
+            walk_dir_source,
+        }
+    }
+
+    pub fn create_compiler_state(&self, perf_logger: &impl PerfLogger) -> Result<CompilerState> {
+        self.walk_dir_source.create_compiler_state(perf_logger)
+    }
+
+    /// Start watching all roots of the config, then build the initial
+    /// compiler state from a directory traversal. Watching starts first so
+    /// that changes made during the traversal aren't lost.
+    pub fn subscribe(
+        self,
+        perf_logger_event: &impl PerfLogEvent,
+        perf_logger: &impl PerfLogger,
+    ) -> Result<(CompilerState, NativeWatchFileSourceSubscription)> {
+        let roots = self
+            .config
+            .get_all_roots()
+            .iter()
+            .map(|root| self.config.root_dir.join(root))
+            .collect::<Vec<_>>();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let watch_timer = perf_logger_event.start("native_watch_start_time");
+        let watcher = watcher::spawn(roots, sender)?;
+        perf_logger_event.stop(watch_timer);
+
+        let files = perf_logger_event.time("native_watch_find_files_time", || {
+            self.walk_dir_source.find_files()
+        });
+        let known_files = files.iter().map(|file| file.name.clone()).collect();
+        let compiler_state = CompilerState::from_file_source_changes(
+            &self.config,
+            &FileSourceResult::WalkDir(WalkDirFileSourceResult {
+                files,
+                resolved_root: self.config.root_dir.clone(),
+            }),
+            perf_logger_event,
+            perf_logger,
+        )?;
+
+        Ok((
+            compiler_state,
+            NativeWatchFileSourceSubscription {
+                _watcher: watcher,
+                receiver,
+                walk_dir_source: self.walk_dir_source,
+                known_files,
+            },
+        ))
+    }
+}
+
+pub struct NativeWatchFileSourceSubscription {
+    /// Watches the roots for as long as the subscription exists.
+    _watcher: RecommendedWatcher,
+    receiver: mpsc::UnboundedReceiver<WatchEvent>,
+    walk_dir_source: WalkDirFileSource,
+    /// The files, relative to the root dir, that currently exist as far as
+    /// the subscription knows. Used to report the files of removed
+    /// directories, and removed files after an overflow.
+    known_files: HashSet<PathBuf>,
+}
+
+impl NativeWatchFileSourceSubscription {
+    /// Wait for the next change of relevant files. Events are debounced:
+    /// a change is reported once no further event arrived for
+    /// `DEBOUNCE_DURATION`, or at the latest `MAX_DEBOUNCE_DURATION` after
+    /// the first event.
+    pub async fn next_change(&mut self) -> Result<WalkDirFileSourceResult> {
+        loop {
+            let first_event = self.receiver.recv().await.ok_or(Error::FileWatchStopped)?;
+            let deadline = Instant::now() + MAX_DEBOUNCE_DURATION;
+            let mut events = vec![first_event];
+            loop {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    break;
+                }
+                match tokio::time::timeout(DEBOUNCE_DURATION.min(remaining), self.receiver.recv())
+                    .await
+                {
+                    Ok(Some(event)) => events.push(event),
+                    _ => break,
+                }
+            }
+
+            let files = self.changed_files(events);
+            if !files.is_empty() {
+                return Ok(WalkDirFileSourceResult {
+                    files,
+                    resolved_root: self.walk_dir_source.config.root_dir.clone(),
+                });
+            }
+        }
+    }
+
+    /// Turn a batch of events into the list of changed files, relative to
+    /// the root dir, and update the known files accordingly.
+    fn changed_files(&mut self, events: Vec<WatchEvent>) -> Vec<File> {
+        let root_dir = self.walk_dir_source.config.root_dir.clone();
+        let mut changed: BTreeMap<PathBuf, bool> = BTreeMap::new();
+        if events.contains(&WatchEvent::Overflow) {
+            warn!("File system events were lost, checking all files for changes...");
+            let current_files = self
+                .walk_dir_source
+                .find_files()
+                .into_iter()
+                .map(|file| file.name)
+                .collect::<HashSet<_>>();
+            for name in self.known_files.difference(&current_files) {
+                changed.insert(name.clone(), false);
+            }
+            for name in current_files {
+                changed.insert(name, true);
+            }
+        } else {
+            for event in events {
+                let WatchEvent::Changed(path) = event else {
+                    unreachable!()
+                };
+                let Ok(name) = path.strip_prefix(&root_dir) else {
+                    continue;
+                };
+                if path.is_dir() {
+                    for file in self.walk_dir_source.find_files_in(&path) {
+                        changed.insert(file.name, true);
+                    }
+                } else if path.exists() {
+                    if self.walk_dir_source.should_include_file(name) {
+                        changed.insert(name.to_path_buf(), true);
+                    }
+                } else {
+                    // A removed file, or a removed directory with all the
+                    // files below it.
+                    let removed = self
+                        .known_files
+                        .iter()
+                        .chain(changed.keys())
+                        .filter(|known| known.starts_with(name))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    for name in removed {
+                        changed.insert(name, false);
+                    }
+                }
+            }
+        }
+
+        changed
+            .into_iter()
+            .map(|(name, exists)| {
+                if exists {
+                    self.known_files.insert(name.clone());
+                } else {
+                    self.known_files.remove(&name);
+                }
+                File { name, exists }
+            })
+            .collect()
+    }
+}
+
+mod watcher {
+    use std::io;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use log::warn;
+    use notify::EventKind;
+    use notify::RecommendedWatcher;
+    use notify::RecursiveMode;
+    use notify::Watcher;
+    use notify::event::ModifyKind;
+    use tokio::sync::mpsc;
+
+    use super::WatchEvent;
+    use crate::errors::Error;
+    use crate::errors::Result;
+
+    /// Watch `roots` with the recommended watcher of the platform, sending
+    /// the observed changes to `sender`. Directories are watched
+    /// recursively; for file roots, e.g. a schema file, the containing
+    /// directory is watched. Watching stops when the returned watcher is
+    /// dropped.
+    pub(super) fn spawn(
+        roots: Vec<PathBuf>,
+        sender: mpsc::UnboundedSender<WatchEvent>,
+    ) -> Result<RecommendedWatcher> {
+        let watched = roots
+            .iter()
+            .filter_map(|root| {
+                if root.is_file() {
+                    Some((root.parent()?.to_path_buf(), RecursiveMode::NonRecursive))
+                } else if root.is_dir() {
+                    Some((root.clone(), RecursiveMode::Recursive))
+                } else {
+                    // Roots that don't exist (yet) are skipped.
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        // Some platforms, e.g. FSEvents on macOS, report canonical paths,
+        // which are mapped back to the watched paths.
+        let canonical_paths = watched
+            .iter()
+            .filter_map(|(path, _)| {
+                let canonical = path.canonicalize().ok()?;
+                (&canonical != path).then(|| (canonical, path.clone()))
+            })
+            .collect::<Vec<_>>();
+        let mut watcher =
+            notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                let events = match result {
+                    Ok(event) if event.need_rescan() => vec![WatchEvent::Overflow],
+                    Ok(event) => match event.kind {
+                        EventKind::Access(_) | EventKind::Modify(ModifyKind::Metadata(_)) => {
+                            vec![]
+                        }
+                        _ => event
+                            .paths
+                            .into_iter()
+                            .map(|path| WatchEvent::Changed(rebase(path, &canonical_paths)))
+                            .collect(),
+                    },
+                    Err(err) => {
+                        warn!("Unable to watch for file changes: {err}");
+                        vec![WatchEvent::Overflow]
+                    }
+                };
+                for event in events {
+                    // Fails once the subscription was dropped.
+                    let _ = sender.send(event);
+                }
+            })
+            .map_err(|err| to_error(Path::new(""), err))?;
+        for (path, mode) in &watched {
+            watcher
+                .watch(path, *mode)
+                .map_err(|err| to_error(path, err))?;
+        }
+        Ok(watcher)
+    }
+
+    fn rebase(path: PathBuf, canonical_paths: &[(PathBuf, PathBuf)]) -> PathBuf {
+        canonical_paths
+            .iter()
+            .find_map(|(canonical, watched)| {
+                let relative = path.strip_prefix(canonical).ok()?;
+                Some(watched.join(relative))
+            })
+            .unwrap_or(path)
+    }
+
+    fn to_error(path: &Path, err: notify::Error) -> Error {
+        let source = match err.kind {
+            notify::ErrorKind::Io(source) => source,
+            kind => io::Error::other(notify::Error::new(kind)),
+        };
+        Error::FileWatch {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    async fn next_files(
+        subscription: &mut NativeWatchFileSourceSubscription,
+    ) -> Vec<(String, bool)> {
+        let result = tokio::time::timeout(Duration::from_secs(10), subscription.next_change())
+            .await
+            .expect("Expected a change to be reported")
+            .unwrap();
+        result
+            .files
+            .into_iter()
+            .map(|file| (file.name.to_string_lossy().to_string(), file.exists))
+            .collect()
+    }
+
+    fn subscription(root_dir: &Path) -> NativeWatchFileSourceSubscription {
+        fs::create_dir_all(root_dir.join("src/components")).unwrap();
+        fs::write(root_dir.join("src/components/Foo.js"), "").unwrap();
+        let mut config = Config::from_string_for_test(
+            r#"
+                {
+                    "sources": {
+                        "src": "public"
+                    },
+                    "projects": {
+                        "public": {
+                            "schema": "schema.graphql",
+                            "language": "flow"
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        config.root_dir = root_dir.to_path_buf();
+        let config = Arc::new(config);
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let watcher = watcher::spawn(vec![root_dir.join("src")], sender).unwrap();
+        let walk_dir_source = WalkDirFileSource::new(config);
+        let known_files = walk_dir_source
+            .find_files()
+            .into_iter()
+            .map(|file| file.name)
+            .collect();
+        NativeWatchFileSourceSubscription {
+            _watcher: watcher,
+            receiver,
+            walk_dir_source,
+            known_files,
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_created_changed_and_removed_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root_dir = temp_dir.path().canonicalize().unwrap();
+        let mut subscription = subscription(&root_dir);
+
+        fs::write(root_dir.join("src/components/Bar.js"), "").unwrap();
+        fs::write(root_dir.join("src/components/README.md"), "").unwrap();
+        assert_eq!(
+            next_files(&mut subscription).await,
+            vec![("src/components/Bar.js".to_string(), true)]
+        );
+
+        fs::remove_file(root_dir.join("src/components/Foo.js")).unwrap();
+        assert_eq!(
+            next_files(&mut subscription).await,
+            vec![("src/components/Foo.js".to_string(), false)]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_files_of_renamed_directories() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root_dir = temp_dir.path().canonicalize().unwrap();
+        let mut subscription = subscription(&root_dir);
+
+        fs::rename(root_dir.join("src/components"), root_dir.join("src/views")).unwrap();
+        assert_eq!(
+            next_files(&mut subscription).await,
+            vec![
+                ("src/components/Foo.js".to_string(), false),
+                ("src/views/Foo.js".to_string(), true),
+            ]
+        );
+
+        // The renamed directory is watched under its new path.
+        fs::write(root_dir.join("src/views/Bar.js"), "").unwrap();
+        assert_eq!(
+            next_files(&mut subscription).await,
+            vec![("src/views/Bar.js".to_string(), true)]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn steady_events_delay_the_change_at_most_max_debounce_duration() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root_dir = temp_dir.path().canonicalize().unwrap();
+        let mut subscription = subscription(&root_dir);
+        let (sender, receiver) = mpsc::unbounded_channel();
+        subscription.receiver = receiver;
+        let path = root_dir.join("src/components/Foo.js");
+        tokio::spawn(async move {
+            while sender.send(WatchEvent::Changed(path.clone())).is_ok() {
+                tokio::time::sleep(DEBOUNCE_DURATION / 2).await;
+            }
+        });
+
+        let start = Instant::now();
+        assert_eq!(
+            next_files(&mut subscription).await,
+            vec![("src/components/Foo.js".to_string(), true)]
+        );
+        assert!(start.elapsed() <= MAX_DEBOUNCE_DURATION + DEBOUNCE_DURATION);
+    }
+
+    #[test]
+    fn overflow_rescans_all_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root_dir = temp_dir.path().canonicalize().unwrap();
+        let mut subscription = subscription(&root_dir);
+        subscription
+            .known_files
+            .insert(PathBuf::from("src/components/Removed.js"));
+
+        let files = subscription.changed_files(vec![WatchEvent::Overflow]);
+        assert_eq!(
+            files
+                .into_iter()
+                .map(|file| (file.name.to_string_lossy().to_string(), file.exists))
+                .collect::<Vec<_>>(),
+            vec![
+                ("src/components/Foo.js".to_string(), true),
+                ("src/components/Removed.js".to_string(), false),
+            ]
+        );
+    }
+}
@@ -13,10 +13,10 @@ use std::sync::Arc;
 use common::PerfLogEvent;
 use common::PerfLogger;
 use log::debug;
-use relay_typegen::TypegenLanguage;
 use walkdir::WalkDir;
 
 use super::File;
+use super::local_cache::LocalCache;
 use crate::FileSourceResult;
 use crate::compiler_state::CompilerState;
 use crate::config::Config;
@@ -30,26 +30,18 @@ pub struct WalkDirFileSourceResult {
 
 pub struct WalkDirFileSource {
     pub config: Arc<Config>,
-    expected_file_extensions: HashSet<&'static str>,
+    expected_file_extensions: HashSet<String>,
+    local_cache: Option<LocalCache>,
 }
 
-fn get_expected_file_extensions(config: &Config) -> HashSet<&'static str> {
-    let mut file_extensions = HashSet::<&str>::with_capacity(6);
-    file_extensions.insert("graphql");
-    file_extensions.insert("gql");
+fn get_expected_file_extensions(config: &Config) -> HashSet<String> {
+    let mut file_extensions = HashSet::<String>::with_capacity(6);
+    file_extensions.insert("graphql".to_string());
+    file_extensions.insert("gql".to_string());
 
     for project in config.enabled_projects() {
-        match project.typegen_config.language {
-            TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-                file_extensions.insert("js");
-                file_extensions.insert("jsx");
-            }
-            TypegenLanguage::TypeScript => {
-                file_extensions.insert("js");
-                file_extensions.insert("jsx");
-                file_extensions.insert("ts");
-                file_extensions.insert("tsx");
-            }
+        for extension in project.source_file_extensions() {
+            file_extensions.insert(extension.to_string());
         }
     }
     file_extensions
@@ -61,13 +53,15 @@ impl WalkDirFileSource {
             "Watchman server is disabled, or not available. Using GlobFileSource to find files."
         );
         let expected_file_extensions = get_expected_file_extensions(&config);
+        let local_cache = config.local_cache_dir.clone().map(LocalCache::new);
         Self {
             config,
             expected_file_extensions,
+            local_cache,
         }
     }
 
-    fn should_include_file(&self, name: &Path) -> bool {
+    pub(super) fn should_include_file(&self, name: &Path) -> bool {
         matches!(
             name.extension().map(|extension| self
                 .expected_file_extensions
@@ -76,12 +70,18 @@ impl WalkDirFileSource {
         )
     }
 
-    fn find_files(&self) -> Vec<File> {
+    pub(super) fn find_files(&self) -> Vec<File> {
         self.config
             .get_all_roots()
             .iter()
-            .map(|source| self.config.root_dir.join(source))
-            .flat_map(WalkDir::new)
+            .flat_map(|source| self.find_files_in(&self.config.root_dir.join(source)))
+            .collect::<Vec<_>>()
+    }
+
+    /// Find the files below `path`, relative to the root dir.
+    pub(super) fn find_files_in(&self, path: &Path) -> Vec<File> {
+        WalkDir::new(path)
+            .into_iter()
             .filter_map(|entry| {
                 let dir_entry = entry.ok()?;
                 let relative_path = dir_entry
@@ -100,6 +100,17 @@ impl WalkDirFileSource {
 
     pub fn create_compiler_state(&self, perf_logger: &impl PerfLogger) -> Result<CompilerState> {
         let setup_event = perf_logger.create_event("Glob_file_source_create_compiler_state");
+        if let Some(local_cache) = &self.local_cache {
+            let files = setup_event.time("create_compiler_state_file_files", || self.find_files());
+            let compiler_state = local_cache.create_compiler_state(
+                &self.config,
+                files,
+                &setup_event,
+                perf_logger,
+            )?;
+            setup_event.complete();
+            return Ok(compiler_state);
+        }
         let timer = setup_event.start("create_compiler_state_file_files");
         let file_source_changes = FileSourceResult::WalkDir(WalkDirFileSourceResult {
             files: self.find_files(),
@@ -118,6 +129,14 @@ impl WalkDirFileSource {
         Ok(compiler_state)
     }
 
+    /// Save the state of a successful build to the local cache, if enabled.
+    pub fn save_local_cache(&self, compiler_state: &CompilerState) -> Result<()> {
+        match &self.local_cache {
+            Some(local_cache) => local_cache.save(&self.config, compiler_state),
+            None => Ok(()),
+        }
+    }
+
     /// Query all files in the configured roots.
     /// This is used by the test file source subscription for rescanning.
     pub fn query_files(&self) -> Result<WalkDirFileSourceResult> {
@@ -9,11 +9,11 @@ use std::path::PathBuf;
 
 use fnv::FnvBuildHasher;
 use indexmap::IndexMap;
-use relay_typegen::TypegenLanguage;
 use watchman_client::prelude::*;
 
 use crate::compiler_state::ProjectSet;
 use crate::config::Config;
+use crate::config::ProjectConfig;
 
 type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
 
@@ -114,27 +114,22 @@ fn get_sources_dir_exprs(
                     depth: None,
                 }),
                 // Match file extensions
-                get_project_file_ext_expr(project.typegen_config.language),
+                get_project_file_ext_expr(project),
             ])
         })
         .collect()
 }
 
-fn get_project_file_ext_expr(typegen_language: TypegenLanguage) -> Expr {
-    // Ending in *.js(x) or *.ts(x) depending on the project language.
-    Expr::Suffix(match &typegen_language {
-        TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
-            vec![PathBuf::from("js"), PathBuf::from("jsx")]
-        }
-        TypegenLanguage::TypeScript => {
-            vec![
-                PathBuf::from("js"),
-                PathBuf::from("jsx"),
-                PathBuf::from("ts"),
-                PathBuf::from("tsx"),
-            ]
-        }
-    })
+fn get_project_file_ext_expr(project: &ProjectConfig) -> Expr {
+    // Ending in *.js(x) or *.ts(x) depending on the project language, or one
+    // of the additional file extensions.
+    Expr::Suffix(
+        project
+            .source_file_extensions()
+            .into_iter()
+            .map(PathBuf::from)
+            .collect(),
+    )
 }
 
 fn expr_files_in_dirs(roots: Vec<PathBuf>) -> Expr {
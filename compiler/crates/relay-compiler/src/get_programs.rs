@@ -19,7 +19,9 @@ use crate::compiler_state::CompilerState;
 use crate::config::Config;
 use crate::errors::Result;
 
-type RelayPrograms = (
+/// The programs of each project, with the compiler state and config they were
+/// built with.
+pub type RelayPrograms = (
     HashMap<ProjectName, Arc<Programs>>,
     CompilerState,
     Arc<Config>,
@@ -13,20 +13,25 @@ pub mod artifact_content;
 mod artifact_map;
 pub mod build_project;
 pub mod compiler;
+mod compiler_fingerprint;
 pub mod compiler_state;
 pub mod config;
 mod docblocks;
 pub mod errors;
+pub mod explain;
 pub mod file_source;
 mod get_programs;
 mod graphql_asts;
 mod operation_persister;
 mod path_validator;
 mod red_to_green;
+pub mod schema_check;
 #[cfg(unix)]
 pub mod server_daemon;
 pub mod status_reporter;
 pub mod subschema_extraction;
+pub mod subset_schema_check;
+mod trace_summary;
 mod utils;
 
 pub use artifact_map::ArtifactSourceKey;
@@ -36,6 +41,7 @@ pub use build_project::ArtifactContent;
 pub use build_project::ArtifactGeneratedTypes;
 pub use build_project::BuildProjectFailure;
 pub use build_project::SourceHashes;
+pub use build_project::artifact_cache::ArtifactCacheConfig;
 pub use build_project::artifact_writer::ArtifactFileWriter;
 pub use build_project::artifact_writer::ArtifactValidationWriter;
 pub use build_project::artifact_writer::ArtifactWriter;
@@ -47,6 +53,7 @@ pub use build_project::artifact_writer::NoopArtifactWriter;
 pub use build_project::build_programs;
 pub use build_project::build_raw_program;
 pub use build_project::build_schema;
+pub use build_project::compose_subgraph_sources;
 pub use build_project::find_duplicates;
 pub use build_project::generate_artifacts;
 pub use build_project::generate_extra_artifacts::GenerateExtraArtifactsFn;
@@ -55,6 +62,7 @@ pub use build_project::source_control_for_root;
 pub use build_project::transform_program;
 pub use build_project::validate;
 pub use build_project::validate_program;
+pub use compiler_fingerprint::compiler_fingerprint;
 pub use config::ConfigFile;
 pub use config::ConfigFileProject;
 pub use config::FileSourceKind;
@@ -81,10 +89,12 @@ pub use file_source::SourceControlUpdateStatus;
 pub use file_source::SourceReader;
 pub use file_source::WalkDirFileSourceResult;
 pub use file_source::source_for_location;
+pub use get_programs::RelayPrograms;
 pub use get_programs::assert_programs;
 pub use get_programs::get_programs;
 pub use graphql_asts::GraphQLAsts;
 pub use operation_persister::LocalPersister;
 pub use operation_persister::RemotePersister;
 pub use relay_config::ProjectName;
+pub use trace_summary::trace_summary;
 pub use utils::get_parser_features;
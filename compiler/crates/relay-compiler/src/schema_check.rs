@@ -0,0 +1,195 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for checking a candidate server schema against the documents of
+//! the projects that use it.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common::ConsoleLogger;
+use common::Diagnostic;
+use common::PerfLogger;
+use dependency_analyzer::SchemaCheckReport;
+use fnv::FnvHashMap;
+use relay_config::ProjectName;
+use thiserror::Error;
+
+use crate::GraphQLAsts;
+use crate::SchemaLocation;
+use crate::build_project::BuildMode;
+use crate::build_project::ProjectAstData;
+use crate::build_project::build_raw_program;
+use crate::build_project::build_schema;
+use crate::build_project::get_project_asts;
+use crate::compiler_state::SchemaSources;
+use crate::errors::BuildProjectError;
+use crate::errors::print_compiler_error;
+use crate::get_programs;
+
+/// Errors that can occur while checking a candidate schema.
+#[derive(Debug, Error)]
+pub enum SchemaCheckError {
+    #[error("Unable to read the candidate schema `{path}`: {details}")]
+    ReadFailed { path: String, details: String },
+
+    #[error("Project `{0}` uses a compact schema, which can't be checked against an SDL schema")]
+    CompactSchemaNotSupported(ProjectName),
+
+    #[error("Project `{0}` uses a flatbuffer schema, which can't be checked against an SDL schema")]
+    FlatBufferSchemaNotSupported(ProjectName),
+
+    #[error("The candidate schema is invalid for project `{project_name}`:{details}")]
+    InvalidCandidateSchema {
+        project_name: ProjectName,
+        details: String,
+    },
+
+    #[error("Compilation failed: {0}")]
+    CompilationFailed(String),
+}
+
+/// Compile the projects of `config` and check their documents against the
+/// server schema at `candidate_schema_path`, a file or a directory of
+/// `.graphql` files. The candidate replaces the server schema of every
+/// project; client schema extensions and Relay Resolvers are kept.
+pub async fn check_candidate_schema(
+    config: crate::config::Config,
+    candidate_schema_path: &Path,
+) -> Result<BTreeMap<ProjectName, SchemaCheckReport>, SchemaCheckError> {
+    let candidate_sources = read_candidate_sources(candidate_schema_path)?;
+
+    let root_dir = config.root_dir.clone();
+    let perf_logger = Arc::new(ConsoleLogger);
+    let (programs, mut compiler_state, config) = get_programs(config, Arc::clone(&perf_logger))
+        .await
+        .map_err(|e| SchemaCheckError::CompilationFailed(print_compiler_error(&root_dir, e)))?;
+    let graphql_asts = GraphQLAsts::from_graphql_sources_map(
+        &compiler_state.graphql_sources,
+        &Default::default(),
+        &config,
+    )
+    .map_err(|e| SchemaCheckError::CompilationFailed(print_compiler_error(&root_dir, e)))?;
+
+    // The server schema ASTs cached by the compile above are keyed by the
+    // schema location of the projects, which the candidate now replaces.
+    compiler_state.parsed_server_asts_cache.clear();
+    let log_event = perf_logger.create_event("schema_check");
+
+    let mut reports = BTreeMap::new();
+    for (project_name, project_programs) in programs {
+        let project_config = &config.projects[&project_name];
+        match project_config.schema_location {
+            SchemaLocation::CompactFile(_) => {
+                return Err(SchemaCheckError::CompactSchemaNotSupported(project_name));
+            }
+            SchemaLocation::FlatBufferFile(_) => {
+                return Err(SchemaCheckError::FlatBufferSchemaNotSupported(project_name));
+            }
+            // A candidate supergraph is composed as a single subgraph.
+            SchemaLocation::File(_)
+            | SchemaLocation::Directory(_)
+            | SchemaLocation::Subgraphs(_) => {}
+        }
+        compiler_state.schemas.insert(
+            project_name,
+            SchemaSources {
+                pending: FnvHashMap::default(),
+                processed: candidate_sources.clone(),
+            },
+        );
+        compiler_state.schema_cache.remove(&project_name);
+
+        let candidate = build_schema(
+            &compiler_state,
+            &config,
+            project_config,
+            &graphql_asts,
+            &log_event,
+        )
+        .map_err(|diagnostics| SchemaCheckError::InvalidCandidateSchema {
+            project_name,
+            details: format_diagnostics(&diagnostics),
+        })?;
+
+        let (definitions, candidate_errors) =
+            match get_project_asts(&candidate, &graphql_asts, project_config) {
+                Ok(ProjectAstData { project_asts, .. }) => {
+                    let definitions = project_asts.definitions.clone();
+                    let errors = match build_raw_program(
+                        project_config,
+                        project_asts,
+                        Arc::clone(&candidate),
+                        &log_event,
+                        BuildMode::Full,
+                    ) {
+                        Ok(_) => vec![],
+                        Err(BuildProjectError::ValidationErrors { errors, .. }) => errors,
+                        Err(error) => {
+                            return Err(SchemaCheckError::CompilationFailed(error.to_string()));
+                        }
+                    };
+                    (definitions, errors)
+                }
+                Err(BuildProjectError::ValidationErrors { errors, .. }) => (vec![], errors),
+                Err(error) => return Err(SchemaCheckError::CompilationFailed(error.to_string())),
+            };
+
+        reports.insert(
+            project_name,
+            SchemaCheckReport::from_program(
+                &project_programs.source,
+                &candidate,
+                &definitions,
+                &candidate_errors,
+                project_config.typegen_config.no_future_proof_enums,
+            ),
+        );
+    }
+
+    Ok(reports)
+}
+
+pub(crate) fn read_candidate_sources(
+    path: &Path,
+) -> Result<FnvHashMap<PathBuf, String>, SchemaCheckError> {
+    let read_failed = |error: std::io::Error| SchemaCheckError::ReadFailed {
+        path: path.display().to_string(),
+        details: error.to_string(),
+    };
+    let mut paths = vec![];
+    if fs::metadata(path).map_err(read_failed)?.is_dir() {
+        for entry in fs::read_dir(path).map_err(read_failed)? {
+            let entry_path = entry.map_err(read_failed)?.path();
+            if entry_path
+                .extension()
+                .is_some_and(|extension| extension == "graphql")
+            {
+                paths.push(entry_path);
+            }
+        }
+    } else {
+        paths.push(path.to_path_buf());
+    }
+    paths
+        .into_iter()
+        .map(|path| {
+            let text = fs::read_to_string(&path).map_err(read_failed)?;
+            Ok((path, text))
+        })
+        .collect()
+}
+
+fn format_diagnostics(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| format!("\n - {}", diagnostic.print_without_source()))
+        .collect()
+}
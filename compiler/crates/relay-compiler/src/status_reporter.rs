@@ -45,6 +45,9 @@ pub trait StatusReporter {
     fn build_starts(&self);
     fn build_completes(&self, diagnostics: &[Diagnostic]);
     fn build_errors(&self, error: &Error);
+    /// Reports how many projects of a full build were restored from the
+    /// artifact cache (hits) and how many had to be compiled (misses).
+    fn artifact_cache_lookups(&self, _hits: usize, _misses: usize) {}
 }
 
 /// A no-op reporter used as a placeholder when the real reporter is being moved.
@@ -417,6 +420,10 @@ impl StatusReporter for BuildStatus {
         self.build_completed();
     }
 
+    fn artifact_cache_lookups(&self, hits: usize, misses: usize) {
+        self.base_reporter.artifact_cache_lookups(hits, misses);
+    }
+
     fn build_errors(&self, error: &Error) {
         self.base_reporter.build_errors(error);
         let messages = format_build_errors(
@@ -439,6 +446,10 @@ impl StatusReporter for Arc<BuildStatus> {
         (**self).build_completes(diagnostics);
     }
 
+    fn artifact_cache_lookups(&self, hits: usize, misses: usize) {
+        (**self).artifact_cache_lookups(hits, misses);
+    }
+
     fn build_errors(&self, error: &Error) {
         (**self).build_errors(error);
     }
@@ -579,6 +590,10 @@ impl StatusReporter for ConsoleStatusReporter {
         info!("Compilation completed.");
     }
 
+    fn artifact_cache_lookups(&self, hits: usize, misses: usize) {
+        info!("Artifact cache: {hits} hit(s), {misses} miss(es).");
+    }
+
     fn build_errors(&self, error: &Error) {
         let messages = format_build_errors(
             &self.root_dir,
@@ -638,6 +653,12 @@ impl StatusReporter for JSONStatusReporter {
         }
     }
 
+    fn artifact_cache_lookups(&self, hits: usize, misses: usize) {
+        if self.path.is_some() {
+            self.base_reporter.artifact_cache_lookups(hits, misses);
+        }
+    }
+
     fn build_errors(&self, error: &Error) {
         match &self.path {
             Some(path) => {
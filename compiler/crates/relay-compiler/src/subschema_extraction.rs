@@ -38,6 +38,12 @@ pub enum SubschemaError {
     #[error("Expected a single file schema, but found a directory schema location")]
     DirectorySchemaNotSupported,
 
+    #[error("Expected an SDL schema, but found a flatbuffer schema location")]
+    FlatBufferSchemaNotSupported,
+
+    #[error("Expected a single file schema, but found a subgraphs schema location")]
+    SubgraphsSchemaNotSupported,
+
     #[error("Full schema path not found: {0}")]
     FullSchemaNotFound(String),
 
@@ -90,6 +96,12 @@ pub async fn compile_and_extract_subschema(
             return Err(SubschemaError::DirectorySchemaNotSupported);
         }
         SchemaLocation::CompactFile(file) => file.clone(),
+        SchemaLocation::FlatBufferFile(_) => {
+            return Err(SubschemaError::FlatBufferSchemaNotSupported);
+        }
+        SchemaLocation::Subgraphs(_) => {
+            return Err(SubschemaError::SubgraphsSchemaNotSupported);
+        }
     };
 
     // Normalize the full schema path relative to root_dir
@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for checking that the checked-in schema of a project is still a
+//! subset of the service schema it was extracted from.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common::Diagnostic;
+use common::SourceLocationKey;
+use common::TextSource;
+use fnv::FnvHashMap;
+use relay_config::ProjectName;
+use schema_set::DirectivePolicies;
+use schema_set::SchemaSet;
+use schema_set::find_subset_violations::SchemaFileLocation;
+use schema_set::find_subset_violations::SubsetViolation;
+use schema_set::find_subset_violations::find_subset_violations;
+use schema_set::find_subset_violations::locate_subset_violations;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::SchemaLocation;
+use crate::config::Config;
+use crate::schema_check::SchemaCheckError;
+use crate::schema_check::read_candidate_sources;
+
+/// Errors that can occur while checking a subset schema.
+#[derive(Debug, Error)]
+pub enum SubsetSchemaCheckError {
+    #[error("Unable to read the schema `{path}`: {details}")]
+    ReadFailed { path: String, details: String },
+
+    #[error("Project `{0}` uses a compact schema, which can't be checked as a subset schema")]
+    CompactSchemaNotSupported(ProjectName),
+
+    #[error("Project `{0}` uses a flatbuffer schema, which can't be checked as a subset schema")]
+    FlatBufferSchemaNotSupported(ProjectName),
+
+    #[error("Project `{0}` uses subgraph schemas, which can't be checked as a subset schema")]
+    SubgraphsSchemaNotSupported(ProjectName),
+
+    #[error("The schema is invalid:{0}")]
+    InvalidSchema(String),
+}
+
+/// The elements of the schema of a project that aren't covered by the
+/// service schema.
+#[derive(Debug, Serialize)]
+pub struct SubsetSchemaReport {
+    pub violations: Vec<SubsetViolation>,
+}
+
+impl SubsetSchemaReport {
+    pub fn is_valid_subset(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for SubsetSchemaReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.violations.is_empty() {
+            return writeln!(f, "The schema is a valid subset of the service schema.");
+        }
+        for violation in &self.violations {
+            match violation.subset_locations.first() {
+                Some(location) => write!(f, "{}:{}: ", location.file, location.line)?,
+                None => write!(f, "{}: ", violation.schema_coordinate)?,
+            }
+            writeln!(f, "{}", violation.description)?;
+            for location in &violation.base_locations {
+                writeln!(
+                    f,
+                    "  defined in the service schema at {}:{}",
+                    location.file, location.line
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check that the schema of every enabled project of `config` is a subset of
+/// the service schema at `service_schema_path`, a file or a directory of
+/// `.graphql` files. Directives may diverge as described by the
+/// `@divergence` applications of the service schema. Nothing is written.
+pub fn check_subset_schemas(
+    config: &Config,
+    service_schema_path: &Path,
+) -> Result<BTreeMap<ProjectName, SubsetSchemaReport>, SubsetSchemaCheckError> {
+    let mut sources = SchemaSources::default();
+    let service = sources.read(&config.root_dir, service_schema_path)?;
+    let policies = DirectivePolicies::from_service_schema(&service);
+
+    let mut reports = BTreeMap::new();
+    for project_config in config.enabled_projects() {
+        let schema_path = match &project_config.schema_location {
+            SchemaLocation::File(path) | SchemaLocation::Directory(path) => path,
+            SchemaLocation::CompactFile(_) => {
+                return Err(SubsetSchemaCheckError::CompactSchemaNotSupported(
+                    project_config.name,
+                ));
+            }
+            SchemaLocation::FlatBufferFile(_) => {
+                return Err(SubsetSchemaCheckError::FlatBufferSchemaNotSupported(
+                    project_config.name,
+                ));
+            }
+            SchemaLocation::Subgraphs(_) => {
+                return Err(SubsetSchemaCheckError::SubgraphsSchemaNotSupported(
+                    project_config.name,
+                ));
+            }
+        };
+        let subset = sources.read(&config.root_dir, &config.root_dir.join(schema_path))?;
+
+        let mut violations = find_subset_violations(&service, &subset, &policies);
+        locate_subset_violations(&mut violations, &service, &subset, |location| {
+            let path = location.source_location().path();
+            let text = sources.texts.get(path)?;
+            Some(SchemaFileLocation {
+                file: path.to_string(),
+                line: text.to_span_range(location.span()).start.line + 1,
+            })
+        });
+        reports.insert(project_config.name, SubsetSchemaReport { violations });
+    }
+    Ok(reports)
+}
+
+/// The text of every schema file read so far, by the path its locations
+/// refer to.
+#[derive(Default)]
+struct SchemaSources {
+    texts: FnvHashMap<String, TextSource>,
+}
+
+impl SchemaSources {
+    fn read(&mut self, root_dir: &Path, path: &Path) -> Result<SchemaSet, SubsetSchemaCheckError> {
+        let mut files: Vec<(PathBuf, String)> = read_candidate_sources(path)
+            .map_err(|error| match error {
+                SchemaCheckError::ReadFailed { path, details } => {
+                    SubsetSchemaCheckError::ReadFailed { path, details }
+                }
+                error => SubsetSchemaCheckError::InvalidSchema(error.to_string()),
+            })?
+            .into_iter()
+            .collect();
+        files.sort();
+
+        let mut documents = vec![];
+        let mut errors: Vec<Diagnostic> = vec![];
+        for (file_path, text) in files {
+            let display_path = file_path
+                .strip_prefix(root_dir)
+                .unwrap_or(&file_path)
+                .display()
+                .to_string();
+            match graphql_syntax::parse_schema_document(
+                &text,
+                SourceLocationKey::standalone(&display_path),
+            ) {
+                Ok(document) => documents.push(document),
+                Err(diagnostics) => errors.extend(diagnostics),
+            }
+            self.texts
+                .insert(display_path, TextSource::from_whole_document(text));
+        }
+        if !errors.is_empty() {
+            return Err(SubsetSchemaCheckError::InvalidSchema(format_diagnostics(
+                &errors,
+            )));
+        }
+        SchemaSet::from_base_schema_documents(&documents)
+            .map_err(|errors| SubsetSchemaCheckError::InvalidSchema(format_diagnostics(&errors)))
+    }
+}
+
+fn format_diagnostics(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| format!("\n - {}", diagnostic.print_without_source()))
+        .collect()
+}
@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Summarizes the spans recorded by a `TraceLogger` of a build.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use common::TraceSpan;
+use fnv::FnvHashMap;
+
+/// Tables of the `limit` transforms and files that took the most time.
+/// Transforms are the timers of the `apply_*_transforms` events, per stage
+/// and project. Files are the items timed by any event, like the extraction
+/// of a source file or the generation of an artifact.
+pub fn trace_summary(spans: &[TraceSpan], limit: usize) -> String {
+    let mut transforms: FnvHashMap<(&str, &str, &str), Duration> = Default::default();
+    let mut files: FnvHashMap<(&str, &str), Duration> = Default::default();
+    for span in spans {
+        if let Some(item) = &span.item {
+            *files.entry((item, span.name)).or_default() += span.duration;
+        } else if let Some(stage) = transform_stage(span) {
+            let project = span.arg("project").unwrap_or_default();
+            *transforms.entry((stage, project, span.name)).or_default() += span.duration;
+        }
+    }
+
+    let mut summary = String::new();
+    print_table(
+        &mut summary,
+        "Slowest transforms",
+        ["Stage", "Project", "Transform"],
+        transforms
+            .into_iter()
+            .map(|((stage, project, transform), duration)| (duration, [stage, project, transform]))
+            .collect(),
+        limit,
+    );
+    print_table(
+        &mut summary,
+        "Slowest files",
+        ["Step", "File"],
+        files
+            .into_iter()
+            .map(|((file, step), duration)| (duration, [step, file]))
+            .collect(),
+        limit,
+    );
+    summary
+}
+
+/// The stage of the timer span of a transform, like `reader` for the timers
+/// of the `apply_reader_transforms` event.
+fn transform_stage(span: &TraceSpan) -> Option<&'static str> {
+    if span.name == span.event {
+        return None;
+    }
+    span.event
+        .strip_prefix("apply_")
+        .and_then(|event| event.strip_suffix("_transforms"))
+}
+
+fn print_table<const N: usize>(
+    output: &mut String,
+    title: &str,
+    headers: [&str; N],
+    mut rows: Vec<(Duration, [&str; N])>,
+    limit: usize,
+) {
+    if rows.is_empty() {
+        return;
+    }
+    rows.sort_by(|(duration_a, row_a), (duration_b, row_b)| {
+        duration_b.cmp(duration_a).then_with(|| row_a.cmp(row_b))
+    });
+    rows.truncate(limit);
+
+    let mut widths = headers.map(str::len);
+    for (_, row) in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    // The last column isn't padded to avoid trailing whitespace.
+    widths[N - 1] = 0;
+    writeln!(output, "{title}:").unwrap();
+    write!(output, "{:>10}", "Time").unwrap();
+    for (header, width) in headers.iter().zip(widths) {
+        write!(output, "  {header:width$}").unwrap();
+    }
+    writeln!(output).unwrap();
+    for (duration, row) in rows {
+        write!(output, "{:>8.1}ms", duration.as_secs_f64() * 1000.0).unwrap();
+        for (cell, width) in row.iter().zip(widths) {
+            write!(output, "  {cell:width$}").unwrap();
+        }
+        writeln!(output).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(
+        name: &'static str,
+        event: &'static str,
+        item: Option<&str>,
+        project: &str,
+        millis: u64,
+    ) -> TraceSpan {
+        TraceSpan {
+            name,
+            event,
+            item: item.map(str::to_string),
+            args: vec![("project", project.into())],
+            thread_id: 1,
+            start: Duration::ZERO,
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn summarizes_slowest_transforms_and_files() {
+        let spans = vec![
+            span(
+                "inline_fragments",
+                "apply_reader_transforms",
+                None,
+                "web",
+                3,
+            ),
+            span(
+                "inline_fragments",
+                "apply_reader_transforms",
+                None,
+                "web",
+                4,
+            ),
+            span("mask", "apply_reader_transforms", None, "web", 1),
+            span("flatten", "apply_typegen_transforms", None, "web", 5),
+            span(
+                "apply_typegen_transforms",
+                "apply_typegen_transforms",
+                None,
+                "web",
+                20,
+            ),
+            span("build_ir_time", "build_project", None, "web", 30),
+            span(
+                "generate_artifact",
+                "commit_project",
+                Some("src/__generated__/A.graphql.js"),
+                "web",
+                2,
+            ),
+            span(
+                "extract_graphql_strings_from_file",
+                "categorize",
+                Some("src/A.js"),
+                "web",
+                9,
+            ),
+        ];
+        assert_eq!(
+            trace_summary(&spans, 2),
+            r#"Slowest transforms:
+      Time  Stage    Project  Transform
+     7.0ms  reader   web      inline_fragments
+     5.0ms  typegen  web      flatten
+Slowest files:
+      Time  Step                               File
+     9.0ms  extract_graphql_strings_from_file  src/A.js
+     2.0ms  generate_artifact                  src/__generated__/A.graphql.js
+"#
+        );
+    }
+}
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<b6e38a751ec237a6be704122bf142495>>
+ * @generated SignedSource<<7a7bb139fef9480f166232b4dfa0cf97>>
  */
 
 mod relay_compiler_integration;
@@ -327,6 +327,27 @@ async fn incremental_schema_field_nullability_change() {
     test_fixture(transform_fixture, file!(), "incremental_schema_field_nullability_change.input", "relay_compiler_integration/fixtures/incremental_schema_field_nullability_change.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn incremental_schema_types_output_shared_module() {
+    let input = include_str!("relay_compiler_integration/fixtures/incremental_schema_types_output_shared_module.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/incremental_schema_types_output_shared_module.expected");
+    test_fixture(transform_fixture, file!(), "incremental_schema_types_output_shared_module.input", "relay_compiler_integration/fixtures/incremental_schema_types_output_shared_module.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn kotlin_models() {
+    let input = include_str!("relay_compiler_integration/fixtures/kotlin_models.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/kotlin_models.expected");
+    test_fixture(transform_fixture, file!(), "kotlin_models.input", "relay_compiler_integration/fixtures/kotlin_models.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn kotlin_resolver_with_context() {
+    let input = include_str!("relay_compiler_integration/fixtures/kotlin_resolver_with_context.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/kotlin_resolver_with_context.expected");
+    test_fixture(transform_fixture, file!(), "kotlin_resolver_with_context.input", "relay_compiler_integration/fixtures/kotlin_resolver_with_context.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn live_resolver_implements_interface_field() {
     let input = include_str!("relay_compiler_integration/fixtures/live_resolver_implements_interface_field.input");
@@ -481,6 +502,20 @@ async fn preloadable_query_typescript() {
     test_fixture(transform_fixture, file!(), "preloadable_query_typescript.input", "relay_compiler_integration/fixtures/preloadable_query_typescript.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn prettier_flow_artifacts() {
+    let input = include_str!("relay_compiler_integration/fixtures/prettier_flow_artifacts.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/prettier_flow_artifacts.expected");
+    test_fixture(transform_fixture, file!(), "prettier_flow_artifacts.input", "relay_compiler_integration/fixtures/prettier_flow_artifacts.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn prettier_typescript_artifacts() {
+    let input = include_str!("relay_compiler_integration/fixtures/prettier_typescript_artifacts.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/prettier_typescript_artifacts.expected");
+    test_fixture(transform_fixture, file!(), "prettier_typescript_artifacts.input", "relay_compiler_integration/fixtures/prettier_typescript_artifacts.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn refetchable_with_directive_enum_arg() {
     let input = include_str!("relay_compiler_integration/fixtures/refetchable_with_directive_enum_arg.input");
@@ -838,6 +873,13 @@ async fn schema_outside_root_dir() {
     test_fixture(transform_fixture, file!(), "schema_outside_root_dir.input", "relay_compiler_integration/fixtures/schema_outside_root_dir.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn schema_types_output() {
+    let input = include_str!("relay_compiler_integration/fixtures/schema_types_output.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/schema_types_output.expected");
+    test_fixture(transform_fixture, file!(), "schema_types_output.input", "relay_compiler_integration/fixtures/schema_types_output.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn semantic_null_require_bubble_to_required_parent() {
     let input = include_str!("relay_compiler_integration/fixtures/semantic_null_require_bubble_to_required_parent.input");
@@ -999,6 +1041,20 @@ async fn spread_multiple_interface_fragments_on_concrete_type() {
     test_fixture(transform_fixture, file!(), "spread_multiple_interface_fragments_on_concrete_type.input", "relay_compiler_integration/fixtures/spread_multiple_interface_fragments_on_concrete_type.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn swift_models() {
+    let input = include_str!("relay_compiler_integration/fixtures/swift_models.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/swift_models.expected");
+    test_fixture(transform_fixture, file!(), "swift_models.input", "relay_compiler_integration/fixtures/swift_models.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn swift_resolver_with_context() {
+    let input = include_str!("relay_compiler_integration/fixtures/swift_resolver_with_context.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/swift_resolver_with_context.expected");
+    test_fixture(transform_fixture, file!(), "swift_resolver_with_context.input", "relay_compiler_integration/fixtures/swift_resolver_with_context.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn typescript_resolver_type_import() {
     let input = include_str!("relay_compiler_integration/fixtures/typescript_resolver_type_import.input");
@@ -1012,3 +1068,24 @@ async fn typescript_resolver_with_context() {
     let expected = include_str!("relay_compiler_integration/fixtures/typescript_resolver_with_context.expected");
     test_fixture(transform_fixture, file!(), "typescript_resolver_with_context.input", "relay_compiler_integration/fixtures/typescript_resolver_with_context.expected", input, expected).await;
 }
+
+#[tokio::test]
+async fn workspace_packages() {
+    let input = include_str!("relay_compiler_integration/fixtures/workspace_packages.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/workspace_packages.expected");
+    test_fixture(transform_fixture, file!(), "workspace_packages.input", "relay_compiler_integration/fixtures/workspace_packages.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn workspace_packages_duplicate_definitions() {
+    let input = include_str!("relay_compiler_integration/fixtures/workspace_packages_duplicate_definitions.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/workspace_packages_duplicate_definitions.expected");
+    test_fixture(transform_fixture, file!(), "workspace_packages_duplicate_definitions.input", "relay_compiler_integration/fixtures/workspace_packages_duplicate_definitions.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn workspace_packages_without_relay_configs() {
+    let input = include_str!("relay_compiler_integration/fixtures/workspace_packages_without_relay_configs.input");
+    let expected = include_str!("relay_compiler_integration/fixtures/workspace_packages_without_relay_configs.expected");
+    test_fixture(transform_fixture, file!(), "workspace_packages_without_relay_configs.input", "relay_compiler_integration/fixtures/workspace_packages_without_relay_configs.expected", input, expected).await;
+}
@@ -15,9 +15,12 @@ mod diagnostic_report_config;
 mod js_module_format;
 mod module_import_config;
 mod non_node_id_fields_config;
+mod prettier_config;
 mod project_config;
 mod project_name;
+mod query_limits_config;
 mod resolvers_schema_module_config;
+mod transform_plugin_config;
 mod typegen_config;
 
 pub use connection_interface::ConnectionInterface;
@@ -29,7 +32,10 @@ pub use module_import_config::ModuleImportConfig;
 pub use module_import_config::ModuleProvider;
 pub use module_import_config::Surface;
 pub use non_node_id_fields_config::NonNodeIdFieldsConfig;
+pub use prettier_config::PrettierConfig;
+pub use prettier_config::TrailingCommaConfig;
 pub use project_config::ExtraArtifactsConfig;
+pub use project_config::GlobPatterns;
 pub use project_config::LocalPersistAlgorithm;
 pub use project_config::LocalPersistConfig;
 pub use project_config::PersistConfig;
@@ -38,7 +44,9 @@ pub use project_config::RemotePersistConfig;
 pub use project_config::SchemaConfig;
 pub use project_config::SchemaLocation;
 pub use project_name::ProjectName;
+pub use query_limits_config::QueryLimitsConfig;
 pub use resolvers_schema_module_config::ResolversSchemaModuleConfig;
+pub use transform_plugin_config::TransformPluginConfig;
 pub use typegen_config::CustomType;
 pub use typegen_config::CustomTypeImport;
 pub use typegen_config::OneOfGeneration;
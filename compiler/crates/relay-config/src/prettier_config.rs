@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Options of the in-process formatting of the generated JavaScript and
+/// TypeScript artifacts, a subset of the options of Prettier with the same
+/// names and defaults. Artifacts are formatted before they are signed, so
+/// they don't need to be formatted by a `codegenCommand`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PrettierConfig {
+    /// The line length that the formatter wraps on.
+    #[serde(default = "default_print_width")]
+    pub print_width: usize,
+
+    /// Use single quotes instead of double quotes.
+    #[serde(default)]
+    pub single_quote: bool,
+
+    /// Print trailing commas wherever possible in multi-line
+    /// comma-separated syntactic structures.
+    #[serde(default)]
+    pub trailing_comma: TrailingCommaConfig,
+}
+
+fn default_print_width() -> usize {
+    80
+}
+
+impl Default for PrettierConfig {
+    fn default() -> Self {
+        Self {
+            print_width: default_print_width(),
+            single_quote: false,
+            trailing_comma: TrailingCommaConfig::default(),
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    JsonSchema
+)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailingCommaConfig {
+    /// Trailing commas wherever possible, including function parameters and
+    /// calls.
+    #[default]
+    All,
+    /// Trailing commas where valid in ES5 (objects, arrays, etc.).
+    Es5,
+    /// No trailing commas.
+    None,
+}
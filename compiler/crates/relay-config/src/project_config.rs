@@ -6,6 +6,7 @@
  */
 
 use std::fmt;
+use std::ops::Deref;
 use std::path::MAIN_SEPARATOR;
 use std::path::Path;
 use std::path::PathBuf;
@@ -18,7 +19,9 @@ use common::SourceLocationKey;
 use common::WithLocation;
 use fmt::Debug;
 use fnv::FnvBuildHasher;
+use globset::Glob;
 use globset::GlobSet;
+use globset::GlobSetBuilder;
 use indexmap::IndexMap;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
@@ -40,6 +43,8 @@ use crate::diagnostic_report_config::DiagnosticReportConfig;
 use crate::module_import_config::ModuleImportConfig;
 use crate::module_import_config::ModuleProvider;
 use crate::non_node_id_fields_config::NonNodeIdFieldsConfig;
+use crate::prettier_config::PrettierConfig;
+use crate::query_limits_config::QueryLimitsConfig;
 use crate::resolvers_schema_module_config::ResolversSchemaModuleConfig;
 
 type FnvIndexMap<K, V> = IndexMap<K, V, FnvBuildHasher>;
@@ -177,6 +182,12 @@ pub enum SchemaLocation {
     Directory(PathBuf),
     /// A single file containing a compact-serialized schema.
     CompactFile(PathBuf),
+    /// A single file containing a schema serialized as a flatbuffer, which is
+    /// memory-mapped and read lazily.
+    FlatBufferFile(PathBuf),
+    /// The SDL files of the subgraphs served by a gateway, which are composed
+    /// into the supergraph schema.
+    Subgraphs(Vec<PathBuf>),
 }
 
 pub struct ExtraArtifactsConfig {
@@ -259,6 +270,53 @@ impl Default for SchemaConfig {
 
 type CustomArtifactFilePath = Box<dyn Fn(&PathBuf) -> PathBuf + Send + Sync>;
 
+/// Glob patterns matched together, which keep the patterns to tell which one
+/// matched a path. Derefs to the underlying `GlobSet`.
+#[derive(Clone, Debug)]
+pub struct GlobPatterns {
+    patterns: Vec<String>,
+    glob_set: GlobSet,
+}
+
+impl GlobPatterns {
+    /// Fails with the first invalid pattern.
+    pub fn new(patterns: Vec<String>) -> Result<Self, (String, globset::Error)> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &patterns {
+            builder.add(Glob::new(pattern).map_err(|error| (pattern.clone(), error))?);
+        }
+        let glob_set = builder
+            .build()
+            .map_err(|error| (patterns.join(", "), error))?;
+        Ok(Self { patterns, glob_set })
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    pub fn is_match(&self, path: impl AsRef<Path>) -> bool {
+        self.glob_set.is_match(path)
+    }
+
+    /// The first pattern matching the path.
+    pub fn matching_pattern(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.glob_set
+            .matches(path)
+            .into_iter()
+            .min()
+            .map(|index| self.patterns[index].as_str())
+    }
+}
+
+impl Deref for GlobPatterns {
+    type Target = GlobSet;
+
+    fn deref(&self) -> &GlobSet {
+        &self.glob_set
+    }
+}
+
 /// Configuration for a Relay project.
 ///
 /// This struct contains various settings and options that control how Relay compiles and generates code for a project.
@@ -272,7 +330,15 @@ pub struct ProjectConfig {
     /// The configuration for extra artifacts.
     pub extra_artifacts_config: Option<ExtraArtifactsConfig>,
     /// A list of glob patterns specifying file extensions to exclude from compilation.
-    pub excludes_extensions: Option<GlobSet>,
+    ///
+    /// This used to be a `GlobSet`; `GlobPatterns` derefs to it, so the
+    /// `GlobSet` methods are still available.
+    pub excludes_extensions: Option<GlobPatterns>,
+    /// Glob patterns of source files that are not part of this project.
+    pub excludes: Option<GlobPatterns>,
+    /// Extensions of source files to extract GraphQL from, besides the
+    /// JavaScript extensions of the typegen language.
+    pub additional_file_extensions: Vec<String>,
     /// The output directory for compiled artifacts.
     pub output: Option<PathBuf>,
     /// Whether to shard output into separate files.
@@ -311,6 +377,14 @@ pub struct ProjectConfig {
     pub diagnostic_report_config: DiagnosticReportConfig,
     /// Configuration for resolvers schema module.
     pub resolvers_schema_module: Option<ResolversSchemaModuleConfig>,
+    /// Limits on the cost and depth of operations.
+    pub query_limits: Option<QueryLimitsConfig>,
+    /// Options of the in-process formatting of JavaScript and TypeScript
+    /// artifacts. Artifacts aren't formatted without them.
+    pub prettier: Option<PrettierConfig>,
+    /// Report client schema elements that aren't used by any document as
+    /// hints in the language server.
+    pub report_unused_client_schema: bool,
     /// Command to run after code generation.
     pub codegen_command: Option<String>,
     /// Custom function to get the path for an artifact.
@@ -329,6 +403,8 @@ impl Default for ProjectConfig {
             extra_artifacts_output: None,
             extra_artifacts_config: None,
             excludes_extensions: None,
+            excludes: None,
+            additional_file_extensions: vec![],
             output: None,
             shard_output: false,
             shard_strip_regex: None,
@@ -347,6 +423,9 @@ impl Default for ProjectConfig {
             module_import_config: Default::default(),
             diagnostic_report_config: Default::default(),
             resolvers_schema_module: Default::default(),
+            query_limits: None,
+            prettier: None,
+            report_unused_client_schema: false,
             codegen_command: Default::default(),
             get_custom_path_for_artifact: None,
             relativize_js_module_paths: true,
@@ -362,6 +441,8 @@ impl Debug for ProjectConfig {
             extra_artifacts_output,
             extra_artifacts_config,
             excludes_extensions,
+            excludes,
+            additional_file_extensions,
             output,
             shard_output,
             shard_strip_regex,
@@ -381,6 +462,9 @@ impl Debug for ProjectConfig {
             module_import_config,
             diagnostic_report_config,
             resolvers_schema_module,
+            query_limits,
+            prettier,
+            report_unused_client_schema,
             codegen_command,
             get_custom_path_for_artifact: _,
             relativize_js_module_paths,
@@ -392,6 +476,8 @@ impl Debug for ProjectConfig {
             .field("extra_artifacts_config", extra_artifacts_config)
             .field("extra_artifacts_output", extra_artifacts_output)
             .field("excludes_extensions", excludes_extensions)
+            .field("excludes", excludes)
+            .field("additional_file_extensions", additional_file_extensions)
             .field("shard_output", shard_output)
             .field("shard_strip_regex", shard_strip_regex)
             .field("schema_extensions", schema_extensions)
@@ -410,6 +496,9 @@ impl Debug for ProjectConfig {
             .field("module_import_config", module_import_config)
             .field("diagnostic_report_config", diagnostic_report_config)
             .field("resolvers_schema_module", resolvers_schema_module)
+            .field("query_limits", query_limits)
+            .field("prettier", prettier)
+            .field("report_unused_client_schema", report_unused_client_schema)
             .field("codegen_command", codegen_command)
             .field("relativize_js_module_paths", relativize_js_module_paths)
             .finish()
@@ -417,6 +506,24 @@ impl Debug for ProjectConfig {
 }
 
 impl ProjectConfig {
+    /// Extensions of the source files of the project: `js` and `jsx`, `ts` and
+    /// `tsx` unless the project generates Flow or JavaScript types, and the
+    /// additional file extensions.
+    pub fn source_file_extensions(&self) -> Vec<&str> {
+        let mut extensions = match self.typegen_config.language {
+            TypegenLanguage::Flow | TypegenLanguage::JavaScript => vec!["js", "jsx"],
+            TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
+                vec!["js", "jsx", "ts", "tsx"]
+            }
+        };
+        for extension in &self.additional_file_extensions {
+            if !extensions.contains(&extension.as_str()) {
+                extensions.push(extension);
+            }
+        }
+        extensions
+    }
+
     /// Gets the correct path for a generated artifact based on its originating source file's
     /// location, and the project's configuration.
     pub fn create_path_for_artifact(
@@ -531,14 +638,38 @@ impl ProjectConfig {
         source_file: SourceLocationKey,
         artifact_file_name: String,
     ) -> PathBuf {
-        let filename = match &self.typegen_config.language {
+        self.create_path_for_artifact(
+            source_file,
+            self.language_specific_file_name(artifact_file_name),
+        )
+    }
+
+    /// Gets the path of the shared type module generated for the input object
+    /// or custom scalar `type_name`, if `schemaTypesOutput` is configured.
+    /// Native languages declare every type within the artifact using it, so
+    /// they have no shared modules.
+    pub fn path_for_schema_type_module(&self, type_name: impl Into<StringKey>) -> Option<PathBuf> {
+        if self.typegen_config.language.is_native() {
+            return None;
+        }
+        let type_name = type_name.into();
+        self.typegen_config
+            .schema_types_output
+            .as_ref()
+            .map(|output| {
+                output.join(self.language_specific_file_name(format!("{type_name}.schema")))
+            })
+    }
+
+    fn language_specific_file_name(&self, artifact_file_name: String) -> String {
+        match &self.typegen_config.language {
             TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
                 format!("{artifact_file_name}.js")
             }
             TypegenLanguage::TypeScript => format!("{artifact_file_name}.ts"),
-        };
-
-        self.create_path_for_artifact(source_file, filename)
+            TypegenLanguage::Kotlin => format!("{artifact_file_name}.kt"),
+            TypegenLanguage::Swift => format!("{artifact_file_name}.swift"),
+        }
     }
 
     /// Returns `module_import_config` with `dynamic_module_provider` filled in when absent.
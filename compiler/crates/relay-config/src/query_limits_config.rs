@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::DiagnosticLevel;
+
+/// Limits on the cost and depth of the operations of a project, checked at
+/// compile time against their normalization AST, i.e. the selections that are
+/// sent to the server.
+///
+/// The cost of an operation is the sum of the weights of its server fields,
+/// where the weight of each field is multiplied by the size of the lists it is
+/// nested in. The weight of a field comes from an `@cost(weight: ...)`
+/// directive on its definition or on its type in the schema, and defaults to
+/// `defaultFieldCost`. The size of a list comes from the `first` or `last`
+/// argument of the field that returns it, and defaults to `defaultListSize`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct QueryLimitsConfig {
+    /// Maximum cost of an operation.
+    #[serde(default)]
+    pub max_cost: Option<u64>,
+
+    /// Maximum depth of an operation, counted in nested linked fields.
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// Weight of fields without an `@cost` directive.
+    #[serde(default = "default_field_cost")]
+    pub default_field_cost: u64,
+
+    /// Assumed size of lists that aren't bounded by a `first` or `last`
+    /// argument.
+    #[serde(default = "default_list_size")]
+    pub default_list_size: u64,
+
+    /// Severity of operations exceeding a limit. Defaults to `error`.
+    #[serde(default = "default_level")]
+    pub level: DiagnosticLevel,
+
+    /// Number of the most expensive fields listed when an operation exceeds
+    /// `maxCost`.
+    #[serde(default = "default_top_contributors")]
+    pub top_contributors: usize,
+}
+
+fn default_field_cost() -> u64 {
+    1
+}
+
+fn default_list_size() -> u64 {
+    10
+}
+
+fn default_level() -> DiagnosticLevel {
+    DiagnosticLevel::Error
+}
+
+fn default_top_contributors() -> usize {
+    5
+}
+
+impl Default for QueryLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_cost: None,
+            max_depth: None,
+            default_field_cost: default_field_cost(),
+            default_list_size: default_list_size(),
+            level: default_level(),
+            top_contributors: default_top_contributors(),
+        }
+    }
+}
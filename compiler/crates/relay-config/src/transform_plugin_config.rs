@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A transform plugin: an executable that is started by the compiler and
+/// transforms or validates the IR of each project over the versioned transform
+/// plugin protocol on its stdin and stdout. The plugin declares the pipeline
+/// stages (`common`, `reader`, `operation`, `normalization`, `operationText`
+/// and `typegen`) it runs before or after when it is started.
+///
+/// WebAssembly plugins can be run with a WASI runtime as the command, e.g.
+/// `{"command": "wasmtime", "args": ["run", "plugin.wasm"]}`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TransformPluginConfig {
+    /// Name of the plugin in logs and diagnostics. Defaults to the file name
+    /// of the command.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The executable of the plugin. Paths starting with `.` are relative to
+    /// the root directory, other commands are looked up in the `PATH`.
+    pub command: PathBuf,
+
+    /// Arguments passed to the command.
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// How long to wait for the plugin to respond to a request before the
+    /// build fails.
+    #[serde(default = "default_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_timeout_seconds() -> u64 {
+    60
+}
+
+impl TransformPluginConfig {
+    pub fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            self.command
+                .file_stem()
+                .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+        })
+    }
+
+    /// Whether the command is a path relative to the root directory rather
+    /// than a command looked up in the `PATH`.
+    pub fn is_relative_command(&self) -> bool {
+        self.command.starts_with(".") || self.command.starts_with("..")
+    }
+}
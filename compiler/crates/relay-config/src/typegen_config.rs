@@ -35,6 +35,12 @@ pub enum TypegenLanguage {
     JavaScript,
     TypeScript,
     Flow,
+    /// Kotlin data classes for native clients. Fragment spreads are treated as
+    /// unmasked and no runtime artifacts are generated.
+    Kotlin,
+    /// Swift `Codable` structs for native clients. Fragment spreads are treated
+    /// as unmasked and no runtime artifacts are generated.
+    Swift,
 }
 
 impl TypegenLanguage {
@@ -45,6 +51,11 @@ impl TypegenLanguage {
         }
         res
     }
+
+    /// Whether this language targets a native client rather than JavaScript.
+    pub fn is_native(&self) -> bool {
+        matches!(self, Self::Kotlin | Self::Swift)
+    }
 }
 
 #[derive(
@@ -189,6 +200,13 @@ pub struct TypegenConfig {
     /// generates the type as if the @oneOf annotation does not exist.
     #[serde(default)]
     pub one_of_type: OneOfGeneration,
+
+    /// When set, the types of input objects and custom scalars are emitted
+    /// once each, into their own `<TypeName>.schema` module in this directory
+    /// (relative to the project root), and operation artifacts import them
+    /// instead of re-declaring them inline.
+    #[serde(default)]
+    pub schema_types_output: Option<PathBuf>,
 }
 
 fn get_true() -> bool {
@@ -210,6 +228,7 @@ impl Default for TypegenConfig {
             custom_error_type: None,
             resolver_context_type: Default::default(),
             one_of_type: Default::default(),
+            schema_types_output: None,
         }
     }
 }
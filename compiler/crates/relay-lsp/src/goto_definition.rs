@@ -30,6 +30,7 @@ use serde::Serialize;
 
 use self::goto_docblock_definition::get_docblock_definition_description;
 use self::goto_graphql_definition::get_graphql_definition_description;
+pub(crate) use self::goto_graphql_definition::get_graphql_definition_description_from_resolution_path;
 use self::goto_graphql_definition::get_graphql_schema_definition_description;
 use crate::FieldDefinitionSourceInfo;
 use crate::FieldSchemaInfo;
@@ -91,62 +92,76 @@ pub fn on_goto_definition(
         }
     };
 
-    let extra_data_provider = state.get_extra_data_provider();
-    let root_dir = state.root_dir();
+    let goto_definition_response = locate_definition(
+        definition_description,
+        &schema,
+        program,
+        state.get_extra_data_provider(),
+        project_name,
+        &state.root_dir(),
+    )?;
+
+    // For some lsp-clients, such as clients relying on org.eclipse.lsp4j,
+    // (see https://javadoc.io/static/org.eclipse.lsp4j/org.eclipse.lsp4j/0.8.1/org/eclipse/lsp4j/services/TextDocumentService.html)
+    // the definition response should be vector of location or locationlink.
+    // Therefore, let's convert the GotoDefinitionResponse::Scalar into Vector
+    if let GotoDefinitionResponse::Scalar(l) = goto_definition_response {
+        return Ok(Some(GotoDefinitionResponse::Array(vec![l])));
+    }
+
+    Ok(Some(goto_definition_response))
+}
 
-    let goto_definition_response: GotoDefinitionResponse = match definition_description {
+/// Find the location of the definition described by `definition_description`.
+pub(crate) fn locate_definition(
+    definition_description: DefinitionDescription,
+    schema: &Arc<SDLSchema>,
+    program: graphql_ir::Program,
+    extra_data_provider: &dyn LSPExtraDataProvider,
+    project_name: StringKey,
+    root_dir: &std::path::Path,
+) -> LSPRuntimeResult<GotoDefinitionResponse> {
+    Ok(match definition_description {
         DefinitionDescription::FieldArgument {
             parent_type,
             field_name,
             argument_name,
         } => locate_field_argument_definition(
-            &schema,
+            schema,
             parent_type,
             field_name,
             argument_name,
-            &root_dir,
+            root_dir,
         )?,
         DefinitionDescription::DirectiveArgument {
             directive_name,
             argument_name,
-        } => {
-            locate_directive_argument_definition(&schema, directive_name, argument_name, &root_dir)?
-        }
+        } => locate_directive_argument_definition(schema, directive_name, argument_name, root_dir)?,
         DefinitionDescription::Field {
             parent_type,
             field_name,
         } => locate_field_definition(
-            &schema,
+            schema,
             parent_type,
             field_name,
             extra_data_provider,
             project_name,
-            &root_dir,
+            root_dir,
         )?,
         DefinitionDescription::Fragment { fragment_name } => {
-            locate_fragment_definition(program, fragment_name, &root_dir)?
+            locate_fragment_definition(program, fragment_name, root_dir)?
         }
         DefinitionDescription::Type { type_name } => locate_type_definition(
             extra_data_provider,
             project_name,
             type_name,
-            &schema,
-            &root_dir,
+            schema,
+            root_dir,
         )?,
         DefinitionDescription::Directive { directive_name } => {
-            locate_directive_definition(directive_name, &schema, &root_dir)?
+            locate_directive_definition(directive_name, schema, root_dir)?
         }
-    };
-
-    // For some lsp-clients, such as clients relying on org.eclipse.lsp4j,
-    // (see https://javadoc.io/static/org.eclipse.lsp4j/org.eclipse.lsp4j/0.8.1/org/eclipse/lsp4j/services/TextDocumentService.html)
-    // the definition response should be vector of location or locationlink.
-    // Therefore, let's convert the GotoDefinitionResponse::Scalar into Vector
-    if let GotoDefinitionResponse::Scalar(l) = goto_definition_response {
-        return Ok(Some(GotoDefinitionResponse::Array(vec![l])));
-    }
-
-    Ok(Some(goto_definition_response))
+    })
 }
 
 fn locate_fragment_definition(
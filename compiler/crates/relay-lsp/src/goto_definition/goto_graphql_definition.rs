@@ -88,7 +88,13 @@ pub fn get_graphql_definition_description(
     schema: &Arc<SDLSchema>,
 ) -> LSPRuntimeResult<DefinitionDescription> {
     let node_path = document.resolve((), position_span);
+    get_graphql_definition_description_from_resolution_path(&node_path, schema)
+}
 
+pub fn get_graphql_definition_description_from_resolution_path(
+    node_path: &ResolutionPath<'_>,
+    schema: &SDLSchema,
+) -> LSPRuntimeResult<DefinitionDescription> {
     match node_path {
         ResolutionPath::Ident(IdentPath {
             inner: fragment_name,
@@ -124,7 +130,7 @@ pub fn get_graphql_definition_description(
         }) => resolve_field_argument(
             field.name.value,
             ArgumentName(argument_name.value),
-            selection_path.parent,
+            &selection_path.parent,
             schema,
         ),
         ResolutionPath::Ident(IdentPath {
@@ -141,7 +147,7 @@ pub fn get_graphql_definition_description(
         }) => resolve_field_argument(
             field.name.value,
             ArgumentName(argument_name.value),
-            selection_path.parent,
+            &selection_path.parent,
             schema,
         ),
         ResolutionPath::Ident(IdentPath {
@@ -151,7 +157,7 @@ pub fn get_graphql_definition_description(
                     inner: _,
                     parent: selection_path,
                 }),
-        }) => resolve_field(field_name.value, selection_path.parent, schema),
+        }) => resolve_field(field_name.value, &selection_path.parent, schema),
         ResolutionPath::Ident(IdentPath {
             inner: field_name,
             parent:
@@ -159,7 +165,7 @@ pub fn get_graphql_definition_description(
                     inner: _,
                     parent: selection_path,
                 }),
-        }) => resolve_field(field_name.value, selection_path.parent, schema),
+        }) => resolve_field(field_name.value, &selection_path.parent, schema),
         ResolutionPath::Ident(IdentPath {
             inner: _,
             parent:
@@ -188,8 +194,8 @@ pub fn get_graphql_definition_description(
 
 fn resolve_field(
     field_name: StringKey,
-    selection_parent: SelectionParent<'_>,
-    schema: &Arc<SDLSchema>,
+    selection_parent: &SelectionParent<'_>,
+    schema: &SDLSchema,
 ) -> LSPRuntimeResult<DefinitionDescription> {
     let parent_type = selection_parent
         .find_parent_type(schema)
@@ -204,8 +210,8 @@ fn resolve_field(
 fn resolve_field_argument(
     field_name: StringKey,
     argument_name: ArgumentName,
-    selection_parent: SelectionParent<'_>,
-    schema: &Arc<SDLSchema>,
+    selection_parent: &SelectionParent<'_>,
+    schema: &SDLSchema,
 ) -> LSPRuntimeResult<DefinitionDescription> {
     let parent_type = selection_parent
         .find_parent_type(schema)
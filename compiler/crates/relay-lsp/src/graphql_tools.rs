@@ -263,7 +263,7 @@ pub(crate) fn get_query_text<
                 project_config,
                 Arc::new(program),
                 Arc::clone(&state.perf_logger),
-                state.config.custom_transforms.as_ref(),
+                state.config.all_custom_transforms().as_ref(),
                 state
                     .config
                     .transferrable_refetchable_query_directives
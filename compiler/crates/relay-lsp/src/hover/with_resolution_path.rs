@@ -65,12 +65,14 @@ use resolution_path::VariableIdentifierParent;
 use resolution_path::VariableIdentifierPath;
 use schema::SDLSchema;
 use schema::Schema;
+use schema_coordinates::SchemaCoordinate;
 use schema_documentation::SchemaDocumentation;
 use schema_print::print_directive;
 
 use crate::LSPExtraDataProvider;
 use crate::hover::GraphQLSchemaExplorerParams;
 use crate::hover::get_open_schema_explorer_command_link;
+use crate::schema_coordinate::get_schema_coordinate;
 
 /// Enum, that allows us to adjust content of the hover
 /// tooltip based on the consumer type (Relay, GraphQL)
@@ -115,11 +117,34 @@ pub fn get_hover<'a>(
     );
 
     hover_content.map(|contents| Hover {
-        contents,
+        contents: match get_schema_coordinate(path, schema) {
+            Some(coordinate) => with_schema_coordinate(contents, &coordinate),
+            None => contents,
+        },
         range: None,
     })
 }
 
+/// Append the schema coordinate of the hovered element, so that it can be
+/// copied for use in tooling that accepts coordinates.
+fn with_schema_coordinate(contents: HoverContents, coordinate: &SchemaCoordinate) -> HoverContents {
+    let coordinate = format!("Schema coordinate: `{coordinate}`");
+    match contents {
+        HoverContents::Array(mut contents) => {
+            contents.push(MarkedString::String(coordinate));
+            HoverContents::Array(contents)
+        }
+        HoverContents::Scalar(content) => {
+            HoverContents::Array(vec![content, MarkedString::String(coordinate)])
+        }
+        HoverContents::Markup(mut content) => {
+            content.value.push_str("\n\n");
+            content.value.push_str(&coordinate);
+            HoverContents::Markup(content)
+        }
+    }
+}
+
 enum HoverBehavior<'a> {
     OperationDefinitionName(&'a OperationDefinition),
     OperationDefinitionRemainder,
@@ -29,6 +29,7 @@ pub mod print_operation;
 pub mod references;
 pub mod rename;
 mod resolved_types_at_location;
+mod schema_coordinate;
 mod search_schema_items;
 pub mod server;
 pub mod shutdown;
@@ -61,14 +61,14 @@ pub fn transform_relay_location_to_lsp_location_with_cache(
             let path_to_fragment = root_dir.join(PathBuf::from(path.lookup()));
             let uri = get_uri(&path_to_fragment)?;
 
-            // Embedded locations are always `.js` files, so we'll look in the
+            // Embedded locations are always source files, so we'll look in the
             // source feature cache first.
             let range = match source_feature_cache.and_then(|cache| cache.get(&uri)) {
                 Some(response) => feature_location_to_range(&response, index, location),
                 None => {
                     // If the file is not in the cache, read it from disk.
                     let content = get_file_contents(&path_to_fragment)?;
-                    let response = extract_graphql::extract(&content);
+                    let response = extract_graphql::extract_from_file(&path_to_fragment, &content);
                     feature_location_to_range(&response, index, location)
                 }
             }?;
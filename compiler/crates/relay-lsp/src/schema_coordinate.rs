@@ -0,0 +1,206 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Utilities for copying the schema coordinate of the element under the
+//! cursor and for opening the definition of a schema coordinate
+
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::Location;
+use lsp_types::TextDocumentIdentifier;
+use lsp_types::TextDocumentPositionParams;
+use lsp_types::request::Request;
+use resolution_path::ResolutionPath;
+use resolution_path::ResolvePosition;
+use schema::SDLSchema;
+use schema::Schema;
+use schema_coordinates::SchemaCoordinate;
+use schema_coordinates::SchemaElement;
+use schema_coordinates::parse_schema_coordinate;
+use schema_coordinates::resolve_schema_coordinate;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::goto_definition::DefinitionDescription;
+use crate::goto_definition::get_graphql_definition_description_from_resolution_path;
+use crate::goto_definition::locate_definition;
+use crate::location::transform_relay_location_on_disk_to_lsp_location;
+use crate::lsp_runtime_error::LSPRuntimeError;
+use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::server::GlobalState;
+
+/// The schema coordinate of the field, argument, directive or type `path`
+/// resolves to.
+pub(crate) fn get_schema_coordinate(
+    path: &ResolutionPath<'_>,
+    schema: &SDLSchema,
+) -> Option<SchemaCoordinate> {
+    let definition_description =
+        get_graphql_definition_description_from_resolution_path(path, schema).ok()?;
+    Some(match definition_description {
+        DefinitionDescription::Field {
+            parent_type,
+            field_name,
+        } => SchemaCoordinate::Member {
+            parent_name: schema.get_type_name(parent_type),
+            member_name: field_name,
+        },
+        DefinitionDescription::FieldArgument {
+            parent_type,
+            field_name,
+            argument_name,
+        } => SchemaCoordinate::Argument {
+            parent_name: schema.get_type_name(parent_type),
+            member_name: field_name,
+            argument_name: argument_name.0,
+        },
+        DefinitionDescription::DirectiveArgument {
+            directive_name,
+            argument_name,
+        } => SchemaCoordinate::DirectiveArgument {
+            directive_name: directive_name.0,
+            argument_name: argument_name.0,
+        },
+        DefinitionDescription::Type { type_name } => SchemaCoordinate::Type { name: type_name },
+        DefinitionDescription::Directive { directive_name } => SchemaCoordinate::Directive {
+            name: directive_name.0,
+        },
+        DefinitionDescription::Fragment { .. } => return None,
+    })
+}
+
+pub(crate) fn on_get_schema_coordinate(
+    state: &impl GlobalState,
+    params: <GetSchemaCoordinate as Request>::Params,
+) -> LSPRuntimeResult<<GetSchemaCoordinate as Request>::Result> {
+    let (document, position_span) =
+        state.extract_executable_document_from_text(&params.text_document_position_params, 1)?;
+    let project_name = state
+        .extract_project_name_from_uri(&params.text_document_position_params.text_document.uri)?;
+    let schema = state.get_schema(&project_name)?;
+
+    let path = document.resolve((), position_span);
+    let coordinate = get_schema_coordinate(&path, &schema).ok_or(LSPRuntimeError::ExpectedError)?;
+
+    Ok(GetSchemaCoordinateResult {
+        coordinate: coordinate.to_string(),
+    })
+}
+
+pub(crate) fn on_open_schema_coordinate(
+    state: &impl GlobalState,
+    params: <OpenSchemaCoordinate as Request>::Params,
+) -> LSPRuntimeResult<<OpenSchemaCoordinate as Request>::Result> {
+    let project_name = state.extract_project_name_from_uri(&params.text_document.uri)?;
+    let schema = state.get_schema(&project_name)?;
+    let root_dir = state.root_dir();
+
+    let coordinate = parse_schema_coordinate(&params.coordinate).map_err(|_| {
+        LSPRuntimeError::UnexpectedError(format!(
+            "`{}` is not a valid schema coordinate.",
+            params.coordinate
+        ))
+    })?;
+    let element = resolve_schema_coordinate(&schema, &coordinate)
+        .map_err(|error| LSPRuntimeError::UnexpectedError(error.to_string()))?;
+
+    let definition_description = match element {
+        SchemaElement::Type(type_) => DefinitionDescription::Type {
+            type_name: schema.get_type_name(type_),
+        },
+        SchemaElement::Field { parent_type, field } => DefinitionDescription::Field {
+            parent_type,
+            field_name: schema.field(field).name.item,
+        },
+        SchemaElement::FieldArgument {
+            parent_type,
+            field,
+            argument_name,
+        } => DefinitionDescription::FieldArgument {
+            parent_type,
+            field_name: schema.field(field).name.item,
+            argument_name,
+        },
+        SchemaElement::Directive(directive_name) => {
+            DefinitionDescription::Directive { directive_name }
+        }
+        SchemaElement::DirectiveArgument {
+            directive_name,
+            argument_name,
+        } => DefinitionDescription::DirectiveArgument {
+            directive_name,
+            argument_name,
+        },
+        // Goto definition never points at input object fields or enum
+        // values, these are located straight from the schema.
+        SchemaElement::InputField { .. } | SchemaElement::EnumValue { .. } => {
+            let location = element
+                .definition_location(&schema)
+                .ok_or(LSPRuntimeError::ExpectedError)?;
+            return transform_relay_location_on_disk_to_lsp_location(&root_dir, location);
+        }
+    };
+
+    match locate_definition(
+        definition_description,
+        &schema,
+        state.get_program(&project_name)?,
+        state.get_extra_data_provider(),
+        project_name,
+        &root_dir,
+    )? {
+        GotoDefinitionResponse::Scalar(location) => Ok(location),
+        GotoDefinitionResponse::Array(locations) => locations
+            .into_iter()
+            .next()
+            .ok_or(LSPRuntimeError::ExpectedError),
+        GotoDefinitionResponse::Link(links) => links
+            .into_iter()
+            .next()
+            .map(|link| Location {
+                uri: link.target_uri,
+                range: link.target_selection_range,
+            })
+            .ok_or(LSPRuntimeError::ExpectedError),
+    }
+}
+
+pub(crate) enum GetSchemaCoordinate {}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetSchemaCoordinateParams {
+    #[serde(flatten)]
+    pub text_document_position_params: TextDocumentPositionParams,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetSchemaCoordinateResult {
+    pub coordinate: String,
+}
+
+impl Request for GetSchemaCoordinate {
+    type Params = GetSchemaCoordinateParams;
+    type Result = GetSchemaCoordinateResult;
+    const METHOD: &'static str = "relay/getSchemaCoordinate";
+}
+
+pub(crate) enum OpenSchemaCoordinate {}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OpenSchemaCoordinateParams {
+    /// Any document of the project whose schema the coordinate refers to.
+    pub text_document: TextDocumentIdentifier,
+    pub coordinate: String,
+}
+
+impl Request for OpenSchemaCoordinate {
+    type Params = OpenSchemaCoordinateParams;
+    type Result = Location;
+    const METHOD: &'static str = "relay/openSchemaCoordinate";
+}
@@ -90,6 +90,10 @@ use crate::rename::on_prepare_rename;
 use crate::rename::on_rename;
 use crate::resolved_types_at_location::ResolvedTypesAtLocation;
 use crate::resolved_types_at_location::on_get_resolved_types_at_location;
+use crate::schema_coordinate::GetSchemaCoordinate;
+use crate::schema_coordinate::OpenSchemaCoordinate;
+use crate::schema_coordinate::on_get_schema_coordinate;
+use crate::schema_coordinate::on_open_schema_coordinate;
 use crate::search_schema_items::SearchSchemaItems;
 use crate::search_schema_items::on_search_schema_items;
 use crate::server::lsp_state::handle_lsp_state_tasks;
@@ -322,6 +326,8 @@ fn dispatch_request(request: lsp_server::Request, lsp_state: &impl GlobalState)
                 on_get_source_location_of_type_definition,
             )?
             .on_request_sync::<PrintOperation>(on_print_operation)?
+            .on_request_sync::<GetSchemaCoordinate>(on_get_schema_coordinate)?
+            .on_request_sync::<OpenSchemaCoordinate>(on_open_schema_coordinate)?
             .on_request_sync::<HoverRequest>(on_hover)?
             .on_request_sync::<GotoDefinition>(on_goto_definition)?
             .on_request_sync::<References>(on_references)?
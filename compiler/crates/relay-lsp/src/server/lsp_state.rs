@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -641,7 +642,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             Default::default(),
             Arc::clone(&self.perf_logger),
             None,
-            self.config.custom_transforms.as_ref(),
+            self.config.all_custom_transforms().as_ref(),
             self.config
                 .transferrable_refetchable_query_directives
                 .clone(),
@@ -679,12 +680,14 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
 
                 Ok(())
             }
-            FileGroup::CompactSchema { project_set: _ } => {
+            FileGroup::CompactSchema { project_set: _ }
+            | FileGroup::FlatBufferSchema { project_set: _ } => {
                 self.initialize_lsp_state_resources(project_name);
                 Ok(())
             }
             FileGroup::Source { project_set: _ } => {
-                let mut embedded_sources = extract_graphql::extract(text);
+                let mut embedded_sources =
+                    extract_graphql::extract_from_file(Path::new(uri.path().as_str()), text);
                 if text.contains("relay:enable-new-relay-resolver") {
                     embedded_sources
                         .retain(|source| !matches!(source, JavaScriptSourceFeature::Docblock(_)));
@@ -711,9 +714,11 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
 
                 Ok(())
             }
-            FileGroup::CompactSchema { project_set: _ } => Ok(()),
+            FileGroup::CompactSchema { project_set: _ }
+            | FileGroup::FlatBufferSchema { project_set: _ } => Ok(()),
             FileGroup::Source { project_set: _ } => {
-                let mut embedded_sources = extract_graphql::extract(text);
+                let mut embedded_sources =
+                    extract_graphql::extract_from_file(Path::new(uri.path().as_str()), text);
                 if text.contains("relay:enable-new-relay-resolver") {
                     embedded_sources
                         .retain(|source| !matches!(source, JavaScriptSourceFeature::Docblock(_)));
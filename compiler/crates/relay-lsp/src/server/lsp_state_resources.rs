@@ -11,6 +11,7 @@ use std::sync::RwLock;
 use common::PerfLogEvent;
 use common::PerfLogger;
 use dashmap::mapref::entry::Entry;
+use dependency_analyzer::UnusedClientSchemaReport;
 use fnv::FnvHashMap;
 use graphql_ir::FragmentDefinitionNameSet;
 use graphql_watchman::WatchmanFileSourceSubscriptionNextChange;
@@ -543,6 +544,18 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             }
         }
 
+        // Unused client schema is only meaningful for the merged program,
+        // which contains all documents of the project.
+        let unused_client_schema = if project_config.report_unused_client_schema {
+            self.lsp_state
+                .source_programs
+                .get(&project_config.name.into())
+                .map(|program| UnusedClientSchemaReport::from_program(&program).diagnostics())
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
         // Call validation rules that go beyond type checking.
         validate_program(
             &self.lsp_state.config,
@@ -563,7 +576,7 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             Arc::new(base_fragment_names),
             Arc::clone(&self.lsp_state.perf_logger),
             log_event,
-            self.lsp_state.config.custom_transforms.as_ref(),
+            self.lsp_state.config.all_custom_transforms().as_ref(),
             self.lsp_state
                 .config
                 .transferrable_refetchable_query_directives
@@ -576,28 +589,26 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
             })
         })?;
 
-        match validate_reader_program(
+        let diagnostics = match validate_reader_program(
             &self.lsp_state.config,
             project_config,
             &transformed_programs.reader,
             log_event,
         ) {
             // Non-blocking validation errors
-            Ok(diagnostics) => Err(BuildProjectFailure::Error(
-                BuildProjectError::ValidationErrors {
-                    errors: diagnostics,
-                    project_name: project_config.name,
-                },
-            )),
+            Ok(diagnostics) => diagnostics,
             // Compilation-blocking validation errors
-            Err(diagnostics) => Err(BuildProjectFailure::Error(
-                BuildProjectError::ValidationErrors {
-                    errors: diagnostics,
-                    project_name: project_config.name,
-                },
-            )),
-        }?;
-        Ok(())
+            Err(diagnostics) => diagnostics,
+        };
+        Err(BuildProjectFailure::Error(
+            BuildProjectError::ValidationErrors {
+                errors: diagnostics
+                    .into_iter()
+                    .chain(unused_client_schema)
+                    .collect(),
+                project_name: project_config.name,
+            },
+        ))
     }
 
     fn watchman_subscription_handler(
@@ -639,6 +650,14 @@ impl<TPerfLogger: PerfLogger + 'static, TSchemaDocumentation: SchemaDocumentatio
                             }
                         }
                     }
+                    Ok(FileSourceSubscriptionNextChange::NativeWatch(file_source_changes)) => {
+                        pending_file_source_changes
+                            .write()
+                            .expect("LSPState::watch_and_update_schemas: expect to acquire write lock on pending_file_source_changes")
+                            .push(FileSourceResult::WalkDir(file_source_changes));
+
+                        notify_sender.notify_one();
+                    }
                     Ok(FileSourceSubscriptionNextChange::Test(_))
                     | Ok(FileSourceSubscriptionNextChange::TestSourceControlUpdateEnter)
                     | Ok(FileSourceSubscriptionNextChange::TestSourceControlUpdateLeave)
@@ -4,7 +4,7 @@
  * This source code is licensed under the MIT license found in the
  * LICENSE file in the root directory of this source tree.
  *
- * @generated SignedSource<<7f7ba42d92c3419f1fffd8013ba3d40f>>
+ * @generated SignedSource<<dfc39f94eafede5717dd571a1544e045>>
  */
 
 mod hover;
@@ -26,6 +26,13 @@ async fn double_underscore_typename_field() {
     test_fixture(transform_fixture, file!(), "double_underscore_typename_field.graphql", "hover/fixtures/double_underscore_typename_field.expected", input, expected).await;
 }
 
+#[tokio::test]
+async fn field_argument() {
+    let input = include_str!("hover/fixtures/field_argument.graphql");
+    let expected = include_str!("hover/fixtures/field_argument.expected");
+    test_fixture(transform_fixture, file!(), "field_argument.graphql", "hover/fixtures/field_argument.expected", input, expected).await;
+}
+
 #[tokio::test]
 async fn fragment_definition_name() {
     let input = include_str!("hover/fixtures/fragment_definition_name.graphql");
@@ -13,6 +13,7 @@
 
 pub mod definitions;
 use std::iter::once;
+use std::path::Path;
 use std::sync::LazyLock;
 
 use ::intern::string_key::StringKey;
@@ -73,6 +74,23 @@ pub fn build_schema_with_extensions_from_asts(
     Ok(schema)
 }
 
+/// Loads a schema serialized as a flatbuffer from `path` and layers the Relay
+/// extensions and `extension_sdls` over it.
+pub fn build_schema_with_extensions_from_flat_buffer_file(
+    path: &Path,
+    mut extension_sdls: Vec<SchemaDocument>,
+    memory_map: bool,
+) -> DiagnosticsResult<SDLSchema> {
+    let relay_extensions_ast =
+        graphql_syntax::parse_schema_document(RELAY_EXTENSIONS, SourceLocationKey::generated())?;
+
+    extension_sdls.push(relay_extensions_ast);
+
+    let mut schema = schema::build_schema_with_flat_buffer_file(path, &extension_sdls, memory_map)?;
+    remove_defer_stream_label(&mut schema);
+    Ok(schema)
+}
+
 /// Remove label arg from @defer and @stream directives since the compiler
 /// adds these arguments.
 fn remove_defer_stream_label(schema: &mut SDLSchema) {
@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use intern::string_key::StringKey;
+use serde::Serialize;
+use thiserror::Error;
+
+/// Errors in the IR returned by a transform plugin.
+#[derive(Clone, Debug, Error, Serialize)]
+#[serde(tag = "type")]
+pub(crate) enum WireIrMessage {
+    #[error("Transform plugin returned an unknown type `{type_name}`.")]
+    UnknownType { type_name: StringKey },
+
+    #[error("Transform plugin selected an unknown field `{field_name}` on type `{type_name}`.")]
+    UnknownField {
+        type_name: StringKey,
+        field_name: StringKey,
+    },
+
+    #[error("Transform plugin returned an invalid type `{type_string}`.")]
+    InvalidTypeReference { type_string: String },
+
+    #[error("Transform plugin returned a directive with unknown data `{index}`.")]
+    UnknownDirectiveData { index: usize },
+}
+
+#[derive(Clone, Debug, Error, Serialize)]
+#[serde(tag = "type")]
+pub(crate) enum TransformPluginMessage {
+    #[error("{message}")]
+    PluginDiagnostic { message: String },
+
+    #[error("Transform plugin `{plugin}` failed: {reason}")]
+    PluginFailed { plugin: String, reason: String },
+}
+
+/// Failures to communicate with a plugin process.
+#[derive(Debug, Error)]
+pub(crate) enum PluginProcessError {
+    #[error("Unable to start `{command}`: {source}")]
+    Spawn {
+        command: String,
+        source: std::io::Error,
+    },
+
+    #[error("Unable to communicate with the plugin: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid message from the plugin: {0}")]
+    InvalidMessage(#[from] serde_json::Error),
+
+    #[error("The plugin exited.")]
+    Exited,
+
+    #[error("The plugin didn't respond within {seconds}s.")]
+    Timeout { seconds: u64 },
+
+    #[error("The plugin uses protocol version {version}, supported versions are {min} to {max}.")]
+    ProtocolVersion { version: u32, min: u32, max: u32 },
+}
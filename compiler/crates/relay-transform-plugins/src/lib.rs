@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+#![deny(warnings)]
+#![deny(rust_2018_idioms)]
+#![deny(clippy::all)]
+
+//! Custom transforms that run outside of the compiler binary, as subprocesses
+//! speaking the transform plugin protocol (see [`protocol`]). Plugins hook
+//! into the same pipeline stages as the transforms of a
+//! `CustomTransformsConfig`.
+
+mod errors;
+mod plugin;
+pub mod protocol;
+pub mod wire_ir;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use common::Diagnostic;
+pub use plugin::TransformPlugin;
+use protocol::PROTOCOL_VERSION;
+use protocol::TransformPosition;
+use protocol::TransformStage;
+use relay_config::ProjectName;
+use relay_config::TransformPluginConfig;
+use relay_transforms::CustomTransform;
+use relay_transforms::CustomTransforms;
+use relay_transforms::CustomTransformsConfig;
+
+/// The configured transform plugins. Plugins are started when the first
+/// program is transformed.
+#[derive(Default)]
+pub struct TransformPlugins {
+    configs: Vec<TransformPluginConfig>,
+    plugins: Vec<Arc<TransformPlugin>>,
+}
+
+impl TransformPlugins {
+    pub fn new(plugin_configs: &[TransformPluginConfig], root_dir: &Path) -> Self {
+        Self {
+            configs: plugin_configs.to_vec(),
+            plugins: plugin_configs
+                .iter()
+                .map(|config| Arc::new(TransformPlugin::new(config, root_dir)))
+                .collect(),
+        }
+    }
+
+    /// The custom transforms running the plugins, in order, at every stage
+    /// they hook into.
+    pub fn custom_transforms_config(&self) -> Option<CustomTransformsConfig> {
+        if self.plugins.is_empty() {
+            return None;
+        }
+        let transforms = |stage| {
+            let hooks = |position| {
+                self.plugins
+                    .iter()
+                    .map(|plugin| {
+                        let plugin = Arc::clone(plugin);
+                        CustomTransform {
+                            name: plugin.name().to_string().into(),
+                            transform: Arc::new(
+                                move |project_config, program, base_fragment_names| {
+                                    plugin.transform(
+                                        stage,
+                                        position,
+                                        project_config,
+                                        program,
+                                        base_fragment_names,
+                                    )
+                                },
+                            ),
+                        }
+                    })
+                    .collect()
+            };
+            Some(CustomTransforms {
+                apply_before: hooks(TransformPosition::Before),
+                apply_after: hooks(TransformPosition::After),
+            })
+        };
+        Some(CustomTransformsConfig {
+            common_transforms: transforms(TransformStage::Common),
+            reader_transforms: transforms(TransformStage::Reader),
+            operation_transforms: transforms(TransformStage::Operation),
+            normalization_transforms: transforms(TransformStage::Normalization),
+            operation_text_transforms: transforms(TransformStage::OperationText),
+            typegen_transforms: transforms(TransformStage::Typegen),
+        })
+    }
+
+    /// The command, arguments and protocol version of every plugin. Caches of
+    /// build outputs include it in their keys, so that their entries aren't
+    /// used once a plugin is reconfigured.
+    pub fn cache_key_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(
+            self.configs
+                .iter()
+                .map(|config| {
+                    serde_json::json!({
+                        "command": config.command,
+                        "args": config.args,
+                        "protocolVersion": PROTOCOL_VERSION,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// Take the warnings the plugins reported for `project_name` since the
+    /// last call.
+    pub fn take_warnings(&self, project_name: ProjectName) -> Vec<Diagnostic> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| plugin.take_warnings(project_name))
+            .collect()
+    }
+}
@@ -0,0 +1,457 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::ChildStdin;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+use common::Diagnostic;
+use common::DiagnosticSeverity;
+use common::DiagnosticsResult;
+use common::Location;
+use graphql_ir::Program;
+use log::debug;
+use relay_config::ProjectConfig;
+use relay_config::ProjectName;
+use relay_config::TransformPluginConfig;
+use relay_transforms::BaseFragmentNames;
+use serde::de::DeserializeOwned;
+
+use crate::errors::PluginProcessError;
+use crate::errors::TransformPluginMessage;
+use crate::protocol::InitializeResponse;
+use crate::protocol::MIN_PROTOCOL_VERSION;
+use crate::protocol::PROTOCOL_VERSION;
+use crate::protocol::PluginDiagnostic;
+use crate::protocol::PluginDiagnosticSeverity;
+use crate::protocol::PluginHook;
+use crate::protocol::PluginRequest;
+use crate::protocol::TransformPosition;
+use crate::protocol::TransformResponse;
+use crate::protocol::TransformStage;
+use crate::wire_ir::program_from_wire;
+use crate::wire_ir::program_to_wire;
+
+/// A transform plugin running as subprocesses. A process is started when the
+/// plugin is first used and kept running until the plugin is dropped. Programs
+/// that are transformed in parallel, e.g. in chunks, are sent to processes of
+/// their own, so a plugin may run several processes at once.
+pub struct TransformPlugin {
+    name: String,
+    command: PathBuf,
+    args: Vec<String>,
+    current_dir: PathBuf,
+    timeout: Duration,
+    /// The hooks of the plugin, once a process is started.
+    hooks: OnceLock<Vec<PluginHook>>,
+    /// Processes that are waiting for a request.
+    idle_processes: Mutex<Vec<PluginProcess>>,
+    /// Why the plugin failed to start or respond. Later requests fail the
+    /// same way.
+    failure: OnceLock<String>,
+    /// Diagnostics that don't fail the build, by project, until taken.
+    warnings: Mutex<Vec<(ProjectName, Diagnostic)>>,
+}
+
+impl TransformPlugin {
+    pub fn new(config: &TransformPluginConfig, root_dir: &Path) -> Self {
+        let command = if config.is_relative_command() {
+            root_dir.join(&config.command)
+        } else {
+            config.command.clone()
+        };
+        Self {
+            name: config.name(),
+            command,
+            args: config.args.clone(),
+            current_dir: root_dir.to_path_buf(),
+            timeout: Duration::from_secs(config.timeout_seconds),
+            hooks: OnceLock::new(),
+            idle_processes: Default::default(),
+            failure: OnceLock::new(),
+            warnings: Default::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Transform `program` at the hook of `stage` and `position`. Programs are
+    /// returned unchanged if the plugin doesn't hook into it.
+    pub fn transform(
+        &self,
+        stage: TransformStage,
+        position: TransformPosition,
+        project_config: &ProjectConfig,
+        program: &Program,
+        base_fragment_names: &BaseFragmentNames,
+    ) -> DiagnosticsResult<Program> {
+        if let Some(reason) = self.failure.get() {
+            return Err(self.failed(reason.clone()));
+        }
+        let hook = PluginHook { stage, position };
+        if self.hooks.get().is_some_and(|hooks| !hooks.contains(&hook)) {
+            return Ok(program.clone());
+        }
+        let idle_process = self.idle_processes.lock().unwrap().pop();
+        let mut process = match idle_process {
+            Some(process) => process,
+            None => {
+                debug!("Starting transform plugin `{}`", self.name);
+                let process = PluginProcess::start(
+                    &self.command,
+                    &self.args,
+                    &self.current_dir,
+                    self.timeout,
+                )
+                .map_err(|err| self.fail(err))?;
+                self.hooks.get_or_init(|| process.hooks.clone());
+                process
+            }
+        };
+        if !process.hooks.contains(&hook) {
+            self.idle_processes.lock().unwrap().push(process);
+            return Ok(program.clone());
+        }
+
+        let (definitions, side_tables) = program_to_wire(program);
+        let mut base_fragment_names = base_fragment_names.iter().copied().collect::<Vec<_>>();
+        base_fragment_names.sort();
+        let request = PluginRequest::Transform {
+            stage,
+            position,
+            project: project_config.name,
+            base_fragment_names,
+            definitions,
+        };
+        // A process that failed is dropped, which stops it.
+        let response: TransformResponse = process
+            .request(&request, self.timeout)
+            .map_err(|err| self.fail(err))?;
+        self.idle_processes.lock().unwrap().push(process);
+
+        let diagnostics = response
+            .diagnostics
+            .into_iter()
+            .map(to_diagnostic)
+            .collect::<Vec<_>>();
+        if diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity() == DiagnosticSeverity::ERROR)
+        {
+            return Err(diagnostics);
+        }
+        if !diagnostics.is_empty() {
+            let mut warnings = self.warnings.lock().unwrap();
+            for diagnostic in diagnostics {
+                // Programs are transformed in chunks and again by the LSP, so
+                // the same warning may be reported repeatedly.
+                let is_duplicate = warnings.iter().any(|(project_name, warning)| {
+                    *project_name == project_config.name
+                        && warning.severity() == diagnostic.severity()
+                        && warning.location() == diagnostic.location()
+                        && warning.message().to_string() == diagnostic.message().to_string()
+                });
+                if !is_duplicate {
+                    warnings.push((project_config.name, diagnostic));
+                }
+            }
+        }
+        match response.definitions {
+            Some(definitions) => {
+                program_from_wire(Arc::clone(&program.schema), definitions, &side_tables)
+            }
+            None => Ok(program.clone()),
+        }
+    }
+
+    /// Take the diagnostics of `project_name` that didn't fail the build,
+    /// e.g. warnings.
+    pub fn take_warnings(&self, project_name: ProjectName) -> Vec<Diagnostic> {
+        let mut warnings = self.warnings.lock().unwrap();
+        let (taken, kept) = std::mem::take(&mut *warnings)
+            .into_iter()
+            .partition(|(name, _)| *name == project_name);
+        *warnings = kept;
+        taken.into_iter().map(|(_, warning)| warning).collect()
+    }
+
+    fn fail(&self, err: PluginProcessError) -> Vec<Diagnostic> {
+        let reason = err.to_string();
+        self.failure.get_or_init(|| reason.clone());
+        self.failed(reason)
+    }
+
+    fn failed(&self, reason: String) -> Vec<Diagnostic> {
+        vec![Diagnostic::error(
+            TransformPluginMessage::PluginFailed {
+                plugin: self.name.clone(),
+                reason,
+            },
+            Location::generated(),
+        )]
+    }
+}
+
+fn to_diagnostic(diagnostic: PluginDiagnostic) -> Diagnostic {
+    let message = TransformPluginMessage::PluginDiagnostic {
+        message: diagnostic.message,
+    };
+    let location = diagnostic.location.unwrap_or_else(Location::generated);
+    let mut result = match diagnostic.severity {
+        PluginDiagnosticSeverity::Error => Diagnostic::error(message, location),
+        PluginDiagnosticSeverity::Warning => Diagnostic::warning(message, location, vec![]),
+        PluginDiagnosticSeverity::Info => Diagnostic::info(message, location, vec![]),
+        PluginDiagnosticSeverity::Hint => Diagnostic::hint(message, location, vec![]),
+    };
+    for related in diagnostic.related {
+        result = result.annotate(
+            TransformPluginMessage::PluginDiagnostic {
+                message: related.message,
+            },
+            related.location,
+        );
+    }
+    result
+}
+
+struct PluginProcess {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines of the process's stdout, read by a thread of their own so that
+    /// waiting for a response can time out.
+    responses: Receiver<std::io::Result<String>>,
+    hooks: Vec<PluginHook>,
+}
+
+impl PluginProcess {
+    fn start(
+        command: &Path,
+        args: &[String],
+        current_dir: &Path,
+        timeout: Duration,
+    ) -> Result<Self, PluginProcessError> {
+        let mut child = Command::new(command)
+            .args(args)
+            .current_dir(current_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|source| PluginProcessError::Spawn {
+                command: command.display().to_string(),
+                source,
+            })?;
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let (sender, responses) = mpsc::channel();
+        // Ends when the process closes its stdout, at the latest when it's
+        // killed on drop.
+        thread::spawn(move || {
+            for line in stdout.lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut process = Self {
+            child,
+            stdin,
+            responses,
+            hooks: vec![],
+        };
+        let response: InitializeResponse = process.request(
+            &PluginRequest::Initialize {
+                protocol_version: PROTOCOL_VERSION,
+            },
+            timeout,
+        )?;
+        if !(MIN_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&response.protocol_version) {
+            return Err(PluginProcessError::ProtocolVersion {
+                version: response.protocol_version,
+                min: MIN_PROTOCOL_VERSION,
+                max: PROTOCOL_VERSION,
+            });
+        }
+        process.hooks = response.hooks;
+        Ok(process)
+    }
+
+    fn request<T: DeserializeOwned>(
+        &mut self,
+        request: &PluginRequest,
+        timeout: Duration,
+    ) -> Result<T, PluginProcessError> {
+        serde_json::to_writer(&mut self.stdin, request)?;
+        self.stdin.write_all(b"\n")?;
+        self.stdin.flush()?;
+
+        match self.responses.recv_timeout(timeout) {
+            Ok(line) => Ok(serde_json::from_str(&line?)?),
+            Err(RecvTimeoutError::Timeout) => Err(PluginProcessError::Timeout {
+                seconds: timeout.as_secs(),
+            }),
+            Err(RecvTimeoutError::Disconnected) => Err(PluginProcessError::Exited),
+        }
+    }
+}
+
+impl Drop for PluginProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use relay_test_schema::get_test_schema;
+
+    use super::*;
+
+    fn shell_plugin(script: &str) -> TransformPlugin {
+        TransformPlugin::new(
+            &TransformPluginConfig {
+                name: Some("test-plugin".to_string()),
+                command: PathBuf::from("sh"),
+                args: vec!["-c".to_string(), script.to_string()],
+                timeout_seconds: 1,
+            },
+            &std::env::temp_dir(),
+        )
+    }
+
+    fn transform(plugin: &TransformPlugin, stage: TransformStage) -> DiagnosticsResult<Program> {
+        plugin.transform(
+            stage,
+            TransformPosition::Before,
+            &ProjectConfig::default(),
+            &Program::new(get_test_schema()),
+            &Default::default(),
+        )
+    }
+
+    fn messages(result: DiagnosticsResult<Program>) -> Vec<String> {
+        result
+            .err()
+            .unwrap_or_default()
+            .iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn reports_plugin_diagnostics_of_hooked_stages() {
+        let plugin = shell_plugin(
+            r#"read request
+echo '{"protocolVersion": 1, "hooks": [{"stage": "common", "position": "before"}]}'
+while read request; do
+  echo '{"diagnostics": [{"message": "Unexpected @featureFlag."}]}'
+done"#,
+        );
+        assert_eq!(
+            messages(transform(&plugin, TransformStage::Common)),
+            vec!["Unexpected @featureFlag.".to_string()]
+        );
+        assert!(transform(&plugin, TransformStage::Reader).is_ok());
+    }
+
+    #[test]
+    fn collects_plugin_warnings_without_failing() {
+        let plugin = shell_plugin(
+            r#"read request
+echo '{"protocolVersion": 2, "hooks": [{"stage": "common", "position": "before"}]}'
+while read request; do
+  echo '{"diagnostics": [{"severity": "warning", "message": "@featureFlag is deprecated."}]}'
+done"#,
+        );
+        assert!(transform(&plugin, TransformStage::Common).is_ok());
+        assert!(transform(&plugin, TransformStage::Common).is_ok());
+
+        let warnings = plugin.take_warnings(ProjectConfig::default().name);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| (warning.severity(), warning.message().to_string()))
+                .collect::<Vec<_>>(),
+            vec![(
+                DiagnosticSeverity::WARNING,
+                "@featureFlag is deprecated.".to_string()
+            )]
+        );
+        assert!(
+            plugin
+                .take_warnings(ProjectConfig::default().name)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn fails_on_protocol_version_mismatch() {
+        let plugin = shell_plugin(r#"read request; echo '{"protocolVersion": 0}'"#);
+        assert_eq!(
+            messages(transform(&plugin, TransformStage::Common)),
+            vec![
+                "Transform plugin `test-plugin` failed: The plugin uses protocol version 0, supported versions are 1 to 2."
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_when_the_plugin_does_not_respond() {
+        let plugin = shell_plugin(
+            r#"read request
+echo '{"protocolVersion": 2, "hooks": [{"stage": "common", "position": "before"}]}'
+while read request; do sleep 5; done"#,
+        );
+        let failure = vec![
+            "Transform plugin `test-plugin` failed: The plugin didn't respond within 1s."
+                .to_string(),
+        ];
+        assert_eq!(
+            messages(transform(&plugin, TransformStage::Common)),
+            failure
+        );
+        assert_eq!(
+            messages(transform(&plugin, TransformStage::Common)),
+            failure
+        );
+    }
+
+    #[test]
+    fn fails_when_the_plugin_exits() {
+        let plugin = shell_plugin(
+            r#"read request
+echo '{"protocolVersion": 1, "hooks": [{"stage": "typegen", "position": "before"}]}'"#,
+        );
+        // Depending on timing, writing the request or reading the response
+        // fails.
+        let failure = messages(transform(&plugin, TransformStage::Typegen));
+        assert_eq!(failure.len(), 1);
+        assert!(failure[0].starts_with("Transform plugin `test-plugin` failed: "));
+        assert_eq!(
+            messages(transform(&plugin, TransformStage::Common)),
+            failure
+        );
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Messages of the transform plugin protocol.
+//!
+//! The compiler writes one JSON request per line to the stdin of the plugin
+//! and reads one JSON response per line from its stdout. Stderr is passed
+//! through. The first request initializes the plugin:
+//!
+//! ```json
+//! {"method": "initialize", "protocolVersion": 2}
+//! ```
+//!
+//! The plugin responds with the protocol version it implements, which may be
+//! older than the one of the compiler, and the pipeline stages it hooks into:
+//!
+//! ```json
+//! {"protocolVersion": 2, "hooks": [{"stage": "common", "position": "before"}]}
+//! ```
+//!
+//! Every following request asks to transform the program of a project at one
+//! of these hooks:
+//!
+//! ```json
+//! {"method": "transform", "stage": "common", "position": "before",
+//!  "project": "default", "baseFragmentNames": [], "definitions": [...]}
+//! ```
+//!
+//! The plugin responds with the transformed definitions, or without
+//! `definitions` to leave the program unchanged, and with diagnostics.
+//! Errors fail the build; since version 2, diagnostics with a `severity` of
+//! `warning`, `info` or `hint` are reported without failing it:
+//!
+//! ```json
+//! {"definitions": [...], "diagnostics": [{"message": "...", "location": {...}}]}
+//! ```
+//!
+//! Definitions are serialized as described in [`crate::wire_ir`].
+//!
+//! A process handles one request at a time. The compiler may start several
+//! processes of a plugin to transform programs in parallel, and fails the
+//! build when a response takes longer than the plugin's `timeoutSeconds`.
+
+use common::Location;
+use graphql_ir::FragmentDefinitionName;
+use relay_config::ProjectName;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::wire_ir::WireDefinition;
+
+/// Version of the protocol, incremented when messages change.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// The oldest version of the protocol plugins may still implement.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// The pipeline stages named in `CustomTransformsConfig`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransformStage {
+    Common,
+    Reader,
+    Operation,
+    Normalization,
+    OperationText,
+    Typegen,
+}
+
+/// Whether a hook runs before or after the built-in transforms of a stage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransformPosition {
+    Before,
+    After,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PluginHook {
+    pub stage: TransformStage,
+    pub position: TransformPosition,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(
+    tag = "method",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum PluginRequest {
+    Initialize {
+        protocol_version: u32,
+    },
+    Transform {
+        stage: TransformStage,
+        position: TransformPosition,
+        project: ProjectName,
+        base_fragment_names: Vec<FragmentDefinitionName>,
+        definitions: Vec<WireDefinition>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeResponse {
+    pub protocol_version: u32,
+    #[serde(default)]
+    pub hooks: Vec<PluginHook>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransformResponse {
+    #[serde(default)]
+    pub definitions: Option<Vec<WireDefinition>>,
+    #[serde(default)]
+    pub diagnostics: Vec<PluginDiagnostic>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PluginDiagnostic {
+    /// Added in version 2, diagnostics of older plugins are errors.
+    #[serde(default)]
+    pub severity: PluginDiagnosticSeverity,
+    pub message: String,
+    /// Where the error is, usually a location of the IR sent to the plugin.
+    #[serde(default)]
+    pub location: Option<Location>,
+    /// Other locations relevant to the error.
+    #[serde(default)]
+    pub related: Vec<PluginRelatedInformation>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PluginDiagnosticSeverity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PluginRelatedInformation {
+    pub message: String,
+    pub location: Location,
+}
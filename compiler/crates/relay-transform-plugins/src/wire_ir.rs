@@ -0,0 +1,746 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! The IR as exchanged with transform plugins.
+//!
+//! Schema types are referenced by name, fields by their name on the parent
+//! type of their selection, and type references by their GraphQL syntax,
+//! e.g. `[ID!]!`. Directive data and fragment signatures have no serialized
+//! form: they are kept in [`WireSideTables`] while a plugin runs, directives
+//! refer to their data by index, and fragment spreads get the signature of
+//! the spread fragment back.
+
+use std::sync::Arc;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::WithLocation;
+use graphql_ir::Argument;
+use graphql_ir::AssociatedData;
+use graphql_ir::Condition;
+use graphql_ir::ConditionValue;
+use graphql_ir::ConstantArgument;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::ExecutableDefinition;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::FragmentDefinitionNameMap;
+use graphql_ir::FragmentSignature;
+use graphql_ir::FragmentSpread;
+use graphql_ir::InlineFragment;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::OperationDefinitionName;
+use graphql_ir::Program;
+use graphql_ir::ScalarField;
+use graphql_ir::Selection;
+use graphql_ir::Value;
+use graphql_ir::Variable;
+use graphql_ir::VariableDefinition;
+use graphql_ir::VariableName;
+use graphql_ir::build_type_annotation;
+use graphql_syntax::FloatValue;
+use graphql_syntax::OperationKind;
+use graphql_syntax::parse_type;
+use intern::Lookup;
+use intern::string_key::StringKey;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::TypeReference;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::errors::WireIrMessage;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum WireDefinition {
+    Operation(WireOperation),
+    Fragment(WireFragment),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WireOperation {
+    pub operation: OperationKind,
+    pub name: WithLocation<StringKey>,
+    /// The root type of the operation.
+    #[serde(rename = "type")]
+    pub type_: StringKey,
+    pub variable_definitions: Vec<WireVariableDefinition>,
+    pub directives: Vec<WireDirective>,
+    pub selections: Vec<WireSelection>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WireFragment {
+    pub name: WithLocation<StringKey>,
+    pub variable_definitions: Vec<WireVariableDefinition>,
+    pub used_global_variables: Vec<WireVariableDefinition>,
+    pub type_condition: StringKey,
+    pub directives: Vec<WireDirective>,
+    pub selections: Vec<WireSelection>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WireVariableDefinition {
+    pub name: WithLocation<StringKey>,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub default_value: Option<WithLocation<WireConstantValue>>,
+    pub directives: Vec<WireDirective>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all_fields = "camelCase")]
+pub enum WireSelection {
+    FragmentSpread {
+        fragment: WithLocation<StringKey>,
+        arguments: Vec<WireArgument>,
+        directives: Vec<WireDirective>,
+    },
+    InlineFragment {
+        type_condition: Option<StringKey>,
+        directives: Vec<WireDirective>,
+        selections: Vec<WireSelection>,
+        spread_location: Location,
+    },
+    LinkedField {
+        #[serde(default)]
+        alias: Option<WithLocation<StringKey>>,
+        name: WithLocation<StringKey>,
+        arguments: Vec<WireArgument>,
+        directives: Vec<WireDirective>,
+        selections: Vec<WireSelection>,
+    },
+    ScalarField {
+        #[serde(default)]
+        alias: Option<WithLocation<StringKey>>,
+        name: WithLocation<StringKey>,
+        arguments: Vec<WireArgument>,
+        directives: Vec<WireDirective>,
+    },
+    Condition {
+        value: WireConditionValue,
+        passing_value: bool,
+        selections: Vec<WireSelection>,
+        location: Location,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WireDirective {
+    pub name: WithLocation<StringKey>,
+    pub arguments: Vec<WireArgument>,
+    /// Index of the data of the directive in the side tables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<usize>,
+    pub location: Location,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WireArgument {
+    pub name: WithLocation<StringKey>,
+    pub value: WithLocation<WireValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum WireValue {
+    Constant {
+        value: WireConstantValue,
+    },
+    Variable {
+        name: WithLocation<StringKey>,
+        #[serde(rename = "type")]
+        type_: String,
+    },
+    List {
+        items: Vec<WireValue>,
+    },
+    Object {
+        fields: Vec<WireArgument>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value")]
+pub enum WireConstantValue {
+    Int(i64),
+    Float(f64),
+    String(StringKey),
+    Boolean(bool),
+    Null,
+    Enum(StringKey),
+    List(Vec<WireConstantValue>),
+    Object(Vec<WireConstantArgument>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WireConstantArgument {
+    pub name: WithLocation<StringKey>,
+    pub value: WithLocation<WireConstantValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum WireConditionValue {
+    Constant {
+        value: bool,
+    },
+    Variable {
+        name: WithLocation<StringKey>,
+        #[serde(rename = "type")]
+        type_: String,
+    },
+}
+
+/// The parts of the IR of a program that have no serialized form.
+#[derive(Default)]
+pub struct WireSideTables {
+    directive_data: Vec<Box<dyn AssociatedData>>,
+    signatures: FragmentDefinitionNameMap<FragmentSignature>,
+}
+
+/// Serialize the operations and fragments of `program`, sorted by name.
+pub fn program_to_wire(program: &Program) -> (Vec<WireDefinition>, WireSideTables) {
+    let mut serializer = WireSerializer {
+        schema: &program.schema,
+        side_tables: Default::default(),
+    };
+    let mut operations = program.operations().collect::<Vec<_>>();
+    operations.sort_by_key(|operation| operation.name.item.0.lookup());
+    let mut fragments = program.fragments().collect::<Vec<_>>();
+    fragments.sort_by_key(|fragment| fragment.name.item.0.lookup());
+    let mut definitions = Vec::with_capacity(operations.len() + fragments.len());
+    for operation in operations {
+        definitions.push(WireDefinition::Operation(serializer.operation(operation)));
+    }
+    for fragment in fragments {
+        definitions.push(WireDefinition::Fragment(serializer.fragment(fragment)));
+    }
+    (definitions, serializer.side_tables)
+}
+
+/// Build a program from serialized definitions, e.g. the result of a plugin,
+/// with the side tables of the program they were serialized from.
+pub fn program_from_wire(
+    schema: Arc<SDLSchema>,
+    definitions: Vec<WireDefinition>,
+    side_tables: &WireSideTables,
+) -> DiagnosticsResult<Program> {
+    let deserializer = WireDeserializer {
+        schema: &schema,
+        side_tables,
+    };
+    let mut errors = Vec::new();
+    let mut ir = Vec::with_capacity(definitions.len());
+    for definition in definitions {
+        let result = match definition {
+            WireDefinition::Operation(operation) => deserializer
+                .operation(operation)
+                .map(ExecutableDefinition::Operation),
+            WireDefinition::Fragment(fragment) => deserializer
+                .fragment(fragment)
+                .map(ExecutableDefinition::Fragment),
+        };
+        match result {
+            Ok(definition) => ir.push(definition),
+            Err(diagnostics) => errors.extend(diagnostics),
+        }
+    }
+    if errors.is_empty() {
+        Ok(Program::from_definitions(Arc::clone(&schema), ir))
+    } else {
+        Err(errors)
+    }
+}
+
+struct WireSerializer<'a> {
+    schema: &'a SDLSchema,
+    side_tables: WireSideTables,
+}
+
+impl WireSerializer<'_> {
+    fn operation(&mut self, operation: &OperationDefinition) -> WireOperation {
+        WireOperation {
+            operation: operation.kind,
+            name: operation.name.map(|name| name.0),
+            type_: self.schema.get_type_name(operation.type_),
+            variable_definitions: self.variable_definitions(&operation.variable_definitions),
+            directives: self.directives(&operation.directives),
+            selections: self.selections(&operation.selections),
+        }
+    }
+
+    fn fragment(&mut self, fragment: &FragmentDefinition) -> WireFragment {
+        WireFragment {
+            name: fragment.name.map(|name| name.0),
+            variable_definitions: self.variable_definitions(&fragment.variable_definitions),
+            used_global_variables: self.variable_definitions(&fragment.used_global_variables),
+            type_condition: self.schema.get_type_name(fragment.type_condition),
+            directives: self.directives(&fragment.directives),
+            selections: self.selections(&fragment.selections),
+        }
+    }
+
+    fn variable_definitions(
+        &mut self,
+        variable_definitions: &[VariableDefinition],
+    ) -> Vec<WireVariableDefinition> {
+        variable_definitions
+            .iter()
+            .map(|variable_definition| WireVariableDefinition {
+                name: variable_definition.name.map(|name| name.0),
+                type_: self.schema.get_type_string(&variable_definition.type_),
+                default_value: variable_definition
+                    .default_value
+                    .as_ref()
+                    .map(|value| WithLocation::new(value.location, constant_value(&value.item))),
+                directives: self.directives(&variable_definition.directives),
+            })
+            .collect()
+    }
+
+    fn selections(&mut self, selections: &[Selection]) -> Vec<WireSelection> {
+        selections
+            .iter()
+            .map(|selection| self.selection(selection))
+            .collect()
+    }
+
+    fn selection(&mut self, selection: &Selection) -> WireSelection {
+        match selection {
+            Selection::FragmentSpread(spread) => {
+                if let Some(signature) = &spread.signature {
+                    self.side_tables
+                        .signatures
+                        .entry(spread.fragment.item)
+                        .or_insert_with(|| signature.clone());
+                }
+                WireSelection::FragmentSpread {
+                    fragment: spread.fragment.map(|name| name.0),
+                    arguments: self.arguments(&spread.arguments),
+                    directives: self.directives(&spread.directives),
+                }
+            }
+            Selection::InlineFragment(fragment) => WireSelection::InlineFragment {
+                type_condition: fragment
+                    .type_condition
+                    .map(|type_| self.schema.get_type_name(type_)),
+                directives: self.directives(&fragment.directives),
+                selections: self.selections(&fragment.selections),
+                spread_location: fragment.spread_location,
+            },
+            Selection::LinkedField(field) => WireSelection::LinkedField {
+                alias: field.alias,
+                name: self.field_name(field.definition),
+                arguments: self.arguments(&field.arguments),
+                directives: self.directives(&field.directives),
+                selections: self.selections(&field.selections),
+            },
+            Selection::ScalarField(field) => WireSelection::ScalarField {
+                alias: field.alias,
+                name: self.field_name(field.definition),
+                arguments: self.arguments(&field.arguments),
+                directives: self.directives(&field.directives),
+            },
+            Selection::Condition(condition) => WireSelection::Condition {
+                value: match &condition.value {
+                    ConditionValue::Constant(value) => {
+                        WireConditionValue::Constant { value: *value }
+                    }
+                    ConditionValue::Variable(variable) => WireConditionValue::Variable {
+                        name: variable.name.map(|name| name.0),
+                        type_: self.schema.get_type_string(&variable.type_),
+                    },
+                },
+                passing_value: condition.passing_value,
+                selections: self.selections(&condition.selections),
+                location: condition.location,
+            },
+        }
+    }
+
+    fn field_name(&self, definition: WithLocation<FieldID>) -> WithLocation<StringKey> {
+        definition.map(|id| self.schema.field(id).name.item)
+    }
+
+    fn directives(&mut self, directives: &[Directive]) -> Vec<WireDirective> {
+        directives
+            .iter()
+            .map(|directive| WireDirective {
+                name: directive.name.map(|name| name.0),
+                arguments: self.arguments(&directive.arguments),
+                data: directive.data.as_ref().map(|data| {
+                    self.side_tables.directive_data.push(data.clone());
+                    self.side_tables.directive_data.len() - 1
+                }),
+                location: directive.location,
+            })
+            .collect()
+    }
+
+    fn arguments(&self, arguments: &[Argument]) -> Vec<WireArgument> {
+        arguments
+            .iter()
+            .map(|argument| WireArgument {
+                name: argument.name.map(|name| name.0),
+                value: WithLocation::new(argument.value.location, self.value(&argument.value.item)),
+            })
+            .collect()
+    }
+
+    fn value(&self, value: &Value) -> WireValue {
+        match value {
+            Value::Constant(value) => WireValue::Constant {
+                value: constant_value(value),
+            },
+            Value::Variable(variable) => WireValue::Variable {
+                name: variable.name.map(|name| name.0),
+                type_: self.schema.get_type_string(&variable.type_),
+            },
+            Value::List(items) => WireValue::List {
+                items: items.iter().map(|item| self.value(item)).collect(),
+            },
+            Value::Object(fields) => WireValue::Object {
+                fields: self.arguments(fields),
+            },
+        }
+    }
+}
+
+fn constant_value(value: &ConstantValue) -> WireConstantValue {
+    match value {
+        ConstantValue::Int(value) => WireConstantValue::Int(*value),
+        ConstantValue::Float(value) => WireConstantValue::Float(value.as_float()),
+        ConstantValue::String(value) => WireConstantValue::String(*value),
+        ConstantValue::Boolean(value) => WireConstantValue::Boolean(*value),
+        ConstantValue::Null() => WireConstantValue::Null,
+        ConstantValue::Enum(value) => WireConstantValue::Enum(*value),
+        ConstantValue::List(items) => {
+            WireConstantValue::List(items.iter().map(constant_value).collect())
+        }
+        ConstantValue::Object(fields) => WireConstantValue::Object(
+            fields
+                .iter()
+                .map(|field| WireConstantArgument {
+                    name: field.name.map(|name| name.0),
+                    value: WithLocation::new(
+                        field.value.location,
+                        constant_value(&field.value.item),
+                    ),
+                })
+                .collect(),
+        ),
+    }
+}
+
+struct WireDeserializer<'a> {
+    schema: &'a SDLSchema,
+    side_tables: &'a WireSideTables,
+}
+
+impl WireDeserializer<'_> {
+    fn operation(&self, operation: WireOperation) -> DiagnosticsResult<OperationDefinition> {
+        let type_ = self.type_(operation.type_, operation.name.location)?;
+        Ok(OperationDefinition {
+            kind: operation.operation,
+            name: operation.name.map(OperationDefinitionName),
+            type_,
+            variable_definitions: self.variable_definitions(operation.variable_definitions)?,
+            directives: self.directives(operation.directives)?,
+            selections: self.selections(type_, operation.selections)?,
+        })
+    }
+
+    fn fragment(&self, fragment: WireFragment) -> DiagnosticsResult<FragmentDefinition> {
+        let type_condition = self.type_(fragment.type_condition, fragment.name.location)?;
+        Ok(FragmentDefinition {
+            name: fragment.name.map(FragmentDefinitionName),
+            variable_definitions: self.variable_definitions(fragment.variable_definitions)?,
+            used_global_variables: self.variable_definitions(fragment.used_global_variables)?,
+            type_condition,
+            directives: self.directives(fragment.directives)?,
+            selections: self.selections(type_condition, fragment.selections)?,
+        })
+    }
+
+    fn variable_definitions(
+        &self,
+        variable_definitions: Vec<WireVariableDefinition>,
+    ) -> DiagnosticsResult<Vec<VariableDefinition>> {
+        variable_definitions
+            .into_iter()
+            .map(|variable_definition| {
+                Ok(VariableDefinition {
+                    type_: self.type_reference(
+                        &variable_definition.type_,
+                        variable_definition.name.location,
+                    )?,
+                    name: variable_definition.name.map(VariableName),
+                    default_value: variable_definition.default_value.map(|value| {
+                        WithLocation::new(value.location, from_constant_value(value.item))
+                    }),
+                    directives: self.directives(variable_definition.directives)?,
+                })
+            })
+            .collect()
+    }
+
+    fn selections(
+        &self,
+        parent_type: Type,
+        selections: Vec<WireSelection>,
+    ) -> DiagnosticsResult<Vec<Selection>> {
+        selections
+            .into_iter()
+            .map(|selection| self.selection(parent_type, selection))
+            .collect()
+    }
+
+    fn selection(
+        &self,
+        parent_type: Type,
+        selection: WireSelection,
+    ) -> DiagnosticsResult<Selection> {
+        Ok(match selection {
+            WireSelection::FragmentSpread {
+                fragment,
+                arguments,
+                directives,
+            } => {
+                let fragment = fragment.map(FragmentDefinitionName);
+                Selection::FragmentSpread(Arc::new(FragmentSpread {
+                    signature: self.side_tables.signatures.get(&fragment.item).cloned(),
+                    fragment,
+                    arguments: self.arguments(arguments)?,
+                    directives: self.directives(directives)?,
+                }))
+            }
+            WireSelection::InlineFragment {
+                type_condition,
+                directives,
+                selections,
+                spread_location,
+            } => {
+                let type_condition = type_condition
+                    .map(|type_| self.type_(type_, spread_location))
+                    .transpose()?;
+                Selection::InlineFragment(Arc::new(InlineFragment {
+                    type_condition,
+                    directives: self.directives(directives)?,
+                    selections: self
+                        .selections(type_condition.unwrap_or(parent_type), selections)?,
+                    spread_location,
+                }))
+            }
+            WireSelection::LinkedField {
+                alias,
+                name,
+                arguments,
+                directives,
+                selections,
+            } => {
+                let definition = self.field(parent_type, name)?;
+                let field_type = self.schema.field(definition.item).type_.inner();
+                Selection::LinkedField(Arc::new(LinkedField {
+                    alias,
+                    definition,
+                    arguments: self.arguments(arguments)?,
+                    directives: self.directives(directives)?,
+                    selections: self.selections(field_type, selections)?,
+                }))
+            }
+            WireSelection::ScalarField {
+                alias,
+                name,
+                arguments,
+                directives,
+            } => Selection::ScalarField(Arc::new(ScalarField {
+                alias,
+                definition: self.field(parent_type, name)?,
+                arguments: self.arguments(arguments)?,
+                directives: self.directives(directives)?,
+            })),
+            WireSelection::Condition {
+                value,
+                passing_value,
+                selections,
+                location,
+            } => Selection::Condition(Arc::new(Condition {
+                value: match value {
+                    WireConditionValue::Constant { value } => ConditionValue::Constant(value),
+                    WireConditionValue::Variable { name, type_ } => {
+                        ConditionValue::Variable(self.variable(name, &type_)?)
+                    }
+                },
+                passing_value,
+                selections: self.selections(parent_type, selections)?,
+                location,
+            })),
+        })
+    }
+
+    fn directives(&self, directives: Vec<WireDirective>) -> DiagnosticsResult<Vec<Directive>> {
+        directives
+            .into_iter()
+            .map(|directive| {
+                let data = match directive.data {
+                    Some(index) => Some(
+                        self.side_tables
+                            .directive_data
+                            .get(index)
+                            .cloned()
+                            .ok_or_else(|| {
+                                vec![Diagnostic::error(
+                                    WireIrMessage::UnknownDirectiveData { index },
+                                    directive.location,
+                                )]
+                            })?,
+                    ),
+                    None => None,
+                };
+                Ok(Directive {
+                    name: directive.name.map(DirectiveName),
+                    arguments: self.arguments(directive.arguments)?,
+                    data,
+                    location: directive.location,
+                })
+            })
+            .collect()
+    }
+
+    fn arguments(&self, arguments: Vec<WireArgument>) -> DiagnosticsResult<Vec<Argument>> {
+        arguments
+            .into_iter()
+            .map(|argument| {
+                Ok(Argument {
+                    name: argument.name.map(ArgumentName),
+                    value: WithLocation::new(
+                        argument.value.location,
+                        self.value(argument.value.item)?,
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn value(&self, value: WireValue) -> DiagnosticsResult<Value> {
+        Ok(match value {
+            WireValue::Constant { value } => Value::Constant(from_constant_value(value)),
+            WireValue::Variable { name, type_ } => Value::Variable(self.variable(name, &type_)?),
+            WireValue::List { items } => Value::List(
+                items
+                    .into_iter()
+                    .map(|item| self.value(item))
+                    .collect::<DiagnosticsResult<_>>()?,
+            ),
+            WireValue::Object { fields } => Value::Object(self.arguments(fields)?),
+        })
+    }
+
+    fn variable(&self, name: WithLocation<StringKey>, type_: &str) -> DiagnosticsResult<Variable> {
+        Ok(Variable {
+            type_: self.type_reference(type_, name.location)?,
+            name: name.map(VariableName),
+        })
+    }
+
+    fn type_(&self, name: StringKey, location: Location) -> DiagnosticsResult<Type> {
+        self.schema.get_type(name).ok_or_else(|| {
+            vec![Diagnostic::error(
+                WireIrMessage::UnknownType { type_name: name },
+                location,
+            )]
+        })
+    }
+
+    fn type_reference(
+        &self,
+        type_string: &str,
+        location: Location,
+    ) -> DiagnosticsResult<TypeReference<Type>> {
+        let invalid = || {
+            vec![Diagnostic::error(
+                WireIrMessage::InvalidTypeReference {
+                    type_string: type_string.to_string(),
+                },
+                location,
+            )]
+        };
+        let annotation =
+            parse_type(type_string, location.source_location(), 0).map_err(|_| invalid())?;
+        build_type_annotation(self.schema, &annotation, location).map_err(|_| invalid())
+    }
+
+    /// Resolve a field selected on `parent_type`, including the special
+    /// fields like `__typename` that are selectable on any type.
+    fn field(
+        &self,
+        parent_type: Type,
+        name: WithLocation<StringKey>,
+    ) -> DiagnosticsResult<WithLocation<FieldID>> {
+        let field_id = self.schema.named_field(parent_type, name.item).or_else(|| {
+            [
+                self.schema.typename_field(),
+                self.schema.clientid_field(),
+                self.schema.strongid_field(),
+                self.schema.fetch_token_field(),
+                self.schema.is_fulfilled_field(),
+            ]
+            .into_iter()
+            .find(|id| self.schema.field(*id).name.item == name.item)
+        });
+        field_id
+            .map(|id| WithLocation::new(name.location, id))
+            .ok_or_else(|| {
+                vec![Diagnostic::error(
+                    WireIrMessage::UnknownField {
+                        type_name: self.schema.get_type_name(parent_type),
+                        field_name: name.item,
+                    },
+                    name.location,
+                )]
+            })
+    }
+}
+
+fn from_constant_value(value: WireConstantValue) -> ConstantValue {
+    match value {
+        WireConstantValue::Int(value) => ConstantValue::Int(value),
+        WireConstantValue::Float(value) => ConstantValue::Float(FloatValue::new(value)),
+        WireConstantValue::String(value) => ConstantValue::String(value),
+        WireConstantValue::Boolean(value) => ConstantValue::Boolean(value),
+        WireConstantValue::Null => ConstantValue::Null(),
+        WireConstantValue::Enum(value) => ConstantValue::Enum(value),
+        WireConstantValue::List(items) => {
+            ConstantValue::List(items.into_iter().map(from_constant_value).collect())
+        }
+        WireConstantValue::Object(fields) => ConstantValue::Object(
+            fields
+                .into_iter()
+                .map(|field| ConstantArgument {
+                    name: field.name.map(ArgumentName),
+                    value: WithLocation::new(
+                        field.value.location,
+                        from_constant_value(field.value.item),
+                    ),
+                })
+                .collect(),
+        ),
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_test_schema::get_test_schema;
+use relay_transform_plugins::wire_ir::WireDefinition;
+use relay_transform_plugins::wire_ir::program_from_wire;
+use relay_transform_plugins::wire_ir::program_to_wire;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = get_test_schema();
+    let ast = parse_executable(fixture.content, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let (definitions, side_tables) = program_to_wire(&program);
+    let json = serde_json::to_string_pretty(&definitions).unwrap();
+
+    // The definitions a plugin returns unchanged build the same program.
+    let definitions: Vec<WireDefinition> = serde_json::from_str(&json).unwrap();
+    let round_tripped = program_from_wire(schema, definitions, &side_tables)
+        .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+    for operation in program.operations() {
+        assert_eq!(
+            Some(operation),
+            round_tripped.operation(operation.name.item),
+            "Expected operation {} to be unchanged",
+            operation.name.item
+        );
+    }
+    for fragment in program.fragments() {
+        assert_eq!(
+            Some(fragment),
+            round_tripped.fragment(fragment.name.item),
+            "Expected fragment {} to be unchanged",
+            fragment.name.item
+        );
+    }
+    assert_eq!(program.document_count(), round_tripped.document_count());
+
+    Ok(json)
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<edf7b7a94339dbeede89ed8a21dde250>>
+ */
+
+mod wire_ir;
+
+use wire_ir::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn operation_and_fragment() {
+    let input = include_str!("wire_ir/fixtures/operation-and-fragment.graphql");
+    let expected = include_str!("wire_ir/fixtures/operation-and-fragment.expected");
+    test_fixture(transform_fixture, file!(), "operation-and-fragment.graphql", "wire_ir/fixtures/operation-and-fragment.expected", input, expected).await;
+}
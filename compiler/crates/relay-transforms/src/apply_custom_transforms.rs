@@ -5,6 +5,9 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use common::DiagnosticsResult;
 use common::PerfLogEvent;
 use graphql_ir::FragmentDefinitionNameSet;
@@ -13,10 +16,28 @@ use relay_config::ProjectConfig;
 
 pub type BaseFragmentNames = FragmentDefinitionNameSet;
 
+pub type CustomTransformFn = Arc<
+    dyn Fn(&ProjectConfig, &Program, &BaseFragmentNames) -> DiagnosticsResult<Program>
+        + Send
+        + Sync,
+>;
+
 #[derive(Clone)]
 pub struct CustomTransform {
-    pub name: &'static str,
-    pub transform: fn(&ProjectConfig, &Program, &BaseFragmentNames) -> DiagnosticsResult<Program>,
+    pub name: Cow<'static, str>,
+    pub transform: CustomTransformFn,
+}
+
+impl CustomTransform {
+    pub fn new(
+        name: &'static str,
+        transform: fn(&ProjectConfig, &Program, &BaseFragmentNames) -> DiagnosticsResult<Program>,
+    ) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            transform: Arc::new(transform),
+        }
+    }
 }
 
 impl std::fmt::Debug for CustomTransform {
@@ -27,13 +48,13 @@ impl std::fmt::Debug for CustomTransform {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CustomTransforms {
     pub apply_before: Vec<CustomTransform>,
     pub apply_after: Vec<CustomTransform>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CustomTransformsConfig {
     pub common_transforms: Option<CustomTransforms>,
     pub reader_transforms: Option<CustomTransforms>,
@@ -43,6 +64,42 @@ pub struct CustomTransformsConfig {
     pub typegen_transforms: Option<CustomTransforms>,
 }
 
+impl CustomTransformsConfig {
+    /// The transforms of `self` followed by the transforms of `other`, at
+    /// every stage.
+    pub fn merge(&self, other: &CustomTransformsConfig) -> CustomTransformsConfig {
+        fn merge_stage(
+            first: &Option<CustomTransforms>,
+            second: &Option<CustomTransforms>,
+        ) -> Option<CustomTransforms> {
+            match (first, second) {
+                (Some(first), Some(second)) => Some(CustomTransforms {
+                    apply_before: [&first.apply_before[..], &second.apply_before[..]].concat(),
+                    apply_after: [&first.apply_after[..], &second.apply_after[..]].concat(),
+                }),
+                (first, second) => first.clone().or_else(|| second.clone()),
+            }
+        }
+        CustomTransformsConfig {
+            common_transforms: merge_stage(&self.common_transforms, &other.common_transforms),
+            reader_transforms: merge_stage(&self.reader_transforms, &other.reader_transforms),
+            operation_transforms: merge_stage(
+                &self.operation_transforms,
+                &other.operation_transforms,
+            ),
+            normalization_transforms: merge_stage(
+                &self.normalization_transforms,
+                &other.normalization_transforms,
+            ),
+            operation_text_transforms: merge_stage(
+                &self.operation_text_transforms,
+                &other.operation_text_transforms,
+            ),
+            typegen_transforms: merge_stage(&self.typegen_transforms, &other.typegen_transforms),
+        }
+    }
+}
+
 pub(crate) fn apply_before_custom_transforms(
     program: &Program,
     custom_transforms: &Option<&CustomTransforms>,
@@ -124,9 +181,15 @@ fn apply_custom_transform(
     maybe_print_stats: Option<fn(extra_info: &str, program: &Program) -> ()>,
 ) -> DiagnosticsResult<Program> {
     let CustomTransform { name, transform } = transform;
-    let program = log_event.time(name, || {
-        transform(project_config, program, base_fragment_names)
-    })?;
+    let run = || transform(project_config, program, base_fragment_names);
+    let program = match name {
+        Cow::Borrowed(name) => log_event.time(name, run),
+        // Perf log names are static, so transforms named at runtime, like
+        // transform plugins, are logged as items of one step.
+        Cow::Owned(name) => log_event.time("custom_transform", || {
+            log_event.time_item("custom_transform", || name.clone(), run)
+        }),
+    }?;
 
     if let Some(print_stats) = maybe_print_stats {
         print_stats(name, &program);
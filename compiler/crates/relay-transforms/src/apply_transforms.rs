@@ -700,7 +700,13 @@ fn apply_typegen_transforms(
         )
     });
 
-    program = log_event.time("mask", || mask(&program));
+    program = log_event.time("mask", || {
+        if project_config.typegen_config.language.is_native() {
+            unmask_fragment_spreads(&program)
+        } else {
+            mask(&program)
+        }
+    });
     program = log_event.time("transform_match", || {
         transform_match(
             &program,
@@ -318,6 +318,24 @@ pub enum ValidationMessage {
     ShadowReturnPlaceholderMisplaced {
         return_fragment_name: FragmentDefinitionName,
     },
+
+    #[error(
+        "Operation `{operation_name}` has an estimated cost of {cost}, which exceeds the limit of {limit}. Reduce the number of fields it selects, or the size of the lists they are nested in."
+    )]
+    QueryCostLimitExceeded {
+        operation_name: StringKey,
+        cost: u64,
+        limit: u64,
+    },
+
+    #[error(
+        "Operation `{operation_name}` has a depth of {depth}, which exceeds the limit of {limit}."
+    )]
+    QueryDepthLimitExceeded {
+        operation_name: StringKey,
+        depth: usize,
+        limit: usize,
+    },
 }
 
 #[derive(
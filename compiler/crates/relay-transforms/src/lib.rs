@@ -87,6 +87,7 @@ pub type DependencySet = HashSet<OperationName, BuildIdHasher<u32>>;
 pub use applied_fragment_name::get_applied_fragment_name;
 pub use apply_custom_transforms::BaseFragmentNames;
 pub use apply_custom_transforms::CustomTransform;
+pub use apply_custom_transforms::CustomTransformFn;
 pub use apply_custom_transforms::CustomTransforms;
 pub use apply_custom_transforms::CustomTransformsConfig;
 pub use apply_fragment_arguments::NoInlineFragmentSpreadMetadata;
@@ -157,6 +158,7 @@ pub use inline_data_fragment::inline_data_fragment;
 pub use inline_fragments::inline_fragments;
 pub use inline_fragments::inline_fragments_keep_fragments;
 pub use mask::mask;
+pub use mask::unmask_fragment_spreads;
 pub use match_::DIRECTIVE_SPLIT_OPERATION;
 pub use match_::MATCH_CONSTANTS;
 pub use match_::ModuleMetadata;
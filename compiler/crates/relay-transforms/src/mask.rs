@@ -9,7 +9,9 @@ use std::ops::RangeFull;
 use std::sync::Arc;
 
 use common::Location;
+use common::NamedItem;
 use graphql_ir::FragmentDefinition;
+use graphql_ir::FragmentDefinitionName;
 use graphql_ir::FragmentSpread;
 use graphql_ir::InlineFragment;
 use graphql_ir::OperationDefinition;
@@ -24,11 +26,22 @@ use indexmap::IndexMap;
 use indexmap::map::Entry;
 use schema::Schema;
 
+use crate::MATCH_CONSTANTS;
 use crate::relay_directive::RelayDirective;
 
 /// Transform to inline fragment spreads with @relay(mask:false)
 pub fn mask(program: &Program) -> Program {
-    let mut transform = Mask::new(program);
+    let mut transform = Mask::new(program, false);
+    transform
+        .transform_program(program)
+        .replace_or_else(|| program.clone())
+}
+
+/// Transform to inline all fragment spreads except @module ones, as if each
+/// of them had @relay(mask:false). Used to generate types for clients that
+/// don't mask fragment data.
+pub fn unmask_fragment_spreads(program: &Program) -> Program {
+    let mut transform = Mask::new(program, true);
     transform
         .transform_program(program)
         .replace_or_else(|| program.clone())
@@ -39,13 +52,30 @@ type JoinedArguments<'s> = IndexMap<VariableName, &'s VariableDefinition>;
 struct Mask<'s> {
     program: &'s Program,
     current_reachable_arguments: Vec<&'s VariableDefinition>,
+    unmask_all: bool,
+    /// Fragments currently being inlined, to leave recursive spreads masked.
+    inlined_fragments: Vec<FragmentDefinitionName>,
 }
 
 impl<'s> Mask<'s> {
-    fn new(program: &'s Program) -> Self {
+    fn new(program: &'s Program, unmask_all: bool) -> Self {
         Self {
             program,
             current_reachable_arguments: vec![],
+            unmask_all,
+            inlined_fragments: vec![],
+        }
+    }
+
+    fn is_unmasked(&self, spread: &FragmentSpread) -> bool {
+        if self.unmask_all {
+            spread
+                .directives
+                .named(MATCH_CONSTANTS.module_directive_name)
+                .is_none()
+                && !self.inlined_fragments.contains(&spread.fragment.item)
+        } else {
+            RelayDirective::is_unmasked_fragment_spread(spread)
         }
     }
 
@@ -98,7 +128,9 @@ impl Transformer<'_> for Mask<'_> {
         &mut self,
         fragment: &FragmentDefinition,
     ) -> Transformed<FragmentDefinition> {
+        self.inlined_fragments.push(fragment.name.item);
         let result = self.default_transform_fragment(fragment);
+        self.inlined_fragments.pop();
         if self.current_reachable_arguments.is_empty() {
             result
         } else {
@@ -120,16 +152,19 @@ impl Transformer<'_> for Mask<'_> {
     }
 
     fn transform_fragment_spread(&mut self, spread: &FragmentSpread) -> Transformed<Selection> {
-        if RelayDirective::is_unmasked_fragment_spread(spread) {
+        if self.is_unmasked(spread) {
             let fragment = self.program.fragment(spread.fragment.item).unwrap();
             self.current_reachable_arguments
                 .extend(&fragment.used_global_variables);
+            self.inlined_fragments.push(fragment.name.item);
+            let selections = self
+                .transform_selections(&fragment.selections)
+                .replace_or_else(|| fragment.selections.to_vec());
+            self.inlined_fragments.pop();
             Transformed::Replace(Selection::InlineFragment(Arc::new(InlineFragment {
                 type_condition: Some(fragment.type_condition),
                 directives: vec![],
-                selections: self
-                    .transform_selections(&fragment.selections)
-                    .replace_or_else(|| fragment.selections.to_vec()),
+                selections,
                 spread_location: Location::generated(),
             })))
         } else {
@@ -21,6 +21,7 @@ mod validate_module_names;
 mod validate_no_double_underscore_alias;
 mod validate_no_inline_with_raw_response_type;
 mod validate_no_unselectable_selections;
+mod validate_query_limits;
 mod validate_relay_directives;
 mod validate_required_arguments;
 mod validate_resolver_fragments;
@@ -47,6 +48,7 @@ pub use validate_module_names::validate_module_names;
 pub use validate_no_double_underscore_alias::validate_no_double_underscore_alias;
 pub use validate_no_inline_with_raw_response_type::validate_no_inline_fragments_with_raw_response_type;
 pub use validate_no_unselectable_selections::validate_no_unselectable_selections;
+pub use validate_query_limits::validate_query_limits;
 pub use validate_relay_directives::validate_relay_directives;
 pub use validate_required_arguments::validate_required_arguments;
 pub use validate_resolver_fragments::validate_resolver_fragments;
@@ -0,0 +1,323 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::NamedItem;
+use common::WithLocation;
+use graphql_ir::ConstantValue;
+use graphql_ir::Field;
+use graphql_ir::FragmentDefinitionName;
+use graphql_ir::OperationDefinition;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::Value;
+use graphql_syntax::ConstantValue as SchemaConstantValue;
+use indexmap::IndexMap;
+use intern::intern;
+use relay_config::DiagnosticLevel;
+use relay_config::QueryLimitsConfig;
+use schema::DirectiveValue;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+use crate::SplitOperationMetadata;
+use crate::ValidationMessage;
+
+static COST: LazyLock<DirectiveName> = LazyLock::new(|| DirectiveName(intern!("cost")));
+static WEIGHT: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName(intern!("weight")));
+static FIRST: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName(intern!("first")));
+static LAST: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName(intern!("last")));
+
+/// Checks the operations of a normalization program against the cost and
+/// depth limits of `config`. Only fields sent to the server are counted:
+/// client extensions and resolvers are free.
+pub fn validate_query_limits(
+    program: &Program,
+    config: &QueryLimitsConfig,
+) -> DiagnosticsResult<()> {
+    if config.max_cost.is_none() && config.max_depth.is_none() {
+        return Ok(());
+    }
+    let diagnostics: Vec<Diagnostic> = program
+        .operations()
+        .filter(|operation| SplitOperationMetadata::find(&operation.directives).is_none())
+        .flat_map(|operation| validate_operation(program, config, operation))
+        .collect();
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diagnostics)
+    }
+}
+
+fn validate_operation(
+    program: &Program,
+    config: &QueryLimitsConfig,
+    operation: &OperationDefinition,
+) -> Vec<Diagnostic> {
+    let mut calculator = CostCalculator {
+        program,
+        config,
+        operation,
+        contributors: Default::default(),
+        deepest: None,
+        visited_fragments: vec![],
+    };
+    let cost = calculator.selections(&operation.selections, 1, false, 0, "");
+    let operation_name = operation.name.item.0;
+    let location = operation.name.location;
+
+    let mut diagnostics = vec![];
+    if let Some(limit) = config.max_cost
+        && cost > limit
+    {
+        let mut contributors: Vec<_> = calculator.contributors.into_iter().collect();
+        contributors.sort_by(|(a_path, (a_cost, _)), (b_path, (b_cost, _))| {
+            b_cost.cmp(a_cost).then_with(|| a_path.cmp(b_path))
+        });
+        let diagnostic = contributors.into_iter().take(config.top_contributors).fold(
+            diagnostic(
+                config.level,
+                ValidationMessage::QueryCostLimitExceeded {
+                    operation_name,
+                    cost,
+                    limit,
+                },
+                location,
+            ),
+            |diagnostic, (path, (cost, location))| {
+                diagnostic.annotate(format!("`{path}` costs {cost}"), location)
+            },
+        );
+        diagnostics.push(diagnostic);
+    }
+    if let Some(limit) = config.max_depth
+        && let Some((depth, path, deepest_location)) = calculator.deepest
+        && depth > limit
+    {
+        diagnostics.push(
+            diagnostic(
+                config.level,
+                ValidationMessage::QueryDepthLimitExceeded {
+                    operation_name,
+                    depth,
+                    limit,
+                },
+                location,
+            )
+            .annotate(
+                format!("`{path}` is nested {depth} levels deep"),
+                deepest_location,
+            ),
+        );
+    }
+    diagnostics
+}
+
+fn diagnostic(
+    level: DiagnosticLevel,
+    message: ValidationMessage,
+    location: Location,
+) -> Diagnostic {
+    match level {
+        DiagnosticLevel::Error => Diagnostic::error(message, location),
+        DiagnosticLevel::Warning => Diagnostic::warning(message, location, vec![]),
+        DiagnosticLevel::Info => Diagnostic::info(message, location, vec![]),
+        DiagnosticLevel::Hint => Diagnostic::hint(message, location, vec![]),
+    }
+}
+
+struct CostCalculator<'a> {
+    program: &'a Program,
+    config: &'a QueryLimitsConfig,
+    operation: &'a OperationDefinition,
+    /// Cost of each field path of the operation, aggregated over the
+    /// fragments that select it.
+    contributors: IndexMap<String, (u64, Location)>,
+    /// Depth, path and location of the most deeply nested linked field.
+    deepest: Option<(usize, String, Location)>,
+    visited_fragments: Vec<FragmentDefinitionName>,
+}
+
+impl CostCalculator<'_> {
+    /// Returns the cost of `selections`, each of which is fetched `multiplier`
+    /// times. `sliced` is set when the parent field was bounded by a `first`
+    /// or `last` argument, in which case that bound also applies to a list
+    /// directly below it (e.g. the `edges` of a connection).
+    fn selections(
+        &mut self,
+        selections: &[Selection],
+        multiplier: u64,
+        sliced: bool,
+        depth: usize,
+        path: &str,
+    ) -> u64 {
+        let mut cost: u64 = 0;
+        for selection in selections {
+            let selection_cost = match selection {
+                Selection::ScalarField(field) => self.field(
+                    field.definition,
+                    &field.alias_or_name(&self.program.schema).to_string(),
+                    &field.arguments,
+                    None,
+                    multiplier,
+                    sliced,
+                    depth,
+                    path,
+                ),
+                Selection::LinkedField(field) => self.field(
+                    field.definition,
+                    &field.alias_or_name(&self.program.schema).to_string(),
+                    &field.arguments,
+                    Some(&field.selections),
+                    multiplier,
+                    sliced,
+                    depth,
+                    path,
+                ),
+                Selection::InlineFragment(fragment) => {
+                    self.selections(&fragment.selections, multiplier, sliced, depth, path)
+                }
+                Selection::Condition(condition) => {
+                    self.selections(&condition.selections, multiplier, sliced, depth, path)
+                }
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.fragment.item;
+                    if self.visited_fragments.contains(&name) {
+                        continue;
+                    }
+                    match self.program.fragment(name) {
+                        Some(fragment) => {
+                            self.visited_fragments.push(name);
+                            let cost = self.selections(
+                                &fragment.selections,
+                                multiplier,
+                                sliced,
+                                depth,
+                                path,
+                            );
+                            self.visited_fragments.pop();
+                            cost
+                        }
+                        None => 0,
+                    }
+                }
+            };
+            cost = cost.saturating_add(selection_cost);
+        }
+        cost
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn field(
+        &mut self,
+        definition: WithLocation<FieldID>,
+        name: &str,
+        arguments: &[graphql_ir::Argument],
+        selections: Option<&[Selection]>,
+        multiplier: u64,
+        sliced: bool,
+        depth: usize,
+        path: &str,
+    ) -> u64 {
+        let schema = &self.program.schema;
+        let field = schema.field(definition.item);
+        if field.is_extension {
+            return 0;
+        }
+        let path = if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{path}.{name}")
+        };
+        let weight = cost_weight(&field.directives)
+            .or_else(|| cost_weight(type_directives(schema, field.type_.inner())))
+            .unwrap_or(self.config.default_field_cost);
+        let cost = weight.saturating_mul(multiplier);
+        let entry = self
+            .contributors
+            .entry(path.clone())
+            .or_insert((0, definition.location));
+        entry.0 = entry.0.saturating_add(cost);
+
+        let Some(selections) = selections else {
+            return cost;
+        };
+        let depth = depth + 1;
+        if self
+            .deepest
+            .as_ref()
+            .is_none_or(|(deepest, _, _)| depth > *deepest)
+        {
+            self.deepest = Some((depth, path.clone(), definition.location));
+        }
+        let slice = self.slice_size(arguments);
+        let list_size = match slice {
+            Some(size) => size,
+            None if field.type_.is_list() && !sliced => self.config.default_list_size,
+            None => 1,
+        };
+        cost.saturating_add(self.selections(
+            selections,
+            multiplier.saturating_mul(list_size),
+            slice.is_some(),
+            depth,
+            &path,
+        ))
+    }
+
+    /// The size of the slice of a list selected with a `first` or `last`
+    /// argument, resolving variables to their default value.
+    fn slice_size(&self, arguments: &[graphql_ir::Argument]) -> Option<u64> {
+        let argument = arguments.named(*FIRST).or_else(|| arguments.named(*LAST))?;
+        let value = match &argument.value.item {
+            Value::Constant(value) => Some(value),
+            Value::Variable(variable) => self
+                .operation
+                .variable_definitions
+                .named(variable.name.item)
+                .and_then(|definition| definition.default_value.as_ref())
+                .map(|default_value| &default_value.item),
+            _ => None,
+        };
+        match value {
+            Some(ConstantValue::Int(size)) => Some((*size).max(0) as u64),
+            _ => Some(self.config.default_list_size),
+        }
+    }
+}
+
+fn cost_weight(directives: &[DirectiveValue]) -> Option<u64> {
+    let directive = directives.named(*COST)?;
+    let argument = directive.arguments.named(*WEIGHT)?;
+    let weight = match &argument.value {
+        SchemaConstantValue::Int(node) => node.value as f64,
+        SchemaConstantValue::Float(node) => node.value.as_float(),
+        SchemaConstantValue::String(node) => node.value.to_string().parse().ok()?,
+        _ => return None,
+    };
+    Some(weight.max(0.0).ceil() as u64)
+}
+
+fn type_directives(schema: &SDLSchema, type_: Type) -> &[DirectiveValue] {
+    match type_ {
+        Type::Enum(id) => &schema.enum_(id).directives,
+        Type::InputObject(id) => &schema.input_object(id).directives,
+        Type::Interface(id) => &schema.interface(id).directives,
+        Type::Object(id) => &schema.object(id).directives,
+        Type::Scalar(id) => &schema.scalar(id).directives,
+        Type::Union(id) => &schema.union(id).directives,
+    }
+}
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use graphql_test_helpers::diagnostics_to_sorted_string;
+use relay_config::DiagnosticLevel;
+use relay_config::QueryLimitsConfig;
+use relay_transforms::validate_query_limits;
+use schema::build_schema;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%schema%").collect();
+
+    if let [base, sdl] = parts.as_slice() {
+        let source_location = SourceLocationKey::standalone(fixture.file_name);
+        let ast = parse_executable(base, source_location).unwrap();
+        let schema = Arc::new(build_schema(sdl).unwrap());
+
+        let ir = build(&schema, &ast.definitions).unwrap();
+        let program = Program::from_definitions(Arc::clone(&schema), ir);
+        let config = QueryLimitsConfig {
+            max_cost: Some(100),
+            max_depth: Some(5),
+            top_contributors: 3,
+            level: if fixture.file_name.contains(".warning.") {
+                DiagnosticLevel::Warning
+            } else {
+                DiagnosticLevel::Error
+            },
+            ..Default::default()
+        };
+        validate_query_limits(&program, &config)
+            .map_err(|diagnostics| diagnostics_to_sorted_string(fixture.content, &diagnostics))?;
+
+        Ok("OK".to_owned())
+    } else {
+        panic!("Expected exactly one %schema% section marker.")
+    }
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<48215216d60e6d4329a9fd7a6985138b>>
+ */
+
+mod validate_query_limits;
+
+use validate_query_limits::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn cost_exceeded_invalid() {
+    let input = include_str!("validate_query_limits/fixtures/cost-exceeded.invalid.graphql");
+    let expected = include_str!("validate_query_limits/fixtures/cost-exceeded.invalid.expected");
+    test_fixture(transform_fixture, file!(), "cost-exceeded.invalid.graphql", "validate_query_limits/fixtures/cost-exceeded.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn depth_exceeded_warning_invalid() {
+    let input = include_str!("validate_query_limits/fixtures/depth-exceeded.warning.invalid.graphql");
+    let expected = include_str!("validate_query_limits/fixtures/depth-exceeded.warning.invalid.expected");
+    test_fixture(transform_fixture, file!(), "depth-exceeded.warning.invalid.graphql", "validate_query_limits/fixtures/depth-exceeded.warning.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unbounded_list_invalid() {
+    let input = include_str!("validate_query_limits/fixtures/unbounded-list.invalid.graphql");
+    let expected = include_str!("validate_query_limits/fixtures/unbounded-list.invalid.expected");
+    test_fixture(transform_fixture, file!(), "unbounded-list.invalid.graphql", "validate_query_limits/fixtures/unbounded-list.invalid.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn within_limits() {
+    let input = include_str!("validate_query_limits/fixtures/within-limits.graphql");
+    let expected = include_str!("validate_query_limits/fixtures/within-limits.expected");
+    test_fixture(transform_fixture, file!(), "within-limits.graphql", "validate_query_limits/fixtures/within-limits.expected", input, expected).await;
+}
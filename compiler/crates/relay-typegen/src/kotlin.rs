@@ -0,0 +1,271 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::BTreeSet;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use intern::Lookup;
+
+use crate::native::Declaration;
+use crate::native::NativeModule;
+use crate::native::TypeRef;
+use crate::native::type_ref;
+use crate::writer::AST;
+use crate::writer::Writer;
+
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+/// Prints Kotlin data classes. Declarations are buffered and printed in
+/// `into_string`, wrapped in an `object` named after the artifact.
+#[derive(Default)]
+pub struct KotlinPrinter {
+    result: String,
+    module: NativeModule,
+    indentation: usize,
+    /// Imports of the types of custom scalars.
+    imports: BTreeSet<String>,
+}
+
+impl Write for KotlinPrinter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.result.write_str(s)
+    }
+}
+
+impl Writer for KotlinPrinter {
+    fn into_string(mut self: Box<Self>) -> String {
+        if !self.imports.is_empty() {
+            for import in std::mem::take(&mut self.imports) {
+                writeln!(self.result, "import {import}").unwrap();
+            }
+            writeln!(self.result).unwrap();
+        }
+        let declarations = std::mem::take(&mut self.module.declarations);
+        if let Some(namespace) = self.module.namespace.take() {
+            writeln!(self.result, "object {namespace} {{").unwrap();
+            self.indentation += 1;
+            self.write_declarations(&declarations).unwrap();
+            self.indentation -= 1;
+            writeln!(self.result, "}}").unwrap();
+        } else {
+            self.write_declarations(&declarations).unwrap();
+        }
+        self.result
+    }
+
+    fn get_runtime_fragment_import(&self) -> &'static str {
+        "FragmentRefs"
+    }
+
+    fn write(&mut self, ast: &AST) -> FmtResult {
+        // Resolver type assertions only check JavaScript modules.
+        if let AST::AssertFunctionType(_) = ast {
+            return Ok(());
+        }
+        let type_ = type_ref(ast, "Item", &mut vec![]);
+        self.write_type_ref(&type_)
+    }
+
+    fn write_type_assertion(&mut self, _name: &str, _ast: &AST) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_type(&mut self, name: &str, ast: &AST) -> FmtResult {
+        self.module.add_export(name, ast);
+        Ok(())
+    }
+
+    // Types generated by Relay are declared within the artifact that refers
+    // to them, so only the types of custom scalars are imported.
+    fn write_import_module_default(&mut self, _name: &str, _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_module_named(
+        &mut self,
+        _name: &str,
+        _import_as: Option<&str>,
+        _from: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_import_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    // `from` names the package declaring the type.
+    fn write_import_custom_scalar(&mut self, name: &str, from: &str) -> FmtResult {
+        self.imports.insert(format!("{from}.{name}"));
+        Ok(())
+    }
+
+    fn write_import_fragment_type(&mut self, _types: &[&str], _from: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_type(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_export_fragment_types(
+        &mut self,
+        _fragment_type_name_1: &str,
+        _fragment_type_name_2: &str,
+    ) -> FmtResult {
+        Ok(())
+    }
+
+    fn write_any_type_definition(&mut self, _name: &str) -> FmtResult {
+        Ok(())
+    }
+}
+
+impl KotlinPrinter {
+    fn write_indentation(&mut self) -> FmtResult {
+        self.result.write_str(&"  ".repeat(self.indentation))
+    }
+
+    fn write_declarations(&mut self, declarations: &[Declaration]) -> FmtResult {
+        for (index, declaration) in declarations.iter().enumerate() {
+            if index > 0 {
+                writeln!(self.result)?;
+            }
+            self.write_declaration(declaration)?;
+        }
+        Ok(())
+    }
+
+    fn write_declaration(&mut self, declaration: &Declaration) -> FmtResult {
+        self.write_indentation()?;
+        match declaration {
+            Declaration::Class {
+                name,
+                fields,
+                nested,
+                ..
+            } => {
+                // Data classes need at least one property.
+                if fields.is_empty() {
+                    write!(self.result, "class {name}")?;
+                } else {
+                    writeln!(self.result, "data class {name}(")?;
+                    self.indentation += 1;
+                    for field in fields {
+                        self.write_indentation()?;
+                        write!(
+                            self.result,
+                            "val {}: ",
+                            escape_identifier(field.key.lookup())
+                        )?;
+                        self.write_type_ref(&field.type_)?;
+                        if field.optional {
+                            write!(self.result, " = null")?;
+                        }
+                        writeln!(self.result, ",")?;
+                    }
+                    self.indentation -= 1;
+                    self.write_indentation()?;
+                    write!(self.result, ")")?;
+                }
+                if nested.is_empty() {
+                    writeln!(self.result)
+                } else {
+                    writeln!(self.result, " {{")?;
+                    self.indentation += 1;
+                    self.write_declarations(nested)?;
+                    self.indentation -= 1;
+                    self.write_indentation()?;
+                    writeln!(self.result, "}}")
+                }
+            }
+            Declaration::Enum {
+                name,
+                values,
+                future_proof,
+            } => {
+                writeln!(self.result, "enum class {name} {{")?;
+                self.indentation += 1;
+                for value in values {
+                    self.write_indentation()?;
+                    writeln!(self.result, "{},", escape_identifier(value.lookup()))?;
+                }
+                if *future_proof {
+                    self.write_indentation()?;
+                    writeln!(self.result, "FUTURE_ADDED_VALUE,")?;
+                }
+                self.indentation -= 1;
+                self.write_indentation()?;
+                writeln!(self.result, "}}")
+            }
+            Declaration::Alias { name, type_ } => {
+                write!(self.result, "typealias {name} = ")?;
+                self.write_type_ref(type_)?;
+                writeln!(self.result)
+            }
+        }
+    }
+
+    fn write_type_ref(&mut self, type_: &TypeRef) -> FmtResult {
+        match type_ {
+            TypeRef::String => write!(self.result, "String"),
+            TypeRef::Int => write!(self.result, "Int"),
+            TypeRef::Float => write!(self.result, "Double"),
+            TypeRef::Boolean => write!(self.result, "Boolean"),
+            TypeRef::Never => write!(self.result, "Nothing"),
+            TypeRef::Unknown => write!(self.result, "Any"),
+            TypeRef::Named(name) => write!(self.result, "{name}"),
+            TypeRef::List(of_type) => {
+                write!(self.result, "List<")?;
+                self.write_type_ref(of_type)?;
+                write!(self.result, ">")
+            }
+            TypeRef::Optional(of_type) => {
+                self.write_type_ref(of_type)?;
+                write!(self.result, "?")
+            }
+        }
+    }
+}
+
+fn escape_identifier(identifier: &str) -> String {
+    if KOTLIN_KEYWORDS.contains(&identifier) {
+        format!("`{identifier}`")
+    } else {
+        identifier.to_string()
+    }
+}
@@ -11,6 +11,10 @@
 
 mod flow;
 mod javascript;
+mod kotlin;
+mod native;
+mod schema_types;
+mod swift;
 mod type_selection;
 mod typegen_state;
 mod typescript;
@@ -18,8 +22,10 @@ mod visit;
 mod write;
 mod writer;
 
+use std::path::PathBuf;
 use std::sync::LazyLock;
 
+use ::intern::Lookup;
 use ::intern::string_key::Intern;
 use ::intern::string_key::StringKey;
 use common::DirectiveName;
@@ -28,15 +34,20 @@ use common::ScalarName;
 use common::WithLocation;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::OperationDefinition;
+use relay_config::JsModuleFormat;
 use relay_config::ProjectConfig;
 pub use relay_config::TypegenConfig;
 pub use relay_config::TypegenLanguage;
 use relay_transforms::UPDATABLE_DIRECTIVE;
 use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+pub use schema_types::collect_schema_types;
 pub use typegen_state::FragmentLocations;
 pub use write::has_raw_response_type_directive;
 use write::write_fragment_type_exports_section;
 use write::write_operation_type_exports_section;
+use write::write_schema_type_module;
 use write::write_split_operation_type_exports_section;
 use write::write_validator_function;
 use writer::new_writer_from_config;
@@ -268,6 +279,34 @@ pub fn generate_split_operation_type_exports_section(
     writer.into_string()
 }
 
+/// Generates the shared type module of an input object or custom scalar
+/// returned by [`collect_schema_types`].
+pub fn generate_schema_type_module(
+    type_: Type,
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+) -> String {
+    let artifact_path = project_config
+        .path_for_schema_type_module(schema.get_type_name(type_))
+        .expect("Expected `schemaTypesOutput` to be configured");
+    let fragment_locations = FragmentLocations(Default::default());
+    let typegen_context = TypegenContext {
+        schema,
+        project_config,
+        fragment_locations: &fragment_locations,
+        has_unified_output: project_config.output.is_some(),
+        generating_updatable_types: false,
+        artifact_path,
+        typegen_options: TypegenOptions {
+            no_optional_fields_in_raw_response_type: false,
+            is_extra_artifact_branch_module: false,
+        },
+    };
+    let mut writer = new_writer_from_config(&project_config.typegen_config);
+    write_schema_type_module(&typegen_context, type_, &mut writer).unwrap();
+    writer.into_string()
+}
+
 /// An immutable grab bag of configuration, etc. for type generation.
 /// A new `TypegenContext` is created for each operation, fragment, and so on.
 struct TypegenContext<'a> {
@@ -276,7 +315,8 @@ struct TypegenContext<'a> {
     fragment_locations: &'a FragmentLocations,
     has_unified_output: bool,
     generating_updatable_types: bool,
-    definition_source_location: WithLocation<StringKey>,
+    /// Path of the artifact being generated, used to compute relative imports.
+    artifact_path: PathBuf,
     typegen_options: TypegenOptions,
 }
 
@@ -295,10 +335,26 @@ impl<'a> TypegenContext<'a> {
             fragment_locations,
             has_unified_output: project_config.output.is_some(),
             generating_updatable_types,
-            definition_source_location,
+            artifact_path: project_config.artifact_path_for_definition(definition_source_location),
             typegen_options,
         }
     }
+
+    /// When `schemaTypesOutput` is configured, returns the identifier used
+    /// to import the shared type module of the input object or custom scalar
+    /// `type_name` from the artifact being generated.
+    fn schema_type_import_path(&self, type_name: StringKey) -> Option<StringKey> {
+        let module_path = self.project_config.path_for_schema_type_module(type_name)?;
+        let import_path = self
+            .project_config
+            .js_module_import_identifier(&self.artifact_path, &module_path);
+        match self.project_config.js_module_format {
+            JsModuleFormat::CommonJS if !import_path.lookup().starts_with('.') => {
+                Some(format!("./{import_path}").intern())
+            }
+            _ => Some(import_path),
+        }
+    }
 }
 
 struct TypegenOptions {
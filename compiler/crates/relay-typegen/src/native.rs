@@ -0,0 +1,333 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Shared model for the Kotlin and Swift writers.
+//!
+//! Native targets have nominal types, so the structural `AST` is lowered into
+//! named declarations: objects become classes (nested under the class of the
+//! field that selects them), enums become enums, and everything else becomes a
+//! type alias. All declarations of an artifact are collected under a namespace
+//! named after the artifact, so that enums and input objects redeclared by
+//! several artifacts don't collide.
+
+use intern::Lookup;
+use intern::string_key::StringKey;
+
+use crate::FUTURE_ENUM_VALUE;
+use crate::KEY_FRAGMENT_TYPE;
+use crate::writer::AST;
+use crate::writer::KeyValuePairProp;
+use crate::writer::Prop;
+use crate::writer::SortedASTList;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TypeRef {
+    String,
+    Int,
+    Float,
+    Boolean,
+    Never,
+    /// A value whose shape isn't known to typegen, e.g. an unmapped custom
+    /// scalar.
+    Unknown,
+    Named(String),
+    List(Box<TypeRef>),
+    Optional(Box<TypeRef>),
+}
+
+pub(crate) struct Field {
+    pub(crate) key: StringKey,
+    pub(crate) type_: TypeRef,
+    /// The field may be omitted, and defaults to `null`.
+    pub(crate) optional: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClassKind {
+    /// Data read from a response.
+    Response,
+    /// The variables of an operation.
+    Variables,
+    /// An input object, which may refer to itself.
+    InputObject,
+}
+
+pub(crate) enum Declaration {
+    Class {
+        name: String,
+        kind: ClassKind,
+        fields: Vec<Field>,
+        nested: Vec<Declaration>,
+    },
+    Enum {
+        name: String,
+        values: Vec<StringKey>,
+        /// Whether unknown values decode into a catch-all case.
+        future_proof: bool,
+    },
+    Alias {
+        name: String,
+        type_: TypeRef,
+    },
+}
+
+/// Collects the declarations of an artifact, and the namespace they live in.
+#[derive(Default)]
+pub(crate) struct NativeModule {
+    pub(crate) namespace: Option<String>,
+    pub(crate) declarations: Vec<Declaration>,
+}
+
+impl NativeModule {
+    /// Adds the declarations for an exported type. Exports are named like
+    /// `MyQuery$data` or `MyEnum`. The part before the `$` names the
+    /// namespace, and the part after it the declaration within it.
+    pub(crate) fn add_export(&mut self, name: &str, ast: &AST) {
+        let (name, kind) = match name.split_once('$') {
+            Some((namespace, member)) => {
+                // References to fragments are meaningless once all spreads
+                // are unmasked.
+                if member == "key" || member == &KEY_FRAGMENT_TYPE.lookup()[1..] {
+                    return;
+                }
+                self.namespace
+                    .get_or_insert_with(|| to_pascal_case(namespace));
+                let kind = if member == "variables" {
+                    ClassKind::Variables
+                } else {
+                    ClassKind::Response
+                };
+                (to_pascal_case(member), kind)
+            }
+            None => {
+                // The operation type `MyQuery = { response, variables }`
+                // would shadow its own namespace.
+                if self.namespace.as_deref() == Some(&to_pascal_case(name)) {
+                    return;
+                }
+                (name.to_string(), ClassKind::InputObject)
+            }
+        };
+        declarations_for_export(name, kind, ast, &mut self.declarations);
+    }
+}
+
+fn declarations_for_export(
+    name: String,
+    kind: ClassKind,
+    ast: &AST,
+    declarations: &mut Vec<Declaration>,
+) {
+    if let Some(props) = object_props(ast) {
+        declarations.push(class_declaration(name, kind, props));
+    } else if let Some((values, future_proof)) = enum_values(ast) {
+        declarations.push(Declaration::Enum {
+            name,
+            values,
+            future_proof,
+        });
+    } else {
+        let mut nested = vec![];
+        let type_ = type_ref(ast, &format!("{name}Item"), &mut nested);
+        declarations.extend(nested);
+        declarations.push(Declaration::Alias { name, type_ });
+    }
+}
+
+/// Lowers `ast` into a type reference, declaring a class named
+/// `suggested_name` in `nested` if `ast` is an object.
+pub(crate) fn type_ref(ast: &AST, suggested_name: &str, nested: &mut Vec<Declaration>) -> TypeRef {
+    match ast {
+        AST::Nullable(of_type) => match type_ref(of_type, suggested_name, nested) {
+            optional @ TypeRef::Optional(_) => optional,
+            type_ => TypeRef::Optional(Box::new(type_)),
+        },
+        AST::NonNullable(of_type) => match type_ref(of_type, suggested_name, nested) {
+            TypeRef::Optional(type_) => *type_,
+            type_ => type_,
+        },
+        AST::ReadOnlyArray(of_type) => {
+            TypeRef::List(Box::new(type_ref(of_type, suggested_name, nested)))
+        }
+        AST::String | AST::StringLiteral(_) | AST::OtherTypename => TypeRef::String,
+        AST::Int => TypeRef::Int,
+        AST::Number => TypeRef::Float,
+        AST::Boolean => TypeRef::Boolean,
+        AST::Empty => TypeRef::Never,
+        AST::Identifier(name) | AST::RawType(name) => TypeRef::Named(declaration_name(*name)),
+        AST::ExactObject(_) | AST::InexactObject(_) | AST::Union(_)
+            if object_props(ast).is_some() =>
+        {
+            nested.push(class_declaration(
+                suggested_name.to_string(),
+                ClassKind::Response,
+                object_props(ast).unwrap(),
+            ));
+            TypeRef::Named(suggested_name.to_string())
+        }
+        AST::Union(members) => {
+            let mut types = members
+                .iter()
+                .map(|member| type_ref(member, suggested_name, nested));
+            let first = types.next().unwrap_or(TypeRef::Never);
+            if types.all(|type_| type_ == first) {
+                first
+            } else {
+                TypeRef::Unknown
+            }
+        }
+        AST::Local3DPayload(_, of_type) => type_ref(of_type, suggested_name, nested),
+        AST::GenericType { inner, .. } if !inner.is_empty() => {
+            type_ref(&inner[0], suggested_name, nested)
+        }
+        _ => TypeRef::Unknown,
+    }
+}
+
+/// The name a type exported as `name` is declared under in its namespace.
+fn declaration_name(name: StringKey) -> String {
+    match name.lookup().split_once('$') {
+        Some((_, member)) => to_pascal_case(member),
+        None => name.lookup().to_string(),
+    }
+}
+
+/// Returns the props of an object, or of the merged members of a union of
+/// objects (i.e. an abstract selection).
+fn object_props(ast: &AST) -> Option<Vec<KeyValuePairProp>> {
+    match ast {
+        AST::ExactObject(object) => Some(key_value_pairs(object)),
+        AST::InexactObject(object) => Some(key_value_pairs(object)),
+        AST::Union(members) => {
+            let members = members
+                .iter()
+                .map(|member| match member {
+                    AST::ExactObject(object) => Some(key_value_pairs(object)),
+                    AST::InexactObject(object) => Some(key_value_pairs(object)),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(merge_props(members))
+        }
+        _ => None,
+    }
+}
+
+fn key_value_pairs(props: &[Prop]) -> Vec<KeyValuePairProp> {
+    props
+        .iter()
+        .filter_map(|prop| match prop {
+            Prop::KeyValuePair(key_value_pair) => Some(key_value_pair.clone()),
+            Prop::GetterSetterPair(getter_setter_pair) => Some(KeyValuePairProp {
+                key: getter_setter_pair.key,
+                value: getter_setter_pair.getter_return_value.clone(),
+                read_only: false,
+                optional: false,
+            }),
+            // Spreads of named types only appear in raw response types with
+            // @no_inline fragments, whose props can't be inlined here.
+            Prop::Spread(_) => None,
+        })
+        // `$fragmentSpreads`, `$fragmentType` and friends only exist in masked
+        // data.
+        .filter(|key_value_pair| !key_value_pair.key.lookup().starts_with('$'))
+        .collect()
+}
+
+/// Merges the members of a union of objects into a single set of props. Props
+/// missing from some members become optional, and props whose types differ
+/// between members get the union of those types.
+fn merge_props(members: Vec<Vec<KeyValuePairProp>>) -> Vec<KeyValuePairProp> {
+    let member_count = members.len();
+    let mut merged: Vec<(KeyValuePairProp, Vec<AST>, usize)> = vec![];
+    for prop in members.into_iter().flatten() {
+        match merged
+            .iter_mut()
+            .find(|(merged_prop, _, _)| merged_prop.key == prop.key)
+        {
+            Some((merged_prop, values, count)) => {
+                merged_prop.optional |= prop.optional;
+                if !values.contains(&prop.value) {
+                    values.push(prop.value);
+                }
+                *count += 1;
+            }
+            None => {
+                let value = prop.value.clone();
+                merged.push((prop, vec![value], 1));
+            }
+        }
+    }
+    merged
+        .into_iter()
+        .map(|(mut prop, mut values, count)| {
+            prop.optional |= count < member_count;
+            prop.value = if values.len() == 1 {
+                values.pop().unwrap()
+            } else {
+                AST::Union(SortedASTList::new(values))
+            };
+            prop
+        })
+        .collect()
+}
+
+fn class_declaration(name: String, kind: ClassKind, props: Vec<KeyValuePairProp>) -> Declaration {
+    let mut nested = vec![];
+    let fields = props
+        .into_iter()
+        .map(|prop| {
+            let type_ = type_ref(&prop.value, &to_pascal_case(prop.key.lookup()), &mut nested);
+            let type_ = match type_ {
+                TypeRef::Optional(_) => type_,
+                _ if prop.optional => TypeRef::Optional(Box::new(type_)),
+                _ => type_,
+            };
+            Field {
+                key: prop.key,
+                type_,
+                optional: prop.optional,
+            }
+        })
+        .collect();
+    Declaration::Class {
+        name,
+        kind,
+        fields,
+        nested,
+    }
+}
+
+/// Returns the values of an enum, i.e. a union of string literals.
+fn enum_values(ast: &AST) -> Option<(Vec<StringKey>, bool)> {
+    let AST::Union(members) = ast else {
+        return None;
+    };
+    let mut future_proof = false;
+    let values = members
+        .iter()
+        .filter_map(|member| match member {
+            AST::StringLiteral(literal) if literal.0 == *FUTURE_ENUM_VALUE => {
+                future_proof = true;
+                None
+            }
+            AST::StringLiteral(literal) => Some(Some(literal.0)),
+            _ => Some(None),
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((values, future_proof))
+}
+
+/// `fooBar_baz` -> `FooBar_baz`, `rawResponse` -> `RawResponse`
+pub(crate) fn to_pascal_case(name: &str) -> String {
+    let name = name.trim_start_matches('_');
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Shared "schema types" modules.
+//!
+//! When `schemaTypesOutput` is configured, the types of input objects and
+//! custom scalars are written once into a module per type, and artifacts
+//! import them rather than re-declaring them inline.
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
+use docblock_shared::RESOLVER_VALUE_SCALAR_NAME;
+use graphql_ir::FragmentDefinition;
+use graphql_ir::LinkedField;
+use graphql_ir::OperationDefinition;
+use graphql_ir::ScalarField;
+use graphql_ir::VariableDefinition;
+use graphql_ir::Visitor;
+use indexmap::IndexSet;
+use relay_config::CustomType;
+use relay_config::ProjectConfig;
+use relay_schema::CUSTOM_SCALAR_DIRECTIVE_NAME;
+use relay_schema::EXPORT_NAME_CUSTOM_SCALAR_ARGUMENT_NAME;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::ScalarID;
+use schema::Schema;
+use schema::Type;
+
+use crate::TYPE_BOOLEAN;
+use crate::TYPE_FLOAT;
+use crate::TYPE_ID;
+use crate::TYPE_INT;
+use crate::TYPE_STRING;
+
+/// Whether references to `scalar` go through its shared schema type module.
+///
+/// Built-in scalars are always inlined. A custom scalar whose JS type is
+/// imported under the scalar's own name is already shared, so artifacts keep
+/// importing it directly.
+pub(crate) fn has_scalar_type_module(
+    schema: &SDLSchema,
+    project_config: &ProjectConfig,
+    scalar: ScalarID,
+) -> bool {
+    let scalar_name = schema.scalar(scalar).name.item;
+    if [
+        *TYPE_ID,
+        *TYPE_STRING,
+        *TYPE_INT,
+        *TYPE_FLOAT,
+        *TYPE_BOOLEAN,
+        *RESOLVER_VALUE_SCALAR_NAME,
+    ]
+    .contains(&scalar_name)
+    {
+        return false;
+    }
+    let export_name = if let Some(directive) = schema
+        .scalar(scalar)
+        .directives
+        .named(DirectiveName(*CUSTOM_SCALAR_DIRECTIVE_NAME))
+    {
+        directive
+            .arguments
+            .named(ArgumentName(*EXPORT_NAME_CUSTOM_SCALAR_ARGUMENT_NAME))
+            .map(|argument| argument.expect_string_literal())
+    } else {
+        match project_config
+            .typegen_config
+            .custom_scalar_types
+            .get(&scalar_name)
+        {
+            Some(CustomType::Path(custom_type_import)) => Some(custom_type_import.name),
+            Some(CustomType::Name(_)) | None => None,
+        }
+    };
+    export_name != Some(scalar_name.0)
+}
+
+/// Returns the input objects and custom scalars referenced by the given
+/// operations and fragments that get a shared schema type module, including
+/// input objects and scalars only reachable through the fields of other input
+/// objects.
+pub fn collect_schema_types<'a>(
+    schema: &SDLSchema,
+    operations: impl IntoIterator<Item = &'a OperationDefinition>,
+    fragments: impl IntoIterator<Item = &'a FragmentDefinition>,
+    project_config: &ProjectConfig,
+) -> Vec<Type> {
+    let mut collector = SchemaTypesCollector {
+        schema,
+        types: Default::default(),
+    };
+    for operation in operations {
+        collector.visit_operation(operation);
+    }
+    for fragment in fragments {
+        collector.visit_fragment(fragment);
+    }
+    let mut types = collector.types;
+
+    // Close over the fields of input objects. `types` grows as we go, so
+    // iterate by index.
+    let mut index = 0;
+    while index < types.len() {
+        if let Type::InputObject(input_object_id) = types[index] {
+            for field in schema.input_object(input_object_id).fields.iter() {
+                types.insert(field.type_.inner());
+            }
+        }
+        index += 1;
+    }
+
+    let mut types: Vec<Type> = types
+        .into_iter()
+        .filter(|type_| match type_ {
+            Type::InputObject(_) => true,
+            Type::Scalar(scalar_id) => has_scalar_type_module(schema, project_config, *scalar_id),
+            _ => false,
+        })
+        .collect();
+    types.sort_by_key(|type_| schema.get_type_name(*type_));
+    types
+}
+
+struct SchemaTypesCollector<'s> {
+    schema: &'s SDLSchema,
+    types: IndexSet<Type>,
+}
+
+impl SchemaTypesCollector<'_> {
+    fn add_field(&mut self, field_id: FieldID) {
+        let field = self.schema.field(field_id);
+        self.types.insert(field.type_.inner());
+        self.types.extend(
+            field
+                .arguments
+                .iter()
+                .map(|argument| argument.type_.inner()),
+        );
+    }
+}
+
+impl Visitor for SchemaTypesCollector<'_> {
+    const NAME: &'static str = "SchemaTypesCollector";
+    const VISIT_ARGUMENTS: bool = false;
+    const VISIT_DIRECTIVES: bool = false;
+
+    fn visit_variable_definition(&mut self, variable_definition: &VariableDefinition) {
+        self.types.insert(variable_definition.type_.inner());
+    }
+
+    fn visit_scalar_field(&mut self, field: &ScalarField) {
+        self.add_field(field.definition.item);
+    }
+
+    fn visit_linked_field(&mut self, field: &LinkedField) {
+        self.add_field(field.definition.item);
+        self.default_visit_linked_field(field)
+    }
+}
@@ -70,15 +70,8 @@ impl RuntimeImports {
 pub(crate) enum GeneratedInputObject {
     Pending,
     Resolved(AST),
-}
-
-impl GeneratedInputObject {
-    pub(crate) fn unwrap_resolved_type(self) -> AST {
-        match self {
-            GeneratedInputObject::Pending => panic!("Unexpected pending type"),
-            GeneratedInputObject::Resolved(exact_object) => exact_object,
-        }
-    }
+    /// The type is imported from its shared schema type module at this path.
+    Imported(StringKey),
 }
 
 pub(crate) type InputObjectTypes = IndexMap<InputObjectName, GeneratedInputObject>;
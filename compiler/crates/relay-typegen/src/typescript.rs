@@ -55,7 +55,7 @@ impl Writer for TypeScriptPrinter {
             AST::String => write!(&mut self.result, "string"),
             AST::StringLiteral(literal) => self.write_string_literal(**literal),
             AST::OtherTypename => self.write_other_string(),
-            AST::Number => write!(&mut self.result, "number"),
+            AST::Number | AST::Int => write!(&mut self.result, "number"),
             AST::Boolean => write!(&mut self.result, "boolean"),
             AST::Callable(return_type) => self.write_callable(return_type),
             AST::Identifier(identifier) => write!(&mut self.result, "{identifier}"),
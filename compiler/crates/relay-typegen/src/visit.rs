@@ -93,6 +93,7 @@ use crate::TYPE_INT;
 use crate::TYPE_STRING;
 use crate::TypegenContext;
 use crate::VARIABLES;
+use crate::schema_types::has_scalar_type_module;
 use crate::type_selection::ModuleDirective;
 use crate::type_selection::RawResponseFragmentSpread;
 use crate::type_selection::ScalarFieldSpecialSchemaField;
@@ -483,7 +484,9 @@ fn get_resolver_arguments(
 ) -> Vec<KeyValuePairProp> {
     let void_type = match typegen_context.project_config.typegen_config.language {
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => AST::RawType(intern!("void")),
-        TypegenLanguage::TypeScript => AST::RawType(intern!("undefined")),
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
+            AST::RawType(intern!("undefined"))
+        }
     };
 
     let mut resolver_arguments = vec![];
@@ -612,9 +615,7 @@ fn import_relay_resolver_function_type(
     };
 
     let import_path = typegen_context.project_config.js_module_import_identifier(
-        &typegen_context
-            .project_config
-            .artifact_path_for_definition(typegen_context.definition_source_location),
+        &typegen_context.artifact_path,
         &PathBuf::from(resolver_metadata.import_path.lookup()),
     );
 
@@ -626,9 +627,7 @@ fn import_relay_resolver_function_type(
         Some(ResolverContextTypeInput::Path(context_import)) => Some(ResolverContextType {
             name: context_import.name,
             import_path: typegen_context.project_config.js_module_import_identifier(
-                &typegen_context
-                    .project_config
-                    .artifact_path_for_definition(typegen_context.definition_source_location),
+                &typegen_context.artifact_path,
                 &PathBuf::from(&context_import.path),
             ),
         }),
@@ -1908,9 +1907,9 @@ fn make_prop(
                             TypegenLanguage::Flow | TypegenLanguage::JavaScript => {
                                 AST::RawType(intern!("null | void"))
                             }
-                            TypegenLanguage::TypeScript => {
-                                AST::RawType(intern!("null | undefined"))
-                            }
+                            TypegenLanguage::TypeScript
+                            | TypegenLanguage::Kotlin
+                            | TypegenLanguage::Swift => AST::RawType(intern!("null | undefined")),
                         }
                     }
                 } else {
@@ -2176,6 +2175,27 @@ fn transform_graphql_scalar_type(
     typegen_context: &'_ TypegenContext<'_>,
     scalar: ScalarID,
     custom_scalars: &mut CustomScalarsImports,
+) -> AST {
+    if has_scalar_type_module(
+        typegen_context.schema,
+        typegen_context.project_config,
+        scalar,
+    ) {
+        let scalar_name = typegen_context.schema.scalar(scalar).name.item.0;
+        if let Some(import_path) = typegen_context.schema_type_import_path(scalar_name) {
+            custom_scalars.insert((scalar_name, PathBuf::from(import_path.lookup())));
+            return AST::RawType(scalar_name);
+        }
+    }
+    build_scalar_type(typegen_context, scalar, custom_scalars)
+}
+
+/// Builds the JS type of a scalar from the `@__RelayCustomScalar` directive
+/// or the `customScalarTypes` config, falling back to the built-in types.
+pub(crate) fn build_scalar_type(
+    typegen_context: &'_ TypegenContext<'_>,
+    scalar: ScalarID,
+    custom_scalars: &mut CustomScalarsImports,
 ) -> AST {
     let scalar_definition = typegen_context.schema.scalar(scalar);
     let scalar_name = scalar_definition.name;
@@ -2195,9 +2215,7 @@ fn transform_graphql_scalar_type(
             .expect_string_literal();
 
         let import_path = typegen_context.project_config.js_module_import_identifier(
-            &typegen_context
-                .project_config
-                .artifact_path_for_definition(typegen_context.definition_source_location),
+            &typegen_context.artifact_path,
             &PathBuf::from(path.lookup()),
         );
 
@@ -2233,7 +2251,9 @@ fn transform_graphql_scalar_type(
         }
     } else if scalar_name.item == *TYPE_ID || scalar_name.item == *TYPE_STRING {
         AST::String
-    } else if scalar_name.item == *TYPE_FLOAT || scalar_name.item == *TYPE_INT {
+    } else if scalar_name.item == *TYPE_INT {
+        AST::Int
+    } else if scalar_name.item == *TYPE_FLOAT {
         AST::Number
     } else if scalar_name.item == *TYPE_BOOLEAN {
         AST::Boolean
@@ -2411,40 +2431,26 @@ fn transform_non_nullable_input_type(
             Type::InputObject(input_object_id) => {
                 let input_object = typegen_context.schema.input_object(*input_object_id);
                 if !input_object_types.contains_key(&input_object.name.item) {
-                    input_object_types
-                        .insert(input_object.name.item, GeneratedInputObject::Pending);
-
-                    let node = if typegen_context.project_config.typegen_config.one_of_type
-                        == OneOfGeneration::Strict
-                        && input_object
-                            .directives()
-                            .named(*ONE_OF_DIRECTIVE_NAME)
-                            .is_some()
+                    if let Some(import_path) =
+                        typegen_context.schema_type_import_path(input_object.name.item.0)
                     {
-                        AST::Union(SortedASTList::new(
-                            build_one_of_cases(
-                                typegen_context,
-                                input_object,
-                                input_object_types,
-                                encountered_enums,
-                                custom_scalars,
-                            )
-                            .map(AST::from)
-                            .collect(),
-                        ))
+                        input_object_types.insert(
+                            input_object.name.item,
+                            GeneratedInputObject::Imported(import_path),
+                        );
                     } else {
-                        build_input_object(
+                        input_object_types
+                            .insert(input_object.name.item, GeneratedInputObject::Pending);
+                        let node = build_input_object_type(
                             typegen_context,
                             input_object,
                             input_object_types,
                             encountered_enums,
                             custom_scalars,
-                        )
-                        .into()
-                    };
-
-                    input_object_types
-                        .insert(input_object.name.item, GeneratedInputObject::Resolved(node));
+                        );
+                        input_object_types
+                            .insert(input_object.name.item, GeneratedInputObject::Resolved(node));
+                    }
                 }
                 AST::Identifier(input_object.name.item.0)
             }
@@ -2456,6 +2462,44 @@ fn transform_non_nullable_input_type(
     }
 }
 
+/// Builds the type of an input object: an exact object, or a union of exact
+/// objects for `@oneOf` input objects under the "strict" `oneOfType` setting.
+pub(crate) fn build_input_object_type(
+    typegen_context: &TypegenContext<'_>,
+    input_object: &InputObject,
+    input_object_types: &mut InputObjectTypes,
+    encountered_enums: &mut EncounteredEnums,
+    custom_scalars: &mut CustomScalarsImports,
+) -> AST {
+    if typegen_context.project_config.typegen_config.one_of_type == OneOfGeneration::Strict
+        && input_object
+            .directives()
+            .named(*ONE_OF_DIRECTIVE_NAME)
+            .is_some()
+    {
+        AST::Union(SortedASTList::new(
+            build_one_of_cases(
+                typegen_context,
+                input_object,
+                input_object_types,
+                encountered_enums,
+                custom_scalars,
+            )
+            .map(AST::from)
+            .collect(),
+        ))
+    } else {
+        build_input_object(
+            typegen_context,
+            input_object,
+            input_object_types,
+            encountered_enums,
+            custom_scalars,
+        )
+        .into()
+    }
+}
+
 fn build_input_object(
     typegen_context: &TypegenContext<'_>,
     input_object: &InputObject,
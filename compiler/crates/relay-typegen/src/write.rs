@@ -15,7 +15,6 @@ use ::intern::intern;
 use ::intern::string_key::Intern;
 use ::intern::string_key::StringKey;
 use common::DirectiveName;
-use common::InputObjectName;
 use common::NamedItem;
 use graphql_ir::FragmentDefinition;
 use graphql_ir::FragmentDefinitionName;
@@ -34,6 +33,7 @@ use relay_transforms::RefetchableDerivedFromMetadata;
 use relay_transforms::RefetchableMetadata;
 use relay_transforms::RelayDirective;
 use schema::Schema;
+use schema::Type;
 
 use crate::FUTURE_ENUM_VALUE;
 use crate::KEY_CLIENTID;
@@ -50,12 +50,15 @@ use crate::VALIDATOR_EXPORT_NAME;
 use crate::typegen_state::EncounteredEnums;
 use crate::typegen_state::EncounteredFragment;
 use crate::typegen_state::EncounteredFragments;
+use crate::typegen_state::GeneratedInputObject;
 use crate::typegen_state::ImportedRawResponseTypes;
 use crate::typegen_state::ImportedResolverName;
 use crate::typegen_state::ImportedResolvers;
 use crate::typegen_state::InputObjectTypes;
 use crate::typegen_state::MatchFields;
 use crate::typegen_state::RuntimeImports;
+use crate::visit::build_input_object_type;
+use crate::visit::build_scalar_type;
 use crate::visit::get_data_type;
 use crate::visit::get_input_variables_type;
 use crate::visit::get_operation_type_export;
@@ -227,10 +230,6 @@ pub(crate) fn write_operation_type_exports_section(
         &mut encountered_enums,
         &mut custom_scalars,
     );
-    let input_object_types = input_object_types
-        .into_iter()
-        .map(|(key, val)| (key, val.unwrap_resolved_type()));
-
     write_enum_definitions(typegen_context, encountered_enums, writer)?;
     write_custom_scalar_imports(custom_scalars, writer)?;
     write_input_object_types(input_object_types, writer)?;
@@ -484,10 +483,6 @@ pub(crate) fn write_fragment_type_exports_section(
         }
     }
 
-    let input_object_types = input_object_types
-        .into_iter()
-        .map(|(key, val)| (key, val.unwrap_resolved_type()));
-
     write_input_object_types(input_object_types, writer)?;
     write_fragment_imports(
         typegen_context,
@@ -625,9 +620,7 @@ fn write_fragment_imports(
 
                     let fragment_import_path =
                         typegen_context.project_config.js_module_import_identifier(
-                            &typegen_context.project_config.artifact_path_for_definition(
-                                typegen_context.definition_source_location,
-                            ),
+                            &typegen_context.artifact_path,
                             &typegen_context.project_config.create_path_for_artifact(
                                 fragment_location.source_location(),
                                 current_referenced_fragment.to_string(),
@@ -718,9 +711,7 @@ fn write_split_raw_response_type_imports(
                 {
                     let artifact_import_path =
                         typegen_context.project_config.js_module_import_identifier(
-                            &typegen_context.project_config.artifact_path_for_definition(
-                                typegen_context.definition_source_location,
-                            ),
+                            &typegen_context.artifact_path,
                             &typegen_context.project_config.create_path_for_artifact(
                                 imported_raw_response_document_location.source_location(),
                                 imported_raw_response_type.to_string(),
@@ -844,15 +835,62 @@ fn generate_provided_variables_type(
 }
 
 fn write_input_object_types(
-    input_object_types: impl Iterator<Item = (InputObjectName, AST)>,
+    input_object_types: InputObjectTypes,
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
     for (type_identifier, input_object_type) in input_object_types {
-        writer.write_export_type(type_identifier.lookup(), &input_object_type)?;
+        match input_object_type {
+            GeneratedInputObject::Pending => panic!("Unexpected pending type"),
+            GeneratedInputObject::Resolved(ast) => {
+                writer.write_export_type(type_identifier.lookup(), &ast)?
+            }
+            GeneratedInputObject::Imported(import_path) => {
+                writer.write_import_type(&[type_identifier.lookup()], import_path.lookup())?
+            }
+        }
     }
     Ok(())
 }
 
+/// Writes the shared type module of an input object or custom scalar, see
+/// `TypegenConfig::schema_types_output`.
+pub(crate) fn write_schema_type_module(
+    typegen_context: &'_ TypegenContext<'_>,
+    type_: Type,
+    writer: &mut Box<dyn Writer>,
+) -> FmtResult {
+    let mut encountered_enums = Default::default();
+    let mut custom_scalars = CustomScalarsImports::default();
+    let mut input_object_types = InputObjectTypes::default();
+    let (type_name, type_ast) = match type_ {
+        Type::InputObject(input_object_id) => {
+            let input_object = typegen_context.schema.input_object(input_object_id);
+            // Marking the input object as pending makes recursive references
+            // to it resolve to the local type instead of a self-import.
+            input_object_types.insert(input_object.name.item, GeneratedInputObject::Pending);
+            let ast = build_input_object_type(
+                typegen_context,
+                input_object,
+                &mut input_object_types,
+                &mut encountered_enums,
+                &mut custom_scalars,
+            );
+            input_object_types.shift_remove(&input_object.name.item);
+            (input_object.name.item.0, ast)
+        }
+        Type::Scalar(scalar_id) => (
+            typegen_context.schema.scalar(scalar_id).name.item.0,
+            build_scalar_type(typegen_context, scalar_id, &mut custom_scalars),
+        ),
+        _ => panic!("Expected an input object or a scalar, got {type_:?}"),
+    };
+
+    write_enum_definitions(typegen_context, encountered_enums, writer)?;
+    write_custom_scalar_imports(custom_scalars, writer)?;
+    write_input_object_types(input_object_types, writer)?;
+    writer.write_export_type(type_name.lookup(), &type_ast)
+}
+
 /// Write the assignable fragment validator function.
 ///
 /// Validators accept an item which *may* be valid for assignment and returns either
@@ -953,7 +991,7 @@ fn write_abstract_validator_function(
 
     let (open_comment, close_comment) = match language {
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
-        TypegenLanguage::TypeScript => ("", ""),
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => ("", ""),
     };
 
     write!(
@@ -978,7 +1016,7 @@ fn write_abstract_validator_function(
             writer.write(&AST::Any)?;
             write!(writer, "{}) ", &close_comment)?;
         }
-        TypegenLanguage::TypeScript => {
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
             write!(writer, "value ")?;
         }
     }
@@ -1053,7 +1091,7 @@ fn write_concrete_validator_function(
     let typegen_language = typegen_context.project_config.typegen_config.language;
     let (open_comment, close_comment) = match typegen_language {
         TypegenLanguage::Flow | TypegenLanguage::JavaScript => ("/*", "*/"),
-        TypegenLanguage::TypeScript => ("", ""),
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => ("", ""),
     };
 
     write!(
@@ -1078,7 +1116,7 @@ fn write_concrete_validator_function(
             writer.write(&AST::Any)?;
             write!(writer, "{}) ", &close_comment)?;
         }
-        TypegenLanguage::TypeScript => {
+        TypegenLanguage::TypeScript | TypegenLanguage::Kotlin | TypegenLanguage::Swift => {
             write!(writer, "value ")?;
         }
     }
@@ -1116,7 +1154,7 @@ fn write_custom_scalar_imports(
     writer: &mut Box<dyn Writer>,
 ) -> FmtResult {
     for (name, path) in custom_scalars.iter().sorted_by_key(|(key, _)| *key) {
-        writer.write_import_type(&[name.lookup()], path.to_str().unwrap())?
+        writer.write_import_custom_scalar(name.lookup(), path.to_str().unwrap())?
     }
 
     Ok(())
@@ -21,6 +21,8 @@ use crate::KEY_FRAGMENT_TYPE;
 use crate::KEY_TYPENAME;
 use crate::flow::FlowPrinter;
 use crate::javascript::JavaScriptPrinter;
+use crate::kotlin::KotlinPrinter;
+use crate::swift::SwiftPrinter;
 use crate::typescript::TypeScriptPrinter;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -41,6 +43,8 @@ pub enum AST {
     ExactObject(ExactObject),
     InexactObject(InexactObject),
     Number,
+    /// A GraphQL `Int`. Printed like `Number` by the JavaScript writers.
+    Int,
     Boolean,
     Callable(Box<AST>),
     Any,
@@ -374,6 +378,11 @@ pub trait Writer: Write {
 
     fn write_import_type(&mut self, types: &[&str], from: &str) -> FmtResult;
 
+    /// Imports the type of a custom scalar, exported as `name` from `from`.
+    fn write_import_custom_scalar(&mut self, name: &str, from: &str) -> FmtResult {
+        self.write_import_type(&[name], from)
+    }
+
     fn write_import_fragment_type(&mut self, types: &[&str], from: &str) -> FmtResult;
 
     fn write_export_fragment_type(&mut self, name: &str) -> FmtResult;
@@ -393,6 +402,8 @@ pub(crate) fn new_writer_from_config(config: &TypegenConfig) -> Box<dyn Writer>
         TypegenLanguage::JavaScript => Box::<JavaScriptPrinter>::default(),
         TypegenLanguage::Flow => Box::new(FlowPrinter::new()),
         TypegenLanguage::TypeScript => Box::new(TypeScriptPrinter::new(config)),
+        TypegenLanguage::Kotlin => Box::<KotlinPrinter>::default(),
+        TypegenLanguage::Swift => Box::<SwiftPrinter>::default(),
     }
 }
 
@@ -0,0 +1,167 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<1431f5ae9e015c0122889184c5818123>>
+ */
+
+mod generate_kotlin;
+
+use generate_kotlin::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn conditional() {
+    let input = include_str!("generate_kotlin/fixtures/conditional.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/conditional.expected");
+    test_fixture(transform_fixture, file!(), "conditional.graphql", "generate_kotlin/fixtures/conditional.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalar_type_import() {
+    let input = include_str!("generate_kotlin/fixtures/custom-scalar-type-import.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/custom-scalar-type-import.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalar-type-import.graphql", "generate_kotlin/fixtures/custom-scalar-type-import.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_spread() {
+    let input = include_str!("generate_kotlin/fixtures/fragment-spread.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "fragment-spread.graphql", "generate_kotlin/fixtures/fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn inline_fragment() {
+    let input = include_str!("generate_kotlin/fixtures/inline-fragment.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/inline-fragment.expected");
+    test_fixture(transform_fixture, file!(), "inline-fragment.graphql", "generate_kotlin/fixtures/inline-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn linked_field() {
+    let input = include_str!("generate_kotlin/fixtures/linked-field.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/linked-field.expected");
+    test_fixture(transform_fixture, file!(), "linked-field.graphql", "generate_kotlin/fixtures/linked-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_input_has_array() {
+    let input = include_str!("generate_kotlin/fixtures/mutation-input-has-array.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/mutation-input-has-array.expected");
+    test_fixture(transform_fixture, file!(), "mutation-input-has-array.graphql", "generate_kotlin/fixtures/mutation-input-has-array.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_with_enums_on_fragment() {
+    let input = include_str!("generate_kotlin/fixtures/mutation-with-enums-on-fragment.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/mutation-with-enums-on-fragment.expected");
+    test_fixture(transform_fixture, file!(), "mutation-with-enums-on-fragment.graphql", "generate_kotlin/fixtures/mutation-with-enums-on-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_with_one_of() {
+    let input = include_str!("generate_kotlin/fixtures/mutation-with-one-of.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/mutation-with-one-of.expected");
+    test_fixture(transform_fixture, file!(), "mutation-with-one-of.graphql", "generate_kotlin/fixtures/mutation-with-one-of.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn plural_fragment() {
+    let input = include_str!("generate_kotlin/fixtures/plural-fragment.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/plural-fragment.expected");
+    test_fixture(transform_fixture, file!(), "plural-fragment.graphql", "generate_kotlin/fixtures/plural-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn query_with_match_fields() {
+    let input = include_str!("generate_kotlin/fixtures/query-with-match-fields.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/query-with-match-fields.expected");
+    test_fixture(transform_fixture, file!(), "query-with-match-fields.graphql", "generate_kotlin/fixtures/query-with-match-fields.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn recursive_fragment_spread() {
+    let input = include_str!("generate_kotlin/fixtures/recursive-fragment-spread.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/recursive-fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "recursive-fragment-spread.graphql", "generate_kotlin/fixtures/recursive-fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn recursive_fragments() {
+    let input = include_str!("generate_kotlin/fixtures/recursive-fragments.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/recursive-fragments.expected");
+    test_fixture(transform_fixture, file!(), "recursive-fragments.graphql", "generate_kotlin/fixtures/recursive-fragments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn refetchable_fragment() {
+    let input = include_str!("generate_kotlin/fixtures/refetchable-fragment.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/refetchable-fragment.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment.graphql", "generate_kotlin/fixtures/refetchable-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn required() {
+    let input = include_str!("generate_kotlin/fixtures/required.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/required.expected");
+    test_fixture(transform_fixture, file!(), "required.graphql", "generate_kotlin/fixtures/required.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn roots() {
+    let input = include_str!("generate_kotlin/fixtures/roots.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/roots.expected");
+    test_fixture(transform_fixture, file!(), "roots.graphql", "generate_kotlin/fixtures/roots.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn scalar_field() {
+    let input = include_str!("generate_kotlin/fixtures/scalar-field.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/scalar-field.expected");
+    test_fixture(transform_fixture, file!(), "scalar-field.graphql", "generate_kotlin/fixtures/scalar-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn simple() {
+    let input = include_str!("generate_kotlin/fixtures/simple.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/simple.expected");
+    test_fixture(transform_fixture, file!(), "simple.graphql", "generate_kotlin/fixtures/simple.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn typename_on_union() {
+    let input = include_str!("generate_kotlin/fixtures/typename-on-union.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/typename-on-union.expected");
+    test_fixture(transform_fixture, file!(), "typename-on-union.graphql", "generate_kotlin/fixtures/typename-on-union.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unmasked_fragment_spreads() {
+    let input = include_str!("generate_kotlin/fixtures/unmasked-fragment-spreads.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/unmasked-fragment-spreads.expected");
+    test_fixture(transform_fixture, file!(), "unmasked-fragment-spreads.graphql", "generate_kotlin/fixtures/unmasked-fragment-spreads.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn updatable_operation() {
+    let input = include_str!("generate_kotlin/fixtures/updatable-operation.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/updatable-operation.expected");
+    test_fixture(transform_fixture, file!(), "updatable-operation.graphql", "generate_kotlin/fixtures/updatable-operation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn updatable_operation_assignable_fragment() {
+    let input = include_str!("generate_kotlin/fixtures/updatable-operation-assignable-fragment.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/updatable-operation-assignable-fragment.expected");
+    test_fixture(transform_fixture, file!(), "updatable-operation-assignable-fragment.graphql", "generate_kotlin/fixtures/updatable-operation-assignable-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn updatable_operation_plural_field_no_spreads() {
+    let input = include_str!("generate_kotlin/fixtures/updatable-operation-plural-field-no-spreads.graphql");
+    let expected = include_str!("generate_kotlin/fixtures/updatable-operation-plural-field-no-spreads.expected");
+    test_fixture(transform_fixture, file!(), "updatable-operation-plural-field-no-spreads.graphql", "generate_kotlin/fixtures/updatable-operation-plural-field-no-spreads.expected", input, expected).await;
+}
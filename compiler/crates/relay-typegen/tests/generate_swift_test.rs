@@ -0,0 +1,167 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<a392fa1abbbd5e1abb0d6c88046cfb9f>>
+ */
+
+mod generate_swift;
+
+use generate_swift::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn conditional() {
+    let input = include_str!("generate_swift/fixtures/conditional.graphql");
+    let expected = include_str!("generate_swift/fixtures/conditional.expected");
+    test_fixture(transform_fixture, file!(), "conditional.graphql", "generate_swift/fixtures/conditional.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn custom_scalar_type_import() {
+    let input = include_str!("generate_swift/fixtures/custom-scalar-type-import.graphql");
+    let expected = include_str!("generate_swift/fixtures/custom-scalar-type-import.expected");
+    test_fixture(transform_fixture, file!(), "custom-scalar-type-import.graphql", "generate_swift/fixtures/custom-scalar-type-import.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn fragment_spread() {
+    let input = include_str!("generate_swift/fixtures/fragment-spread.graphql");
+    let expected = include_str!("generate_swift/fixtures/fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "fragment-spread.graphql", "generate_swift/fixtures/fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn inline_fragment() {
+    let input = include_str!("generate_swift/fixtures/inline-fragment.graphql");
+    let expected = include_str!("generate_swift/fixtures/inline-fragment.expected");
+    test_fixture(transform_fixture, file!(), "inline-fragment.graphql", "generate_swift/fixtures/inline-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn linked_field() {
+    let input = include_str!("generate_swift/fixtures/linked-field.graphql");
+    let expected = include_str!("generate_swift/fixtures/linked-field.expected");
+    test_fixture(transform_fixture, file!(), "linked-field.graphql", "generate_swift/fixtures/linked-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_input_has_array() {
+    let input = include_str!("generate_swift/fixtures/mutation-input-has-array.graphql");
+    let expected = include_str!("generate_swift/fixtures/mutation-input-has-array.expected");
+    test_fixture(transform_fixture, file!(), "mutation-input-has-array.graphql", "generate_swift/fixtures/mutation-input-has-array.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_with_enums_on_fragment() {
+    let input = include_str!("generate_swift/fixtures/mutation-with-enums-on-fragment.graphql");
+    let expected = include_str!("generate_swift/fixtures/mutation-with-enums-on-fragment.expected");
+    test_fixture(transform_fixture, file!(), "mutation-with-enums-on-fragment.graphql", "generate_swift/fixtures/mutation-with-enums-on-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn mutation_with_one_of() {
+    let input = include_str!("generate_swift/fixtures/mutation-with-one-of.graphql");
+    let expected = include_str!("generate_swift/fixtures/mutation-with-one-of.expected");
+    test_fixture(transform_fixture, file!(), "mutation-with-one-of.graphql", "generate_swift/fixtures/mutation-with-one-of.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn plural_fragment() {
+    let input = include_str!("generate_swift/fixtures/plural-fragment.graphql");
+    let expected = include_str!("generate_swift/fixtures/plural-fragment.expected");
+    test_fixture(transform_fixture, file!(), "plural-fragment.graphql", "generate_swift/fixtures/plural-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn query_with_match_fields() {
+    let input = include_str!("generate_swift/fixtures/query-with-match-fields.graphql");
+    let expected = include_str!("generate_swift/fixtures/query-with-match-fields.expected");
+    test_fixture(transform_fixture, file!(), "query-with-match-fields.graphql", "generate_swift/fixtures/query-with-match-fields.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn recursive_fragment_spread() {
+    let input = include_str!("generate_swift/fixtures/recursive-fragment-spread.graphql");
+    let expected = include_str!("generate_swift/fixtures/recursive-fragment-spread.expected");
+    test_fixture(transform_fixture, file!(), "recursive-fragment-spread.graphql", "generate_swift/fixtures/recursive-fragment-spread.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn recursive_fragments() {
+    let input = include_str!("generate_swift/fixtures/recursive-fragments.graphql");
+    let expected = include_str!("generate_swift/fixtures/recursive-fragments.expected");
+    test_fixture(transform_fixture, file!(), "recursive-fragments.graphql", "generate_swift/fixtures/recursive-fragments.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn refetchable_fragment() {
+    let input = include_str!("generate_swift/fixtures/refetchable-fragment.graphql");
+    let expected = include_str!("generate_swift/fixtures/refetchable-fragment.expected");
+    test_fixture(transform_fixture, file!(), "refetchable-fragment.graphql", "generate_swift/fixtures/refetchable-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn required() {
+    let input = include_str!("generate_swift/fixtures/required.graphql");
+    let expected = include_str!("generate_swift/fixtures/required.expected");
+    test_fixture(transform_fixture, file!(), "required.graphql", "generate_swift/fixtures/required.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn roots() {
+    let input = include_str!("generate_swift/fixtures/roots.graphql");
+    let expected = include_str!("generate_swift/fixtures/roots.expected");
+    test_fixture(transform_fixture, file!(), "roots.graphql", "generate_swift/fixtures/roots.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn scalar_field() {
+    let input = include_str!("generate_swift/fixtures/scalar-field.graphql");
+    let expected = include_str!("generate_swift/fixtures/scalar-field.expected");
+    test_fixture(transform_fixture, file!(), "scalar-field.graphql", "generate_swift/fixtures/scalar-field.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn simple() {
+    let input = include_str!("generate_swift/fixtures/simple.graphql");
+    let expected = include_str!("generate_swift/fixtures/simple.expected");
+    test_fixture(transform_fixture, file!(), "simple.graphql", "generate_swift/fixtures/simple.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn typename_on_union() {
+    let input = include_str!("generate_swift/fixtures/typename-on-union.graphql");
+    let expected = include_str!("generate_swift/fixtures/typename-on-union.expected");
+    test_fixture(transform_fixture, file!(), "typename-on-union.graphql", "generate_swift/fixtures/typename-on-union.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn unmasked_fragment_spreads() {
+    let input = include_str!("generate_swift/fixtures/unmasked-fragment-spreads.graphql");
+    let expected = include_str!("generate_swift/fixtures/unmasked-fragment-spreads.expected");
+    test_fixture(transform_fixture, file!(), "unmasked-fragment-spreads.graphql", "generate_swift/fixtures/unmasked-fragment-spreads.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn updatable_operation() {
+    let input = include_str!("generate_swift/fixtures/updatable-operation.graphql");
+    let expected = include_str!("generate_swift/fixtures/updatable-operation.expected");
+    test_fixture(transform_fixture, file!(), "updatable-operation.graphql", "generate_swift/fixtures/updatable-operation.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn updatable_operation_assignable_fragment() {
+    let input = include_str!("generate_swift/fixtures/updatable-operation-assignable-fragment.graphql");
+    let expected = include_str!("generate_swift/fixtures/updatable-operation-assignable-fragment.expected");
+    test_fixture(transform_fixture, file!(), "updatable-operation-assignable-fragment.graphql", "generate_swift/fixtures/updatable-operation-assignable-fragment.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn updatable_operation_plural_field_no_spreads() {
+    let input = include_str!("generate_swift/fixtures/updatable-operation-plural-field-no-spreads.graphql");
+    let expected = include_str!("generate_swift/fixtures/updatable-operation-plural-field-no-spreads.expected");
+    test_fixture(transform_fixture, file!(), "updatable-operation-plural-field-no-spreads.graphql", "generate_swift/fixtures/updatable-operation-plural-field-no-spreads.expected", input, expected).await;
+}
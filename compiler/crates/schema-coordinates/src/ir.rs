@@ -0,0 +1,310 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::Location;
+use common::Span;
+use graphql_ir::Argument;
+use graphql_ir::ConstantArgument;
+use graphql_ir::ConstantValue;
+use graphql_ir::Directive;
+use graphql_ir::Program;
+use graphql_ir::Selection;
+use graphql_ir::Value;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+
+use crate::SchemaCoordinate;
+use crate::resolve::SchemaElement;
+
+/// The schema element a selection refers to: the field for linked and scalar
+/// fields, the type condition for inline fragments. Fragment spreads and
+/// conditions don't refer to the schema.
+pub fn selection_schema_element(parent_type: Type, selection: &Selection) -> Option<SchemaElement> {
+    match selection {
+        Selection::LinkedField(field) => Some(SchemaElement::Field {
+            parent_type,
+            field: field.definition.item,
+        }),
+        Selection::ScalarField(field) => Some(SchemaElement::Field {
+            parent_type,
+            field: field.definition.item,
+        }),
+        Selection::InlineFragment(fragment) => fragment.type_condition.map(SchemaElement::Type),
+        Selection::FragmentSpread(_) | Selection::Condition(_) => None,
+    }
+}
+
+/// The coordinate of a selection, see `selection_schema_element`.
+pub fn selection_schema_coordinate(
+    schema: &SDLSchema,
+    parent_type: Type,
+    selection: &Selection,
+) -> Option<SchemaCoordinate> {
+    selection_schema_element(parent_type, selection)
+        .map(|element| element.schema_coordinate(schema))
+}
+
+/// The coordinate of an argument passed to the field `field` selected on
+/// `parent_type`.
+pub fn field_argument_schema_coordinate(
+    schema: &SDLSchema,
+    parent_type: Type,
+    field: FieldID,
+    argument: &Argument,
+) -> SchemaCoordinate {
+    SchemaElement::FieldArgument {
+        parent_type,
+        field,
+        argument_name: argument.name.item,
+    }
+    .schema_coordinate(schema)
+}
+
+/// The coordinate of an argument passed to `directive`.
+pub fn directive_argument_schema_coordinate(
+    directive: &Directive,
+    argument: &Argument,
+) -> SchemaCoordinate {
+    SchemaCoordinate::DirectiveArgument {
+        directive_name: directive.name.item.0,
+        argument_name: argument.name.item.0,
+    }
+}
+
+/// Find the innermost schema element referenced at `location` in any
+/// operation or fragment of `program`: a field, an argument, an input object
+/// field or enum value inside an argument value, a directive, or the type
+/// condition of a fragment.
+pub fn schema_element_at(program: &Program, location: Location) -> Option<SchemaElement> {
+    let finder = ElementFinder {
+        schema: &program.schema,
+        location,
+    };
+    program
+        .operations()
+        .find_map(|operation| {
+            if operation.name.location.source_location() != location.source_location() {
+                return None;
+            }
+            finder
+                .directives(&operation.directives)
+                .or_else(|| finder.selections(operation.type_, &operation.selections))
+        })
+        .or_else(|| {
+            program.fragments().find_map(|fragment| {
+                if fragment.name.location.source_location() != location.source_location() {
+                    return None;
+                }
+                finder
+                    .directives(&fragment.directives)
+                    .or_else(|| finder.selections(fragment.type_condition, &fragment.selections))
+            })
+        })
+}
+
+/// The coordinate of the schema element at `location`, see
+/// `schema_element_at`.
+pub fn schema_coordinate_at(program: &Program, location: Location) -> Option<SchemaCoordinate> {
+    schema_element_at(program, location).map(|element| element.schema_coordinate(&program.schema))
+}
+
+struct ElementFinder<'a> {
+    schema: &'a SDLSchema,
+    location: Location,
+}
+
+impl ElementFinder<'_> {
+    /// Unlike `Span::contains`, a location ending right at the target still
+    /// covers it, so that a cursor placed after a name refers to it.
+    fn covers(&self, location: Location) -> bool {
+        let span: Span = location.span();
+        let target = self.location.span();
+        span.start <= target.start && target.end <= span.end
+    }
+
+    fn selections(&self, parent_type: Type, selections: &[Selection]) -> Option<SchemaElement> {
+        selections
+            .iter()
+            .find_map(|selection| self.selection(parent_type, selection))
+    }
+
+    fn selection(&self, parent_type: Type, selection: &Selection) -> Option<SchemaElement> {
+        match selection {
+            Selection::LinkedField(field) => self
+                .field(parent_type, field.definition.item, &field.arguments)
+                .or_else(|| self.directives(&field.directives))
+                .or_else(|| {
+                    let field_type = self.schema.field(field.definition.item).type_.inner();
+                    self.selections(field_type, &field.selections)
+                })
+                .or_else(|| {
+                    self.covers(field.definition.location)
+                        .then(|| SchemaElement::Field {
+                            parent_type,
+                            field: field.definition.item,
+                        })
+                }),
+            Selection::ScalarField(field) => self
+                .field(parent_type, field.definition.item, &field.arguments)
+                .or_else(|| self.directives(&field.directives))
+                .or_else(|| {
+                    self.covers(field.definition.location)
+                        .then(|| SchemaElement::Field {
+                            parent_type,
+                            field: field.definition.item,
+                        })
+                }),
+            Selection::InlineFragment(fragment) => {
+                let type_condition = fragment.type_condition.unwrap_or(parent_type);
+                self.directives(&fragment.directives)
+                    .or_else(|| self.selections(type_condition, &fragment.selections))
+            }
+            Selection::FragmentSpread(spread) => self.directives(&spread.directives),
+            Selection::Condition(condition) => self.selections(parent_type, &condition.selections),
+        }
+    }
+
+    fn field(
+        &self,
+        parent_type: Type,
+        field: FieldID,
+        arguments: &[Argument],
+    ) -> Option<SchemaElement> {
+        let definitions = &self.schema.field(field).arguments;
+        arguments.iter().find_map(|argument| {
+            let definition = definitions.named(argument.name.item)?;
+            self.value(
+                definition.type_.inner(),
+                &argument.value.item,
+                Some(argument.value.location),
+            )
+            .or_else(|| {
+                (self.covers(argument.name.location) || self.covers(argument.value.location))
+                    .then_some(SchemaElement::FieldArgument {
+                        parent_type,
+                        field,
+                        argument_name: argument.name.item,
+                    })
+            })
+        })
+    }
+
+    fn directives(&self, directives: &[Directive]) -> Option<SchemaElement> {
+        directives.iter().find_map(|directive| {
+            let definition = self.schema.get_directive(directive.name.item)?;
+            directive
+                .arguments
+                .iter()
+                .find_map(|argument| {
+                    let argument_definition = definition.arguments.named(argument.name.item)?;
+                    self.value(
+                        argument_definition.type_.inner(),
+                        &argument.value.item,
+                        Some(argument.value.location),
+                    )
+                    .or_else(|| {
+                        (self.covers(argument.name.location)
+                            || self.covers(argument.value.location))
+                        .then_some(SchemaElement::DirectiveArgument {
+                            directive_name: directive.name.item,
+                            argument_name: argument.name.item,
+                        })
+                    })
+                })
+                .or_else(|| {
+                    self.covers(directive.name.location)
+                        .then_some(SchemaElement::Directive(directive.name.item))
+                })
+        })
+    }
+
+    /// Values nested in lists don't have their own location, those are
+    /// walked with `location: None` and can only be matched through the
+    /// locations of their object fields.
+    fn value(
+        &self,
+        type_: Type,
+        value: &Value,
+        location: Option<Location>,
+    ) -> Option<SchemaElement> {
+        match value {
+            Value::Constant(value) => self.constant_value(type_, value, location),
+            Value::Variable(_) => None,
+            Value::List(items) => items.iter().find_map(|item| self.value(type_, item, None)),
+            Value::Object(fields) => {
+                let Type::InputObject(input_object) = type_ else {
+                    return None;
+                };
+                let definitions = &self.schema.input_object(input_object).fields;
+                fields.iter().find_map(|field| {
+                    let definition = definitions.named(field.name.item)?;
+                    self.value(
+                        definition.type_.inner(),
+                        &field.value.item,
+                        Some(field.value.location),
+                    )
+                    .or_else(|| {
+                        (self.covers(field.name.location) || self.covers(field.value.location))
+                            .then_some(SchemaElement::InputField {
+                                input_object,
+                                field_name: field.name.item,
+                            })
+                    })
+                })
+            }
+        }
+    }
+
+    fn constant_value(
+        &self,
+        type_: Type,
+        value: &ConstantValue,
+        location: Option<Location>,
+    ) -> Option<SchemaElement> {
+        match value {
+            ConstantValue::Enum(enum_value) => match (type_, location) {
+                (Type::Enum(enum_), Some(location)) if self.covers(location) => {
+                    Some(SchemaElement::EnumValue {
+                        enum_,
+                        value: *enum_value,
+                    })
+                }
+                _ => None,
+            },
+            ConstantValue::Object(fields) => self.constant_object(type_, fields),
+            ConstantValue::List(items) => items
+                .iter()
+                .find_map(|item| self.constant_value(type_, item, None)),
+            _ => None,
+        }
+    }
+
+    fn constant_object(&self, type_: Type, fields: &[ConstantArgument]) -> Option<SchemaElement> {
+        let Type::InputObject(input_object) = type_ else {
+            return None;
+        };
+        let definitions = &self.schema.input_object(input_object).fields;
+        fields.iter().find_map(|field| {
+            let definition = definitions.named(field.name.item)?;
+            self.constant_value(
+                definition.type_.inner(),
+                &field.value.item,
+                Some(field.value.location),
+            )
+            .or_else(|| {
+                (self.covers(field.name.location) || self.covers(field.value.location)).then_some(
+                    SchemaElement::InputField {
+                        input_object,
+                        field_name: field.name.item,
+                    },
+                )
+            })
+        })
+    }
+}
@@ -16,6 +16,19 @@ use intern::string_key::StringKey;
 use logos::Lexer;
 use logos::Logos;
 
+mod ir;
+mod resolve;
+
+pub use ir::directive_argument_schema_coordinate;
+pub use ir::field_argument_schema_coordinate;
+pub use ir::schema_coordinate_at;
+pub use ir::schema_element_at;
+pub use ir::selection_schema_coordinate;
+pub use ir::selection_schema_element;
+pub use resolve::SchemaCoordinateResolutionError;
+pub use resolve::SchemaElement;
+pub use resolve::resolve_schema_coordinate;
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SchemaCoordinate {
     Directive {
@@ -120,7 +133,7 @@ enum Token {
     Colon,
 
     // https://spec.graphql.org/draft/#sec-Names
-    #[regex("[a-zA-Z][a-zA-Z0-9_]*", |lex| lex.slice().intern())]
+    #[regex("[_a-zA-Z][_a-zA-Z0-9]*", |lex| lex.slice().intern())]
     Name(StringKey),
 }
 
@@ -565,6 +578,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn strict_leading_underscore() {
+        assert_eq!(
+            parse_schema_coordinate("__Type.__typename"),
+            Ok(SchemaCoordinate::Member {
+                parent_name: "__Type".intern(),
+                member_name: "__typename".intern(),
+            })
+        );
+    }
+
     // ── parse_schema_coordinate_best_effort ─────────────────────────
 
     #[test]
@@ -877,4 +901,213 @@ mod tests {
         let b = parse_schema_coordinate("Query.user").unwrap();
         assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
     }
+
+    // ── resolve_schema_coordinate ───────────────────────────────────
+
+    const RESOLVE_SCHEMA: &str = r#"
+        directive @feature(name: String!) on FIELD
+
+        type Query {
+          user(id: ID!, size: Size = SMALL): User
+          search(filter: UserFilter): [User]
+        }
+
+        type User {
+          name: String
+          friends(first: Int, orderBy: Order): [User]
+        }
+
+        input UserFilter {
+          nameContains: String
+          order: Order
+        }
+
+        enum Order {
+          ASCENDING
+          DESCENDING
+        }
+
+        enum Size {
+          SMALL
+          LARGE
+        }
+
+        scalar ID
+    "#;
+
+    fn resolve_schema() -> schema::SDLSchema {
+        schema::build_schema(RESOLVE_SCHEMA).unwrap()
+    }
+
+    fn round_trip(coordinate: &str) -> String {
+        let schema = resolve_schema();
+        let element =
+            resolve_schema_coordinate(&schema, &parse_schema_coordinate(coordinate).unwrap())
+                .unwrap();
+        element.schema_coordinate(&schema).to_string()
+    }
+
+    fn resolution_error(coordinate: &str) -> String {
+        resolve_schema_coordinate(
+            &resolve_schema(),
+            &parse_schema_coordinate(coordinate).unwrap(),
+        )
+        .unwrap_err()
+        .to_string()
+    }
+
+    #[test]
+    fn resolve_round_trips() {
+        for coordinate in [
+            "User",
+            "User.friends",
+            "User.friends(orderBy:)",
+            "UserFilter.order",
+            "Order.DESCENDING",
+            "@feature",
+            "@feature(name:)",
+            "User.__typename",
+        ] {
+            assert_eq!(round_trip(coordinate), coordinate);
+        }
+    }
+
+    #[test]
+    fn resolve_kinds() {
+        let schema = resolve_schema();
+        let resolve = |coordinate: &str| {
+            resolve_schema_coordinate(&schema, &parse_schema_coordinate(coordinate).unwrap())
+                .unwrap()
+        };
+        assert!(matches!(
+            resolve("Order"),
+            SchemaElement::Type(schema::Type::Enum(_))
+        ));
+        assert!(matches!(resolve("Query.user"), SchemaElement::Field { .. }));
+        assert!(matches!(
+            resolve("Query.user(id:)"),
+            SchemaElement::FieldArgument { .. }
+        ));
+        assert!(matches!(
+            resolve("UserFilter.order"),
+            SchemaElement::InputField { .. }
+        ));
+        assert!(matches!(
+            resolve("Size.LARGE"),
+            SchemaElement::EnumValue { .. }
+        ));
+        assert!(matches!(
+            resolve("@feature(name:)"),
+            SchemaElement::DirectiveArgument { .. }
+        ));
+    }
+
+    #[test]
+    fn resolve_unknown_type() {
+        assert_eq!(
+            resolution_error("Usr"),
+            "Type `Usr` does not exist in the schema. Did you mean `User`?"
+        );
+    }
+
+    #[test]
+    fn resolve_unknown_member() {
+        assert_eq!(
+            resolution_error("User.frends"),
+            "Type `User` has no member `frends`. Did you mean `friends`?"
+        );
+        assert_eq!(
+            resolution_error("Order.ASCEND"),
+            "Type `Order` has no member `ASCEND`. Did you mean `ASCENDING`?"
+        );
+    }
+
+    #[test]
+    fn resolve_unknown_argument() {
+        assert_eq!(
+            resolution_error("User.friends(frist:)"),
+            "Field `User.friends` has no argument `frist`. Did you mean `first`?"
+        );
+        assert_eq!(
+            resolution_error("UserFilter.order(by:)"),
+            "`UserFilter.order` is not a field, only fields have arguments."
+        );
+    }
+
+    #[test]
+    fn resolve_unknown_directive() {
+        assert_eq!(
+            resolution_error("@featur"),
+            "Directive `@featur` does not exist in the schema. Did you mean `@feature`?"
+        );
+    }
+
+    // ── schema_coordinate_at ────────────────────────────────────────
+
+    /// Builds the program for `document`, with `|` marking the position to
+    /// look up, and returns the coordinate found there.
+    fn coordinate_at(document: &str) -> Option<String> {
+        let offset = document.find('|').unwrap() as u32;
+        let document = document.replace('|', "");
+        let source_location = common::SourceLocationKey::standalone("test.graphql");
+        let ast = graphql_syntax::parse_executable(&document, source_location).unwrap();
+        let schema = std::sync::Arc::new(resolve_schema());
+        let definitions = graphql_ir::build(&schema, &ast.definitions).unwrap();
+        let program = graphql_ir::Program::from_definitions(schema, definitions);
+        schema_coordinate_at(
+            &program,
+            common::Location::new(source_location, common::Span::new(offset, offset)),
+        )
+        .map(|coordinate| coordinate.to_string())
+    }
+
+    #[test]
+    fn coordinate_at_fields() {
+        assert_eq!(
+            coordinate_at("query Q { us|er(id: 4) { name } }").as_deref(),
+            Some("Query.user")
+        );
+        assert_eq!(
+            coordinate_at("query Q { user(id: 4) { name| } }").as_deref(),
+            Some("User.name")
+        );
+        assert_eq!(
+            coordinate_at("fragment F on User { ... on User { __type|name } }").as_deref(),
+            Some("User.__typename")
+        );
+        assert_eq!(coordinate_at("query Q { user(id: 4) { name } | }"), None);
+    }
+
+    #[test]
+    fn coordinate_at_arguments() {
+        assert_eq!(
+            coordinate_at("query Q { user(i|d: 4) { name } }").as_deref(),
+            Some("Query.user(id:)")
+        );
+        assert_eq!(
+            coordinate_at("query Q { user(id: 4, size: LA|RGE) { name } }").as_deref(),
+            Some("Size.LARGE")
+        );
+        assert_eq!(
+            coordinate_at("query Q($o: Order) { search(filter: {order: $|o}) { name } }")
+                .as_deref(),
+            Some("UserFilter.order")
+        );
+        assert_eq!(
+            coordinate_at("query Q { search(filter: {order: ASC|ENDING}) { name } }").as_deref(),
+            Some("Order.ASCENDING")
+        );
+    }
+
+    #[test]
+    fn coordinate_at_directives() {
+        assert_eq!(
+            coordinate_at(r#"query Q { user(id: 4) { name @feat|ure(name: "a") } }"#).as_deref(),
+            Some("@feature")
+        );
+        assert_eq!(
+            coordinate_at(r#"query Q { user(id: 4) { name @feature(name: "|a") } }"#).as_deref(),
+            Some("@feature(name:)")
+        );
+    }
 }
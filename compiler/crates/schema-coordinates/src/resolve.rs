@@ -0,0 +1,351 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt;
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::Location;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use schema::EnumID;
+use schema::FieldID;
+use schema::InputObjectID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use schema::suggestion_list::did_you_mean;
+use schema::suggestion_list::suggestion_list;
+
+use crate::SchemaCoordinate;
+
+const MAX_SUGGESTIONS: usize = 5;
+
+/// The element of a schema a `SchemaCoordinate` refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaElement {
+    Type(Type),
+    Field {
+        parent_type: Type,
+        field: FieldID,
+    },
+    FieldArgument {
+        parent_type: Type,
+        field: FieldID,
+        argument_name: ArgumentName,
+    },
+    InputField {
+        input_object: InputObjectID,
+        field_name: ArgumentName,
+    },
+    EnumValue {
+        enum_: EnumID,
+        value: StringKey,
+    },
+    Directive(DirectiveName),
+    DirectiveArgument {
+        directive_name: DirectiveName,
+        argument_name: ArgumentName,
+    },
+}
+
+impl SchemaElement {
+    /// The coordinate referring to this element. Fields are referred to
+    /// through the type they were selected on, which lets fields such as
+    /// `__typename` that don't have a single parent type round-trip.
+    pub fn schema_coordinate(&self, schema: &SDLSchema) -> SchemaCoordinate {
+        match *self {
+            SchemaElement::Type(type_) => SchemaCoordinate::Type {
+                name: schema.get_type_name(type_),
+            },
+            SchemaElement::Field { parent_type, field } => SchemaCoordinate::Member {
+                parent_name: schema.get_type_name(parent_type),
+                member_name: schema.field(field).name.item,
+            },
+            SchemaElement::FieldArgument {
+                parent_type,
+                field,
+                argument_name,
+            } => SchemaCoordinate::Argument {
+                parent_name: schema.get_type_name(parent_type),
+                member_name: schema.field(field).name.item,
+                argument_name: argument_name.0,
+            },
+            SchemaElement::InputField {
+                input_object,
+                field_name,
+            } => SchemaCoordinate::Member {
+                parent_name: schema.input_object(input_object).name.item.0,
+                member_name: field_name.0,
+            },
+            SchemaElement::EnumValue { enum_, value } => SchemaCoordinate::Member {
+                parent_name: schema.enum_(enum_).name.item.0,
+                member_name: value,
+            },
+            SchemaElement::Directive(name) => SchemaCoordinate::Directive { name: name.0 },
+            SchemaElement::DirectiveArgument {
+                directive_name,
+                argument_name,
+            } => SchemaCoordinate::DirectiveArgument {
+                directive_name: directive_name.0,
+                argument_name: argument_name.0,
+            },
+        }
+    }
+
+    /// The location of the definition of this element. Enum values don't
+    /// track their own location, the location of their enum is used instead.
+    pub fn definition_location(&self, schema: &SDLSchema) -> Option<Location> {
+        Some(match *self {
+            SchemaElement::Type(type_) => type_definition_location(schema, type_),
+            SchemaElement::Field { field, .. } => schema.field(field).name.location,
+            SchemaElement::FieldArgument {
+                field,
+                argument_name,
+                ..
+            } => {
+                schema
+                    .field(field)
+                    .arguments
+                    .named(argument_name)?
+                    .name
+                    .location
+            }
+            SchemaElement::InputField {
+                input_object,
+                field_name,
+            } => {
+                schema
+                    .input_object(input_object)
+                    .fields
+                    .named(field_name)?
+                    .name
+                    .location
+            }
+            SchemaElement::EnumValue { enum_, .. } => schema.enum_(enum_).name.location,
+            SchemaElement::Directive(name) => schema.get_directive(name)?.name.location,
+            SchemaElement::DirectiveArgument {
+                directive_name,
+                argument_name,
+            } => {
+                schema
+                    .get_directive(directive_name)?
+                    .arguments
+                    .named(argument_name)?
+                    .name
+                    .location
+            }
+        })
+    }
+}
+
+/// A coordinate that doesn't refer to an element of the schema, with the
+/// closest existing names for the part that couldn't be resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaCoordinateResolutionError {
+    pub message: String,
+    pub suggestions: Vec<StringKey>,
+}
+
+impl SchemaCoordinateResolutionError {
+    fn new(message: String, input: StringKey, options: &[StringKey]) -> Self {
+        Self {
+            message,
+            suggestions: suggestion_list(input, options, MAX_SUGGESTIONS),
+        }
+    }
+}
+
+impl fmt::Display for SchemaCoordinateResolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.message, did_you_mean(&self.suggestions))
+    }
+}
+
+impl std::error::Error for SchemaCoordinateResolutionError {}
+
+/// Resolve `coordinate` to the element of `schema` it refers to.
+pub fn resolve_schema_coordinate(
+    schema: &SDLSchema,
+    coordinate: &SchemaCoordinate,
+) -> Result<SchemaElement, SchemaCoordinateResolutionError> {
+    match *coordinate {
+        SchemaCoordinate::Type { name } => resolve_type(schema, name).map(SchemaElement::Type),
+        SchemaCoordinate::Member {
+            parent_name,
+            member_name,
+        } => resolve_member(schema, parent_name, member_name),
+        SchemaCoordinate::Argument {
+            parent_name,
+            member_name,
+            argument_name,
+        } => {
+            let (parent_type, field) = match resolve_member(schema, parent_name, member_name)? {
+                SchemaElement::Field { parent_type, field } => (parent_type, field),
+                _ => {
+                    return Err(SchemaCoordinateResolutionError {
+                        message: format!(
+                            "`{}.{}` is not a field, only fields have arguments.",
+                            parent_name, member_name
+                        ),
+                        suggestions: vec![],
+                    });
+                }
+            };
+            let arguments = &schema.field(field).arguments;
+            if arguments.named(ArgumentName(argument_name)).is_none() {
+                return Err(SchemaCoordinateResolutionError::new(
+                    format!(
+                        "Field `{}.{}` has no argument `{}`.",
+                        parent_name, member_name, argument_name
+                    ),
+                    argument_name,
+                    &arguments
+                        .iter()
+                        .map(|argument| argument.name.item.0)
+                        .collect::<Vec<_>>(),
+                ));
+            }
+            Ok(SchemaElement::FieldArgument {
+                parent_type,
+                field,
+                argument_name: ArgumentName(argument_name),
+            })
+        }
+        SchemaCoordinate::Directive { name } => {
+            resolve_directive(schema, name).map(SchemaElement::Directive)
+        }
+        SchemaCoordinate::DirectiveArgument {
+            directive_name,
+            argument_name,
+        } => {
+            let directive_name = resolve_directive(schema, directive_name)?;
+            let arguments = &schema.get_directive(directive_name).unwrap().arguments;
+            if arguments.named(ArgumentName(argument_name)).is_none() {
+                return Err(SchemaCoordinateResolutionError::new(
+                    format!(
+                        "Directive `@{}` has no argument `{}`.",
+                        directive_name, argument_name
+                    ),
+                    argument_name,
+                    &arguments
+                        .iter()
+                        .map(|argument| argument.name.item.0)
+                        .collect::<Vec<_>>(),
+                ));
+            }
+            Ok(SchemaElement::DirectiveArgument {
+                directive_name,
+                argument_name: ArgumentName(argument_name),
+            })
+        }
+    }
+}
+
+fn resolve_type(
+    schema: &SDLSchema,
+    name: StringKey,
+) -> Result<Type, SchemaCoordinateResolutionError> {
+    schema.get_type(name).ok_or_else(|| {
+        SchemaCoordinateResolutionError::new(
+            format!("Type `{}` does not exist in the schema.", name),
+            name,
+            &schema
+                .get_type_map()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>(),
+        )
+    })
+}
+
+fn resolve_member(
+    schema: &SDLSchema,
+    parent_name: StringKey,
+    member_name: StringKey,
+) -> Result<SchemaElement, SchemaCoordinateResolutionError> {
+    let parent_type = resolve_type(schema, parent_name)?;
+    let member_names: Vec<StringKey> = match parent_type {
+        Type::Object(_) | Type::Interface(_) => {
+            if let Some(field) = schema.named_field(parent_type, member_name) {
+                return Ok(SchemaElement::Field { parent_type, field });
+            }
+            let fields = match parent_type {
+                Type::Object(id) => &schema.object(id).fields,
+                Type::Interface(id) => &schema.interface(id).fields,
+                _ => unreachable!(),
+            };
+            fields
+                .iter()
+                .map(|field| schema.field(*field).name.item)
+                .collect()
+        }
+        Type::InputObject(id) => {
+            let fields = &schema.input_object(id).fields;
+            if fields.named(ArgumentName(member_name)).is_some() {
+                return Ok(SchemaElement::InputField {
+                    input_object: id,
+                    field_name: ArgumentName(member_name),
+                });
+            }
+            fields.iter().map(|field| field.name.item.0).collect()
+        }
+        Type::Enum(id) => {
+            let values = &schema.enum_(id).values;
+            if values.iter().any(|value| value.value == member_name) {
+                return Ok(SchemaElement::EnumValue {
+                    enum_: id,
+                    value: member_name,
+                });
+            }
+            values.iter().map(|value| value.value).collect()
+        }
+        Type::Scalar(_) | Type::Union(_) => {
+            return Err(SchemaCoordinateResolutionError {
+                message: format!("Type `{}` has no members.", parent_name),
+                suggestions: vec![],
+            });
+        }
+    };
+    Err(SchemaCoordinateResolutionError::new(
+        format!("Type `{}` has no member `{}`.", parent_name, member_name),
+        member_name,
+        &member_names,
+    ))
+}
+
+fn resolve_directive(
+    schema: &SDLSchema,
+    name: StringKey,
+) -> Result<DirectiveName, SchemaCoordinateResolutionError> {
+    let name = DirectiveName(name);
+    if schema.get_directive(name).is_some() {
+        return Ok(name);
+    }
+    let mut error = SchemaCoordinateResolutionError::new(
+        format!("Directive `@{}` does not exist in the schema.", name),
+        name.0,
+        &schema
+            .get_directives()
+            .map(|directive| directive.name.item.0)
+            .collect::<Vec<_>>(),
+    );
+    for suggestion in error.suggestions.iter_mut() {
+        *suggestion = format!("@{}", suggestion).intern();
+    }
+    Err(error)
+}
+
+fn type_definition_location(schema: &SDLSchema, type_: Type) -> Location {
+    match type_ {
+        Type::Enum(id) => schema.enum_(id).name.location,
+        Type::InputObject(id) => schema.input_object(id).name.location,
+        Type::Interface(id) => schema.interface(id).name.location,
+        Type::Object(id) => schema.object(id).name.location,
+        Type::Scalar(id) => schema.scalar(id).name.location,
+        Type::Union(id) => schema.union(id).name.location,
+    }
+}
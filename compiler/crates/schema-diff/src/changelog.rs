@@ -0,0 +1,419 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Printers that describe a `SchemaChange` as a changelog, either as
+//! human-readable text or as JSON.
+
+use intern::string_key::StringKey;
+use serde::Serialize;
+
+use crate::definitions::ArgumentChange;
+use crate::definitions::DefinitionChange;
+use crate::definitions::SchemaChange;
+use crate::definitions::TypeChange;
+
+/// A single line of a schema changelog.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ChangelogEntry {
+    /// The schema coordinate of the changed element, e.g. `User.name(first:)`.
+    pub coordinate: String,
+    /// A stable, snake_case identifier of the kind of change, e.g.
+    /// `field_removed`.
+    pub kind: &'static str,
+    pub message: String,
+}
+
+/// Returns the changelog entries of `change`, sorted by coordinate.
+pub fn changelog_entries(change: &SchemaChange) -> Vec<ChangelogEntry> {
+    let mut entries = vec![];
+    match change {
+        SchemaChange::None => {}
+        SchemaChange::GenericChange => entries.push(ChangelogEntry {
+            coordinate: String::new(),
+            kind: "generic_change",
+            message: "The schema changed in a way that can't be described in detail".to_string(),
+        }),
+        SchemaChange::InvalidSchema => entries.push(ChangelogEntry {
+            coordinate: String::new(),
+            kind: "invalid_schema",
+            message: "The schema is invalid".to_string(),
+        }),
+        SchemaChange::DefinitionChanges(changes) => {
+            for change in changes {
+                add_definition_change(&mut entries, change);
+            }
+        }
+    }
+    entries.sort();
+    entries
+}
+
+/// Prints `change` as a human-readable changelog, one change per line.
+pub fn print_changelog(change: &SchemaChange) -> String {
+    let entries = changelog_entries(change);
+    if entries.is_empty() {
+        return "No changes\n".to_string();
+    }
+    entries
+        .iter()
+        .map(|entry| format!("- {}\n", entry.message))
+        .collect()
+}
+
+/// Prints `change` as a pretty-printed JSON array of `ChangelogEntry`.
+pub fn print_changelog_json(change: &SchemaChange) -> String {
+    serde_json::to_string_pretty(&changelog_entries(change)).unwrap()
+}
+
+fn add_definition_change(entries: &mut Vec<ChangelogEntry>, change: &DefinitionChange) {
+    let mut push = |kind: &'static str, coordinate: String, message: String| {
+        entries.push(ChangelogEntry {
+            coordinate,
+            kind,
+            message,
+        })
+    };
+    match change {
+        DefinitionChange::EnumAdded(name) => push(
+            "type_added",
+            name.to_string(),
+            format!("Enum `{name}` was added"),
+        ),
+        DefinitionChange::EnumRemoved(name) => push(
+            "type_removed",
+            name.to_string(),
+            format!("Enum `{name}` was removed"),
+        ),
+        DefinitionChange::EnumChanged {
+            name,
+            added,
+            removed,
+        } => {
+            for value in added {
+                push(
+                    "enum_value_added",
+                    format!("{name}.{value}"),
+                    format!("Value `{value}` was added to enum `{name}`"),
+                );
+            }
+            for value in removed {
+                push(
+                    "enum_value_removed",
+                    format!("{name}.{value}"),
+                    format!("Value `{value}` was removed from enum `{name}`"),
+                );
+            }
+            if added.is_empty() && removed.is_empty() {
+                push(
+                    "enum_values_reordered",
+                    name.to_string(),
+                    format!("The values of enum `{name}` were reordered"),
+                );
+            }
+        }
+        DefinitionChange::UnionAdded(name) => push(
+            "type_added",
+            name.to_string(),
+            format!("Union `{name}` was added"),
+        ),
+        DefinitionChange::UnionRemoved(name) => push(
+            "type_removed",
+            name.to_string(),
+            format!("Union `{name}` was removed"),
+        ),
+        DefinitionChange::UnionChanged {
+            name,
+            added,
+            removed,
+        } => {
+            for member in added {
+                push(
+                    "union_member_added",
+                    name.to_string(),
+                    format!("Type `{member}` was added to union `{name}`"),
+                );
+            }
+            for member in removed {
+                push(
+                    "union_member_removed",
+                    name.to_string(),
+                    format!("Type `{member}` was removed from union `{name}`"),
+                );
+            }
+        }
+        DefinitionChange::ScalarAdded(name) => push(
+            "type_added",
+            name.to_string(),
+            format!("Scalar `{name}` was added"),
+        ),
+        DefinitionChange::ScalarRemoved(name) => push(
+            "type_removed",
+            name.to_string(),
+            format!("Scalar `{name}` was removed"),
+        ),
+        DefinitionChange::ScalarChanged {
+            name,
+            previous_specified_by,
+            specified_by,
+        } => {
+            let message = match (previous_specified_by, specified_by) {
+                (None, Some(url)) => format!("Scalar `{name}` is now specified by `{url}`"),
+                (Some(_), None) => format!("Scalar `{name}` is no longer specified by a URL"),
+                (Some(previous), Some(url)) => {
+                    format!("Scalar `{name}` is now specified by `{url}` instead of `{previous}`")
+                }
+                (None, None) => format!("The specification URL of scalar `{name}` changed"),
+            };
+            push("scalar_specified_by_changed", name.to_string(), message)
+        }
+        DefinitionChange::InputObjectAdded(name) => push(
+            "type_added",
+            name.to_string(),
+            format!("Input object `{name}` was added"),
+        ),
+        DefinitionChange::InputObjectRemoved(name) => push(
+            "type_removed",
+            name.to_string(),
+            format!("Input object `{name}` was removed"),
+        ),
+        DefinitionChange::InputObjectChanged {
+            name,
+            added,
+            removed,
+        } => add_member_changes(entries, "Input field", *name, added, removed),
+        DefinitionChange::InterfaceAdded(name) => push(
+            "type_added",
+            name.to_string(),
+            format!("Interface `{name}` was added"),
+        ),
+        DefinitionChange::InterfaceRemoved(name) => push(
+            "type_removed",
+            name.to_string(),
+            format!("Interface `{name}` was removed"),
+        ),
+        DefinitionChange::InterfaceChanged {
+            name,
+            added,
+            removed,
+            changed,
+        } => {
+            add_member_changes(entries, "Field", *name, added, removed);
+            add_argument_changes(entries, *name, changed);
+        }
+        DefinitionChange::ObjectAdded(name) => push(
+            "type_added",
+            name.to_string(),
+            format!("Object `{name}` was added"),
+        ),
+        DefinitionChange::ObjectRemoved { name, .. } => push(
+            "type_removed",
+            name.to_string(),
+            format!("Object `{name}` was removed"),
+        ),
+        DefinitionChange::ObjectChanged {
+            name,
+            added,
+            removed,
+            changed,
+            interfaces_added,
+            interfaces_removed,
+        } => {
+            for interface in interfaces_added {
+                push(
+                    "interface_implementation_added",
+                    name.to_string(),
+                    format!("Object `{name}` now implements interface `{interface}`"),
+                );
+            }
+            for interface in interfaces_removed {
+                push(
+                    "interface_implementation_removed",
+                    name.to_string(),
+                    format!("Object `{name}` no longer implements interface `{interface}`"),
+                );
+            }
+            add_member_changes(entries, "Field", *name, added, removed);
+            add_argument_changes(entries, *name, changed);
+        }
+        DefinitionChange::DirectiveAdded(name) => push(
+            "directive_added",
+            format!("@{name}"),
+            format!("Directive `@{name}` was added"),
+        ),
+        DefinitionChange::DirectiveRemoved(name) => push(
+            "directive_removed",
+            format!("@{name}"),
+            format!("Directive `@{name}` was removed"),
+        ),
+        DefinitionChange::DirectiveChanged {
+            name,
+            added,
+            removed,
+            locations_added,
+            locations_removed,
+        } => {
+            for location in locations_added {
+                push(
+                    "directive_location_added",
+                    format!("@{name}"),
+                    format!("Location `{location}` was added to directive `@{name}`"),
+                );
+            }
+            for location in locations_removed {
+                push(
+                    "directive_location_removed",
+                    format!("@{name}"),
+                    format!("Location `{location}` was removed from directive `@{name}`"),
+                );
+            }
+            add_typed_changes(
+                entries,
+                added,
+                removed,
+                |argument| format!("@{name}({argument}:)"),
+                |argument| format!("argument `{argument}` of directive `@{name}`"),
+                [
+                    "argument_added",
+                    "argument_removed",
+                    "argument_type_changed",
+                ],
+            );
+        }
+        DefinitionChange::DefaultValueChanged {
+            coordinate,
+            previous,
+            current,
+        } => {
+            let message = match (previous, current) {
+                (None, Some(current)) => {
+                    format!("`{coordinate}` now has the default value `{current}`")
+                }
+                (Some(previous), None) => {
+                    format!("`{coordinate}` no longer has the default value `{previous}`")
+                }
+                (Some(previous), Some(current)) => format!(
+                    "The default value of `{coordinate}` changed from `{previous}` to `{current}`"
+                ),
+                (None, None) => format!("The default value of `{coordinate}` changed"),
+            };
+            push("default_value_changed", coordinate.to_string(), message)
+        }
+        DefinitionChange::DeprecationAdded { coordinate, reason } => {
+            let message = match reason {
+                Some(reason) => format!("`{coordinate}` was deprecated: {reason}"),
+                None => format!("`{coordinate}` was deprecated"),
+            };
+            push("deprecation_added", coordinate.to_string(), message)
+        }
+        DefinitionChange::DeprecationRemoved { coordinate } => push(
+            "deprecation_removed",
+            coordinate.to_string(),
+            format!("`{coordinate}` is no longer deprecated"),
+        ),
+    }
+}
+
+/// Fields (or input fields) whose type changed are reported as both removed
+/// and added by the diff; these are paired into a single type change.
+fn add_member_changes(
+    entries: &mut Vec<ChangelogEntry>,
+    member_kind: &str,
+    parent_name: StringKey,
+    added: &[TypeChange],
+    removed: &[TypeChange],
+) {
+    let label = member_kind.to_lowercase();
+    add_typed_changes(
+        entries,
+        added,
+        removed,
+        |member| format!("{parent_name}.{member}"),
+        |member| format!("{label} `{parent_name}.{member}`"),
+        if member_kind == "Field" {
+            ["field_added", "field_removed", "field_type_changed"]
+        } else {
+            [
+                "input_field_added",
+                "input_field_removed",
+                "input_field_type_changed",
+            ]
+        },
+    );
+}
+
+fn add_argument_changes(
+    entries: &mut Vec<ChangelogEntry>,
+    parent_name: StringKey,
+    changed: &[ArgumentChange],
+) {
+    for field in changed {
+        let field_name = field.name;
+        add_typed_changes(
+            entries,
+            &field.added,
+            &field.removed,
+            |argument| format!("{parent_name}.{field_name}({argument}:)"),
+            |argument| format!("argument `{argument}` of field `{parent_name}.{field_name}`"),
+            [
+                "argument_added",
+                "argument_removed",
+                "argument_type_changed",
+            ],
+        );
+    }
+}
+
+fn add_typed_changes(
+    entries: &mut Vec<ChangelogEntry>,
+    added: &[TypeChange],
+    removed: &[TypeChange],
+    coordinate: impl Fn(StringKey) -> String,
+    label: impl Fn(StringKey) -> String,
+    [added_kind, removed_kind, changed_kind]: [&'static str; 3],
+) {
+    for change in added {
+        let entry = match removed.iter().find(|removed| removed.name == change.name) {
+            Some(previous) => ChangelogEntry {
+                coordinate: coordinate(change.name),
+                kind: changed_kind,
+                message: capitalize(format!(
+                    "{} changed type from `{}` to `{}`",
+                    label(change.name),
+                    previous.type_,
+                    change.type_
+                )),
+            },
+            None => ChangelogEntry {
+                coordinate: coordinate(change.name),
+                kind: added_kind,
+                message: capitalize(format!(
+                    "{} was added with type `{}`",
+                    label(change.name),
+                    change.type_
+                )),
+            },
+        };
+        entries.push(entry);
+    }
+    for change in removed {
+        if added.iter().all(|added| added.name != change.name) {
+            entries.push(ChangelogEntry {
+                coordinate: coordinate(change.name),
+                kind: removed_kind,
+                message: capitalize(format!("{} was removed", label(change.name))),
+            });
+        }
+    }
+}
+
+fn capitalize(message: String) -> String {
+    let mut chars = message.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => message,
+    }
+}
@@ -20,6 +20,7 @@ use schema::Schema;
 use crate::definitions::ArgumentChange;
 use crate::definitions::DefinitionChange;
 use crate::definitions::SchemaChange;
+use crate::definitions::SchemaCoordinate;
 use crate::definitions::Type;
 use crate::definitions::TypeChange;
 
@@ -174,10 +175,17 @@ impl SchemaChange {
                         | DefinitionChange::EnumAdded(_)
                         | DefinitionChange::ScalarAdded(_)
                         | DefinitionChange::UnionAdded(_)
-                        | DefinitionChange::InputObjectAdded(_) => {}
+                        | DefinitionChange::InputObjectAdded(_)
+                        | DefinitionChange::DirectiveAdded(_) => {}
+
+                        // Deprecations and `@specifiedBy` URLs don't affect
+                        // the generated artifacts.
+                        DefinitionChange::ScalarChanged { .. }
+                        | DefinitionChange::DeprecationAdded { .. }
+                        | DefinitionChange::DeprecationRemoved { .. } => {}
 
                         // safe with incremental build changes
-                        DefinitionChange::EnumChanged { name }
+                        DefinitionChange::EnumChanged { name, .. }
                         | DefinitionChange::EnumRemoved(name) => {
                             needs_incremental_build
                                 .insert(IncrementalBuildSchemaChange::Enum(name));
@@ -232,6 +240,27 @@ impl SchemaChange {
                                 .insert(IncrementalBuildSchemaChange::InputObject(name));
                         }
 
+                        // Directive definitions don't appear in the generated
+                        // artifacts, and build_ir_in_relay_mode validates
+                        // all definitions regardless, so uses of a removed
+                        // or changed directive are still reported.
+                        DefinitionChange::DirectiveChanged { .. }
+                        | DefinitionChange::DirectiveRemoved(_) => {}
+
+                        // Input fields with a default value are optional in
+                        // the generated types of the input object. Defaults
+                        // of field and directive arguments are applied by the
+                        // server and don't affect the artifacts.
+                        DefinitionChange::DefaultValueChanged { coordinate, .. } => {
+                            if let SchemaCoordinate::Member { parent_name, .. } = coordinate
+                                && let Some(schema::Type::InputObject(_)) =
+                                    schema.get_type(parent_name)
+                            {
+                                needs_incremental_build
+                                    .insert(IncrementalBuildSchemaChange::InputObject(parent_name));
+                            }
+                        }
+
                         // unsafe changes
                         DefinitionChange::ScalarRemoved(_)
                         | DefinitionChange::InterfaceRemoved(_) => {
@@ -7,14 +7,18 @@
 
 use std::fmt;
 
+pub use graphql_syntax::DirectiveLocation;
 pub use graphql_syntax::TypeAnnotation;
 use intern::string_key::StringKey;
+pub use schema_coordinates::SchemaCoordinate;
 
 #[derive(Eq, PartialEq, PartialOrd, Ord)]
 pub enum DefinitionChange {
     EnumAdded(StringKey),
     EnumChanged {
         name: StringKey,
+        added: Vec<StringKey>,
+        removed: Vec<StringKey>,
     },
     EnumRemoved(StringKey),
     UnionAdded(StringKey),
@@ -26,6 +30,12 @@ pub enum DefinitionChange {
     UnionRemoved(StringKey),
     ScalarAdded(StringKey),
     ScalarRemoved(StringKey),
+    /// The `@specifiedBy(url:)` of a scalar was added, removed or changed.
+    ScalarChanged {
+        name: StringKey,
+        previous_specified_by: Option<StringKey>,
+        specified_by: Option<StringKey>,
+    },
     InputObjectAdded(StringKey),
     InputObjectChanged {
         name: StringKey,
@@ -54,12 +64,45 @@ pub enum DefinitionChange {
         name: StringKey,
         interfaces: Vec<StringKey>,
     },
+    DirectiveAdded(StringKey),
+    DirectiveChanged {
+        name: StringKey,
+        added: Vec<TypeChange>,
+        removed: Vec<TypeChange>,
+        locations_added: Vec<DirectiveLocation>,
+        locations_removed: Vec<DirectiveLocation>,
+    },
+    DirectiveRemoved(StringKey),
+    /// The default value of a field argument, input field or directive
+    /// argument changed. Values are printed as GraphQL literals.
+    DefaultValueChanged {
+        coordinate: SchemaCoordinate,
+        previous: Option<String>,
+        current: Option<String>,
+    },
+    /// A field, argument, input field or enum value became `@deprecated`.
+    DeprecationAdded {
+        coordinate: SchemaCoordinate,
+        reason: Option<StringKey>,
+    },
+    /// A field, argument, input field or enum value is no longer
+    /// `@deprecated`.
+    DeprecationRemoved {
+        coordinate: SchemaCoordinate,
+    },
 }
 
 impl fmt::Debug for DefinitionChange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            DefinitionChange::EnumChanged { name } => write!(f, "EnumChanged {name:?}.",),
+            DefinitionChange::EnumChanged {
+                name,
+                added,
+                removed,
+            } => write!(
+                f,
+                "EnumChanged {name:?}: added:{added:?} removed:{removed:?}",
+            ),
             DefinitionChange::UnionChanged {
                 added,
                 removed,
@@ -110,6 +153,40 @@ impl fmt::Debug for DefinitionChange {
             DefinitionChange::InterfaceRemoved(name) => write!(f, "InterfaceRemoved {name:?}"),
             DefinitionChange::ObjectAdded(name) => write!(f, "ObjectAdded {name:?}"),
             DefinitionChange::ObjectRemoved { name, .. } => write!(f, "ObjectRemoved {name:?}"),
+            DefinitionChange::ScalarChanged {
+                name,
+                previous_specified_by,
+                specified_by,
+            } => write!(
+                f,
+                "ScalarChanged {name:?}: specifiedBy:{previous_specified_by:?} -> {specified_by:?}"
+            ),
+            DefinitionChange::DirectiveAdded(name) => write!(f, "DirectiveAdded {name:?}"),
+            DefinitionChange::DirectiveChanged {
+                name,
+                added,
+                removed,
+                locations_added,
+                locations_removed,
+            } => write!(
+                f,
+                "DirectiveChanged {name:?}: added:{added:?} removed:{removed:?}; locations: added:{locations_added:?} removed:{locations_removed:?}"
+            ),
+            DefinitionChange::DirectiveRemoved(name) => write!(f, "DirectiveRemoved {name:?}"),
+            DefinitionChange::DefaultValueChanged {
+                coordinate,
+                previous,
+                current,
+            } => write!(
+                f,
+                "DefaultValueChanged {coordinate}: {previous:?} -> {current:?}"
+            ),
+            DefinitionChange::DeprecationAdded { coordinate, reason } => {
+                write!(f, "DeprecationAdded {coordinate}: {reason:?}")
+            }
+            DefinitionChange::DeprecationRemoved { coordinate } => {
+                write!(f, "DeprecationRemoved {coordinate}")
+            }
         }
     }
 }
@@ -150,6 +227,17 @@ impl From<TypeAnnotation> for Type {
     }
 }
 
+/// Prints the type in SDL notation, e.g. `[String!]`.
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Named(name) => write!(f, "{name}"),
+            Type::List(inner) => write!(f, "[{inner}]"),
+            Type::NonNull(inner) => write!(f, "{inner}!"),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, PartialOrd, Ord)]
 pub struct TypeChange {
     pub name: StringKey,
@@ -9,8 +9,14 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+pub mod changelog;
 pub mod check;
 pub mod definitions;
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
 use common::SourceLocationKey;
 use fnv::FnvHashMap;
 use fnv::FnvHashSet;
@@ -26,14 +32,21 @@ use graphql_syntax::ScalarTypeDefinition;
 use graphql_syntax::TypeSystemDefinition;
 use graphql_syntax::UnionTypeDefinition;
 use graphql_syntax::parse_schema_document;
+use intern::string_key::Intern;
 use intern::string_key::StringKey;
+use schema::DirectiveValue;
 use schema::SDLSchema;
 use schema::Schema;
 use schema::Type as SchemaType;
 use schema::TypeReference;
+use schema::definitions::Deprecation;
 
 use crate::definitions::*;
 
+static SPECIFIED_BY: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("specifiedBy".intern()));
+static URL: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("url".intern()));
+
 fn add_definition(changes: &mut Vec<DefinitionChange>, def: &TypeSystemDefinition) -> bool {
     use DefinitionChange::*;
 
@@ -320,17 +333,17 @@ fn diff(current: Vec<TypeSystemDefinition>, previous: Vec<TypeSystemDefinition>)
                         })),
                         Some(previous_values),
                     ) => {
-                        if values.items.len() != previous_values.items.len() {
-                            changes.push(DefinitionChange::EnumChanged { name: name.value });
-                        } else {
-                            for i in 0..values.items.len() {
-                                if values.items[i].name.value != previous_values.items[i].name.value
-                                {
-                                    changes
-                                        .push(DefinitionChange::EnumChanged { name: name.value });
-                                    break;
-                                }
-                            }
+                        let current_values: Vec<StringKey> =
+                            values.items.iter().map(|value| value.name.value).collect();
+                        let previous_values: Vec<StringKey> = previous_values
+                            .items
+                            .iter()
+                            .map(|value| value.name.value)
+                            .collect();
+                        if let Some(change) =
+                            compare_enum_values(name.value, &current_values, &previous_values)
+                        {
+                            changes.push(change);
                         }
                     }
                     (None, _) => {
@@ -752,14 +765,29 @@ pub fn detect_changes_from_schemas(current: &SDLSchema, previous: &SDLSchema) ->
                 (SchemaType::Enum(prev_id), SchemaType::Enum(curr_id)) => {
                     let prev_enum = previous.enum_(prev_id);
                     let curr_enum = current.enum_(curr_id);
-                    if prev_enum.values.len() != curr_enum.values.len() {
-                        changes.push(DefinitionChange::EnumChanged { name: prev_name });
-                    } else {
-                        for i in 0..prev_enum.values.len() {
-                            if prev_enum.values[i].value != curr_enum.values[i].value {
-                                changes.push(DefinitionChange::EnumChanged { name: prev_name });
-                                break;
-                            }
+                    let prev_values: Vec<StringKey> =
+                        prev_enum.values.iter().map(|value| value.value).collect();
+                    let curr_values: Vec<StringKey> =
+                        curr_enum.values.iter().map(|value| value.value).collect();
+                    if let Some(change) = compare_enum_values(prev_name, &curr_values, &prev_values)
+                    {
+                        changes.push(change);
+                    }
+                    for prev_value in &prev_enum.values {
+                        if let Some(curr_value) = curr_enum
+                            .values
+                            .iter()
+                            .find(|value| value.value == prev_value.value)
+                        {
+                            compare_deprecation(
+                                &mut changes,
+                                SchemaCoordinate::Member {
+                                    parent_name: prev_name,
+                                    member_name: prev_value.value,
+                                },
+                                prev_value.deprecated(),
+                                curr_value.deprecated(),
+                            );
                         }
                     }
                 }
@@ -804,6 +832,15 @@ pub fn detect_changes_from_schemas(current: &SDLSchema, previous: &SDLSchema) ->
                             removed,
                         });
                     }
+                    compare_argument_details(
+                        &mut changes,
+                        &curr_input.fields,
+                        &prev_input.fields,
+                        |field_name| SchemaCoordinate::Member {
+                            parent_name: prev_name,
+                            member_name: field_name,
+                        },
+                    );
                 }
                 (SchemaType::Interface(prev_id), SchemaType::Interface(curr_id)) => {
                     let prev_iface = previous.interface(prev_id);
@@ -822,6 +859,14 @@ pub fn detect_changes_from_schemas(current: &SDLSchema, previous: &SDLSchema) ->
                             changed,
                         });
                     }
+                    compare_field_details(
+                        &mut changes,
+                        prev_name,
+                        current,
+                        &curr_iface.fields,
+                        previous,
+                        &prev_iface.fields,
+                    );
                 }
                 (SchemaType::Object(prev_id), SchemaType::Object(curr_id)) => {
                     let prev_obj = previous.object(prev_id);
@@ -865,9 +910,25 @@ pub fn detect_changes_from_schemas(current: &SDLSchema, previous: &SDLSchema) ->
                             interfaces_removed,
                         });
                     }
+                    compare_field_details(
+                        &mut changes,
+                        prev_name,
+                        current,
+                        &curr_obj.fields,
+                        previous,
+                        &prev_obj.fields,
+                    );
                 }
-                (SchemaType::Scalar(_), SchemaType::Scalar(_)) => {
-                    // Scalars are identified by name only; already matched.
+                (SchemaType::Scalar(prev_id), SchemaType::Scalar(curr_id)) => {
+                    let previous_specified_by = specified_by(&previous.scalar(prev_id).directives);
+                    let specified_by = specified_by(&current.scalar(curr_id).directives);
+                    if previous_specified_by != specified_by {
+                        changes.push(DefinitionChange::ScalarChanged {
+                            name: prev_name,
+                            previous_specified_by,
+                            specified_by,
+                        });
+                    }
                 }
                 // Type kind changed — record as removal of old kind + addition of new kind.
                 (prev, curr) => {
@@ -938,9 +999,200 @@ pub fn detect_changes_from_schemas(current: &SDLSchema, previous: &SDLSchema) ->
         }
     }
 
+    compare_directives(&mut changes, current, previous);
+
     if changes.is_empty() {
         SchemaChange::None
     } else {
         SchemaChange::DefinitionChanges(changes)
     }
 }
+
+/// Returns an `EnumChanged` if the values of the enum were added, removed or
+/// reordered. Added and removed values keep the order of their definition.
+fn compare_enum_values(
+    name: StringKey,
+    current: &[StringKey],
+    previous: &[StringKey],
+) -> Option<DefinitionChange> {
+    if current == previous {
+        return None;
+    }
+    Some(DefinitionChange::EnumChanged {
+        name,
+        added: current
+            .iter()
+            .filter(|value| !previous.contains(value))
+            .copied()
+            .collect(),
+        removed: previous
+            .iter()
+            .filter(|value| !current.contains(value))
+            .copied()
+            .collect(),
+    })
+}
+
+fn compare_deprecation(
+    changes: &mut Vec<DefinitionChange>,
+    coordinate: SchemaCoordinate,
+    previous: Option<Deprecation>,
+    current: Option<Deprecation>,
+) {
+    match (previous, current) {
+        (None, Some(deprecation)) => changes.push(DefinitionChange::DeprecationAdded {
+            coordinate,
+            reason: deprecation.reason,
+        }),
+        (Some(_), None) => changes.push(DefinitionChange::DeprecationRemoved { coordinate }),
+        (None, None) | (Some(_), Some(_)) => {}
+    }
+}
+
+/// Records deprecation and default value changes of the arguments (or input
+/// fields) that exist in both versions of a definition.
+fn compare_argument_details(
+    changes: &mut Vec<DefinitionChange>,
+    current_args: &schema::ArgumentDefinitions,
+    previous_args: &schema::ArgumentDefinitions,
+    coordinate: impl Fn(StringKey) -> SchemaCoordinate,
+) {
+    for prev_arg in previous_args.iter() {
+        let Some(curr_arg) = current_args.named(prev_arg.name.item) else {
+            continue;
+        };
+        let name = prev_arg.name.item.0;
+        compare_deprecation(
+            changes,
+            coordinate(name),
+            prev_arg.deprecated(),
+            curr_arg.deprecated(),
+        );
+        let previous = prev_arg
+            .default_value
+            .as_ref()
+            .map(|value| value.to_string());
+        let current = curr_arg
+            .default_value
+            .as_ref()
+            .map(|value| value.to_string());
+        if previous != current {
+            changes.push(DefinitionChange::DefaultValueChanged {
+                coordinate: coordinate(name),
+                previous,
+                current,
+            });
+        }
+    }
+}
+
+/// Records deprecation changes of the fields that exist in both versions of
+/// an object or interface, and the changes of their arguments.
+fn compare_field_details(
+    changes: &mut Vec<DefinitionChange>,
+    parent_name: StringKey,
+    current_schema: &SDLSchema,
+    current_field_ids: &[schema::FieldID],
+    previous_schema: &SDLSchema,
+    previous_field_ids: &[schema::FieldID],
+) {
+    let current_fields: FnvHashMap<StringKey, &schema::Field> = current_field_ids
+        .iter()
+        .map(|id| {
+            let field = current_schema.field(*id);
+            (field.name.item, field)
+        })
+        .collect();
+    for prev_id in previous_field_ids {
+        let prev_field = previous_schema.field(*prev_id);
+        let member_name = prev_field.name.item;
+        let Some(curr_field) = current_fields.get(&member_name) else {
+            continue;
+        };
+        compare_deprecation(
+            changes,
+            SchemaCoordinate::Member {
+                parent_name,
+                member_name,
+            },
+            prev_field.deprecated(),
+            curr_field.deprecated(),
+        );
+        compare_argument_details(
+            changes,
+            &curr_field.arguments,
+            &prev_field.arguments,
+            |argument_name| SchemaCoordinate::Argument {
+                parent_name,
+                member_name,
+                argument_name,
+            },
+        );
+    }
+}
+
+fn specified_by(directives: &[DirectiveValue]) -> Option<StringKey> {
+    directives
+        .named(*SPECIFIED_BY)
+        .and_then(|directive| directive.arguments.named(*URL))
+        .and_then(|url| url.value.get_string_literal())
+}
+
+fn compare_directives(
+    changes: &mut Vec<DefinitionChange>,
+    current: &SDLSchema,
+    previous: &SDLSchema,
+) {
+    for prev_directive in previous.get_directives() {
+        let name = prev_directive.name.item.0;
+        let Some(curr_directive) = current.get_directive(prev_directive.name.item) else {
+            changes.push(DefinitionChange::DirectiveRemoved(name));
+            continue;
+        };
+        let (added, removed) = compare_schema_arguments(
+            current,
+            &curr_directive.arguments,
+            previous,
+            &prev_directive.arguments,
+        );
+        let locations_added: Vec<DirectiveLocation> = curr_directive
+            .locations
+            .iter()
+            .filter(|location| !prev_directive.locations.contains(location))
+            .copied()
+            .collect();
+        let locations_removed: Vec<DirectiveLocation> = prev_directive
+            .locations
+            .iter()
+            .filter(|location| !curr_directive.locations.contains(location))
+            .copied()
+            .collect();
+        if !added.is_empty()
+            || !removed.is_empty()
+            || !locations_added.is_empty()
+            || !locations_removed.is_empty()
+        {
+            changes.push(DefinitionChange::DirectiveChanged {
+                name,
+                added,
+                removed,
+                locations_added,
+                locations_removed,
+            });
+        }
+        compare_argument_details(
+            changes,
+            &curr_directive.arguments,
+            &prev_directive.arguments,
+            |argument_name| SchemaCoordinate::DirectiveArgument {
+                directive_name: name,
+                argument_name,
+            },
+        );
+    }
+    for curr_directive in current.get_directives() {
+        if previous.get_directive(curr_directive.name.item).is_none() {
+            changes.push(DefinitionChange::DirectiveAdded(curr_directive.name.item.0));
+        }
+    }
+}
@@ -97,7 +97,11 @@ fn test_add_enum_value() {
            }
          #",
         ),
-        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged { name: "A".intern() }])
+        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged {
+            name: "A".intern(),
+            added: vec!["MAYBE".intern()],
+            removed: vec![],
+        }])
     );
 }
 
@@ -120,7 +124,11 @@ fn test_sort_enum_value() {
            }
          #",
         ),
-        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged { name: "A".intern() }])
+        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged {
+            name: "A".intern(),
+            added: vec![],
+            removed: vec![],
+        }])
     );
 }
 
@@ -142,7 +150,11 @@ fn test_remove_enum_value() {
            }
          #",
         ),
-        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged { name: "A".intern() }])
+        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged {
+            name: "A".intern(),
+            added: vec![],
+            removed: vec!["MAYBE".intern()],
+        }])
     );
 }
 
@@ -167,7 +179,11 @@ fn test_add_remove_enum_value() {
            }
          #",
         ),
-        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged { name: "A".intern() }])
+        SchemaChange::DefinitionChanges(vec![DefinitionChange::EnumChanged {
+            name: "A".intern(),
+            added: vec!["ZUCK".intern(), "NOT_ZUCK".intern()],
+            removed: vec!["MARK".intern(), "NOT_MARK".intern()],
+        }])
     );
 }
 
@@ -2280,6 +2296,391 @@ fn test_schema_diff_identical_complex_schema() {
     assert_eq!(diff_from_schemas(schema, schema), SchemaChange::None);
 }
 
+#[test]
+fn test_schema_diff_enum_value_details() {
+    let current = r#"
+        enum A {
+            OK
+            NOT_OK @deprecated(reason: "Use OK")
+            MAYBE
+        }
+    "#;
+    let previous = r"
+        enum A {
+            OK
+            NOT_OK
+            UNKNOWN
+        }
+    ";
+    assert_eq!(
+        diff_from_schemas(current, previous),
+        SchemaChange::DefinitionChanges(vec![
+            DefinitionChange::EnumChanged {
+                name: "A".intern(),
+                added: vec!["MAYBE".intern()],
+                removed: vec!["UNKNOWN".intern()],
+            },
+            DefinitionChange::DeprecationAdded {
+                coordinate: SchemaCoordinate::Member {
+                    parent_name: "A".intern(),
+                    member_name: "NOT_OK".intern(),
+                },
+                reason: Some("Use OK".intern()),
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_schema_diff_scalar_specified_by() {
+    let current = r#"
+        scalar URL @specifiedBy(url: "https://tools.ietf.org/html/rfc3986")
+        scalar Date
+    "#;
+    let previous = r#"
+        scalar URL
+        scalar Date @specifiedBy(url: "https://example.com/date")
+    "#;
+    assert_eq!(
+        diff_from_schemas(current, previous),
+        SchemaChange::DefinitionChanges(vec![
+            DefinitionChange::ScalarChanged {
+                name: "Date".intern(),
+                previous_specified_by: Some("https://example.com/date".intern()),
+                specified_by: None,
+            },
+            DefinitionChange::ScalarChanged {
+                name: "URL".intern(),
+                previous_specified_by: None,
+                specified_by: Some("https://tools.ietf.org/html/rfc3986".intern()),
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_schema_diff_field_deprecation_and_argument_defaults() {
+    let current = r"
+        type Query {
+            users(first: Int = 20, after: String): [User] @deprecated
+        }
+        type User {
+            name(format: String): String
+        }
+        input UserInput {
+            role: String = ADMIN
+        }
+    ";
+    let previous = r#"
+        type Query {
+            users(first: Int = 10, after: String @deprecated): [User]
+        }
+        type User {
+            name(format: String = "short"): String @deprecated(reason: "Use fullName")
+        }
+        input UserInput {
+            role: String
+        }
+    "#;
+    assert_eq!(
+        diff_from_schemas(current, previous),
+        SchemaChange::DefinitionChanges(vec![
+            DefinitionChange::DefaultValueChanged {
+                coordinate: SchemaCoordinate::Argument {
+                    parent_name: "Query".intern(),
+                    member_name: "users".intern(),
+                    argument_name: "first".intern(),
+                },
+                previous: Some("10".to_string()),
+                current: Some("20".to_string()),
+            },
+            DefinitionChange::DefaultValueChanged {
+                coordinate: SchemaCoordinate::Argument {
+                    parent_name: "User".intern(),
+                    member_name: "name".intern(),
+                    argument_name: "format".intern(),
+                },
+                previous: Some("\"short\"".to_string()),
+                current: None,
+            },
+            DefinitionChange::DefaultValueChanged {
+                coordinate: SchemaCoordinate::Member {
+                    parent_name: "UserInput".intern(),
+                    member_name: "role".intern(),
+                },
+                previous: None,
+                current: Some("ADMIN".to_string()),
+            },
+            DefinitionChange::DeprecationAdded {
+                coordinate: SchemaCoordinate::Member {
+                    parent_name: "Query".intern(),
+                    member_name: "users".intern(),
+                },
+                reason: None,
+            },
+            DefinitionChange::DeprecationRemoved {
+                coordinate: SchemaCoordinate::Argument {
+                    parent_name: "Query".intern(),
+                    member_name: "users".intern(),
+                    argument_name: "after".intern(),
+                },
+            },
+            DefinitionChange::DeprecationRemoved {
+                coordinate: SchemaCoordinate::Member {
+                    parent_name: "User".intern(),
+                    member_name: "name".intern(),
+                },
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_schema_diff_directives() {
+    let current = r"
+        directive @auth(role: String!, scope: String = READ) on FIELD_DEFINITION | OBJECT
+        directive @cost(weight: Int) on FIELD_DEFINITION
+    ";
+    let previous = r"
+        directive @auth(role: String, scope: String) on FIELD_DEFINITION | INTERFACE
+        directive @legacy on FIELD_DEFINITION
+    ";
+    assert_eq!(
+        diff_from_schemas(current, previous),
+        SchemaChange::DefinitionChanges(vec![
+            DefinitionChange::DirectiveAdded("cost".intern()),
+            DefinitionChange::DirectiveChanged {
+                name: "auth".intern(),
+                added: vec![TypeChange {
+                    name: "role".intern(),
+                    type_: Type::NonNull(Box::new(Type::Named("String".intern()))),
+                }],
+                removed: vec![TypeChange {
+                    name: "role".intern(),
+                    type_: Type::Named("String".intern()),
+                }],
+                locations_added: vec![DirectiveLocation::Object],
+                locations_removed: vec![DirectiveLocation::Interface],
+            },
+            DefinitionChange::DirectiveRemoved("legacy".intern()),
+            DefinitionChange::DefaultValueChanged {
+                coordinate: SchemaCoordinate::DirectiveArgument {
+                    directive_name: "auth".intern(),
+                    argument_name: "scope".intern(),
+                },
+                previous: None,
+                current: Some("READ".to_string()),
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_schema_diff_detail_changes_safety() {
+    let current = r#"
+        type Query {
+            users: [User] @deprecated(reason: "Use viewer")
+        }
+        type User {
+            name: String
+        }
+    "#;
+    let previous = r"
+        type Query {
+            users: [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let schema = build_schema(current).unwrap();
+    assert_eq!(
+        diff_from_schemas(current, previous).get_safety(&schema, &Default::default()),
+        SchemaChangeSafety::Safe
+    );
+
+    let current = r"
+        type Query {
+            users(first: Int = 20): [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let previous = r"
+        type Query {
+            users(first: Int = 10): [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let schema = build_schema(current).unwrap();
+    assert_eq!(
+        diff_from_schemas(current, previous).get_safety(&schema, &Default::default()),
+        SchemaChangeSafety::Safe
+    );
+}
+
+#[test]
+fn test_directive_changes_safety() {
+    let previous = r"
+        directive @auth(role: String) on FIELD_DEFINITION
+        directive @legacy on FIELD_DEFINITION
+        type Query {
+            users: [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+
+    let current = r"
+        directive @auth(role: String!) on FIELD_DEFINITION | OBJECT
+        directive @legacy on FIELD_DEFINITION
+        type Query {
+            users: [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let schema = build_schema(current).unwrap();
+    assert_eq!(
+        diff_from_schemas(current, previous).get_safety(&schema, &Default::default()),
+        SchemaChangeSafety::Safe
+    );
+
+    let current = r"
+        directive @auth(role: String) on FIELD_DEFINITION
+        type Query {
+            users: [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let schema = build_schema(current).unwrap();
+    assert_eq!(
+        diff_from_schemas(current, previous).get_safety(&schema, &Default::default()),
+        SchemaChangeSafety::Safe
+    );
+
+    let current = r#"
+        directive @auth(role: String = "admin") on FIELD_DEFINITION
+        directive @legacy on FIELD_DEFINITION
+        type Query {
+            users: [User]
+        }
+        type User {
+            name: String
+        }
+    "#;
+    let schema = build_schema(current).unwrap();
+    assert_eq!(
+        diff_from_schemas(current, previous).get_safety(&schema, &Default::default()),
+        SchemaChangeSafety::Safe
+    );
+}
+
+#[test]
+fn test_input_field_default_value_change_safety() {
+    let current = r"
+        input UserFilter {
+            limit: Int = 20
+        }
+        type Query {
+            users(filter: UserFilter): [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let previous = r"
+        input UserFilter {
+            limit: Int
+        }
+        type Query {
+            users(filter: UserFilter): [User]
+        }
+        type User {
+            name: String
+        }
+    ";
+    let schema = build_schema(current).unwrap();
+    assert_eq!(
+        diff_from_schemas(current, previous).get_safety(&schema, &Default::default()),
+        SchemaChangeSafety::SafeWithIncrementalBuild(FxHashSet::from_iter([
+            IncrementalBuildSchemaChange::InputObject("UserFilter".intern())
+        ]))
+    );
+}
+
+#[test]
+fn test_print_changelog() {
+    let current = r#"
+        directive @auth(role: String!) on FIELD_DEFINITION | OBJECT
+        enum Status { ACTIVE ARCHIVED }
+        type Query {
+            users(first: Int = 20): [User] @deprecated(reason: "Use viewer")
+        }
+        type User {
+            name: String!
+            email: String
+        }
+    "#;
+    let previous = r"
+        directive @auth(role: String) on FIELD_DEFINITION
+        enum Status { ACTIVE INACTIVE }
+        type Query {
+            users(first: Int = 10): [User]
+        }
+        type User {
+            name: String
+            age: Int
+        }
+    ";
+    let change = diff_from_schemas(current, previous);
+    assert_eq!(
+        changelog::print_changelog(&change),
+        r#"- Location `OBJECT` was added to directive `@auth`
+- Argument `role` of directive `@auth` changed type from `String` to `String!`
+- `Query.users` was deprecated: Use viewer
+- The default value of `Query.users(first:)` changed from `10` to `20`
+- Value `ARCHIVED` was added to enum `Status`
+- Value `INACTIVE` was removed from enum `Status`
+- Field `User.age` was removed
+- Field `User.email` was added with type `String`
+- Field `User.name` changed type from `String` to `String!`
+"#
+    );
+    assert_eq!(
+        changelog::print_changelog(&SchemaChange::None),
+        "No changes\n"
+    );
+}
+
+#[test]
+fn test_print_changelog_json() {
+    let current = r"
+        enum Status { ACTIVE ARCHIVED }
+    ";
+    let previous = r"
+        enum Status { ACTIVE }
+    ";
+    let change = diff_from_schemas(current, previous);
+    assert_eq!(
+        changelog::print_changelog_json(&change),
+        r#"[
+  {
+    "coordinate": "Status.ARCHIVED",
+    "kind": "enum_value_added",
+    "message": "Value `ARCHIVED` was added to enum `Status`"
+  }
+]"#
+    );
+}
+
 fn sort_change(change: &mut SchemaChange) {
     if let SchemaChange::DefinitionChanges(changes) = change {
         changes.sort();
@@ -2314,6 +2715,10 @@ fn sort_change(change: &mut SchemaChange) {
                 DefinitionChange::ObjectRemoved { interfaces, .. } => {
                     interfaces.sort();
                 }
+                DefinitionChange::DirectiveChanged { added, removed, .. } => {
+                    added.sort_by_key(|item| item.name);
+                    removed.sort_by_key(|item| item.name);
+                }
                 _ => {}
             }
         }
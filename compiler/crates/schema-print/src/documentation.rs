@@ -0,0 +1,725 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Renders an `SDLSchema` as static documentation pages (HTML or Markdown):
+//! an index, one page per type and a page listing the operations and
+//! fragments that use the schema, linked back from the fields they select.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use common::NamedItem;
+use docblock_shared::RELAY_RESOLVER_DIRECTIVE_NAME;
+use intern::Lookup;
+use intern::string_key::StringKey;
+use schema::definitions::Deprecation;
+use schema::*;
+use schema_coordinates::SchemaCoordinate;
+
+/// Operations and fragments referring to each schema coordinate, as labels
+/// such as `Name (path/to/file)`.
+pub type DocumentationReferences = BTreeMap<SchemaCoordinate, BTreeSet<String>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocumentationFormat {
+    Html,
+    Markdown,
+}
+
+impl DocumentationFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            DocumentationFormat::Html => "html",
+            DocumentationFormat::Markdown => "md",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DocumentationOptions {
+    pub format: DocumentationFormat,
+    /// Document client schema extensions and Relay Resolver fields in
+    /// addition to the server schema.
+    pub include_client_extensions: bool,
+}
+
+impl Default for DocumentationOptions {
+    fn default() -> Self {
+        Self {
+            format: DocumentationFormat::Html,
+            include_client_extensions: true,
+        }
+    }
+}
+
+/// A page of the generated documentation, with a path relative to the
+/// output directory.
+#[derive(Debug)]
+pub struct DocumentationPage {
+    pub path: String,
+    pub content: String,
+}
+
+/// Render the documentation of `schema`, using `references` for the
+/// "Used by" sections and the documents page. Pages are ordered by path.
+pub fn print_documentation(
+    schema: &SDLSchema,
+    references: &DocumentationReferences,
+    options: DocumentationOptions,
+) -> Vec<DocumentationPage> {
+    let printer = DocumentationPrinter {
+        schema,
+        references,
+        options,
+    };
+    let types = schema
+        .get_type_map()
+        .filter(|(_, type_)| printer.includes_type(**type_))
+        .map(|(name, type_)| (*name, *type_))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut pages = vec![
+        printer.print_index_page(&types),
+        printer.print_documents_page(),
+    ];
+    pages.extend(
+        types
+            .into_iter()
+            .map(|(name, type_)| printer.print_type_page(name, type_)),
+    );
+    pages.sort_by(|a, b| a.path.cmp(&b.path));
+    pages
+}
+
+struct DocumentationPrinter<'a> {
+    schema: &'a SDLSchema,
+    references: &'a DocumentationReferences,
+    options: DocumentationOptions,
+}
+
+impl DocumentationPrinter<'_> {
+    fn includes_type(&self, type_: Type) -> bool {
+        !self.schema.get_type_name(type_).lookup().starts_with("__")
+            && (self.options.include_client_extensions || !self.schema.is_extension_type(type_))
+    }
+
+    fn includes_field(&self, field: &Field) -> bool {
+        !field.name.item.lookup().starts_with("__")
+            && (self.options.include_client_extensions || !field.is_extension)
+    }
+
+    fn page_path(&self, name: &str) -> String {
+        format!("{}.{}", name, self.options.format.extension())
+    }
+
+    fn type_page_path(&self, name: StringKey) -> String {
+        format!("types/{}", self.page_path(name.lookup()))
+    }
+
+    fn print_index_page(&self, types: &BTreeMap<StringKey, Type>) -> DocumentationPage {
+        let mut page = PageWriter::new(self.options.format, "Schema");
+        page.heading(1, "Schema", None);
+
+        let root_types = [
+            ("Query", self.schema.query_type()),
+            ("Mutation", self.schema.mutation_type()),
+            ("Subscription", self.schema.subscription_type()),
+        ]
+        .into_iter()
+        .filter_map(|(label, type_)| {
+            type_
+                .filter(|type_| self.includes_type(*type_))
+                .map(|type_| {
+                    let name = self.schema.get_type_name(type_);
+                    format!(
+                        "{}: {}",
+                        label,
+                        page.link(&page.code(name.lookup()), &self.type_page_path(name))
+                    )
+                })
+        })
+        .collect::<Vec<_>>();
+        if !root_types.is_empty() {
+            page.heading(2, "Operation types", None);
+            page.list(root_types);
+        }
+
+        for kind in [
+            TypeKind::Object,
+            TypeKind::Interface,
+            TypeKind::Union,
+            TypeKind::Enum,
+            TypeKind::InputObject,
+            TypeKind::Scalar,
+        ] {
+            let items = types
+                .iter()
+                .filter(|(_, type_)| TypeKind::of(**type_) == kind)
+                .map(|(name, type_)| {
+                    let mut item =
+                        page.link(&page.code(name.lookup()), &self.type_page_path(*name));
+                    if self.schema.is_extension_type(*type_) {
+                        item.push_str(" — ");
+                        item.push_str(&page.emphasis("client schema extension"));
+                    }
+                    item
+                })
+                .collect::<Vec<_>>();
+            if !items.is_empty() {
+                page.heading(2, kind.plural(), None);
+                page.list(items);
+            }
+        }
+
+        page.paragraph(&page.link(
+            &page.text("Operations and fragments"),
+            &self.page_path("documents"),
+        ));
+        page.finish(self.page_path("index"))
+    }
+
+    fn print_documents_page(&self) -> DocumentationPage {
+        let mut documents: BTreeMap<&str, Vec<&SchemaCoordinate>> = BTreeMap::new();
+        for (coordinate, used_by) in self.references {
+            for label in used_by {
+                documents.entry(label).or_default().push(coordinate);
+            }
+        }
+
+        let mut page = PageWriter::new(self.options.format, "Operations and fragments");
+        page.paragraph(&page.link(&page.text("Schema"), &self.page_path("index")));
+        page.heading(1, "Operations and fragments", None);
+        for (label, coordinates) in documents {
+            page.heading(2, &page.text(label), Some(&anchor(label)));
+            page.list(
+                coordinates
+                    .into_iter()
+                    .map(|coordinate| {
+                        let text = page.code(&coordinate.to_string());
+                        match coordinate {
+                            SchemaCoordinate::Member {
+                                parent_name,
+                                member_name,
+                            }
+                            | SchemaCoordinate::Argument {
+                                parent_name,
+                                member_name,
+                                ..
+                            } if self.documents_type(*parent_name) => page.link(
+                                &text,
+                                &format!(
+                                    "{}#{}",
+                                    self.type_page_path(*parent_name),
+                                    anchor(member_name.lookup())
+                                ),
+                            ),
+                            _ => text,
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        page.finish(self.page_path("documents"))
+    }
+
+    fn documents_type(&self, name: StringKey) -> bool {
+        self.schema
+            .get_type(name)
+            .is_some_and(|type_| self.includes_type(type_))
+    }
+
+    fn print_type_page(&self, name: StringKey, type_: Type) -> DocumentationPage {
+        let mut page = PageWriter::new(self.options.format, name.lookup());
+        page.paragraph(&page.link(
+            &page.text("Schema"),
+            &format!("../{}", self.page_path("index")),
+        ));
+        page.heading(1, &page.text(name.lookup()), None);
+
+        let mut kind = page.emphasis(TypeKind::of(type_).singular());
+        if self.schema.is_extension_type(type_) {
+            kind.push_str(" · ");
+            kind.push_str(&page.emphasis("client schema extension"));
+        }
+        page.paragraph(&kind);
+
+        match type_ {
+            Type::Scalar(id) => {
+                let scalar = self.schema.scalar(id);
+                page.description(scalar.description);
+            }
+            Type::Object(id) => {
+                let object = self.schema.object(id);
+                page.description(object.description);
+                self.print_type_list(
+                    &mut page,
+                    "Implements",
+                    object.interfaces.iter().map(|id| Type::Interface(*id)),
+                );
+                self.print_fields(&mut page, type_, &object.fields);
+            }
+            Type::Interface(id) => {
+                let interface = self.schema.interface(id);
+                page.description(interface.description);
+                self.print_type_list(
+                    &mut page,
+                    "Implements",
+                    interface.interfaces.iter().map(|id| Type::Interface(*id)),
+                );
+                self.print_type_list(
+                    &mut page,
+                    "Implemented by",
+                    interface
+                        .implementing_interfaces
+                        .iter()
+                        .map(|id| Type::Interface(*id))
+                        .chain(
+                            interface
+                                .implementing_objects
+                                .iter()
+                                .map(|id| Type::Object(*id)),
+                        ),
+                );
+                self.print_fields(&mut page, type_, &interface.fields);
+            }
+            Type::Union(id) => {
+                let union = self.schema.union(id);
+                page.description(union.description);
+                self.print_type_list(
+                    &mut page,
+                    "Possible types",
+                    union.members.iter().map(|id| Type::Object(*id)),
+                );
+            }
+            Type::Enum(id) => {
+                let enum_ = self.schema.enum_(id);
+                page.description(enum_.description);
+                if !enum_.values.is_empty() {
+                    page.heading(2, "Values", None);
+                }
+                for value in &enum_.values {
+                    page.heading(
+                        3,
+                        &page.code(value.value.lookup()),
+                        Some(&anchor(value.value.lookup())),
+                    );
+                    page.deprecation(value.deprecated());
+                    page.description(value.description);
+                    self.print_used_by(&mut page, name, value.value);
+                }
+            }
+            Type::InputObject(id) => {
+                let input_object = self.schema.input_object(id);
+                page.description(input_object.description);
+                if !input_object.fields.is_empty() {
+                    page.heading(2, "Fields", None);
+                }
+                for field in input_object.fields.iter() {
+                    let field_name = field.name.item.0;
+                    page.heading(
+                        3,
+                        &page.code(field_name.lookup()),
+                        Some(&anchor(field_name.lookup())),
+                    );
+                    page.paragraph(&format!(
+                        "{} {}",
+                        page.text("Type:"),
+                        self.print_input_value_type(&page, field)
+                    ));
+                    page.deprecation(field.deprecated());
+                    page.description(field.description);
+                    self.print_used_by(&mut page, name, field_name);
+                }
+            }
+        }
+        page.finish(self.type_page_path(name))
+    }
+
+    fn print_type_list(
+        &self,
+        page: &mut PageWriter,
+        title: &str,
+        types: impl Iterator<Item = Type>,
+    ) {
+        let mut items = types
+            .filter(|type_| self.includes_type(*type_))
+            .map(|type_| self.schema.get_type_name(type_))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            return;
+        }
+        items.sort();
+        page.heading(2, title, None);
+        page.list(
+            items
+                .into_iter()
+                .map(|name| page.link(&page.code(name.lookup()), &self.page_path(name.lookup())))
+                .collect(),
+        );
+    }
+
+    fn print_fields(&self, page: &mut PageWriter, parent_type: Type, fields: &[FieldID]) {
+        let parent_name = self.schema.get_type_name(parent_type);
+        let parent_is_extension = self.schema.is_extension_type(parent_type);
+        let fields = fields
+            .iter()
+            .map(|id| self.schema.field(*id))
+            .filter(|field| self.includes_field(field))
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return;
+        }
+        page.heading(2, "Fields", None);
+        for field in fields {
+            let field_name = field.name.item.lookup();
+            page.heading(3, &page.code(field_name), Some(&anchor(field_name)));
+            page.paragraph(&format!(
+                "{} {}",
+                page.text("Type:"),
+                self.print_type_reference(page, &field.type_)
+            ));
+            if field
+                .directives
+                .named(*RELAY_RESOLVER_DIRECTIVE_NAME)
+                .is_some()
+            {
+                page.paragraph(&page.emphasis("Relay Resolver"));
+            } else if field.is_extension && !parent_is_extension {
+                page.paragraph(&page.emphasis("Client schema extension"));
+            }
+            page.deprecation(field.deprecated());
+            page.description(field.description);
+
+            if !field.arguments.is_empty() {
+                page.paragraph(&page.strong("Arguments"));
+                page.list(
+                    field
+                        .arguments
+                        .iter()
+                        .map(|argument| {
+                            let mut item = format!(
+                                "{}: {}",
+                                page.code(argument.name.item.0.lookup()),
+                                self.print_input_value_type(page, argument)
+                            );
+                            if let Some(deprecation) = argument.deprecated() {
+                                item.push_str(" — ");
+                                item.push_str(&page.deprecation_text(deprecation));
+                            }
+                            if let Some(description) = argument.description {
+                                item.push_str(" — ");
+                                item.push_str(&page.inline_description(description));
+                            }
+                            item
+                        })
+                        .collect(),
+                );
+            }
+            self.print_used_by(page, parent_name, field.name.item);
+        }
+    }
+
+    fn print_used_by(&self, page: &mut PageWriter, parent_name: StringKey, member_name: StringKey) {
+        let Some(used_by) = self.references.get(&SchemaCoordinate::Member {
+            parent_name,
+            member_name,
+        }) else {
+            return;
+        };
+        if used_by.is_empty() {
+            return;
+        }
+        page.paragraph(&page.strong("Used by"));
+        page.list(
+            used_by
+                .iter()
+                .map(|label| {
+                    page.link(
+                        &page.text(label),
+                        &format!("../{}#{}", self.page_path("documents"), anchor(label)),
+                    )
+                })
+                .collect(),
+        );
+    }
+
+    fn print_input_value_type(&self, page: &PageWriter, argument: &Argument) -> String {
+        let mut result = self.print_type_reference(page, &argument.type_);
+        if let Some(default_value) = &argument.default_value {
+            write!(result, " = {}", page.code(&default_value.to_string())).unwrap();
+        }
+        result
+    }
+
+    fn print_type_reference(&self, page: &PageWriter, type_: &TypeReference<Type>) -> String {
+        match type_ {
+            TypeReference::Named(type_) => {
+                let name = self.schema.get_type_name(*type_);
+                let text = page.code(name.lookup());
+                if self.includes_type(*type_) {
+                    page.link(&text, &self.page_path(name.lookup()))
+                } else {
+                    text
+                }
+            }
+            TypeReference::NonNull(of_type) => {
+                format!("{}!", self.print_type_reference(page, of_type))
+            }
+            TypeReference::List(of_type) => format!(
+                "{}{}{}",
+                page.text("["),
+                self.print_type_reference(page, of_type),
+                page.text("]")
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    Scalar,
+}
+
+impl TypeKind {
+    fn of(type_: Type) -> Self {
+        match type_ {
+            Type::Scalar(_) => TypeKind::Scalar,
+            Type::Object(_) => TypeKind::Object,
+            Type::Interface(_) => TypeKind::Interface,
+            Type::Union(_) => TypeKind::Union,
+            Type::Enum(_) => TypeKind::Enum,
+            Type::InputObject(_) => TypeKind::InputObject,
+        }
+    }
+
+    fn singular(self) -> &'static str {
+        match self {
+            TypeKind::Object => "Object",
+            TypeKind::Interface => "Interface",
+            TypeKind::Union => "Union",
+            TypeKind::Enum => "Enum",
+            TypeKind::InputObject => "Input object",
+            TypeKind::Scalar => "Scalar",
+        }
+    }
+
+    fn plural(self) -> &'static str {
+        match self {
+            TypeKind::Object => "Objects",
+            TypeKind::Interface => "Interfaces",
+            TypeKind::Union => "Unions",
+            TypeKind::Enum => "Enums",
+            TypeKind::InputObject => "Input objects",
+            TypeKind::Scalar => "Scalars",
+        }
+    }
+}
+
+/// Accumulates the content of a page. Inline helpers (`text`, `code`,
+/// `link`, ...) return already formatted fragments that can be passed to the
+/// block helpers (`heading`, `paragraph`, `list`).
+struct PageWriter {
+    format: DocumentationFormat,
+    title: String,
+    out: String,
+}
+
+impl PageWriter {
+    fn new(format: DocumentationFormat, title: &str) -> Self {
+        Self {
+            format,
+            title: title.to_string(),
+            out: String::new(),
+        }
+    }
+
+    fn finish(self, path: String) -> DocumentationPage {
+        let content = match self.format {
+            DocumentationFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+                escape_html(&self.title),
+                self.out
+            ),
+            DocumentationFormat::Markdown => self.out,
+        };
+        DocumentationPage { path, content }
+    }
+
+    fn heading(&mut self, level: usize, content: &str, id: Option<&str>) {
+        match self.format {
+            DocumentationFormat::Html => {
+                let id = id.map(|id| format!(" id=\"{}\"", id)).unwrap_or_default();
+                writeln!(self.out, "<h{level}{id}>{content}</h{level}>").unwrap();
+            }
+            DocumentationFormat::Markdown => {
+                if let Some(id) = id {
+                    writeln!(self.out, "<a id=\"{}\"></a>\n", id).unwrap();
+                }
+                writeln!(self.out, "{} {}\n", "#".repeat(level), content).unwrap();
+            }
+        }
+    }
+
+    fn paragraph(&mut self, content: &str) {
+        match self.format {
+            DocumentationFormat::Html => writeln!(self.out, "<p>{}</p>", content).unwrap(),
+            DocumentationFormat::Markdown => writeln!(self.out, "{}\n", content).unwrap(),
+        }
+    }
+
+    fn list(&mut self, items: Vec<String>) {
+        match self.format {
+            DocumentationFormat::Html => {
+                writeln!(self.out, "<ul>").unwrap();
+                for item in items {
+                    writeln!(self.out, "<li>{}</li>", item).unwrap();
+                }
+                writeln!(self.out, "</ul>").unwrap();
+            }
+            DocumentationFormat::Markdown => {
+                for item in items {
+                    writeln!(self.out, "- {}", item).unwrap();
+                }
+                writeln!(self.out).unwrap();
+            }
+        }
+    }
+
+    /// Descriptions are Markdown by convention: they are kept as-is in
+    /// Markdown pages and split into escaped paragraphs in HTML pages.
+    fn description(&mut self, description: Option<StringKey>) {
+        let Some(description) = description else {
+            return;
+        };
+        for paragraph in description
+            .lookup()
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|paragraph| !paragraph.is_empty())
+        {
+            let paragraph = match self.format {
+                DocumentationFormat::Html => escape_html(paragraph),
+                DocumentationFormat::Markdown => paragraph.to_string(),
+            };
+            self.paragraph(&paragraph);
+        }
+    }
+
+    fn deprecation(&mut self, deprecation: Option<Deprecation>) {
+        if let Some(deprecation) = deprecation {
+            let text = self.deprecation_text(deprecation);
+            self.paragraph(&text);
+        }
+    }
+
+    fn deprecation_text(&self, deprecation: Deprecation) -> String {
+        match deprecation.reason {
+            Some(reason) => format!(
+                "{} {}",
+                self.strong("Deprecated:"),
+                self.inline_description(reason)
+            ),
+            None => self.strong("Deprecated"),
+        }
+    }
+
+    fn inline_description(&self, description: StringKey) -> String {
+        let description = description
+            .lookup()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        match self.format {
+            DocumentationFormat::Html => escape_html(&description),
+            DocumentationFormat::Markdown => description,
+        }
+    }
+
+    fn text(&self, text: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => escape_html(text),
+            DocumentationFormat::Markdown => escape_markdown(text),
+        }
+    }
+
+    fn code(&self, text: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!("<code>{}</code>", escape_html(text)),
+            DocumentationFormat::Markdown => format!("`{}`", text),
+        }
+    }
+
+    fn emphasis(&self, text: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!("<em>{}</em>", escape_html(text)),
+            DocumentationFormat::Markdown => format!("_{}_", escape_markdown(text)),
+        }
+    }
+
+    fn strong(&self, text: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => format!("<strong>{}</strong>", escape_html(text)),
+            DocumentationFormat::Markdown => format!("**{}**", escape_markdown(text)),
+        }
+    }
+
+    fn link(&self, content: &str, href: &str) -> String {
+        match self.format {
+            DocumentationFormat::Html => {
+                format!("<a href=\"{}\">{}</a>", escape_html(href), content)
+            }
+            DocumentationFormat::Markdown => format!("[{}]({})", content, href),
+        }
+    }
+}
+
+/// A stable id for headings, usable in both HTML and Markdown links.
+fn anchor(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            result.push(c.to_ascii_lowercase());
+        } else if !result.is_empty() && !result.ends_with('-') {
+            result.push('-');
+        }
+    }
+    while result.ends_with('-') {
+        result.pop();
+    }
+    result
+}
+
+fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
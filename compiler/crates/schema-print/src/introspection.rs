@@ -0,0 +1,382 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Serializes an `SDLSchema` as the result of the standard introspection
+//! query (`{ "__schema": ... }`), as consumed by GraphiQL, codegen tools and
+//! mock servers.
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::DirectiveName;
+use common::NamedItem;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use schema::*;
+use serde::Serialize;
+
+static SPECIFIED_BY: LazyLock<DirectiveName> =
+    LazyLock::new(|| DirectiveName("specifiedBy".intern()));
+static URL: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("url".intern()));
+static ONE_OF: LazyLock<DirectiveName> = LazyLock::new(|| DirectiveName("oneOf".intern()));
+
+#[derive(Clone, Copy, Debug)]
+pub struct IntrospectionOptions {
+    /// Include client schema extensions (and Relay's own extension
+    /// directives) in the result. When excluded, only the server schema is
+    /// serialized.
+    pub include_client_extensions: bool,
+}
+
+impl Default for IntrospectionOptions {
+    fn default() -> Self {
+        Self {
+            include_client_extensions: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct IntrospectionResult {
+    #[serde(rename = "__schema")]
+    pub schema: IntrospectionSchema,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionSchema {
+    pub description: Option<StringKey>,
+    pub query_type: Option<IntrospectionRootType>,
+    pub mutation_type: Option<IntrospectionRootType>,
+    pub subscription_type: Option<IntrospectionRootType>,
+    pub types: Vec<IntrospectionType>,
+    pub directives: Vec<IntrospectionDirective>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IntrospectionRootType {
+    pub name: StringKey,
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IntrospectionTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionType {
+    pub kind: IntrospectionTypeKind,
+    pub name: StringKey,
+    pub description: Option<StringKey>,
+    #[serde(rename = "specifiedByURL")]
+    pub specified_by_url: Option<StringKey>,
+    pub fields: Option<Vec<IntrospectionField>>,
+    pub input_fields: Option<Vec<IntrospectionInputValue>>,
+    pub interfaces: Option<Vec<IntrospectionTypeRef>>,
+    pub enum_values: Option<Vec<IntrospectionEnumValue>>,
+    pub possible_types: Option<Vec<IntrospectionTypeRef>>,
+    pub is_one_of: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionField {
+    pub name: StringKey,
+    pub description: Option<StringKey>,
+    pub args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    pub type_: IntrospectionTypeRef,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<StringKey>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionInputValue {
+    pub name: StringKey,
+    pub description: Option<StringKey>,
+    #[serde(rename = "type")]
+    pub type_: IntrospectionTypeRef,
+    /// The default value printed as a GraphQL literal.
+    pub default_value: Option<String>,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<StringKey>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionEnumValue {
+    pub name: StringKey,
+    pub description: Option<StringKey>,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<StringKey>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionTypeRef {
+    pub kind: IntrospectionTypeKind,
+    pub name: Option<StringKey>,
+    pub of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionDirective {
+    pub name: DirectiveName,
+    pub description: Option<StringKey>,
+    pub is_repeatable: bool,
+    pub locations: Vec<String>,
+    pub args: Vec<IntrospectionInputValue>,
+}
+
+/// Prints the introspection result of `schema` as pretty-printed JSON.
+pub fn print_introspection(schema: &SDLSchema, options: IntrospectionOptions) -> String {
+    serde_json::to_string_pretty(&build_introspection(schema, options)).unwrap()
+}
+
+/// Builds the introspection result of `schema`. Types and directives are
+/// sorted by name; fields, arguments and enum values keep the schema order.
+pub fn build_introspection(
+    schema: &SDLSchema,
+    options: IntrospectionOptions,
+) -> IntrospectionResult {
+    let builder = IntrospectionBuilder { schema, options };
+    let root_type = |type_: Option<Type>| {
+        type_
+            .filter(|type_| builder.includes_type(*type_))
+            .map(|type_| IntrospectionRootType {
+                name: schema.get_type_name(type_),
+            })
+    };
+    let ordered_types = schema
+        .get_type_map()
+        .filter(|(_, type_)| builder.includes_type(**type_))
+        .collect::<BTreeMap<_, _>>();
+    let mut directives = schema
+        .get_directives()
+        .filter(|directive| options.include_client_extensions || !directive.is_extension)
+        .collect::<Vec<_>>();
+    directives.sort_by_key(|directive| directive.name.item);
+
+    IntrospectionResult {
+        schema: IntrospectionSchema {
+            description: None,
+            query_type: root_type(schema.query_type()),
+            mutation_type: root_type(schema.mutation_type()),
+            subscription_type: root_type(schema.subscription_type()),
+            types: ordered_types
+                .into_values()
+                .map(|type_| builder.build_type(*type_))
+                .collect(),
+            directives: directives
+                .into_iter()
+                .map(|directive| builder.build_directive(directive))
+                .collect(),
+        },
+    }
+}
+
+struct IntrospectionBuilder<'schema> {
+    schema: &'schema SDLSchema,
+    options: IntrospectionOptions,
+}
+
+impl IntrospectionBuilder<'_> {
+    fn includes_type(&self, type_: Type) -> bool {
+        self.options.include_client_extensions || !self.schema.is_extension_type(type_)
+    }
+
+    fn build_type(&self, type_: Type) -> IntrospectionType {
+        let mut result = IntrospectionType {
+            kind: type_kind(type_),
+            name: self.schema.get_type_name(type_),
+            description: None,
+            specified_by_url: None,
+            fields: None,
+            input_fields: None,
+            interfaces: None,
+            enum_values: None,
+            possible_types: None,
+            is_one_of: None,
+        };
+        match type_ {
+            Type::Scalar(id) => {
+                let scalar = self.schema.scalar(id);
+                result.description = scalar.description;
+                result.specified_by_url = scalar
+                    .directives
+                    .named(*SPECIFIED_BY)
+                    .and_then(|directive| directive.arguments.named(*URL))
+                    .and_then(|url| url.value.get_string_literal());
+            }
+            Type::Object(id) => {
+                let object = self.schema.object(id);
+                result.description = object.description;
+                result.fields = Some(self.build_fields(&object.fields));
+                result.interfaces = Some(self.build_interfaces(&object.interfaces));
+            }
+            Type::Interface(id) => {
+                let interface = self.schema.interface(id);
+                result.description = interface.description;
+                result.fields = Some(self.build_fields(&interface.fields));
+                result.interfaces = Some(self.build_interfaces(&interface.interfaces));
+                result.possible_types = Some(
+                    self.build_possible_types(
+                        interface
+                            .implementing_objects
+                            .iter()
+                            .map(|id| Type::Object(*id)),
+                    ),
+                );
+            }
+            Type::Union(id) => {
+                let union = self.schema.union(id);
+                result.description = union.description;
+                result.possible_types = Some(
+                    self.build_possible_types(union.members.iter().map(|id| Type::Object(*id))),
+                );
+            }
+            Type::Enum(id) => {
+                let enum_ = self.schema.enum_(id);
+                result.description = enum_.description;
+                result.enum_values = Some(
+                    enum_
+                        .values
+                        .iter()
+                        .map(|value| {
+                            let deprecation = value.deprecated();
+                            IntrospectionEnumValue {
+                                name: value.value,
+                                description: value.description,
+                                is_deprecated: deprecation.is_some(),
+                                deprecation_reason: deprecation
+                                    .and_then(|deprecation| deprecation.reason),
+                            }
+                        })
+                        .collect(),
+                );
+            }
+            Type::InputObject(id) => {
+                let input_object = self.schema.input_object(id);
+                result.description = input_object.description;
+                result.input_fields = Some(self.build_input_values(&input_object.fields));
+                result.is_one_of = Some(input_object.directives.named(*ONE_OF).is_some());
+            }
+        }
+        result
+    }
+
+    fn build_fields(&self, fields: &[FieldID]) -> Vec<IntrospectionField> {
+        fields
+            .iter()
+            .map(|id| self.schema.field(*id))
+            .filter(|field| self.options.include_client_extensions || !field.is_extension)
+            .map(|field| {
+                let deprecation = field.deprecated();
+                IntrospectionField {
+                    name: field.name.item,
+                    description: field.description,
+                    args: self.build_input_values(&field.arguments),
+                    type_: self.build_type_ref(&field.type_),
+                    is_deprecated: deprecation.is_some(),
+                    deprecation_reason: deprecation.and_then(|deprecation| deprecation.reason),
+                }
+            })
+            .collect()
+    }
+
+    fn build_input_values(&self, arguments: &ArgumentDefinitions) -> Vec<IntrospectionInputValue> {
+        arguments
+            .iter()
+            .map(|argument| {
+                let deprecation = argument.deprecated();
+                IntrospectionInputValue {
+                    name: argument.name.item.0,
+                    description: argument.description,
+                    type_: self.build_type_ref(&argument.type_),
+                    default_value: argument
+                        .default_value
+                        .as_ref()
+                        .map(|value| value.to_string()),
+                    is_deprecated: deprecation.is_some(),
+                    deprecation_reason: deprecation.and_then(|deprecation| deprecation.reason),
+                }
+            })
+            .collect()
+    }
+
+    fn build_interfaces(&self, interfaces: &[InterfaceID]) -> Vec<IntrospectionTypeRef> {
+        self.build_possible_types(interfaces.iter().map(|id| Type::Interface(*id)))
+    }
+
+    fn build_possible_types(&self, types: impl Iterator<Item = Type>) -> Vec<IntrospectionTypeRef> {
+        types
+            .filter(|type_| self.includes_type(*type_))
+            .map(|type_| self.build_named_type_ref(type_))
+            .collect()
+    }
+
+    fn build_type_ref(&self, type_: &TypeReference<Type>) -> IntrospectionTypeRef {
+        match type_ {
+            TypeReference::Named(type_) => self.build_named_type_ref(*type_),
+            TypeReference::NonNull(of_type) => IntrospectionTypeRef {
+                kind: IntrospectionTypeKind::NonNull,
+                name: None,
+                of_type: Some(Box::new(self.build_type_ref(of_type))),
+            },
+            TypeReference::List(of_type) => IntrospectionTypeRef {
+                kind: IntrospectionTypeKind::List,
+                name: None,
+                of_type: Some(Box::new(self.build_type_ref(of_type))),
+            },
+        }
+    }
+
+    fn build_named_type_ref(&self, type_: Type) -> IntrospectionTypeRef {
+        IntrospectionTypeRef {
+            kind: type_kind(type_),
+            name: Some(self.schema.get_type_name(type_)),
+            of_type: None,
+        }
+    }
+
+    fn build_directive(&self, directive: &Directive) -> IntrospectionDirective {
+        IntrospectionDirective {
+            name: directive.name.item,
+            description: directive.description,
+            is_repeatable: directive.repeatable,
+            locations: directive
+                .locations
+                .iter()
+                .map(|location| location.to_string())
+                .collect(),
+            args: self.build_input_values(&directive.arguments),
+        }
+    }
+}
+
+fn type_kind(type_: Type) -> IntrospectionTypeKind {
+    match type_ {
+        Type::Scalar(_) => IntrospectionTypeKind::Scalar,
+        Type::Object(_) => IntrospectionTypeKind::Object,
+        Type::Interface(_) => IntrospectionTypeKind::Interface,
+        Type::Union(_) => IntrospectionTypeKind::Union,
+        Type::Enum(_) => IntrospectionTypeKind::Enum,
+        Type::InputObject(_) => IntrospectionTypeKind::InputObject,
+    }
+}
@@ -9,6 +9,8 @@
 #![deny(rust_2018_idioms)]
 #![deny(clippy::all)]
 
+mod documentation;
+mod introspection;
 mod print_schema;
 mod printer;
 mod shard_printer;
@@ -17,6 +19,8 @@ use std::hash::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
 
+pub use documentation::*;
+pub use introspection::*;
 pub use print_schema::*;
 pub use printer::*;
 pub use shard_printer::*;
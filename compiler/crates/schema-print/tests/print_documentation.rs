@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::Arc;
+
+use common::SourceLocationKey;
+use dependency_analyzer::SchemaUsageOptions;
+use dependency_analyzer::SchemaUsageReport;
+use fixture_tests::Fixture;
+use graphql_ir::Program;
+use graphql_ir::build;
+use graphql_syntax::parse_executable;
+use schema::build_schema_with_extensions_parallel;
+use schema_print::DocumentationFormat;
+use schema_print::DocumentationOptions;
+use schema_print::print_documentation;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let parts: Vec<_> = fixture.content.split("%extensions%").collect();
+    let [document, server_sdl, extension_sdl] = parts.as_slice() else {
+        panic!("Expected exactly two %extensions% section markers.")
+    };
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = Arc::new(
+        build_schema_with_extensions_parallel(
+            &[(*server_sdl, source_location)],
+            &[(*extension_sdl, source_location)],
+        )
+        .map_err(|diagnostics| format!("{diagnostics:?}"))?,
+    );
+    let ast = parse_executable(document, source_location).unwrap();
+    let ir = build(&schema, &ast.definitions).map_err(|diagnostics| format!("{diagnostics:?}"))?;
+    let program = Program::from_definitions(Arc::clone(&schema), ir);
+
+    let include_client_extensions = !fixture.file_name.contains("server-only");
+    let usage = SchemaUsageReport::from_program_with_options(
+        &program,
+        SchemaUsageOptions {
+            include_client_extensions,
+        },
+    );
+    let references = usage
+        .coordinates
+        .into_iter()
+        .map(|(coordinate, usage)| (coordinate, usage.used_by))
+        .collect();
+    let options = DocumentationOptions {
+        format: if fixture.file_name.contains(".html.") {
+            DocumentationFormat::Html
+        } else {
+            DocumentationFormat::Markdown
+        },
+        include_client_extensions,
+    };
+    Ok(print_documentation(&schema, &references, options)
+        .into_iter()
+        .map(|page| {
+            format!(
+                "==================== {} ====================\n{}",
+                page.path, page.content
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
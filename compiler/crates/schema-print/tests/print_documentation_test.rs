@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<46175871df9fe8a0a90dad8507f048e4>>
+ */
+
+mod print_documentation;
+
+use print_documentation::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn client_extensions_and_resolvers() {
+    let input = include_str!("print_documentation/fixtures/client-extensions-and-resolvers.graphql");
+    let expected = include_str!("print_documentation/fixtures/client-extensions-and-resolvers.expected");
+    test_fixture(transform_fixture, file!(), "client-extensions-and-resolvers.graphql", "print_documentation/fixtures/client-extensions-and-resolvers.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn client_extensions_and_resolvers_html() {
+    let input = include_str!("print_documentation/fixtures/client-extensions-and-resolvers.html.graphql");
+    let expected = include_str!("print_documentation/fixtures/client-extensions-and-resolvers.html.expected");
+    test_fixture(transform_fixture, file!(), "client-extensions-and-resolvers.html.graphql", "print_documentation/fixtures/client-extensions-and-resolvers.html.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn client_extensions_and_resolvers_server_only() {
+    let input = include_str!("print_documentation/fixtures/client-extensions-and-resolvers.server-only.graphql");
+    let expected = include_str!("print_documentation/fixtures/client-extensions-and-resolvers.server-only.expected");
+    test_fixture(transform_fixture, file!(), "client-extensions-and-resolvers.server-only.graphql", "print_documentation/fixtures/client-extensions-and-resolvers.server-only.expected", input, expected).await;
+}
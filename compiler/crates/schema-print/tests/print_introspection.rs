@@ -0,0 +1,29 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use fixture_tests::Fixture;
+use schema::build_schema_with_extensions_parallel;
+use schema_print::IntrospectionOptions;
+use schema_print::print_introspection;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let (server_sdl, extension_sdl) = fixture
+        .content
+        .split_once("%extensions%")
+        .unwrap_or((fixture.content, ""));
+    let source_location = SourceLocationKey::standalone(fixture.file_name);
+    let schema = build_schema_with_extensions_parallel(
+        &[(server_sdl, source_location)],
+        &[(extension_sdl, source_location)],
+    )
+    .map_err(|diagnostics| format!("{diagnostics:?}"))?;
+    let options = IntrospectionOptions {
+        include_client_extensions: !fixture.file_name.contains("server-only"),
+    };
+    Ok(print_introspection(&schema, options))
+}
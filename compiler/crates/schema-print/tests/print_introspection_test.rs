@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<8e29d88ef8a8bae381f757ad396e632f>>
+ */
+
+mod print_introspection;
+
+use print_introspection::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn client_extensions() {
+    let input = include_str!("print_introspection/fixtures/client-extensions.graphql");
+    let expected = include_str!("print_introspection/fixtures/client-extensions.expected");
+    test_fixture(transform_fixture, file!(), "client-extensions.graphql", "print_introspection/fixtures/client-extensions.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn client_extensions_server_only() {
+    let input = include_str!("print_introspection/fixtures/client-extensions.server-only.graphql");
+    let expected = include_str!("print_introspection/fixtures/client-extensions.server-only.expected");
+    test_fixture(transform_fixture, file!(), "client-extensions.server-only.graphql", "print_introspection/fixtures/client-extensions.server-only.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn kitchen_sink() {
+    let input = include_str!("print_introspection/fixtures/kitchen-sink.graphql");
+    let expected = include_str!("print_introspection/fixtures/kitchen-sink.expected");
+    test_fixture(transform_fixture, file!(), "kitchen-sink.graphql", "print_introspection/fixtures/kitchen-sink.expected", input, expected).await;
+}
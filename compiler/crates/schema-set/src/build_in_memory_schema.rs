@@ -256,6 +256,7 @@ pub fn build_in_memory_schema(schema_set: &SchemaSet) -> DiagnosticsResult<InMem
                 .values()
                 .map(|value| EnumValue {
                     value: value.value,
+                    location: first_location(&value.definition),
                     directives: build_directive_values(&value.directives),
                     description: value.description,
                 })
@@ -282,6 +283,7 @@ pub fn build_in_memory_schema(schema_set: &SchemaSet) -> DiagnosticsResult<InMem
         .map(|io| {
             Ok(InputObject {
                 name: WithLocation::new(first_location(&io.definition), io.name),
+                is_extension: io.definition.is_client_definition,
                 fields: build_arguments(&io.fields, &type_map)?,
                 directives: build_directive_values(&io.directives),
                 description: io.definition.description,
@@ -0,0 +1,752 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Composes the SDL of several subgraphs into the single supergraph schema
+//! served by a gateway.
+//!
+//! Every subgraph is collected into its own [`SchemaSet`] and the sets are then
+//! unioned with the regular set merges. Before merging, the subgraphs are
+//! checked for the conflicts a gateway would reject: a type defined with
+//! different kinds, a field whose type differs beyond nullability, an enum
+//! whose values diverge between subgraphs, and entities whose `@key`
+//! directives are missing or select fields that don't exist.
+//!
+//! Subgraphs commonly `extend` the types they share with other subgraphs, so
+//! extensions are treated as definitions: the supergraph defines every type.
+//! Schema-level directives (such as `@link`) only configure a subgraph and
+//! are not carried into the supergraph.
+
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DiagnosticsResult;
+use common::DirectiveName;
+use common::Location;
+use common::SourceLocationKey;
+use graphql_syntax::ConstantValue;
+use graphql_syntax::ExecutableDefinition;
+use graphql_syntax::ExtensionIntoDefinition;
+use graphql_syntax::SchemaDocument;
+use graphql_syntax::Selection;
+use graphql_syntax::TypeSystemDefinition;
+use intern::Lookup;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use schema::TypeReference;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::OutputNonNull;
+use crate::OutputTypeReference;
+use crate::SchemaSet;
+use crate::SetDirectiveValue;
+use crate::SetType;
+use crate::print_schema_set::print_output_type_reference;
+use crate::print_schema_set::print_type_reference;
+use crate::schema_set::CanHaveDirectives;
+use crate::schema_set::HasDefinitionItem;
+use crate::schema_set::HasFields;
+use crate::schema_set::SchemaDefinitionItem;
+use crate::schema_set::StringKeyNamed;
+use crate::set_merges::Merges;
+use crate::set_merges::set_type_kind_name;
+
+static KEY: LazyLock<DirectiveName> = LazyLock::new(|| DirectiveName("key".intern()));
+static KEY_FIELDS_ARG: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("fields".intern()));
+
+/// The SDL of one subgraph, named after the service serving it.
+pub struct Subgraph<'a> {
+    pub name: StringKey,
+    pub document: &'a SchemaDocument,
+}
+
+#[derive(Debug, Error, Serialize)]
+#[serde(tag = "type", content = "args")]
+pub enum SubgraphCompositionError {
+    #[error(
+        "Type '{type_name}' is defined as {kind} in subgraph '{subgraph}', but as {other_kind} in subgraph '{other_subgraph}'."
+    )]
+    MismatchedTypeKinds {
+        type_name: StringKey,
+        subgraph: StringKey,
+        kind: String,
+        other_subgraph: StringKey,
+        other_kind: String,
+    },
+
+    #[error(
+        "Field '{parent_type}.{field_name}' has type '{type_}' in subgraph '{subgraph}', but '{other_type}' in subgraph '{other_subgraph}'. Subgraphs may only disagree on the nullability of a field."
+    )]
+    MismatchedFieldTypes {
+        parent_type: StringKey,
+        field_name: StringKey,
+        subgraph: StringKey,
+        type_: String,
+        other_subgraph: StringKey,
+        other_type: String,
+    },
+
+    #[error(
+        "Enum '{enum_name}' has diverging values in subgraphs '{subgraph}' and '{other_subgraph}': {details}."
+    )]
+    DivergentEnumValues {
+        enum_name: StringKey,
+        subgraph: StringKey,
+        other_subgraph: StringKey,
+        details: String,
+    },
+
+    #[error(
+        "Type '{type_name}' is an entity in subgraph '{entity_subgraph}', but subgraph '{subgraph}' defines it without a @key directive."
+    )]
+    MissingEntityKey {
+        type_name: StringKey,
+        entity_subgraph: StringKey,
+        subgraph: StringKey,
+    },
+
+    #[error(
+        "The @key directive on '{type_name}' in subgraph '{subgraph}' must have a `fields` argument with a selection of the key fields: {reason}"
+    )]
+    InvalidEntityKey {
+        type_name: StringKey,
+        subgraph: StringKey,
+        reason: String,
+    },
+
+    #[error(
+        "The @key(fields: \"{fields}\") directive on '{type_name}' in subgraph '{subgraph}' selects '{field_name}', which is not a field of '{parent_type}'."
+    )]
+    UnknownEntityKeyField {
+        type_name: StringKey,
+        subgraph: StringKey,
+        fields: StringKey,
+        field_name: StringKey,
+        parent_type: StringKey,
+    },
+}
+
+/// Compose `subgraphs` into the supergraph schema. All conflicts between the
+/// subgraphs are reported, pointing at the conflicting definitions.
+pub fn compose_subgraphs(subgraphs: &[Subgraph<'_>]) -> DiagnosticsResult<SchemaSet> {
+    let mut errors = Vec::new();
+    let mut subgraph_sets = Vec::with_capacity(subgraphs.len());
+    for subgraph in subgraphs {
+        match subgraph_schema_set(subgraph.document) {
+            Ok(set) => subgraph_sets.push((subgraph.name, set)),
+            Err(diagnostics) => errors.extend(diagnostics),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut type_names: Vec<StringKey> = subgraph_sets
+        .iter()
+        .flat_map(|(_, set)| set.types.keys().copied())
+        .collect();
+    type_names.sort_by_key(|name| name.lookup());
+    type_names.dedup();
+
+    for type_name in &type_names {
+        let mut definitions: Vec<(StringKey, &SetType)> = Vec::new();
+        for (subgraph, set) in &subgraph_sets {
+            if let Some(type_) = set.types.get(type_name) {
+                validate_entity_keys(set, *subgraph, type_, &mut errors);
+                definitions.push((*subgraph, type_));
+            }
+        }
+        validate_entity(&definitions, &mut errors);
+        validate_type_definitions(&definitions, &mut errors);
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut supergraph = SchemaSet::new();
+    let mut entity_keys: Vec<(StringKey, Vec<SetDirectiveValue>)> = Vec::new();
+    for (_, set) in subgraph_sets {
+        for (type_name, type_) in &set.types {
+            let keys = entity_key_directives(type_).cloned().collect::<Vec<_>>();
+            if !keys.is_empty() {
+                entity_keys.push((*type_name, keys));
+            }
+        }
+        if let Err(diagnostics) = supergraph.merge(set) {
+            errors.extend(diagnostics);
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    // Merging directive values keeps a single application per directive, but
+    // an entity keeps every distinct key of every subgraph.
+    for (type_name, keys) in entity_keys {
+        let directives = supergraph
+            .types
+            .get_mut(&type_name)
+            .unwrap()
+            .directives_mut();
+        for key in keys {
+            let fields = key_fields(&key);
+            if !directives
+                .iter()
+                .any(|existing| existing.name == *KEY && key_fields(existing) == fields)
+            {
+                directives.push(key);
+            }
+        }
+    }
+    supergraph.root_schema.directives.clear();
+
+    Ok(supergraph)
+}
+
+fn subgraph_schema_set(document: &SchemaDocument) -> DiagnosticsResult<SchemaSet> {
+    let source = document.location.source_location();
+    let mut set = SchemaSet::new();
+    for definition in &document.definitions {
+        set.merge_type_system_definition(&extension_as_definition(definition), source, false)?;
+    }
+    Ok(set)
+}
+
+fn extension_as_definition(definition: &TypeSystemDefinition) -> TypeSystemDefinition {
+    match definition.clone() {
+        TypeSystemDefinition::SchemaExtension(extension) => {
+            TypeSystemDefinition::SchemaDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::EnumTypeExtension(extension) => {
+            TypeSystemDefinition::EnumTypeDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::InterfaceTypeExtension(extension) => {
+            TypeSystemDefinition::InterfaceTypeDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::ObjectTypeExtension(extension) => {
+            TypeSystemDefinition::ObjectTypeDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::UnionTypeExtension(extension) => {
+            TypeSystemDefinition::UnionTypeDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::InputObjectTypeExtension(extension) => {
+            TypeSystemDefinition::InputObjectTypeDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::ScalarTypeExtension(extension) => {
+            TypeSystemDefinition::ScalarTypeDefinition(extension.into_definition())
+        }
+        TypeSystemDefinition::DirectiveDefinitionExtension(extension) => {
+            TypeSystemDefinition::DirectiveDefinition(extension.into_definition())
+        }
+        definition => definition,
+    }
+}
+
+/// Checks the definitions of one type across all subgraphs defining it
+/// against the first of them.
+fn validate_type_definitions(definitions: &[(StringKey, &SetType)], errors: &mut Vec<Diagnostic>) {
+    let Some(((first_subgraph, first), others)) = definitions.split_first() else {
+        return;
+    };
+    for (subgraph, other) in others {
+        match (first, other) {
+            (SetType::Object(first), SetType::Object(other)) => {
+                validate_fields(*first_subgraph, first, *subgraph, other, errors);
+            }
+            (SetType::Interface(first), SetType::Interface(other)) => {
+                validate_fields(*first_subgraph, first, *subgraph, other, errors);
+            }
+            (SetType::InputObject(first), SetType::InputObject(other)) => {
+                for (field_name, other_field) in &other.fields {
+                    let Some(first_field) = first.fields.get(field_name) else {
+                        continue;
+                    };
+                    if nullable_type(&first_field.type_) != nullable_type(&other_field.type_) {
+                        errors.push(mismatched_field_types(
+                            first.name.0,
+                            *field_name,
+                            (
+                                *first_subgraph,
+                                print_type_reference(&first_field.type_),
+                                &first_field.definition,
+                            ),
+                            (
+                                *subgraph,
+                                print_type_reference(&other_field.type_),
+                                &other_field.definition,
+                            ),
+                        ));
+                    }
+                }
+            }
+            (SetType::Enum(first), SetType::Enum(other)) => {
+                let only_in_first = first
+                    .values
+                    .keys()
+                    .filter(|value| !other.values.contains_key(value))
+                    .map(|value| format!("'{}' is only defined in '{}'", value, first_subgraph));
+                let only_in_other = other
+                    .values
+                    .keys()
+                    .filter(|value| !first.values.contains_key(value))
+                    .map(|value| format!("'{}' is only defined in '{}'", value, subgraph));
+                let details = only_in_first.chain(only_in_other).collect::<Vec<_>>();
+                if !details.is_empty() {
+                    errors.push(
+                        Diagnostic::error(
+                            SubgraphCompositionError::DivergentEnumValues {
+                                enum_name: first.name.0,
+                                subgraph: *first_subgraph,
+                                other_subgraph: *subgraph,
+                                details: details.join(", "),
+                            },
+                            definition_location(&other.definition),
+                        )
+                        .annotate_if_location_exists(
+                            format!("enum defined in subgraph '{}'", first_subgraph),
+                            definition_location(&first.definition),
+                        ),
+                    );
+                }
+            }
+            (first, other) if set_type_kind_name(first) != set_type_kind_name(other) => {
+                errors.push(
+                    Diagnostic::error(
+                        SubgraphCompositionError::MismatchedTypeKinds {
+                            type_name: first.string_key_name(),
+                            subgraph: *first_subgraph,
+                            kind: set_type_kind_name(first).to_lowercase(),
+                            other_subgraph: *subgraph,
+                            other_kind: set_type_kind_name(other).to_lowercase(),
+                        },
+                        definition_location(other.definition_item()),
+                    )
+                    .annotate_if_location_exists(
+                        format!("defined in subgraph '{}'", first_subgraph),
+                        definition_location(first.definition_item()),
+                    ),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An object with a `@key` in one subgraph is an entity: every other subgraph
+/// defining it needs a key for the gateway to resolve it by.
+fn validate_entity(definitions: &[(StringKey, &SetType)], errors: &mut Vec<Diagnostic>) {
+    let Some((entity_subgraph, key)) = definitions.iter().find_map(|(subgraph, type_)| {
+        entity_key_directives(type_)
+            .next()
+            .map(|key| (*subgraph, key))
+    }) else {
+        return;
+    };
+    for (subgraph, type_) in definitions {
+        if matches!(type_, SetType::Object(_)) && entity_key_directives(type_).next().is_none() {
+            errors.push(
+                Diagnostic::error(
+                    SubgraphCompositionError::MissingEntityKey {
+                        type_name: type_.string_key_name(),
+                        entity_subgraph,
+                        subgraph: *subgraph,
+                    },
+                    definition_location(type_.definition_item()),
+                )
+                .annotate_if_location_exists(
+                    format!("key defined in subgraph '{}'", entity_subgraph),
+                    definition_location(&key.definition),
+                ),
+            );
+        }
+    }
+}
+
+fn validate_fields<T: HasFields + StringKeyNamed>(
+    first_subgraph: StringKey,
+    first: &T,
+    subgraph: StringKey,
+    other: &T,
+    errors: &mut Vec<Diagnostic>,
+) {
+    for (field_name, other_field) in other.fields() {
+        let Some(first_field) = first.fields().get(field_name) else {
+            continue;
+        };
+        if nullable_output_type(&first_field.type_) != nullable_output_type(&other_field.type_) {
+            errors.push(mismatched_field_types(
+                first.string_key_name(),
+                *field_name,
+                (
+                    first_subgraph,
+                    print_output_type_reference(&first_field.type_),
+                    &first_field.definition,
+                ),
+                (
+                    subgraph,
+                    print_output_type_reference(&other_field.type_),
+                    &other_field.definition,
+                ),
+            ));
+        }
+    }
+}
+
+fn mismatched_field_types(
+    parent_type: StringKey,
+    field_name: StringKey,
+    (subgraph, type_, definition): (StringKey, String, &SchemaDefinitionItem),
+    (other_subgraph, other_type, other_definition): (StringKey, String, &SchemaDefinitionItem),
+) -> Diagnostic {
+    Diagnostic::error(
+        SubgraphCompositionError::MismatchedFieldTypes {
+            parent_type,
+            field_name,
+            subgraph,
+            type_: type_.clone(),
+            other_subgraph,
+            other_type,
+        },
+        definition_location(other_definition),
+    )
+    .annotate_if_location_exists(
+        format!("'{}' in subgraph '{}'", type_, subgraph),
+        definition_location(definition),
+    )
+}
+
+/// Every `@key` of `type_` needs to select existing fields of the type in the
+/// subgraph defining it.
+fn validate_entity_keys(
+    set: &SchemaSet,
+    subgraph: StringKey,
+    type_: &SetType,
+    errors: &mut Vec<Diagnostic>,
+) {
+    let type_name = type_.string_key_name();
+    for key in entity_key_directives(type_) {
+        let location = definition_location(&key.definition);
+        let invalid_key = |reason: String| {
+            Diagnostic::error(
+                SubgraphCompositionError::InvalidEntityKey {
+                    type_name,
+                    subgraph,
+                    reason,
+                },
+                location,
+            )
+        };
+        let Some(fields) = key_fields(key) else {
+            errors.push(invalid_key("expected a string".to_string()));
+            continue;
+        };
+        let selections = match parse_key_fields(fields) {
+            Ok(selections) => selections,
+            Err(diagnostics) => {
+                errors.push(invalid_key(diagnostics[0].message().to_string()));
+                continue;
+            }
+        };
+        validate_key_selections(
+            set,
+            type_name,
+            &selections,
+            &mut |field_name, parent_type| {
+                errors.push(Diagnostic::error(
+                    SubgraphCompositionError::UnknownEntityKeyField {
+                        type_name,
+                        subgraph,
+                        fields,
+                        field_name,
+                        parent_type,
+                    },
+                    location,
+                ))
+            },
+        );
+    }
+}
+
+fn validate_key_selections(
+    set: &SchemaSet,
+    parent_type: StringKey,
+    selections: &[Selection],
+    report_unknown_field: &mut dyn FnMut(StringKey, StringKey),
+) {
+    for selection in selections {
+        let (field_name, sub_selections) = match selection {
+            Selection::ScalarField(field) => (field.name.value, None),
+            Selection::LinkedField(field) => (field.name.value, Some(&field.selections.items)),
+            Selection::InlineFragment(fragment) => {
+                let type_condition = fragment
+                    .type_condition
+                    .as_ref()
+                    .map_or(parent_type, |type_condition| type_condition.type_.value);
+                validate_key_selections(
+                    set,
+                    type_condition,
+                    &fragment.selections.items,
+                    report_unknown_field,
+                );
+                continue;
+            }
+            Selection::FragmentSpread(_) => continue,
+        };
+        let field = match set.types.get(&parent_type) {
+            Some(SetType::Object(object)) => object.fields.get(&field_name),
+            Some(SetType::Interface(interface)) => interface.fields.get(&field_name),
+            _ => None,
+        };
+        match (field, sub_selections) {
+            (None, _) => report_unknown_field(field_name, parent_type),
+            (Some(field), Some(sub_selections)) => validate_key_selections(
+                set,
+                field.type_.inner(),
+                sub_selections,
+                report_unknown_field,
+            ),
+            (Some(_), None) => {}
+        }
+    }
+}
+
+fn entity_key_directives(type_: &SetType) -> impl Iterator<Item = &SetDirectiveValue> {
+    type_
+        .directives()
+        .iter()
+        .filter(|directive| directive.name == *KEY)
+}
+
+fn key_fields(key: &SetDirectiveValue) -> Option<StringKey> {
+    key.arguments
+        .iter()
+        .find(|argument| argument.name == *KEY_FIELDS_ARG)
+        .and_then(|argument| match &argument.value {
+            ConstantValue::String(fields) => Some(fields.value),
+            _ => None,
+        })
+}
+
+/// The type with all non-null wrappers removed: subgraphs may disagree on
+/// the nullability of a field, the supergraph uses the most nullable type.
+fn nullable_output_type(type_: &OutputTypeReference<StringKey>) -> TypeReference<StringKey> {
+    match type_ {
+        OutputTypeReference::Named(name) => TypeReference::Named(*name),
+        OutputTypeReference::List(of) => TypeReference::List(Box::new(nullable_output_type(of))),
+        OutputTypeReference::NonNull(OutputNonNull::KillsParent(of))
+        | OutputTypeReference::NonNull(OutputNonNull::Semantic(of)) => nullable_output_type(of),
+    }
+}
+
+fn nullable_type(type_: &TypeReference<StringKey>) -> TypeReference<StringKey> {
+    match type_ {
+        TypeReference::Named(name) => TypeReference::Named(*name),
+        TypeReference::List(of) => TypeReference::List(Box::new(nullable_type(of))),
+        TypeReference::NonNull(of) => nullable_type(of),
+    }
+}
+
+fn definition_location(definition: &SchemaDefinitionItem) -> Location {
+    definition
+        .locations
+        .first()
+        .copied()
+        .unwrap_or_else(Location::generated)
+}
+
+/// The `fields` of a key are a selection set without the enclosing braces.
+fn parse_key_fields(fields: StringKey) -> DiagnosticsResult<Vec<Selection>> {
+    let document = graphql_syntax::parse_executable(
+        &format!("{{ {} }}", fields),
+        SourceLocationKey::generated(),
+    )?;
+    Ok(match document.definitions.into_iter().next() {
+        Some(ExecutableDefinition::Operation(operation)) => operation.selections.items,
+        _ => Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::SourceLocationKey;
+    use graphql_syntax::parse_schema_document;
+    use indoc::indoc;
+
+    use super::*;
+
+    fn compose(subgraphs: &[(&str, &str)]) -> DiagnosticsResult<String> {
+        let documents = subgraphs
+            .iter()
+            .map(|(name, sdl)| {
+                parse_schema_document(sdl, SourceLocationKey::standalone(name)).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let subgraphs = subgraphs
+            .iter()
+            .zip(&documents)
+            .map(|((name, _), document)| Subgraph {
+                name: name.intern(),
+                document,
+            })
+            .collect::<Vec<_>>();
+        let supergraph = compose_subgraphs(&subgraphs)?;
+        Ok(supergraph.printed_base_and_client_schema()?.0)
+    }
+
+    fn composition_errors(subgraphs: &[(&str, &str)]) -> Vec<String> {
+        compose(subgraphs)
+            .unwrap_err()
+            .iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_compose_merges_subgraph_fields() {
+        let supergraph = compose(&[
+            (
+                "accounts",
+                "type Query { me: User } type User @key(fields: \"id\") { id: ID! name: String! }",
+            ),
+            (
+                "reviews",
+                "type Query { topReviews: [Review] } type Review { body: String author: User } extend type User @key(fields: \"id\") { id: ID! name: String reviews: [Review] }",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(
+            supergraph,
+            indoc! {r#"
+                type Query {
+                  me: User
+                  topReviews: [Review]
+                }
+
+                type Review {
+                  author: User
+                  body: String
+                }
+
+                type User @key(fields: "id") {
+                  id: ID!
+                  name: String
+                  reviews: [Review]
+                }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_compose_keeps_every_entity_key() {
+        let supergraph = compose(&[
+            (
+                "accounts",
+                "type User @key(fields: \"id\") { id: ID! email: String }",
+            ),
+            (
+                "billing",
+                "type User @key(fields: \"email\") { email: String plan: String }",
+            ),
+        ])
+        .unwrap();
+        assert!(
+            supergraph.contains(r#"type User @key(fields: "email") @key(fields: "id")"#),
+            "{}",
+            supergraph
+        );
+    }
+
+    #[test]
+    fn test_compose_drops_schema_directives() {
+        let supergraph = compose(&[(
+            "accounts",
+            "extend schema @link(url: \"https://specs.apollo.dev/federation/v2.3\") type Query { me: String }",
+        )])
+        .unwrap();
+        assert_eq!(supergraph, "type Query {\n  me: String\n}\n");
+    }
+
+    #[test]
+    fn test_compose_mismatched_field_types() {
+        assert_eq!(
+            composition_errors(&[
+                ("accounts", "type User { id: ID! age: Int }"),
+                ("reviews", "type User { id: ID age: [Int] }"),
+            ]),
+            vec![
+                "Field 'User.age' has type 'Int' in subgraph 'accounts', but '[Int]' in subgraph 'reviews'. Subgraphs may only disagree on the nullability of a field."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose_mismatched_input_field_types() {
+        assert_eq!(
+            composition_errors(&[
+                ("accounts", "input UserFilter { id: ID! }"),
+                ("reviews", "input UserFilter { id: String }"),
+            ]),
+            vec![
+                "Field 'UserFilter.id' has type 'ID!' in subgraph 'accounts', but 'String' in subgraph 'reviews'. Subgraphs may only disagree on the nullability of a field."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose_mismatched_type_kinds() {
+        assert_eq!(
+            composition_errors(&[
+                ("accounts", "type Status { id: ID }"),
+                ("reviews", "enum Status { OPEN }"),
+            ]),
+            vec![
+                "Type 'Status' is defined as object in subgraph 'accounts', but as enum in subgraph 'reviews'."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose_divergent_enum_values() {
+        assert_eq!(
+            composition_errors(&[
+                ("accounts", "enum Status { ACTIVE ARCHIVED }"),
+                ("reviews", "enum Status { ACTIVE DRAFT }"),
+            ]),
+            vec![
+                "Enum 'Status' has diverging values in subgraphs 'accounts' and 'reviews': 'ARCHIVED' is only defined in 'accounts', 'DRAFT' is only defined in 'reviews'."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose_entity_without_key() {
+        assert_eq!(
+            composition_errors(&[
+                ("accounts", "type User @key(fields: \"id\") { id: ID! }"),
+                ("reviews", "type User { id: ID! reviews: [String] }"),
+            ]),
+            vec![
+                "Type 'User' is an entity in subgraph 'accounts', but subgraph 'reviews' defines it without a @key directive."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compose_unknown_key_fields() {
+        assert_eq!(
+            composition_errors(&[(
+                "accounts",
+                "type User @key(fields: \"id organization { uid }\") @key(fields: \"\") { id: ID! organization: Organization } type Organization { id: ID! }",
+            )]),
+            vec![
+                "The @key(fields: \"id organization { uid }\") directive on 'User' in subgraph 'accounts' selects 'uid', which is not a field of 'Organization'.",
+                "The @key directive on 'User' in subgraph 'accounts' must have a `fields` argument with a selection of the key fields: Expected the list to be non-empty",
+            ]
+        );
+    }
+}
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use common::Location;
 use common::NamedItem;
 use intern::Lookup;
 use intern::string_key::StringKey;
@@ -12,6 +13,7 @@ use intern::string_key::StringKeyIndexMap;
 use intern::string_key::StringKeyMap;
 use schema::TypeReference;
 use schema_coordinates::SchemaCoordinate;
+use schema_coordinates::parse_schema_coordinate;
 use serde::Serialize;
 
 use crate::OutputNonNull;
@@ -29,6 +31,7 @@ pub struct SchemaFileLocation {
 
 use crate::CanHaveDirectives;
 use crate::DirectivePolicies;
+use crate::HasDefinitionItem;
 use crate::OutputTypeReference;
 use crate::SchemaSet;
 use crate::SetArgument;
@@ -110,6 +113,92 @@ pub fn find_subset_violations(
     violations
 }
 
+/// Fills in the `base_locations` and `subset_locations` of `violations` from
+/// the locations their schema coordinate is defined at in `base` and `subset`.
+/// `to_file_location` maps a location to its file and line, or to `None` for
+/// locations that aren't backed by a file.
+pub fn locate_subset_violations(
+    violations: &mut [SubsetViolation],
+    base: &SchemaSet,
+    subset: &SchemaSet,
+    to_file_location: impl Fn(Location) -> Option<SchemaFileLocation>,
+) {
+    for violation in violations {
+        let Ok(coordinate) = parse_schema_coordinate(&violation.schema_coordinate) else {
+            continue;
+        };
+        violation.base_locations = coordinate_locations(base, &coordinate)
+            .iter()
+            .filter_map(|location| to_file_location(*location))
+            .collect();
+        violation.subset_locations = coordinate_locations(subset, &coordinate)
+            .iter()
+            .filter_map(|location| to_file_location(*location))
+            .collect();
+    }
+}
+
+fn coordinate_locations<'a>(
+    schema_set: &'a SchemaSet,
+    coordinate: &SchemaCoordinate,
+) -> &'a [Location] {
+    let locations = match *coordinate {
+        SchemaCoordinate::Type { name } => schema_set
+            .types
+            .get(&name)
+            .map(|type_| &type_.definition_item().locations),
+        SchemaCoordinate::Member {
+            parent_name,
+            member_name,
+        } => match schema_set.types.get(&parent_name) {
+            Some(SetType::Object(object)) => object
+                .fields
+                .get(&member_name)
+                .map(|field| &field.definition.locations),
+            Some(SetType::Interface(interface)) => interface
+                .fields
+                .get(&member_name)
+                .map(|field| &field.definition.locations),
+            Some(SetType::InputObject(input_object)) => input_object
+                .fields
+                .get(&member_name)
+                .map(|field| &field.definition.locations),
+            Some(SetType::Enum(enum_)) => enum_
+                .values
+                .get(&member_name)
+                .map(|value| &value.definition.locations),
+            _ => None,
+        },
+        SchemaCoordinate::Argument {
+            parent_name,
+            member_name,
+            argument_name,
+        } => {
+            let field = match schema_set.types.get(&parent_name) {
+                Some(SetType::Object(object)) => object.fields.get(&member_name),
+                Some(SetType::Interface(interface)) => interface.fields.get(&member_name),
+                _ => None,
+            };
+            field
+                .and_then(|field| field.arguments.get(&argument_name))
+                .map(|argument| &argument.definition.locations)
+        }
+        SchemaCoordinate::Directive { name } => schema_set
+            .directives
+            .get(&name)
+            .map(|directive| &directive.definition.locations),
+        SchemaCoordinate::DirectiveArgument {
+            directive_name,
+            argument_name,
+        } => schema_set
+            .directives
+            .get(&directive_name)
+            .and_then(|directive| directive.arguments.get(&argument_name))
+            .map(|argument| &argument.definition.locations),
+    };
+    locations.map_or(&[], Vec::as_slice)
+}
+
 fn walk_type_violations(
     violations: &mut Vec<SubsetViolation>,
     type_name: StringKey,
@@ -1692,4 +1781,54 @@ mod tests {
             v.iter().map(|v| &v.description).collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_locate_subset_violations() {
+        let base_sdl = "type Query {\n  a: String\n  b(x: Int): String\n}\n";
+        let subset_sdl = "type Query {\n  a: String\n  b(y: Int): String\n  c: Int\n}\n";
+        let base = SchemaSet::from_base_schema_documents(&[parse_schema_document(
+            base_sdl,
+            SourceLocationKey::standalone("base.graphql"),
+        )
+        .unwrap()])
+        .unwrap();
+        let subset = SchemaSet::from_base_schema_documents(&[parse_schema_document(
+            subset_sdl,
+            SourceLocationKey::standalone("subset.graphql"),
+        )
+        .unwrap()])
+        .unwrap();
+        let mut v = find_subset_violations(&base, &subset, &DirectivePolicies::default());
+        locate_subset_violations(&mut v, &base, &subset, |location| {
+            let text = match location.source_location().path() {
+                "base.graphql" => base_sdl,
+                _ => subset_sdl,
+            };
+            Some(SchemaFileLocation {
+                file: location.source_location().path().to_string(),
+                line: text[..location.span().start as usize].matches('\n').count() as u32 + 1,
+            })
+        });
+
+        let located: Vec<_> = v
+            .iter()
+            .map(|v| {
+                (
+                    v.schema_coordinate.as_str(),
+                    v.base_locations.iter().map(|l| l.line).collect::<Vec<_>>(),
+                    v.subset_locations
+                        .iter()
+                        .map(|l| l.line)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            located,
+            vec![
+                ("Query.b(y:)", vec![], vec![3]),
+                ("Query.c", vec![], vec![4])
+            ]
+        );
+    }
 }
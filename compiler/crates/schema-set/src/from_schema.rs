@@ -365,8 +365,7 @@ impl SchemaDefault<InputObjectID> for SetInputObject {
         SetInputObject {
             definition: SchemaDefinitionItem {
                 locations: vec![from_schema.name.location],
-                // schema::InputObject does not have extension (which is really client definitions) yet
-                is_client_definition: false,
+                is_client_definition: from_schema.is_extension,
                 description: None,
                 hack_source: None,
             },
@@ -8,6 +8,7 @@
 mod build_in_memory_schema;
 mod build_schema_document;
 mod builtin_scalars;
+pub mod compose_subgraphs;
 pub mod directive_policies;
 pub mod find_subset_violations;
 mod from_schema;
@@ -38,6 +39,8 @@ pub use crate::build_schema_document::ToSDLDefinition;
 pub use crate::build_schema_document::ToTypeSystemDefinition;
 pub use crate::builtin_scalars::add_built_in_scalars;
 pub use crate::builtin_scalars::remove_built_in_scalars;
+pub use crate::compose_subgraphs::Subgraph;
+pub use crate::compose_subgraphs::compose_subgraphs;
 pub use crate::directive_policies::DirectivePolicies;
 pub use crate::directive_policies::DirectivePolicy;
 pub use crate::from_schema::SchemaDefault;
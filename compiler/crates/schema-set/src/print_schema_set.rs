@@ -636,7 +636,7 @@ fn print_set_argument_value(arg: &SetArgumentValue) -> String {
     format!("{}: {}", arg.name.0, arg.value)
 }
 
-fn print_type_reference(type_reference: &TypeReference<StringKey>) -> String {
+pub(crate) fn print_type_reference(type_reference: &TypeReference<StringKey>) -> String {
     match type_reference {
         TypeReference::Named(t) => t.to_string(),
         TypeReference::NonNull(t) => format!("{}!", print_type_reference(t)),
@@ -644,7 +644,9 @@ fn print_type_reference(type_reference: &TypeReference<StringKey>) -> String {
     }
 }
 
-fn print_output_type_reference(type_reference: &OutputTypeReference<StringKey>) -> String {
+pub(crate) fn print_output_type_reference(
+    type_reference: &OutputTypeReference<StringKey>,
+) -> String {
     let (type_reference_sdl, semantic_non_null) =
         output_type_ref_to_semantic_sdl_type(type_reference);
     if let Some(semantic_non_null_directive) = semantic_non_null {
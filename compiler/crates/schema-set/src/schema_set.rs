@@ -961,6 +961,12 @@ impl SetEnumValue {
     pub fn to_enum_value(&self) -> EnumValue {
         EnumValue {
             value: self.value,
+            location: self
+                .definition
+                .locations
+                .first()
+                .copied()
+                .unwrap_or_else(Location::generated),
             directives: self
                 .directives
                 .iter()
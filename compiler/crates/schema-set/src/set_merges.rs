@@ -748,7 +748,7 @@ fn merge_arguments<T: HasArguments>(
     Ok(())
 }
 
-fn set_type_kind_name(t: &SetType) -> &'static str {
+pub(crate) fn set_type_kind_name(t: &SetType) -> &'static str {
     match t {
         SetType::Scalar(_) => "Scalar",
         SetType::Enum(_) => "Enum",
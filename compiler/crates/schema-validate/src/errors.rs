@@ -85,3 +85,49 @@ pub enum SchemaValidationError {
     #[error("Cyclic reference found for interface inheritance: {0}.")]
     CyclicInterfaceInheritance(String),
 }
+
+#[derive(Clone, Debug, Error, serde::Serialize)]
+pub enum SchemaLintError {
+    #[error("Type name '{0}' should be PascalCase.")]
+    TypeNameNotPascalCase(StringKey),
+
+    #[error("Field name '{0}.{1}' should be camelCase.")]
+    FieldNameNotCamelCase(StringKey, StringKey),
+
+    #[error("Argument name '{0}.{1}({2}:)' should be camelCase.")]
+    ArgumentNameNotCamelCase(StringKey, StringKey, ArgumentName),
+
+    #[error("Enum value '{0}.{1}' should be SCREAMING_SNAKE_CASE.")]
+    EnumValueNotScreamingCase(StringKey, StringKey),
+
+    #[error("Type '{0}' should have a description.")]
+    MissingTypeDescription(StringKey),
+
+    #[error("Connection type '{0}' must have an 'edges' field that returns a list of edge types.")]
+    ConnectionWithoutEdges(StringKey),
+
+    #[error("Connection type '{0}' must have a 'pageInfo' field that returns 'PageInfo!'.")]
+    ConnectionWithoutPageInfo(StringKey),
+
+    #[error(
+        "Edge type '{0}' must have a 'node' field that does not return a list and a 'cursor' field that returns a scalar."
+    )]
+    InvalidEdgeType(StringKey),
+
+    #[error("'{0}.{1}' must return 'Boolean!'.")]
+    InvalidPageInfoField(StringKey, StringKey),
+
+    #[error(
+        "Field '{0}.{1}' returns a connection and must accept either 'first' and 'after' or 'last' and 'before' arguments."
+    )]
+    ConnectionFieldWithoutPaginationArguments(StringKey, StringKey),
+
+    #[error("Type '{0}' has an 'id: ID!' field and should implement the 'Node' interface.")]
+    MissingNodeInterface(StringKey),
+
+    #[error("Field '{0}.{1}' of a @oneOf input object must be nullable.")]
+    NonNullOneOfField(StringKey, ArgumentName),
+
+    #[error("Field '{0}.{1}' of a @oneOf input object must not have a default value.")]
+    OneOfFieldWithDefaultValue(StringKey, ArgumentName),
+}
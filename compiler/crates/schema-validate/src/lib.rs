@@ -6,6 +6,7 @@
  */
 
 mod errors;
+mod lint;
 
 use std::sync::LazyLock;
 
@@ -39,6 +40,11 @@ use schema::TypeReference;
 use schema::TypeWithFields;
 use schema::UnionID;
 
+pub use crate::lint::LintSeverity;
+pub use crate::lint::SchemaLintConfig;
+pub use crate::lint::SchemaLintRule;
+pub use crate::lint::lint;
+
 static INTROSPECTION_TYPES: LazyLock<FnvHashSet<StringKey>> = LazyLock::new(|| {
     vec![
         "__Schema".intern(),
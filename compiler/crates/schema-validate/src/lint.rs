@@ -0,0 +1,410 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+//! Configurable lint rules for schema governance. Unlike `validate`, which
+//! enforces the GraphQL spec, these rules check conventions and are enabled
+//! one by one, each with its own severity.
+
+use std::sync::LazyLock;
+
+use common::ArgumentName;
+use common::Diagnostic;
+use common::DirectiveName;
+use common::Location;
+use common::NamedItem;
+use fnv::FnvHashMap;
+use intern::Lookup;
+use intern::string_key::Intern;
+use intern::string_key::StringKey;
+use regex::Regex;
+use schema::Field;
+use schema::FieldID;
+use schema::SDLSchema;
+use schema::Schema;
+use schema::Type;
+use serde::Deserialize;
+
+use crate::SchemaValidationOptions;
+use crate::ValidationContext;
+use crate::errors::SchemaLintError;
+use crate::is_introspection_type;
+
+static PASCAL_CASE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z][a-zA-Z0-9]*$").unwrap());
+static CAMEL_CASE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^_*[a-z][a-zA-Z0-9]*$").unwrap());
+static SCREAMING_CASE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z][A-Z0-9_]*$").unwrap());
+static BUILTIN_SCALARS: [&str; 5] = ["ID", "String", "Int", "Float", "Boolean"];
+static NODE: LazyLock<StringKey> = LazyLock::new(|| "Node".intern());
+static ID: LazyLock<StringKey> = LazyLock::new(|| "id".intern());
+static EDGES: LazyLock<StringKey> = LazyLock::new(|| "edges".intern());
+static NODE_FIELD: LazyLock<StringKey> = LazyLock::new(|| "node".intern());
+static CURSOR: LazyLock<StringKey> = LazyLock::new(|| "cursor".intern());
+static PAGE_INFO: LazyLock<StringKey> = LazyLock::new(|| "PageInfo".intern());
+static PAGE_INFO_FIELD: LazyLock<StringKey> = LazyLock::new(|| "pageInfo".intern());
+static HAS_NEXT_PAGE: LazyLock<StringKey> = LazyLock::new(|| "hasNextPage".intern());
+static HAS_PREVIOUS_PAGE: LazyLock<StringKey> = LazyLock::new(|| "hasPreviousPage".intern());
+static FIRST: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("first".intern()));
+static AFTER: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("after".intern()));
+static LAST: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("last".intern()));
+static BEFORE: LazyLock<ArgumentName> = LazyLock::new(|| ArgumentName("before".intern()));
+static ONE_OF: LazyLock<DirectiveName> = LazyLock::new(|| DirectiveName("oneOf".intern()));
+
+/// The lint rules that can be enabled in a `SchemaLintConfig`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaLintRule {
+    /// Type names are PascalCase.
+    TypeNames,
+    /// Field and argument names are camelCase.
+    FieldNames,
+    /// Enum values are SCREAMING_SNAKE_CASE.
+    EnumValues,
+    /// Types, other than the built-in scalars, have a description.
+    TypeDescriptions,
+    /// `*Connection` types, their edges and `PageInfo` follow the Relay
+    /// Cursor Connections spec, and fields returning connections accept
+    /// pagination arguments.
+    ConnectionSpec,
+    /// Object types with an `id: ID!` field implement `Node`, if the schema
+    /// defines it.
+    NodeInterface,
+    /// Fields of `@oneOf` input objects are nullable and have no default.
+    OneOfInputs,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    #[default]
+    Off,
+    Hint,
+    Info,
+    Warning,
+    Error,
+}
+
+/// Lint configuration, usually read from a JSON file such as:
+///
+/// ```json
+/// { "rules": { "typeNames": "error", "typeDescriptions": "warning" } }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaLintConfig {
+    #[serde(default)]
+    pub rules: FnvHashMap<SchemaLintRule, LintSeverity>,
+}
+
+impl SchemaLintConfig {
+    pub fn severity(&self, rule: SchemaLintRule) -> LintSeverity {
+        self.rules.get(&rule).copied().unwrap_or_default()
+    }
+}
+
+/// Runs the lint rules enabled in `config` and returns their diagnostics,
+/// sorted by location.
+pub fn lint(schema: &SDLSchema, config: &SchemaLintConfig) -> Vec<Diagnostic> {
+    let options = SchemaValidationOptions {
+        allow_introspection_names: true,
+    };
+    let mut validation_context = ValidationContext::new(schema, &options);
+    validation_context.lint(config);
+    validation_context
+        .diagnostics
+        .sort_by_key(|diagnostic| diagnostic.location());
+    validation_context.diagnostics
+}
+
+impl ValidationContext<'_> {
+    fn lint(&mut self, config: &SchemaLintConfig) {
+        let mut types: Vec<(StringKey, Type)> = self
+            .schema
+            .get_type_map()
+            .filter(|(type_name, type_)| !is_introspection_type(type_, **type_name))
+            .map(|(type_name, type_)| (*type_name, *type_))
+            .collect();
+        types.sort_by_key(|(type_name, _)| type_name.lookup());
+        for (type_name, type_) in types {
+            self.lint_type(config, type_name, type_);
+        }
+    }
+
+    fn lint_type(&mut self, config: &SchemaLintConfig, type_name: StringKey, type_: Type) {
+        let location = self.get_type_definition_location(type_);
+        let is_builtin_scalar = BUILTIN_SCALARS.contains(&type_name.lookup());
+        if !is_builtin_scalar && !PASCAL_CASE_REGEX.is_match(type_name.lookup()) {
+            self.report_lint(
+                config,
+                SchemaLintRule::TypeNames,
+                SchemaLintError::TypeNameNotPascalCase(type_name),
+                location,
+            );
+        }
+        if !is_builtin_scalar && self.type_description(type_).is_none() {
+            self.report_lint(
+                config,
+                SchemaLintRule::TypeDescriptions,
+                SchemaLintError::MissingTypeDescription(type_name),
+                location,
+            );
+        }
+        match type_ {
+            Type::Object(id) => {
+                let object = self.schema.object(id);
+                self.lint_fields(config, type_name, &object.fields);
+                self.lint_node_interface(config, type_name, type_, location);
+                self.lint_connection_type(config, type_name, type_, location);
+            }
+            Type::Interface(id) => {
+                let interface = self.schema.interface(id);
+                self.lint_fields(config, type_name, &interface.fields);
+            }
+            Type::Enum(id) => {
+                for value in self.schema.enum_(id).values.iter() {
+                    if !SCREAMING_CASE_REGEX.is_match(value.value.lookup()) {
+                        self.report_lint(
+                            config,
+                            SchemaLintRule::EnumValues,
+                            SchemaLintError::EnumValueNotScreamingCase(type_name, value.value),
+                            value.location,
+                        );
+                    }
+                }
+            }
+            Type::InputObject(id) => {
+                let input_object = self.schema.input_object(id);
+                let is_one_of = input_object.directives.named(*ONE_OF).is_some();
+                for field in input_object.fields.iter() {
+                    if !CAMEL_CASE_REGEX.is_match(field.name.item.0.lookup()) {
+                        self.report_lint(
+                            config,
+                            SchemaLintRule::FieldNames,
+                            SchemaLintError::FieldNameNotCamelCase(type_name, field.name.item.0),
+                            field.name.location,
+                        );
+                    }
+                    if is_one_of && field.type_.is_non_null() {
+                        self.report_lint(
+                            config,
+                            SchemaLintRule::OneOfInputs,
+                            SchemaLintError::NonNullOneOfField(type_name, field.name.item),
+                            field.name.location,
+                        );
+                    }
+                    if is_one_of && field.default_value.is_some() {
+                        self.report_lint(
+                            config,
+                            SchemaLintRule::OneOfInputs,
+                            SchemaLintError::OneOfFieldWithDefaultValue(type_name, field.name.item),
+                            field.name.location,
+                        );
+                    }
+                }
+            }
+            Type::Scalar(_) | Type::Union(_) => {}
+        }
+    }
+
+    fn lint_fields(&mut self, config: &SchemaLintConfig, type_name: StringKey, fields: &[FieldID]) {
+        for field_id in fields {
+            let field = self.schema.field(*field_id);
+            if field.name.item.lookup().starts_with("__") {
+                continue;
+            }
+            if !CAMEL_CASE_REGEX.is_match(field.name.item.lookup()) {
+                self.report_lint(
+                    config,
+                    SchemaLintRule::FieldNames,
+                    SchemaLintError::FieldNameNotCamelCase(type_name, field.name.item),
+                    field.name.location,
+                );
+            }
+            for argument in field.arguments.iter() {
+                if !CAMEL_CASE_REGEX.is_match(argument.name.item.0.lookup()) {
+                    self.report_lint(
+                        config,
+                        SchemaLintRule::FieldNames,
+                        SchemaLintError::ArgumentNameNotCamelCase(
+                            type_name,
+                            field.name.item,
+                            argument.name.item,
+                        ),
+                        argument.name.location,
+                    );
+                }
+            }
+            if is_connection_type_name(field.type_.inner(), self.schema) {
+                let arguments = &field.arguments;
+                let forward =
+                    arguments.named(*FIRST).is_some() && arguments.named(*AFTER).is_some();
+                let backward =
+                    arguments.named(*LAST).is_some() && arguments.named(*BEFORE).is_some();
+                if !forward && !backward {
+                    self.report_lint(
+                        config,
+                        SchemaLintRule::ConnectionSpec,
+                        SchemaLintError::ConnectionFieldWithoutPaginationArguments(
+                            type_name,
+                            field.name.item,
+                        ),
+                        field.name.location,
+                    );
+                }
+            }
+        }
+    }
+
+    fn lint_node_interface(
+        &mut self,
+        config: &SchemaLintConfig,
+        type_name: StringKey,
+        type_: Type,
+        location: Location,
+    ) {
+        let Some(Type::Interface(node_id)) = self.schema.get_type(*NODE) else {
+            return;
+        };
+        let Type::Object(id) = type_ else {
+            return;
+        };
+        let has_id = self
+            .schema
+            .named_field(type_, *ID)
+            .map(|field_id| self.schema.field(field_id))
+            .is_some_and(|field| {
+                field.type_.is_non_null()
+                    && self.schema.get_type_name(field.type_.inner()).lookup() == "ID"
+            });
+        if has_id && !self.schema.object(id).interfaces.contains(&node_id) {
+            self.report_lint(
+                config,
+                SchemaLintRule::NodeInterface,
+                SchemaLintError::MissingNodeInterface(type_name),
+                location,
+            );
+        }
+    }
+
+    fn lint_connection_type(
+        &mut self,
+        config: &SchemaLintConfig,
+        type_name: StringKey,
+        type_: Type,
+        location: Location,
+    ) {
+        if type_name == *PAGE_INFO {
+            for field_name in [*HAS_NEXT_PAGE, *HAS_PREVIOUS_PAGE] {
+                let is_boolean = self.named_field(type_, field_name).is_some_and(|field| {
+                    field.type_.is_non_null()
+                        && self.schema.get_type_name(field.type_.inner()).lookup() == "Boolean"
+                });
+                if !is_boolean {
+                    self.report_lint(
+                        config,
+                        SchemaLintRule::ConnectionSpec,
+                        SchemaLintError::InvalidPageInfoField(type_name, field_name),
+                        location,
+                    );
+                }
+            }
+            return;
+        }
+        if !is_connection_type_name(type_, self.schema) {
+            return;
+        }
+
+        let edge_type = self
+            .named_field(type_, *EDGES)
+            .filter(|field| field.type_.nullable_type().is_list())
+            .map(|field| field.type_.inner())
+            .filter(|edge_type| edge_type.is_object());
+        match edge_type {
+            Some(edge_type) => self.lint_edge_type(config, edge_type),
+            None => self.report_lint(
+                config,
+                SchemaLintRule::ConnectionSpec,
+                SchemaLintError::ConnectionWithoutEdges(type_name),
+                location,
+            ),
+        }
+
+        let has_page_info = self
+            .named_field(type_, *PAGE_INFO_FIELD)
+            .is_some_and(|field| {
+                field.type_.is_non_null()
+                    && self.schema.get_type_name(field.type_.inner()) == *PAGE_INFO
+            });
+        if !has_page_info {
+            self.report_lint(
+                config,
+                SchemaLintRule::ConnectionSpec,
+                SchemaLintError::ConnectionWithoutPageInfo(type_name),
+                location,
+            );
+        }
+    }
+
+    fn lint_edge_type(&mut self, config: &SchemaLintConfig, edge_type: Type) {
+        let has_node = self
+            .named_field(edge_type, *NODE_FIELD)
+            .is_some_and(|field| !field.type_.nullable_type().is_list());
+        let has_cursor = self
+            .named_field(edge_type, *CURSOR)
+            .is_some_and(|field| field.type_.non_list_type().is_some_and(|t| t.is_scalar()));
+        if !has_node || !has_cursor {
+            self.report_lint(
+                config,
+                SchemaLintRule::ConnectionSpec,
+                SchemaLintError::InvalidEdgeType(self.schema.get_type_name(edge_type)),
+                self.get_type_definition_location(edge_type),
+            );
+        }
+    }
+
+    fn named_field(&self, type_: Type, name: StringKey) -> Option<&Field> {
+        self.schema
+            .named_field(type_, name)
+            .map(|field_id| self.schema.field(field_id))
+    }
+
+    fn type_description(&self, type_: Type) -> Option<StringKey> {
+        match type_ {
+            Type::Enum(id) => self.schema.enum_(id).description,
+            Type::InputObject(id) => self.schema.input_object(id).description,
+            Type::Interface(id) => self.schema.interface(id).description,
+            Type::Object(id) => self.schema.object(id).description,
+            Type::Scalar(id) => self.schema.scalar(id).description,
+            Type::Union(id) => self.schema.union(id).description,
+        }
+    }
+
+    fn report_lint(
+        &mut self,
+        config: &SchemaLintConfig,
+        rule: SchemaLintRule,
+        error: SchemaLintError,
+        location: Location,
+    ) {
+        let diagnostic = match config.severity(rule) {
+            LintSeverity::Off => return,
+            LintSeverity::Hint => Diagnostic::hint(error, location, vec![]),
+            LintSeverity::Info => Diagnostic::info(error, location, vec![]),
+            LintSeverity::Warning => Diagnostic::warning(error, location, vec![]),
+            LintSeverity::Error => Diagnostic::error(error, location),
+        };
+        self.report_diagnostic(diagnostic);
+    }
+}
+
+fn is_connection_type_name(type_: Type, schema: &SDLSchema) -> bool {
+    type_.is_object() && {
+        let name = schema.get_type_name(type_).lookup();
+        name.len() > "Connection".len() && name.ends_with("Connection")
+    }
+}
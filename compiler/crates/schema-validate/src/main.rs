@@ -9,6 +9,7 @@ use std::fs;
 use std::path::Path;
 
 use clap::Parser;
+use common::DiagnosticSeverity;
 use common::DiagnosticsResult;
 use common::SourceLocationKey;
 use common::TextSource;
@@ -16,7 +17,9 @@ use graphql_cli::DiagnosticPrinter;
 use intern::intern::Lookup;
 use schema::SDLSchema;
 use schema::build_schema_with_extensions_parallel;
+use schema_validate_lib::SchemaLintConfig;
 use schema_validate_lib::SchemaValidationOptions;
+use schema_validate_lib::lint;
 use schema_validate_lib::validate;
 
 #[derive(Parser)]
@@ -25,6 +28,11 @@ struct Opt {
     /// Path to Schema SDL. If schema is sharded, this is directory.
     #[clap(long)]
     schema_path: String,
+
+    /// Path to a JSON file enabling schema lint rules, e.g.
+    /// `{ "rules": { "typeNames": "error", "typeDescriptions": "warning" } }`.
+    #[clap(long)]
+    lint_config: Option<String>,
 }
 
 pub fn main() {
@@ -44,6 +52,22 @@ pub fn main() {
                 );
                 std::process::exit(1);
             }
+            if let Some(lint_config_path) = &opt.lint_config {
+                let diagnostics = lint(&schema, &read_lint_config(lint_config_path));
+                if !diagnostics.is_empty() {
+                    let printer = DiagnosticPrinter::new(sources);
+                    println!(
+                        "Schema lint found below issues:\n{}",
+                        printer.diagnostics_to_string(&diagnostics)
+                    );
+                }
+                if diagnostics
+                    .iter()
+                    .any(|diagnostic| diagnostic.severity() == DiagnosticSeverity::ERROR)
+                {
+                    std::process::exit(1);
+                }
+            }
         }
         Err(diagnostics) => {
             let printer = DiagnosticPrinter::new(sources);
@@ -84,6 +108,19 @@ fn build_schema_from_path(schema_file: &str) -> DiagnosticsResult<SDLSchema> {
     }
 }
 
+fn read_lint_config(path: &str) -> SchemaLintConfig {
+    let parsed = fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|text| serde_json::from_str(&text).map_err(|error| error.to_string()));
+    match parsed {
+        Ok(config) => config,
+        Err(error) => {
+            println!("Failed to read lint config {}: {}", path, error);
+            std::process::exit(1);
+        }
+    }
+}
+
 fn path_to_schema_source(path: &Path) -> (String, SourceLocationKey) {
     (
         fs::read_to_string(path).unwrap(),
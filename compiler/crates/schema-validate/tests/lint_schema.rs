@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use common::SourceLocationKey;
+use common::TextSource;
+use fixture_tests::Fixture;
+use graphql_cli::DiagnosticPrinter;
+use schema::build_schema_with_extensions_parallel;
+use schema_validate_lib::SchemaLintConfig;
+use schema_validate_lib::lint;
+
+pub async fn transform_fixture(fixture: &Fixture<'_>) -> Result<String, String> {
+    let (sdl, config) = fixture
+        .content
+        .split_once("%config%")
+        .ok_or("Expected a %config% section")?;
+    let config: SchemaLintConfig = serde_json::from_str(config).map_err(|e| e.to_string())?;
+    let schema = build_schema_with_extensions_parallel::<&str, &str>(
+        &[(sdl, SourceLocationKey::standalone(fixture.file_name))],
+        &[],
+    )
+    .map_err(|diagnostics| format!("{diagnostics:?}"))?;
+    let diagnostics = lint(&schema, &config);
+    if diagnostics.is_empty() {
+        return Ok("OK".to_string());
+    }
+    let printer = DiagnosticPrinter::new(|_| {
+        Some(TextSource::from_whole_document(fixture.content.to_string()))
+    });
+    Ok(printer.diagnostics_to_string(&diagnostics))
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ *
+ * @generated SignedSource<<9907509cf284dd94fda384bf955ca927>>
+ */
+
+mod lint_schema;
+
+use lint_schema::transform_fixture;
+use fixture_tests::test_fixture;
+
+#[tokio::test]
+async fn connection_spec() {
+    let input = include_str!("lint_schema/fixtures/connection_spec.graphql");
+    let expected = include_str!("lint_schema/fixtures/connection_spec.expected");
+    test_fixture(transform_fixture, file!(), "connection_spec.graphql", "lint_schema/fixtures/connection_spec.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn naming_and_descriptions() {
+    let input = include_str!("lint_schema/fixtures/naming_and_descriptions.graphql");
+    let expected = include_str!("lint_schema/fixtures/naming_and_descriptions.expected");
+    test_fixture(transform_fixture, file!(), "naming_and_descriptions.graphql", "lint_schema/fixtures/naming_and_descriptions.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn no_rules_enabled() {
+    let input = include_str!("lint_schema/fixtures/no_rules_enabled.graphql");
+    let expected = include_str!("lint_schema/fixtures/no_rules_enabled.expected");
+    test_fixture(transform_fixture, file!(), "no_rules_enabled.graphql", "lint_schema/fixtures/no_rules_enabled.expected", input, expected).await;
+}
+
+#[tokio::test]
+async fn node_interface_and_one_of() {
+    let input = include_str!("lint_schema/fixtures/node_interface_and_one_of.graphql");
+    let expected = include_str!("lint_schema/fixtures/node_interface_and_one_of.expected");
+    test_fixture(transform_fixture, file!(), "node_interface_and_one_of.graphql", "lint_schema/fixtures/node_interface_and_one_of.expected", input, expected).await;
+}
@@ -25,6 +25,7 @@ use common::DirectiveName;
 use common::EnumName;
 use common::InputObjectName;
 use common::InterfaceName;
+use common::Location;
 use common::ObjectName;
 use common::ScalarName;
 use common::UnionName;
@@ -626,6 +627,7 @@ impl<'a> Reader<'a> {
         let description = self.r_opt_sk();
         EnumValue {
             value,
+            location: Location::generated(),
             directives,
             description,
         }
@@ -784,6 +786,7 @@ impl<'a> Reader<'a> {
         let hack_source = self.r_opt_sk();
         InputObject {
             name,
+            is_extension: false,
             fields,
             directives,
             description,
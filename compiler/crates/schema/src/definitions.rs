@@ -460,6 +460,7 @@ impl Object {
 )]
 pub struct InputObject {
     pub name: WithLocation<InputObjectName>,
+    pub is_extension: bool,
     pub fields: ArgumentDefinitions,
     pub directives: Vec<DirectiveValue>,
     pub description: Option<StringKey>,
@@ -680,21 +681,34 @@ pub struct DirectiveValue {
     pub arguments: Vec<ArgumentValue>,
 }
 
-#[derive(
-    Clone,
-    Debug,
-    Eq,
-    PartialEq,
-    Hash,
-    serde::Serialize,
-    serde::Deserialize
-)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct EnumValue {
     pub value: StringKey,
+    pub location: Location,
     pub directives: Vec<DirectiveValue>,
     pub description: Option<StringKey>,
 }
 
+// The location is left out, so that a value repeated by an extension is still
+// recognized as the same value.
+impl PartialEq for EnumValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.directives == other.directives
+            && self.description == other.description
+    }
+}
+
+impl Eq for EnumValue {}
+
+impl Hash for EnumValue {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.directives.hash(state);
+        self.description.hash(state);
+    }
+}
+
 impl EnumValue {
     pub fn deprecated(&self) -> Option<Deprecation> {
         self.directives
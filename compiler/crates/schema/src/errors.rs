@@ -57,4 +57,7 @@ pub enum SchemaError {
 
     #[error("The directive `@{0}` can only be used once at this location, but was used {1} times.")]
     RepeatedNonRepeatableDirective(StringKey, usize),
+
+    #[error("Failed to read the flatbuffer schema at '{0}': {1}")]
+    FlatBufferRead(String, String),
 }
@@ -15,12 +15,14 @@ use common::DirectiveName;
 use common::EnumName;
 use common::InputObjectName;
 use common::InterfaceName;
+use common::Location;
 use common::ObjectName;
 use common::ScalarName;
 use common::Span;
 use common::UnionName;
 use common::WithLocation;
 use flatbuffers::ForwardsUOffset;
+use flatbuffers::InvalidFlatbuffer;
 use flatbuffers::Vector;
 use flatbuffers::VerifierOptions;
 use graphql_syntax::BooleanNode;
@@ -64,6 +66,10 @@ pub struct FlatBufferSchema<'fb> {
 
 impl<'fb> FlatBufferSchema<'fb> {
     pub fn build(bytes: &'fb [u8]) -> Self {
+        Self::try_build(bytes).expect("Failed to get root as schema")
+    }
+
+    pub fn try_build(bytes: &'fb [u8]) -> Result<Self, InvalidFlatbuffer> {
         #![allow(deprecated)]
         // Use custom verifier options with increased max_tables limit (default 1M) to handle large schemas
         let opts: VerifierOptions = flatbuffers::VerifierOptions {
@@ -72,10 +78,9 @@ impl<'fb> FlatBufferSchema<'fb> {
         };
 
         let fb_schema: schema_flatbuffer::Schema<'fb> =
-            schema_flatbuffer::root_as_schema_with_opts(&opts, bytes)
-                .expect("Failed to get root as schema");
+            schema_flatbuffer::root_as_schema_with_opts(&opts, bytes)?;
 
-        Self::from_flatbuffer_schema(fb_schema)
+        Ok(Self::from_flatbuffer_schema(fb_schema))
     }
 
     pub fn build_unchecked(bytes: &'fb [u8]) -> Self {
@@ -257,6 +262,7 @@ impl<'fb> FlatBufferSchema<'fb> {
         let name = InputObjectName(input_object.name()?.to_string().intern());
         let parsed_input_object = InputObject {
             name: WithLocation::generated(name),
+            is_extension: false,
             fields: self.parse_arguments(input_object.fields()?)?,
             directives: self.parse_directive_values(input_object.directives()?)?,
             description: None,
@@ -355,6 +361,7 @@ impl<'fb> FlatBufferSchema<'fb> {
         let directives = self.parse_directive_values(value.directives()?)?;
         Some(EnumValue {
             value: value.value()?.intern(),
+            location: Location::generated(),
             directives,
             description: None,
         })
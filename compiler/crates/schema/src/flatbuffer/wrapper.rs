@@ -9,33 +9,60 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt;
+use std::fs;
+use std::fs::File;
 use std::hash::Hash;
+use std::io;
+use std::ops::Deref;
+use std::path::Path;
 
 use common::ArgumentName;
 use common::Diagnostic;
 use common::DiagnosticsResult;
 use common::DirectiveName;
+use common::EnumName;
+use common::InputObjectName;
+use common::InterfaceName;
 use common::Location;
 use common::ObjectName;
 use common::ScalarName;
 use common::SourceLocationKey;
+use common::UnionName;
 use common::WithLocation;
 use dashmap::DashMap;
 use fnv::FnvBuildHasher;
 use graphql_syntax::ConstantDirective;
+use graphql_syntax::DirectiveDefinition;
+use graphql_syntax::DirectiveDefinitionExtension;
 use graphql_syntax::DirectiveLocation;
+use graphql_syntax::EnumTypeDefinition;
+use graphql_syntax::EnumTypeExtension;
+use graphql_syntax::EnumValueDefinition;
 use graphql_syntax::FieldDefinition;
 use graphql_syntax::Identifier;
+use graphql_syntax::InputObjectTypeDefinition;
+use graphql_syntax::InputObjectTypeExtension;
 use graphql_syntax::InputValueDefinition;
+use graphql_syntax::InterfaceTypeDefinition;
 use graphql_syntax::InterfaceTypeExtension;
 use graphql_syntax::List;
 use graphql_syntax::ObjectTypeDefinition;
 use graphql_syntax::ObjectTypeExtension;
+use graphql_syntax::OperationType;
+use graphql_syntax::OperationTypeDefinition;
 use graphql_syntax::ScalarTypeDefinition;
+use graphql_syntax::ScalarTypeExtension;
+use graphql_syntax::SchemaDefinition;
+use graphql_syntax::SchemaDocument;
+use graphql_syntax::SchemaExtension;
 use graphql_syntax::TypeAnnotation;
+use graphql_syntax::TypeSystemDefinition;
+use graphql_syntax::UnionTypeDefinition;
+use graphql_syntax::UnionTypeExtension;
 use intern::Lookup;
 use intern::string_key::Intern;
 use intern::string_key::StringKey;
+use memmap2::Mmap;
 use ouroboros::self_referencing;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::IntoParallelRefIterator;
@@ -49,6 +76,7 @@ use crate::Directive;
 use crate::DirectiveValue;
 use crate::Enum;
 use crate::EnumID;
+use crate::EnumValue;
 use crate::Field;
 use crate::FieldID;
 use crate::InputObject;
@@ -67,10 +95,29 @@ use crate::UnionID;
 use crate::errors::SchemaError;
 use crate::field_descriptions::CLIENT_ID_DESCRIPTION;
 
+/// The bytes backing a `SchemaWrapper`: either owned in memory, or a
+/// read-only memory map of a schema file so that only the pages of the
+/// definitions that are actually read get loaded.
+enum FlatBufferData {
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl Deref for FlatBufferData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            FlatBufferData::Owned(bytes) => bytes,
+            FlatBufferData::Mapped(mmap) => mmap,
+        }
+    }
+}
+
 #[self_referencing]
 struct OwnedFlatBufferSchema {
     #[allow(dead_code)]
-    data: Vec<u8>,
+    data: FlatBufferData,
 
     #[borrows(data)]
     #[covariant]
@@ -115,17 +162,29 @@ pub struct SchemaWrapper {
     type_map: Cache<(), Vec<(StringKey, Type)>>,
     fb: OwnedFlatBufferSchema,
 
-    // Overlay for docblock-injected types and fields
+    // Overlay for client schema extensions and docblock-injected types and fields
     overlay_type_map: HashMap<StringKey, Type>,
     overlay_objects: Vec<Object>,
     overlay_scalars: Vec<Scalar>,
     overlay_fields: Vec<Field>,
     overlay_interfaces: Vec<Interface>,
+    overlay_enums: Vec<Enum>,
+    overlay_unions: Vec<Union>,
+    overlay_input_objects: Vec<InputObject>,
+    // Directives defined or modified by extensions, and the names of the
+    // directives that are not defined in the flatbuffer.
+    overlay_directives: HashMap<DirectiveName, Directive>,
+    overlay_directive_names: Vec<DirectiveName>,
+    overlay_mutation_type: Option<Type>,
+    overlay_subscription_type: Option<Type>,
     // Base entity counts from flatbuffer (for ID offsetting)
     fb_object_count: u32,
     fb_scalar_count: u32,
     fb_field_count: u32,
     fb_interface_count: u32,
+    fb_enum_count: u32,
+    fb_union_count: u32,
+    fb_input_object_count: u32,
 }
 impl fmt::Debug for SchemaWrapper {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -141,7 +200,7 @@ fn _assert_send_sync2(s: &SchemaWrapper) {
 impl SchemaWrapper {
     pub fn from_vec(data: Vec<u8>) -> Self {
         let fb = OwnedFlatBufferSchemaBuilder {
-            data,
+            data: FlatBufferData::Owned(data),
             schema_builder: |data| FlatBufferSchema::build(data),
         }
         .build();
@@ -151,7 +210,7 @@ impl SchemaWrapper {
 
     pub fn from_vec_unchecked(data: Vec<u8>) -> Self {
         let fb = OwnedFlatBufferSchemaBuilder {
-            data,
+            data: FlatBufferData::Owned(data),
             schema_builder: |data| FlatBufferSchema::build_unchecked(data),
         }
         .build();
@@ -159,12 +218,51 @@ impl SchemaWrapper {
         Self::init(fb)
     }
 
+    /// Reads the flatbuffer schema at `path` into memory. The buffer is
+    /// verified once up front; definitions are then decoded lazily as they
+    /// are read.
+    pub fn read_file(path: &Path) -> io::Result<Self> {
+        Self::try_from_data(FlatBufferData::Owned(fs::read(path)?))
+    }
+
+    /// Memory-maps the flatbuffer schema at `path`, like `read_file` but only
+    /// loading the pages of the definitions that are read. Reading a mapped
+    /// file that is truncated or rewritten in place faults (SIGBUS), so only
+    /// map files for a schema that doesn't outlive a single build.
+    pub fn map_file(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the map is read-only, and the buffer is verified before any
+        // definition is read from it. As with any memory-mapped file, the
+        // file must not be truncated while the schema is alive.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Self::try_from_data(FlatBufferData::Mapped(mmap))
+    }
+
+    fn try_from_data(data: FlatBufferData) -> io::Result<Self> {
+        let fb = OwnedFlatBufferSchemaTryBuilder {
+            data,
+            schema_builder: |data| FlatBufferSchema::try_build(data),
+        }
+        .try_build()
+        .map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid flatbuffer schema: {error}"),
+            )
+        })?;
+
+        Ok(Self::init(fb))
+    }
+
     fn init(fb: OwnedFlatBufferSchema) -> Self {
         let fb_schema = fb.borrow_schema();
         let fb_object_count = fb_schema.objects.len() as u32;
         let fb_scalar_count = fb_schema.scalars.len() as u32;
         let fb_field_count = fb_schema.fields.len() as u32;
         let fb_interface_count = fb_schema.interfaces.len() as u32;
+        let fb_enum_count = fb_schema.enums.len() as u32;
+        let fb_union_count = fb_schema.unions.len() as u32;
+        let fb_input_object_count = fb_schema.input_objects.len() as u32;
 
         let mut result = Self {
             clientid_field_name: "__id".intern(),
@@ -188,10 +286,20 @@ impl SchemaWrapper {
             overlay_scalars: Vec::new(),
             overlay_fields: Vec::new(),
             overlay_interfaces: Vec::new(),
+            overlay_enums: Vec::new(),
+            overlay_unions: Vec::new(),
+            overlay_input_objects: Vec::new(),
+            overlay_directives: HashMap::new(),
+            overlay_directive_names: Vec::new(),
+            overlay_mutation_type: None,
+            overlay_subscription_type: None,
             fb_object_count,
             fb_scalar_count,
             fb_field_count,
             fb_interface_count,
+            fb_enum_count,
+            fb_union_count,
+            fb_input_object_count,
         };
 
         // prepopulate special fields
@@ -283,13 +391,20 @@ impl SchemaWrapper {
     pub fn get_directives(&self) -> Vec<&Directive> {
         let fb = self.flatbuffer_schema();
         (0..fb.directives.len())
-            .filter_map(|i| {
-                let name = DirectiveName(fb.directives.get(i).name().intern());
-                self.get_directive(name)
-            })
+            .map(|i| DirectiveName(fb.directives.get(i).name().intern()))
+            .chain(self.overlay_directive_names.iter().copied())
+            .filter_map(|name| self.get_directive(name))
             .collect()
     }
 
+    pub fn get_directive_mut(&mut self, name: DirectiveName) -> Option<&mut Directive> {
+        if !self.overlay_directives.contains_key(&name) {
+            let directive = self.flatbuffer_schema().get_directive(name)?;
+            self.overlay_directives.insert(name, directive);
+        }
+        self.overlay_directives.get_mut(&name)
+    }
+
     pub fn get_type_map(&self) -> impl Iterator<Item = (&StringKey, &Type)> {
         let overlay_ref = &self.overlay_type_map;
         let type_map = self.type_map.get((), || {
@@ -329,12 +444,8 @@ impl SchemaWrapper {
     }
 
     pub fn directives_for_location(&self, location: DirectiveLocation) -> Vec<&Directive> {
-        let fb = self.flatbuffer_schema();
-        (0..fb.directives.len())
-            .filter_map(|i| {
-                let name = DirectiveName(fb.directives.get(i).name().intern());
-                self.get_directive(name)
-            })
+        self.get_directives()
+            .into_iter()
             .filter(|directive| directive.locations.contains(&location))
             .collect()
     }
@@ -346,7 +457,13 @@ impl SchemaWrapper {
                 self.enum_(EnumID(i.try_into().unwrap()));
             }
         }
-        let enums: Vec<&Enum> = self.enums.map.iter().map(|ref_| *ref_.value()).collect();
+        let enums: Vec<&Enum> = self
+            .enums
+            .map
+            .iter()
+            .map(|ref_| *ref_.value())
+            .chain(self.overlay_enums.iter())
+            .collect();
         enums.into_par_iter()
     }
 
@@ -429,7 +546,7 @@ impl SchemaWrapper {
                             .default_value
                             .as_ref()
                             .map(|default_value| default_value.value.clone()),
-                        description: None,
+                        description: arg_def.description.as_ref().map(|node| node.value),
                         directives: self.build_directive_values(&arg_def.directives),
                     })
                 })
@@ -597,23 +714,7 @@ impl SchemaWrapper {
             .collect::<DiagnosticsResult<Vec<_>>>()?;
 
         for interface_id in &interfaces {
-            if interface_id.0 < self.fb_interface_count {
-                let mut iface = self.interface(*interface_id).clone();
-                if !iface.implementing_objects.contains(&object_id) {
-                    iface.implementing_objects.push(object_id);
-                }
-                self.interfaces.set(*interface_id, iface);
-            } else {
-                let idx = (interface_id.0 - self.fb_interface_count) as usize;
-                if !self.overlay_interfaces[idx]
-                    .implementing_objects
-                    .contains(&object_id)
-                {
-                    self.overlay_interfaces[idx]
-                        .implementing_objects
-                        .push(object_id);
-                }
-            }
+            self.add_implementing_object(*interface_id, object_id);
         }
 
         let mut existing_fields = HashMap::new();
@@ -690,21 +791,7 @@ impl SchemaWrapper {
             .collect::<DiagnosticsResult<Vec<_>>>()?;
 
         for interface_id in &built_interfaces {
-            if interface_id.0 < self.fb_interface_count {
-                let mut iface = self.interface(*interface_id).clone();
-                if !iface.implementing_objects.contains(&id) {
-                    iface.implementing_objects.push(id);
-                }
-                self.interfaces.set(*interface_id, iface);
-            } else {
-                let idx = (interface_id.0 - self.fb_interface_count) as usize;
-                if !self.overlay_interfaces[idx]
-                    .implementing_objects
-                    .contains(&id)
-                {
-                    self.overlay_interfaces[idx].implementing_objects.push(id);
-                }
-            }
+            self.add_implementing_object(*interface_id, id);
         }
 
         extend_without_duplicates(&mut obj.interfaces, built_interfaces);
@@ -712,13 +799,7 @@ impl SchemaWrapper {
         let built_directives = self.build_directive_values(&ext.directives);
         extend_without_duplicates(&mut obj.directives, built_directives);
 
-        // Update the object (in cache or overlay)
-        if id.0 < self.fb_object_count {
-            self.objects.set(id, obj);
-        } else {
-            let idx = (id.0 - self.fb_object_count) as usize;
-            self.overlay_objects[idx] = obj;
-        }
+        self.set_object(id, obj);
 
         Ok(())
     }
@@ -776,16 +857,660 @@ impl SchemaWrapper {
         let built_directives = self.build_directive_values(&ext.directives);
         extend_without_duplicates(&mut iface.directives, built_directives);
 
-        // Update the interface (in cache or overlay)
+        self.set_interface(id, iface);
+
+        Ok(())
+    }
+
+    /// Layers client schema extension documents over the flatbuffer schema
+    /// without materializing it: new definitions are added to the overlay,
+    /// and extensions of flatbuffer types replace the cached copies of those
+    /// types.
+    pub fn extend_with_documents(&mut self, documents: &[SchemaDocument]) -> DiagnosticsResult<()> {
+        let definitions: Vec<(&TypeSystemDefinition, SourceLocationKey)> = documents
+            .iter()
+            .flat_map(|document| {
+                let location_key = document.location.source_location();
+                document
+                    .definitions
+                    .iter()
+                    .map(move |definition| (definition, location_key))
+            })
+            .collect();
+
+        // Step 1: reserve IDs for the new types, so definitions can reference
+        // each other regardless of their order.
+        for (definition, location_key) in &definitions {
+            self.reserve_type(definition, *location_key)?;
+        }
+        // Step 2: build the new definitions.
+        for (definition, location_key) in &definitions {
+            self.add_definition(definition, *location_key)?;
+        }
+        // Step 3: apply the extensions, now that every type is defined.
+        for (definition, location_key) in &definitions {
+            self.add_extension(definition, *location_key)?;
+        }
+        Ok(())
+    }
+
+    fn reserve_type(
+        &mut self,
+        definition: &TypeSystemDefinition,
+        location_key: SourceLocationKey,
+    ) -> DiagnosticsResult<()> {
+        let name = match definition {
+            TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition { name, .. })
+            | TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                name, ..
+            })
+            | TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition { name, .. })
+            | TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                name,
+                ..
+            })
+            | TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition { name, .. })
+            | TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition { name, .. }) => name,
+            _ => return Ok(()),
+        };
+        let location = Location::new(location_key, name.span);
+        // Like `InMemorySchema`, we allow duplicate scalar definitions
+        if !matches!(definition, TypeSystemDefinition::ScalarTypeDefinition(_))
+            && self.has_type(name.value)
+        {
+            return Err(vec![Diagnostic::error(
+                SchemaError::DuplicateType(name.value),
+                location,
+            )]);
+        }
+
+        // Push placeholders, which are replaced by `add_definition`.
+        let type_ = match definition {
+            TypeSystemDefinition::ObjectTypeDefinition(_) => {
+                let id = ObjectID(self.fb_object_count + self.overlay_objects.len() as u32);
+                self.overlay_objects.push(Object {
+                    name: WithLocation::new(location, ObjectName(name.value)),
+                    is_extension: true,
+                    fields: Vec::new(),
+                    interfaces: Vec::new(),
+                    directives: Vec::new(),
+                    description: None,
+                    hack_source: None,
+                });
+                Type::Object(id)
+            }
+            TypeSystemDefinition::InterfaceTypeDefinition(_) => {
+                let id =
+                    InterfaceID(self.fb_interface_count + self.overlay_interfaces.len() as u32);
+                self.overlay_interfaces.push(Interface {
+                    name: WithLocation::new(location, InterfaceName(name.value)),
+                    is_extension: true,
+                    implementing_interfaces: Vec::new(),
+                    implementing_objects: Vec::new(),
+                    fields: Vec::new(),
+                    directives: Vec::new(),
+                    interfaces: Vec::new(),
+                    description: None,
+                    hack_source: None,
+                });
+                Type::Interface(id)
+            }
+            TypeSystemDefinition::UnionTypeDefinition(_) => {
+                let id = UnionID(self.fb_union_count + self.overlay_unions.len() as u32);
+                self.overlay_unions.push(Union {
+                    name: WithLocation::new(location, UnionName(name.value)),
+                    is_extension: true,
+                    members: Vec::new(),
+                    directives: Vec::new(),
+                    description: None,
+                    hack_source: None,
+                });
+                Type::Union(id)
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(_) => {
+                let id = InputObjectID(
+                    self.fb_input_object_count + self.overlay_input_objects.len() as u32,
+                );
+                self.overlay_input_objects.push(InputObject {
+                    name: WithLocation::new(location, InputObjectName(name.value)),
+                    is_extension: true,
+                    fields: ArgumentDefinitions::new(Vec::new()),
+                    directives: Vec::new(),
+                    description: None,
+                    hack_source: None,
+                });
+                Type::InputObject(id)
+            }
+            TypeSystemDefinition::EnumTypeDefinition(_) => {
+                let id = EnumID(self.fb_enum_count + self.overlay_enums.len() as u32);
+                self.overlay_enums.push(Enum {
+                    name: WithLocation::new(location, EnumName(name.value)),
+                    is_extension: true,
+                    values: Vec::new(),
+                    directives: Vec::new(),
+                    description: None,
+                    hack_source: None,
+                });
+                Type::Enum(id)
+            }
+            _ => {
+                let id = ScalarID(self.fb_scalar_count + self.overlay_scalars.len() as u32);
+                self.overlay_scalars.push(Scalar {
+                    name: WithLocation::new(location, ScalarName(name.value)),
+                    is_extension: true,
+                    directives: Vec::new(),
+                    description: None,
+                    hack_source: None,
+                });
+                Type::Scalar(id)
+            }
+        };
+        self.overlay_type_map.insert(name.value, type_);
+        Ok(())
+    }
+
+    fn add_definition(
+        &mut self,
+        definition: &TypeSystemDefinition,
+        location_key: SourceLocationKey,
+    ) -> DiagnosticsResult<()> {
+        match definition {
+            TypeSystemDefinition::SchemaDefinition(SchemaDefinition {
+                operation_types, ..
+            }) => {
+                self.add_operation_types(&operation_types.items, location_key)?;
+            }
+            TypeSystemDefinition::SchemaExtension(SchemaExtension {
+                operation_types: Some(operation_types),
+                ..
+            }) => {
+                self.add_operation_types(&operation_types.items, location_key)?;
+            }
+            TypeSystemDefinition::DirectiveDefinition(DirectiveDefinition {
+                name,
+                arguments,
+                repeatable,
+                locations,
+                directives,
+                description,
+                hack_source,
+                ..
+            }) => {
+                let directive_name = DirectiveName(name.value);
+                if self.has_directive(directive_name) {
+                    let str_name = name.value.lookup();
+                    if str_name != "skip" && str_name != "include" {
+                        return Err(vec![Diagnostic::error(
+                            SchemaError::DuplicateDirectiveDefinition(name.value),
+                            Location::new(location_key, name.span),
+                        )]);
+                    }
+                    return Ok(());
+                }
+                let arguments = self.build_arguments(arguments, location_key)?;
+                let directives = self.build_directive_values(directives);
+                self.overlay_directives.insert(
+                    directive_name,
+                    Directive {
+                        name: WithLocation::new(
+                            Location::new(location_key, name.span),
+                            directive_name,
+                        ),
+                        arguments,
+                        locations: locations.clone(),
+                        repeatable: *repeatable,
+                        is_extension: true,
+                        directives,
+                        description: description.as_ref().map(|node| node.value),
+                        hack_source: hack_source.as_ref().map(|node| node.value),
+                    },
+                );
+                self.overlay_directive_names.push(directive_name);
+            }
+            TypeSystemDefinition::ObjectTypeDefinition(ObjectTypeDefinition {
+                name,
+                interfaces,
+                fields,
+                directives,
+                description,
+                ..
+            }) => {
+                let Some(Type::Object(id)) = self.overlay_type_map.get(&name.value).copied() else {
+                    unreachable!("Expected `reserve_type` to reserve an object ID")
+                };
+                let fields = self.build_extend_fields(
+                    fields,
+                    &mut HashMap::with_capacity(len_of_option_list(fields)),
+                    location_key,
+                    Some(Type::Object(id)),
+                )?;
+                let interfaces = interfaces
+                    .iter()
+                    .map(|name| self.build_interface_id(name, &location_key))
+                    .collect::<DiagnosticsResult<Vec<_>>>()?;
+                for interface_id in &interfaces {
+                    self.add_implementing_object(*interface_id, id);
+                }
+                let directives = self.build_directive_values(directives);
+                let object = Object {
+                    name: WithLocation::new(
+                        Location::new(location_key, name.span),
+                        ObjectName(name.value),
+                    ),
+                    is_extension: true,
+                    fields,
+                    interfaces,
+                    directives,
+                    description: description.as_ref().map(|node| node.value),
+                    hack_source: None,
+                };
+                self.set_object(id, object);
+            }
+            TypeSystemDefinition::InterfaceTypeDefinition(InterfaceTypeDefinition {
+                name,
+                interfaces,
+                fields,
+                directives,
+                description,
+                ..
+            }) => {
+                let Some(Type::Interface(id)) = self.overlay_type_map.get(&name.value).copied()
+                else {
+                    unreachable!("Expected `reserve_type` to reserve an interface ID")
+                };
+                let fields = self.build_extend_fields(
+                    fields,
+                    &mut HashMap::with_capacity(len_of_option_list(fields)),
+                    location_key,
+                    Some(Type::Interface(id)),
+                )?;
+                let interfaces = interfaces
+                    .iter()
+                    .map(|name| self.build_interface_id(name, &location_key))
+                    .collect::<DiagnosticsResult<Vec<_>>>()?;
+                for parent_id in &interfaces {
+                    let mut parent = self.interface(*parent_id).clone();
+                    extend_without_duplicates(&mut parent.implementing_interfaces, vec![id]);
+                    self.set_interface(*parent_id, parent);
+                }
+                let directives = self.build_directive_values(directives);
+                // Keep the implementations linked by definitions that were
+                // built before this one.
+                let mut interface = self.interface(id).clone();
+                interface.fields = fields;
+                interface.interfaces = interfaces;
+                interface.directives = directives;
+                interface.description = description.as_ref().map(|node| node.value);
+                self.set_interface(id, interface);
+            }
+            TypeSystemDefinition::UnionTypeDefinition(UnionTypeDefinition {
+                name,
+                members,
+                directives,
+                description,
+                ..
+            }) => {
+                let Some(Type::Union(id)) = self.overlay_type_map.get(&name.value).copied() else {
+                    unreachable!("Expected `reserve_type` to reserve a union ID")
+                };
+                let members = members
+                    .iter()
+                    .map(|name| self.build_object_id(name, &location_key))
+                    .collect::<DiagnosticsResult<Vec<_>>>()?;
+                let directives = self.build_directive_values(directives);
+                let union = Union {
+                    name: WithLocation::new(
+                        Location::new(location_key, name.span),
+                        UnionName(name.value),
+                    ),
+                    is_extension: true,
+                    members,
+                    directives,
+                    description: description.as_ref().map(|node| node.value),
+                    hack_source: None,
+                };
+                self.set_union(id, union);
+            }
+            TypeSystemDefinition::InputObjectTypeDefinition(InputObjectTypeDefinition {
+                name,
+                fields,
+                directives,
+                description,
+                ..
+            }) => {
+                let Some(Type::InputObject(id)) = self.overlay_type_map.get(&name.value).copied()
+                else {
+                    unreachable!("Expected `reserve_type` to reserve an input object ID")
+                };
+                let fields = self.build_arguments(fields, location_key)?;
+                let directives = self.build_directive_values(directives);
+                let input_object = InputObject {
+                    name: WithLocation::new(
+                        Location::new(location_key, name.span),
+                        InputObjectName(name.value),
+                    ),
+                    is_extension: true,
+                    fields,
+                    directives,
+                    description: description.as_ref().map(|node| node.value),
+                    hack_source: None,
+                };
+                self.set_input_object(id, input_object);
+            }
+            TypeSystemDefinition::EnumTypeDefinition(EnumTypeDefinition {
+                name,
+                values,
+                directives,
+                description,
+                ..
+            }) => {
+                let Some(Type::Enum(id)) = self.overlay_type_map.get(&name.value).copied() else {
+                    unreachable!("Expected `reserve_type` to reserve an enum ID")
+                };
+                let values = self.build_enum_values(values, location_key);
+                let directives = self.build_directive_values(directives);
+                let enum_ = Enum {
+                    name: WithLocation::new(
+                        Location::new(location_key, name.span),
+                        EnumName(name.value),
+                    ),
+                    is_extension: true,
+                    values,
+                    directives,
+                    description: description.as_ref().map(|node| node.value),
+                    hack_source: None,
+                };
+                self.set_enum(id, enum_);
+            }
+            TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
+                name,
+                directives,
+                description,
+                ..
+            }) => {
+                let Some(Type::Scalar(id)) = self.overlay_type_map.get(&name.value).copied() else {
+                    unreachable!("Expected `reserve_type` to reserve a scalar ID")
+                };
+                let directives = self.build_directive_values(directives);
+                let scalar = Scalar {
+                    name: WithLocation::new(
+                        Location::new(location_key, name.span),
+                        ScalarName(name.value),
+                    ),
+                    is_extension: true,
+                    directives,
+                    description: description.as_ref().map(|node| node.value),
+                    hack_source: None,
+                };
+                self.set_scalar(id, scalar);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn add_extension(
+        &mut self,
+        definition: &TypeSystemDefinition,
+        location_key: SourceLocationKey,
+    ) -> DiagnosticsResult<()> {
+        match definition {
+            TypeSystemDefinition::ObjectTypeExtension(extension) => {
+                self.add_object_type_extension(extension.clone(), location_key)?;
+            }
+            TypeSystemDefinition::InterfaceTypeExtension(extension) => {
+                self.add_interface_type_extension(extension.clone(), location_key)?;
+            }
+            TypeSystemDefinition::DirectiveDefinitionExtension(DirectiveDefinitionExtension {
+                name,
+                directives,
+                ..
+            }) => {
+                let directive_values = self.build_directive_values(directives);
+                match self.get_directive_mut(DirectiveName(name.value)) {
+                    Some(directive) => directive.directives.extend(directive_values),
+                    None => {
+                        return Err(vec![Diagnostic::error(
+                            SchemaError::ExtendUndefinedDirective(name.value),
+                            Location::new(location_key, name.span),
+                        )]);
+                    }
+                }
+            }
+            TypeSystemDefinition::UnionTypeExtension(UnionTypeExtension {
+                name,
+                members,
+                directives,
+                ..
+            }) => {
+                let Some(Type::Union(id)) = self.get_type(name.value) else {
+                    return Err(extend_undefined_type(name, location_key));
+                };
+                let members = members
+                    .iter()
+                    .map(|name| self.build_object_id(name, &location_key))
+                    .collect::<DiagnosticsResult<Vec<_>>>()?;
+                let mut union = self.union(id).clone();
+                extend_without_duplicates(&mut union.members, members);
+                union
+                    .directives
+                    .extend(self.build_directive_values(directives));
+                self.set_union(id, union);
+            }
+            TypeSystemDefinition::InputObjectTypeExtension(InputObjectTypeExtension {
+                name,
+                fields,
+                directives,
+                ..
+            }) => {
+                let Some(Type::InputObject(id)) = self.get_type(name.value) else {
+                    return Err(extend_undefined_type(name, location_key));
+                };
+                let mut input_object = self.input_object(id).clone();
+                let mut existing_fields: HashMap<StringKey, Location> = input_object
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.item.0, field.name.location))
+                    .collect();
+                let client_fields = self.build_arguments(fields, location_key)?;
+                for field in client_fields.iter() {
+                    let field_name = field.name.item.0;
+                    if let Some(prev_location) =
+                        existing_fields.insert(field_name, field.name.location)
+                    {
+                        return Err(vec![
+                            Diagnostic::error(
+                                SchemaError::DuplicateField(field_name),
+                                field.name.location,
+                            )
+                            .annotate("previously defined here", prev_location),
+                        ]);
+                    }
+                }
+                input_object.fields.0.extend(client_fields.0);
+                input_object
+                    .directives
+                    .extend(self.build_directive_values(directives));
+                self.set_input_object(id, input_object);
+            }
+            TypeSystemDefinition::EnumTypeExtension(EnumTypeExtension {
+                name,
+                values,
+                directives,
+                ..
+            }) => {
+                let Some(Type::Enum(id)) = self.get_type(name.value) else {
+                    return Err(extend_undefined_type(name, location_key));
+                };
+                let mut enum_ = self.enum_(id).clone();
+                extend_without_duplicates(
+                    &mut enum_.values,
+                    self.build_enum_values(values, location_key),
+                );
+                enum_
+                    .directives
+                    .extend(self.build_directive_values(directives));
+                self.set_enum(id, enum_);
+            }
+            TypeSystemDefinition::ScalarTypeExtension(ScalarTypeExtension {
+                name,
+                directives,
+                ..
+            }) => {
+                let Some(Type::Scalar(id)) = self.get_type(name.value) else {
+                    return Err(extend_undefined_type(name, location_key));
+                };
+                let mut scalar = self.scalar(id).clone();
+                scalar
+                    .directives
+                    .extend(self.build_directive_values(directives));
+                self.set_scalar(id, scalar);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn add_operation_types(
+        &mut self,
+        operation_types: &[OperationTypeDefinition],
+        location_key: SourceLocationKey,
+    ) -> DiagnosticsResult<()> {
+        for OperationTypeDefinition {
+            operation, type_, ..
+        } in operation_types
+        {
+            let operation_id = Type::Object(self.build_object_id(type_, &location_key)?);
+            let previous = match operation {
+                OperationType::Query => self.query_type(),
+                OperationType::Mutation => self.mutation_type(),
+                OperationType::Subscription => self.subscription_type(),
+            };
+            if let Some(previous) = previous {
+                return Err(vec![Diagnostic::error(
+                    SchemaError::DuplicateOperationDefinition(
+                        operation.to_string(),
+                        type_.value,
+                        self.get_type_name(previous),
+                    ),
+                    Location::new(location_key, type_.span),
+                )]);
+            }
+            match operation {
+                OperationType::Query => {
+                    unreachable!("The flatbuffer schema always defines a query type")
+                }
+                OperationType::Mutation => self.overlay_mutation_type = Some(operation_id),
+                OperationType::Subscription => self.overlay_subscription_type = Some(operation_id),
+            }
+        }
+        Ok(())
+    }
+
+    fn build_object_id(
+        &self,
+        name: &Identifier,
+        location_key: &SourceLocationKey,
+    ) -> DiagnosticsResult<ObjectID> {
+        match self.get_type(name.value) {
+            Some(Type::Object(id)) => Ok(id),
+            Some(non_object_type) => Err(vec![Diagnostic::error(
+                SchemaError::ExpectedObjectReference(
+                    name.value,
+                    non_object_type.get_variant_name().to_string(),
+                ),
+                Location::new(*location_key, name.span),
+            )]),
+            None => Err(vec![Diagnostic::error(
+                SchemaError::UndefinedType(name.value),
+                Location::new(*location_key, name.span),
+            )]),
+        }
+    }
+
+    fn build_enum_values(
+        &self,
+        values: &Option<List<EnumValueDefinition>>,
+        location_key: SourceLocationKey,
+    ) -> Vec<EnumValue> {
+        values.as_ref().map_or_else(Vec::new, |values| {
+            values
+                .items
+                .iter()
+                .map(|value| EnumValue {
+                    value: value.name.value,
+                    location: Location::new(location_key, value.name.span),
+                    directives: self.build_directive_values(&value.directives),
+                    description: value.description.as_ref().map(|node| node.value),
+                })
+                .collect()
+        })
+    }
+
+    fn add_implementing_object(&mut self, interface_id: InterfaceID, object_id: ObjectID) {
+        let mut interface = self.interface(interface_id).clone();
+        if !interface.implementing_objects.contains(&object_id) {
+            interface.implementing_objects.push(object_id);
+            self.set_interface(interface_id, interface);
+        }
+    }
+
+    // --- Updates of flatbuffer types (in the cache) or overlay types ---
+
+    fn set_object(&mut self, id: ObjectID, object: Object) {
+        if id.0 < self.fb_object_count {
+            self.objects.set(id, object);
+        } else {
+            self.overlay_objects[(id.0 - self.fb_object_count) as usize] = object;
+        }
+    }
+
+    fn set_interface(&mut self, id: InterfaceID, interface: Interface) {
         if id.0 < self.fb_interface_count {
-            self.interfaces.set(id, iface);
+            self.interfaces.set(id, interface);
         } else {
-            let idx = (id.0 - self.fb_interface_count) as usize;
-            self.overlay_interfaces[idx] = iface;
+            self.overlay_interfaces[(id.0 - self.fb_interface_count) as usize] = interface;
         }
+    }
 
-        Ok(())
+    fn set_union(&mut self, id: UnionID, union: Union) {
+        if id.0 < self.fb_union_count {
+            self.unions.set(id, union);
+        } else {
+            self.overlay_unions[(id.0 - self.fb_union_count) as usize] = union;
+        }
     }
+
+    fn set_input_object(&mut self, id: InputObjectID, input_object: InputObject) {
+        if id.0 < self.fb_input_object_count {
+            self.input_objects.set(id, input_object);
+        } else {
+            self.overlay_input_objects[(id.0 - self.fb_input_object_count) as usize] = input_object;
+        }
+    }
+
+    fn set_enum(&mut self, id: EnumID, enum_: Enum) {
+        if id.0 < self.fb_enum_count {
+            self.enums.set(id, enum_);
+        } else {
+            self.overlay_enums[(id.0 - self.fb_enum_count) as usize] = enum_;
+        }
+    }
+
+    fn set_scalar(&mut self, id: ScalarID, scalar: Scalar) {
+        if id.0 < self.fb_scalar_count {
+            self.scalars.set(id, scalar);
+        } else {
+            self.overlay_scalars[(id.0 - self.fb_scalar_count) as usize] = scalar;
+        }
+    }
+}
+
+fn extend_undefined_type(name: &Identifier, location_key: SourceLocationKey) -> Vec<Diagnostic> {
+    vec![Diagnostic::error(
+        SchemaError::ExtendUndefinedType(name.value),
+        Location::new(location_key, name.span),
+    )]
 }
 
 impl Schema for SchemaWrapper {
@@ -794,11 +1519,13 @@ impl Schema for SchemaWrapper {
     }
 
     fn mutation_type(&self) -> Option<Type> {
-        self.flatbuffer_schema().mutation_type()
+        self.overlay_mutation_type
+            .or_else(|| self.flatbuffer_schema().mutation_type())
     }
 
     fn subscription_type(&self) -> Option<Type> {
-        self.flatbuffer_schema().subscription_type()
+        self.overlay_subscription_type
+            .or_else(|| self.flatbuffer_schema().subscription_type())
     }
 
     fn clientid_field(&self) -> FieldID {
@@ -829,17 +1556,32 @@ impl Schema for SchemaWrapper {
     }
 
     fn get_directive(&self, name: DirectiveName) -> Option<&Directive> {
+        if let Some(directive) = self.overlay_directives.get(&name) {
+            return Some(directive);
+        }
         self.directives
             .get(name, || self.flatbuffer_schema().get_directive(name))
             .as_ref()
     }
 
     fn input_object(&self, id: InputObjectID) -> &InputObject {
+        if id.0 >= self.fb_input_object_count {
+            let overlay_idx = (id.0 - self.fb_input_object_count) as usize;
+            if overlay_idx < self.overlay_input_objects.len() {
+                return &self.overlay_input_objects[overlay_idx];
+            }
+        }
         self.input_objects
             .get(id, || self.flatbuffer_schema().input_object(id))
     }
 
     fn enum_(&self, id: EnumID) -> &Enum {
+        if id.0 >= self.fb_enum_count {
+            let overlay_idx = (id.0 - self.fb_enum_count) as usize;
+            if overlay_idx < self.overlay_enums.len() {
+                return &self.overlay_enums[overlay_idx];
+            }
+        }
         self.enums.get(id, || self.flatbuffer_schema().enum_(id))
     }
 
@@ -874,6 +1616,12 @@ impl Schema for SchemaWrapper {
     }
 
     fn union(&self, id: UnionID) -> &Union {
+        if id.0 >= self.fb_union_count {
+            let overlay_idx = (id.0 - self.fb_union_count) as usize;
+            if overlay_idx < self.overlay_unions.len() {
+                return &self.overlay_unions[overlay_idx];
+            }
+        }
         self.unions.get(id, || self.flatbuffer_schema().union(id))
     }
 
@@ -1021,7 +1769,13 @@ impl Schema for SchemaWrapper {
             }
         }
 
-        Box::new(self.input_objects.map.iter().map(|ref_| *ref_.value()))
+        Box::new(
+            self.input_objects
+                .map
+                .iter()
+                .map(|ref_| *ref_.value())
+                .chain(self.overlay_input_objects.iter()),
+        )
     }
 
     fn enums<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Enum> + 'a> {
@@ -1032,7 +1786,13 @@ impl Schema for SchemaWrapper {
             }
         }
 
-        Box::new(self.enums.map.iter().map(|ref_| *ref_.value()))
+        Box::new(
+            self.enums
+                .map
+                .iter()
+                .map(|ref_| *ref_.value())
+                .chain(self.overlay_enums.iter()),
+        )
     }
 
     fn scalars<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Scalar> + 'a> {
@@ -1094,7 +1854,13 @@ impl Schema for SchemaWrapper {
             }
         }
 
-        Box::new(self.unions.map.iter().map(|ref_| *ref_.value()))
+        Box::new(
+            self.unions
+                .map
+                .iter()
+                .map(|ref_| *ref_.value())
+                .chain(self.overlay_unions.iter()),
+        )
     }
 
     fn interfaces<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Interface> + 'a> {
@@ -1126,6 +1892,12 @@ impl<K: Hash + Eq, V> Cache<K, V> {
     }
 
     fn get(&self, key: K, f: impl FnOnce() -> V) -> &V {
+        // Look up hits with a read lock first: `entry` takes the shard's
+        // write lock, which would deadlock while the caller iterates the
+        // same map, e.g. looking up an object while iterating `objects()`.
+        if let Some(value) = self.map.get(&key) {
+            return *value;
+        }
         *self
             .map
             .entry(key)
@@ -1335,4 +2107,120 @@ mod tests {
 
         Ok(())
     }
+
+    /// Verifies that client extensions layered over a flatbuffer schema add
+    /// new types and extend existing ones without materializing the schema.
+    #[test]
+    fn extend_with_documents() -> DiagnosticsResult<()> {
+        let sdl = "
+        type Query { me: User }
+        type User { id: ID }
+        enum Role { ADMIN }
+        ";
+        let sdl_schema = build_schema(sdl)?;
+        let bytes = serialize_as_flatbuffer(&sdl_schema);
+        let mut fb_schema = SchemaWrapper::from_vec(bytes);
+
+        let extensions = graphql_syntax::parse_schema_document(
+            "
+            directive @client_only on FIELD
+            enum Color { RED GREEN }
+            type Pet { name: String color: Color }
+            union Owned = User | Pet
+            extend type User { pet: Pet }
+            extend enum Role { GUEST }
+            ",
+            SourceLocationKey::Generated,
+        )?;
+        fb_schema.extend_with_documents(&[extensions])?;
+
+        let color = fb_schema.get_type("Color".intern()).unwrap();
+        let color = fb_schema.enum_(color.get_enum_id().unwrap());
+        assert_eq!(color.values.len(), 2);
+
+        let owned = fb_schema.get_type("Owned".intern()).unwrap();
+        let owned = fb_schema.union(owned.get_union_id().unwrap());
+        assert_eq!(owned.members.len(), 2);
+
+        let user = fb_schema.get_type("User".intern()).unwrap();
+        let user_id = user.get_object_id().unwrap();
+        assert!(
+            fb_schema
+                .named_field(user, "pet".intern())
+                .map(|field_id| fb_schema.field(field_id).is_extension)
+                .unwrap_or(false)
+        );
+        assert!(fb_schema.object(user_id).fields.len() == 2);
+
+        let role = fb_schema.get_type("Role".intern()).unwrap();
+        let role = fb_schema.enum_(role.get_enum_id().unwrap());
+        assert_eq!(role.values.len(), 2);
+
+        assert!(
+            fb_schema
+                .get_directive(DirectiveName("client_only".intern()))
+                .is_some()
+        );
+        assert!(
+            fb_schema
+                .enums()
+                .any(|enum_| enum_.name.item.0.lookup() == "Color")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn extend_with_documents_duplicate_type() -> DiagnosticsResult<()> {
+        let sdl = "type Query { id: ID }";
+        let sdl_schema = build_schema(sdl)?;
+        let bytes = serialize_as_flatbuffer(&sdl_schema);
+        let mut fb_schema = SchemaWrapper::from_vec(bytes);
+
+        let extensions = graphql_syntax::parse_schema_document(
+            "type Query { other: ID }",
+            SourceLocationKey::Generated,
+        )?;
+        assert!(fb_schema.extend_with_documents(&[extensions]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_file_outlives_rewrites() -> DiagnosticsResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("schema.fb");
+        let bytes = serialize_as_flatbuffer(&build_schema("type Query { id: ID }")?);
+        fs::write(&path, bytes).unwrap();
+        let fb_schema = SchemaWrapper::read_file(&path).unwrap();
+
+        // Truncating a memory-mapped file would fault on the next read.
+        fs::write(&path, []).unwrap();
+        assert!(fb_schema.get_type("Query".intern()).is_some());
+        assert!(SchemaWrapper::read_file(&path).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn lookup_while_iterating() -> DiagnosticsResult<()> {
+        let sdl = "
+        type Query { me: User }
+        type User { id: ID }
+        ";
+        let sdl_schema = build_schema(sdl)?;
+        let bytes = serialize_as_flatbuffer(&sdl_schema);
+        let fb_schema = SchemaWrapper::from_vec(bytes);
+
+        let mut parent_names = fb_schema
+            .objects()
+            .flat_map(|object| &object.fields)
+            .filter_map(|field_id| fb_schema.field(*field_id).parent_type)
+            .map(|parent_type| fb_schema.get_type_name(parent_type).lookup())
+            .collect::<Vec<&str>>();
+        parent_names.sort();
+        assert_eq!(parent_names, vec!["Query", "User"]);
+
+        Ok(())
+    }
 }
@@ -1466,6 +1466,7 @@ impl InMemorySchema {
                 interfaces,
                 fields,
                 directives,
+                description,
                 ..
             }) => {
                 let parent_id = Type::Object(ObjectID(self.objects.len() as u32));
@@ -1493,7 +1494,7 @@ impl InMemorySchema {
                     is_extension,
                     interfaces,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1502,6 +1503,7 @@ impl InMemorySchema {
                 interfaces,
                 directives,
                 fields,
+                description,
                 ..
             }) => {
                 let parent_id = Type::Interface(InterfaceID(self.interfaces.len() as u32));
@@ -1531,7 +1533,7 @@ impl InMemorySchema {
                     fields,
                     directives,
                     interfaces,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1539,6 +1541,7 @@ impl InMemorySchema {
                 name,
                 directives,
                 members,
+                description,
                 ..
             }) => {
                 let members = members
@@ -1554,7 +1557,7 @@ impl InMemorySchema {
                     is_extension,
                     members,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1562,6 +1565,7 @@ impl InMemorySchema {
                 name,
                 fields,
                 directives,
+                description,
                 ..
             }) => {
                 let fields = self.build_arguments(fields, *location_key)?;
@@ -1571,10 +1575,10 @@ impl InMemorySchema {
                         Location::new(*location_key, name.span),
                         InputObjectName(name.value),
                     ),
-
+                    is_extension,
                     fields,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
@@ -1582,6 +1586,7 @@ impl InMemorySchema {
                 name,
                 directives,
                 values,
+                description,
                 ..
             }) => {
                 let directives = self.build_directive_values(directives);
@@ -1591,6 +1596,7 @@ impl InMemorySchema {
                         .iter()
                         .map(|enum_def| EnumValue {
                             value: enum_def.name.value,
+                            location: Location::new(*location_key, enum_def.name.span),
                             directives: self.build_directive_values(&enum_def.directives),
                             description: enum_def.description.as_ref().map(|d| d.value),
                         })
@@ -1606,13 +1612,14 @@ impl InMemorySchema {
                     is_extension,
                     values,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 });
             }
             TypeSystemDefinition::ScalarTypeDefinition(ScalarTypeDefinition {
                 name,
                 directives,
+                description,
                 ..
             }) => {
                 let directives = self.build_directive_values(directives);
@@ -1623,7 +1630,7 @@ impl InMemorySchema {
                     ),
                     is_extension,
                     directives,
-                    description: None,
+                    description: description.as_ref().map(|node| node.value),
                     hack_source: None,
                 })
             }
@@ -1758,6 +1765,7 @@ impl InMemorySchema {
                                 .iter()
                                 .map(|enum_def| EnumValue {
                                     value: enum_def.name.value,
+                                    location: Location::new(*location_key, enum_def.name.span),
                                     directives: self.build_directive_values(&enum_def.directives),
                                     description: enum_def.description.as_ref().map(|d| d.value),
                                 })
@@ -2100,7 +2108,7 @@ impl InMemorySchema {
                             .default_value
                             .as_ref()
                             .map(|default_value| default_value.value.clone()),
-                        description: None,
+                        description: arg_def.description.as_ref().map(|node| node.value),
                         directives: self.build_directive_values(&arg_def.directives),
                     })
                 })
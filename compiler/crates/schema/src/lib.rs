@@ -21,8 +21,11 @@ mod in_memory;
 mod schema;
 pub mod suggestion_list;
 use std::borrow::Cow;
+use std::path::Path;
 
+use common::Diagnostic;
 use common::DiagnosticsResult;
+use common::Location;
 use common::SourceLocationKey;
 use common::sync::IntoParallelIterator;
 use common::sync::ParallelIterator;
@@ -166,6 +169,29 @@ pub fn build_schema_with_flat_buffer_unchecked(bytes: Vec<u8>) -> SDLSchema {
     SDLSchema::FlatBuffer(Box::new(SchemaWrapper::from_vec_unchecked(bytes)))
 }
 
+/// Loads the flatbuffer schema at `path` and layers the client schema
+/// `extensions` over it, without materializing the flatbuffer schema. The
+/// file is memory-mapped with `memory_map`, see `SchemaWrapper::map_file`.
+pub fn build_schema_with_flat_buffer_file(
+    path: &Path,
+    extensions: &[SchemaDocument],
+    memory_map: bool,
+) -> DiagnosticsResult<SDLSchema> {
+    let schema = if memory_map {
+        SchemaWrapper::map_file(path)
+    } else {
+        SchemaWrapper::read_file(path)
+    };
+    let mut schema = schema.map_err(|error| {
+        vec![Diagnostic::error(
+            SchemaError::FlatBufferRead(path.display().to_string(), error.to_string()),
+            Location::generated(),
+        )]
+    })?;
+    schema.extend_with_documents(extensions)?;
+    Ok(SDLSchema::FlatBuffer(Box::new(schema)))
+}
+
 pub fn build_schema_from_flat_buffer(bytes: &[u8]) -> DiagnosticsResult<FlatBufferSchema<'_>> {
     Ok(FlatBufferSchema::build(bytes))
 }
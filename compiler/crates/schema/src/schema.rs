@@ -379,7 +379,7 @@ impl SDLSchema {
 
     pub fn get_directive_mut(&mut self, name: DirectiveName) -> Option<&mut Directive> {
         match self {
-            SDLSchema::FlatBuffer(_schema) => todo!(),
+            SDLSchema::FlatBuffer(schema) => schema.get_directive_mut(name),
             SDLSchema::InMemory(schema) => schema.get_directive_mut(name),
         }
     }
@@ -12,6 +12,10 @@ import {handleShowOutputCommand} from './showOutput';
 import {handleStartCompilerCommand} from './startCompiler';
 import {handleStopCompilerCommand} from './stopCompiler';
 import {handleCopyOperation} from './copyOperation';
+import {
+  handleCopySchemaCoordinate,
+  handleOpenSchemaCoordinate,
+} from './schemaCoordinate';
 
 export function registerCommands(context: RelayExtensionContext) {
   context.extensionContext.subscriptions.push(
@@ -35,5 +39,13 @@ export function registerCommands(context: RelayExtensionContext) {
       'relay.copyOperation',
       handleCopyOperation.bind(null, context),
     ),
+    commands.registerCommand(
+      'relay.copySchemaCoordinate',
+      handleCopySchemaCoordinate.bind(null, context),
+    ),
+    commands.registerCommand(
+      'relay.openSchemaCoordinate',
+      handleOpenSchemaCoordinate.bind(null, context),
+    ),
   );
 }
@@ -0,0 +1,124 @@
+/**
+ * Copyright (c) Meta Platforms, Inc. and affiliates.
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+import {window, env, workspace, Uri, Range, Position} from 'vscode';
+import {
+  Location,
+  RequestType,
+  TextDocumentIdentifier,
+  TextDocumentPositionParams,
+} from 'vscode-languageclient';
+import {RelayExtensionContext} from '../context';
+
+export function handleCopySchemaCoordinate(
+  context: RelayExtensionContext,
+): void {
+  if (!context.client || !context.client.isRunning()) {
+    return;
+  }
+
+  const activeEditor = window.activeTextEditor;
+
+  if (!activeEditor) {
+    return;
+  }
+
+  const request = new RequestType<
+    TextDocumentPositionParams,
+    GetSchemaCoordinateResponse | null,
+    void
+  >('relay/getSchemaCoordinate');
+
+  const params: TextDocumentPositionParams = {
+    textDocument: {uri: activeEditor.document.uri.toString()},
+    position: activeEditor.selection.active,
+  };
+
+  context.client.sendRequest(request, params).then(response => {
+    if (response == null) {
+      window.showWarningMessage('No schema element found under the cursor');
+      return;
+    }
+
+    env.clipboard.writeText(response.coordinate).then(() => {
+      window.showInformationMessage(
+        `Copied schema coordinate "${response.coordinate}" to clipboard`,
+      );
+    });
+  });
+}
+
+export async function handleOpenSchemaCoordinate(
+  context: RelayExtensionContext,
+): Promise<void> {
+  if (!context.client || !context.client.isRunning()) {
+    return;
+  }
+
+  const activeEditor = window.activeTextEditor;
+
+  if (!activeEditor) {
+    window.showWarningMessage(
+      'Open a file of the Relay project to resolve the schema coordinate in',
+    );
+    return;
+  }
+
+  const coordinate = await window.showInputBox({
+    prompt: 'Schema coordinate to open',
+    placeHolder: 'Type.field(argument:)',
+  });
+
+  if (!coordinate) {
+    return;
+  }
+
+  const request = new RequestType<
+    OpenSchemaCoordinateParams,
+    Location | null,
+    void
+  >('relay/openSchemaCoordinate');
+
+  const params: OpenSchemaCoordinateParams = {
+    textDocument: {uri: activeEditor.document.uri.toString()},
+    coordinate: coordinate.trim(),
+  };
+
+  try {
+    const location = await context.client.sendRequest(request, params);
+
+    if (location == null) {
+      window.showWarningMessage(
+        `Could not find the definition of "${params.coordinate}"`,
+      );
+      return;
+    }
+
+    const document = await workspace.openTextDocument(Uri.parse(location.uri));
+    const {start, end} = location.range;
+
+    await window.showTextDocument(document, {
+      selection: new Range(
+        new Position(start.line, start.character),
+        new Position(end.line, end.character),
+      ),
+    });
+  } catch (error) {
+    window.showErrorMessage(
+      error instanceof Error ? error.message : String(error),
+    );
+  }
+}
+
+type GetSchemaCoordinateResponse = {
+  coordinate: string;
+};
+
+type OpenSchemaCoordinateParams = {
+  textDocument: TextDocumentIdentifier;
+  coordinate: string;
+};